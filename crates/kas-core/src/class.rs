@@ -89,4 +89,12 @@ pub trait SetAccel {
 
     /// Set accel string
     fn set_accel_string(&mut self, accel: AccelString) -> TkAction;
+
+    /// Set the accelerator key (mnemonic) only, keeping the displayed text
+    ///
+    /// See [`AccelString::set_mnemonic`]. This is useful e.g. to adapt
+    /// mnemonics to a localized keyboard layout. The result includes
+    /// [`TkAction::RECONFIGURE`] if the key bindings changed; it is
+    /// [`TkAction::empty`] if nothing changed.
+    fn set_mnemonic(&mut self, c: Option<char>) -> TkAction;
 }
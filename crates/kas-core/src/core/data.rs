@@ -10,7 +10,7 @@ use super::Layout;
 use super::{Widget, WidgetId};
 use crate::dir::Direction;
 use crate::event::EventMgr;
-use crate::geom::Rect;
+use crate::geom::{Rect, Size};
 
 #[cfg(feature = "winit")]
 pub use winit::window::Icon;
@@ -44,14 +44,72 @@ pub struct CoreData {
     pub id: WidgetId,
 }
 
-/// Note: the clone has default-initialised identifier.
+/// Note: the clone is default-initialised (both identifier and rect).
 /// Configuration and layout solving is required as for any other widget.
 impl Clone for CoreData {
     fn clone(&self) -> Self {
-        CoreData {
-            rect: self.rect,
-            id: WidgetId::default(),
-        }
+        CoreData::default()
+    }
+}
+
+/// The semantic role of a widget, for accessibility tools
+///
+/// This is reported by [`Widget::accessible_role`]. It describes what a
+/// widget *is* to a user (e.g. to a screen reader), independent of how the
+/// widget is implemented. A composite widget (e.g. a button containing a
+/// label) should report a single role describing the whole; assistive tools
+/// may treat the descendants of any widget with a role other than
+/// [`AccessibleRole::Group`] or [`AccessibleRole::Unknown`] as presentational.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AccessibleRole {
+    /// No role is known
+    Unknown,
+    /// A container grouping child widgets, with no semantics of its own
+    Group,
+    /// A window
+    Window,
+    /// Static text
+    Label,
+    /// A push-button
+    Button,
+    /// A two-state check box or toggle
+    CheckBox,
+    /// A radio button (one of a group of exclusive options)
+    RadioButton,
+    /// An editable text field
+    TextInput,
+    /// A slider, selecting a value from a range
+    Slider,
+    /// A spin box, selecting a value via increment and decrement controls
+    SpinButton,
+    /// A scroll bar
+    ScrollBar,
+    /// A scrollable region
+    ScrollRegion,
+    /// A progress indicator
+    ProgressBar,
+    /// A combination of a button and drop-down menu
+    ComboBox,
+    /// A list of items
+    List,
+    /// A table or grid of items
+    Grid,
+    /// A menu or menu bar
+    Menu,
+    /// An entry within a menu
+    MenuItem,
+    /// A row of tabs
+    TabList,
+    /// A visual separator
+    Separator,
+    /// An image
+    Image,
+}
+
+impl Default for AccessibleRole {
+    fn default() -> Self {
+        AccessibleRole::Unknown
     }
 }
 
@@ -62,6 +120,13 @@ impl Clone for CoreData {
 /// *next to* it's `parent`'s `rect`, in the specified `direction` (or, if not
 /// possible, in the opposite direction).
 ///
+/// If there is insufficient space on either side, the pop-up is placed on the
+/// side with more space and its size is clamped to fit the window; the pop-up
+/// widget should then scroll its content (e.g. via a `ScrollRegion`) as
+/// required. Perpendicular to `direction`, the pop-up is shifted to remain
+/// within the window. When the window is resized, the pop-up stays on the
+/// side it was last placed on as long as it still fits there.
+///
 /// A pop-up is in some ways an ordinary child widget and in some ways not.
 /// The pop-up widget should be a permanent child of its parent, but is not
 /// visible until [`EventMgr::add_popup`] is called.
@@ -69,6 +134,16 @@ impl Clone for CoreData {
 /// A pop-up widget's rect is not contained by its parent, therefore the parent
 /// must not call any [`Layout`] methods on the pop-up (whether or not it is
 /// visible). The window is responsible for calling these methods.
+///
+/// A `modal` pop-up (e.g. a confirmation dialog) blocks interaction with
+/// everything outside of itself until closed: clicks outside the pop-up are
+/// swallowed (the pop-up is not closed), keyboard navigation is trapped within
+/// the pop-up and accelerator keys outside of it are ignored. <kbd>Escape</kbd>
+/// closes the pop-up (if not used by the focussed widget), restoring the
+/// previous navigation focus. A modal pop-up is not closed when the window
+/// loses focus. Pop-ups opened from within a modal pop-up behave as usual.
+///
+/// This struct is non-exhaustive: construct with [`Popup::new`].
 //
 // NOTE: it's tempting to include a pointer to the widget here. There are two
 // options: (a) an unsafe aliased pointer or (b) Rc<RefCell<dyn Widget>>.
@@ -76,10 +151,33 @@ impl Clone for CoreData {
 // theory work but requires adjusting WidgetChildren::get, find etc. to take a
 // closure instead of returning a reference, causing *significant* complication.
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub struct Popup {
     pub id: WidgetId,
     pub parent: WidgetId,
     pub direction: Direction,
+    pub modal: bool,
+}
+
+impl Popup {
+    /// Construct a (non-modal) pop-up description
+    #[inline]
+    pub fn new(id: WidgetId, parent: WidgetId, direction: Direction) -> Self {
+        Popup {
+            id,
+            parent,
+            direction,
+            modal: false,
+        }
+    }
+
+    /// Set whether the pop-up is modal (inline)
+    #[inline]
+    #[must_use]
+    pub fn with_modal(mut self, modal: bool) -> Self {
+        self.modal = modal;
+        self
+    }
 }
 
 /// Functionality required by a window
@@ -112,6 +210,20 @@ pub trait Window: Widget {
         (true, false)
     }
 
+    /// Explicit limits on the window size
+    ///
+    /// Return value is `(min, max)`, in logical pixels. These limits are
+    /// applied in addition to [`Self::restrict_dimensions`]: `min` widens the
+    /// calculated minimum size (where the content's ideal size is smaller, the
+    /// window is initially widened to `min`) while `max` replaces any
+    /// calculated maximum. Where `max < min`, `min` takes precedence.
+    ///
+    /// Default: `(None, None)`
+    #[inline]
+    fn size_limits(&self) -> (Option<Size>, Option<Size>) {
+        (None, None)
+    }
+
     /// Handle closure of self
     ///
     /// This allows for actions on destruction.
@@ -121,3 +233,111 @@ pub trait Window: Widget {
         let _ = mgr;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::geom::{Coord, Rect, Size};
+    use crate::layout::{AxisInfo, RowStorage, SizeRules};
+    use crate::theme::{DrawMgr, SizeMgr};
+    use crate::{AccessibleRole, Layout, Widget};
+    use kas_macros::{autoimpl, impl_scope};
+
+    impl_scope! {
+        #[derive(Clone, Debug, Default)]
+        #[widget]
+        struct Leaf {
+            core: widget_core!(),
+        }
+
+        impl Layout for Self {
+            fn size_rules(&mut self, _: SizeMgr, _: AxisInfo) -> SizeRules {
+                SizeRules::fixed(10, (0, 0))
+            }
+
+            fn draw(&mut self, _: DrawMgr) {}
+        }
+    }
+
+    impl_scope! {
+        #[derive(Clone, Debug, Default)]
+        #[widget{
+            layout = row 'row: [self.a, self.b];
+        }]
+        struct Pair {
+            core: widget_core!(),
+            #[widget]
+            a: Leaf,
+            #[widget]
+            b: Leaf,
+        }
+    }
+
+    impl_scope! {
+        #[autoimpl(PartialEq)]
+        #[derive(Debug, Default)]
+        #[widget]
+        struct Counter {
+            core: widget_core!(),
+            count: u32,
+        }
+
+        impl Layout for Self {
+            fn size_rules(&mut self, _: SizeMgr, _: AxisInfo) -> SizeRules {
+                SizeRules::fixed(10, (0, 0))
+            }
+
+            fn draw(&mut self, _: DrawMgr) {}
+        }
+    }
+
+    impl_scope! {
+        #[autoimpl(PartialEq)]
+        #[derive(Debug, Default)]
+        #[widget{
+            layout = row: [self.a, self.b];
+        }]
+        struct CounterPair {
+            core: widget_core!(),
+            #[widget]
+            a: Counter,
+            #[widget]
+            b: Counter,
+        }
+    }
+
+    #[test]
+    fn partial_eq_ignores_core() {
+        let mut x = CounterPair::default();
+        let mut y = CounterPair::default();
+        x.core.rect = Rect::new(Coord(1, 2), Size(20, 10));
+        x.a.core.rect = Rect::new(Coord(1, 2), Size(10, 10));
+        assert_eq!(x, y);
+
+        y.b.count = 1;
+        assert_ne!(x, y);
+        x.b.count = 1;
+        assert_eq!(x, y);
+    }
+
+    #[test]
+    fn clone_resets_layout_storage() {
+        let mut pair = Pair::default();
+        pair.core.rect = Rect::new(Coord(1, 2), Size(20, 10));
+        pair.core.row.widths().copy_from_slice(&[10, 10]);
+        pair.core.row.rules()[0] = SizeRules::fixed(10, (0, 0));
+        pair.a.core.rect = Rect::new(Coord(1, 2), Size(10, 10));
+
+        let mut clone = pair.clone();
+        assert_eq!(clone.core.rect, Rect::ZERO);
+        assert_eq!(clone.core.row.widths(), &[0, 0]);
+        assert_eq!(clone.core.row.rules()[0], SizeRules::default());
+        assert_eq!(clone.a.core.rect, Rect::ZERO);
+    }
+
+    #[test]
+    fn default_accessible_role() {
+        assert_eq!(Leaf::default().accessible_role(), AccessibleRole::Unknown);
+        assert_eq!(Pair::default().accessible_role(), AccessibleRole::Group);
+        assert_eq!(Pair::default().accessible_label(), None);
+    }
+}
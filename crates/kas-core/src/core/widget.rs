@@ -10,9 +10,10 @@ use std::fmt;
 use crate::event::{self, Event, EventMgr, Response, Scroll};
 use crate::geom::{Coord, Offset, Rect};
 use crate::layout::{AlignHints, AxisInfo, SetRectMgr, SizeRules};
+use crate::state::SaveState;
 use crate::theme::{DrawMgr, SizeMgr};
 use crate::util::IdentifyWidget;
-use crate::WidgetId;
+use crate::{AccessibleRole, WidgetId};
 use kas_macros::autoimpl;
 
 #[allow(unused)]
@@ -251,6 +252,23 @@ pub trait Layout {
     /// -   No default implementation, except,
     /// -   For a widget with the `layout` property, call [`AutoLayout::draw`]
     fn draw(&mut self, draw: DrawMgr);
+
+    /// Get the text baseline, if any
+    ///
+    /// This returns the offset from the top of the widget's rect to the
+    /// baseline of the first line of text, assuming top alignment. It must be
+    /// available after [`Self::size_rules`] for the vertical axis (allowing
+    /// rows to reserve space) and after [`Self::set_rect`]. It is used to
+    /// align text across a row when [`AlignHints::baseline`] is set.
+    ///
+    /// Default implementation:
+    ///
+    /// -   For a widget with the `layout` property, call [`AutoLayout::baseline`]
+    /// -   Otherwise, return `None` (no text baseline)
+    #[inline]
+    fn baseline(&mut self) -> Option<i32> {
+        None
+    }
 }
 
 /// The Widget trait
@@ -449,6 +467,74 @@ pub trait Widget: WidgetChildren + Layout {
         event::CursorIcon::Default
     }
 
+    /// Which cursor icon should be used on hover at `coord`?
+    ///
+    /// This allows the icon to vary over sub-regions of the widget, e.g. to
+    /// show resize arrows over the edges of a panel. It is called whenever
+    /// the mouse moves over this (hovered) widget, thus the icon updates as
+    /// the cursor moves, not only on entry. `coord` is in the same coordinate
+    /// space as [`WidgetCore::rect`].
+    ///
+    /// Defaults to [`Self::cursor_icon`].
+    #[inline]
+    fn cursor_icon_at(&self, coord: Coord) -> event::CursorIcon {
+        let _ = coord;
+        self.cursor_icon()
+    }
+
+    /// Get the tooltip text, if any
+    ///
+    /// When the mouse cursor rests on this widget (or a descendant without its
+    /// own tooltip) for [`WindowConfig::tooltip_delay`], the tooltip is shown
+    /// in a pop-up below this widget. The tooltip is hidden when the cursor
+    /// moves or a mouse button is pressed.
+    ///
+    /// Tooltips are not shown for disabled widgets unless
+    /// [`Widget::tooltip_when_disabled`] returns true.
+    ///
+    /// Defaults to `None`.
+    ///
+    /// [`WindowConfig::tooltip_delay`]: crate::event::config::WindowConfig::tooltip_delay
+    #[inline]
+    fn tooltip(&self) -> Option<&str> {
+        None
+    }
+
+    /// Show the tooltip even when disabled?
+    ///
+    /// Defaults to `false`.
+    #[inline]
+    fn tooltip_when_disabled(&self) -> bool {
+        false
+    }
+
+    /// The semantic role of this widget, for accessibility tools
+    ///
+    /// Composite widgets should report a single role describing the whole;
+    /// see [`AccessibleRole`].
+    ///
+    /// Defaults to [`AccessibleRole::Group`] for widgets with children and
+    /// [`AccessibleRole::Unknown`] otherwise.
+    #[inline]
+    fn accessible_role(&self) -> AccessibleRole {
+        if self.num_children() > 0 {
+            AccessibleRole::Group
+        } else {
+            AccessibleRole::Unknown
+        }
+    }
+
+    /// A label describing this widget, for accessibility tools
+    ///
+    /// This should be the text a user would use to identify the widget, e.g.
+    /// a button's label (without accelerator key markup).
+    ///
+    /// Defaults to `None`.
+    #[inline]
+    fn accessible_label(&self) -> Option<String> {
+        None
+    }
+
     /// Get translation of children relative to this widget
     ///
     /// Usually this is zero; only widgets with scrollable or offset content
@@ -566,6 +652,17 @@ pub trait Widget: WidgetChildren + Layout {
     fn handle_scroll(&mut self, mgr: &mut EventMgr, scroll: Scroll) {
         let _ = (mgr, scroll);
     }
+
+    /// Access saveable state
+    ///
+    /// Widgets implementing [`SaveState`] should return `Some(self)`.
+    /// See [`crate::state`].
+    ///
+    /// The default implementation returns `None`.
+    #[inline]
+    fn as_save_state(&mut self) -> Option<&mut dyn SaveState> {
+        None
+    }
 }
 
 /// Extension trait over widgets
@@ -614,6 +711,32 @@ pub trait WidgetExt: Widget {
         !self.eq_id(id) && self.id().is_ancestor_of(id)
     }
 
+    /// Iterate over child widgets
+    ///
+    /// Children are yielded in index order. This is equivalent to calling
+    /// [`WidgetChildren::get_child`] for each index in
+    /// `0..self.num_children()`, skipping any `None` result.
+    #[inline]
+    fn children(&self) -> ChildIter<'_> {
+        ChildIter {
+            widget: self.as_widget(),
+            index: 0,
+        }
+    }
+
+    /// Call `f` on each child widget
+    ///
+    /// This is the mutable counterpart of [`WidgetExt::children`]. (A mutable
+    /// iterator cannot be provided over [`WidgetChildren::get_child_mut`]
+    /// without `unsafe` code.)
+    fn for_children_mut(&mut self, mut f: impl FnMut(&mut dyn Widget)) {
+        for index in 0..self.num_children() {
+            if let Some(child) = self.get_child_mut(index) {
+                f(child);
+            }
+        }
+    }
+
     /// Find the descendant with this `id`, if any
     fn find_widget(&self, id: &WidgetId) -> Option<&dyn Widget> {
         if let Some(index) = self.find_child_index(id) {
@@ -637,5 +760,81 @@ pub trait WidgetExt: Widget {
             None
         }
     }
+
+    /// Find the first widget with this [`WidgetCore::widget_name`], if any
+    ///
+    /// This is intended for debugging and testing. Names are not unique: this
+    /// performs a depth-first search (starting with `self`, then children in
+    /// index order) and returns the first match in traversal order.
+    fn find_widget_by_name(&self, name: &str) -> Option<&dyn Widget> {
+        if self.widget_name() == name {
+            return Some(self.as_widget());
+        }
+        (0..self.num_children()).find_map(|index| {
+            self.get_child(index)
+                .and_then(|child| child.find_widget_by_name(name))
+        })
+    }
+
+    /// Find the first widget with this [`WidgetCore::widget_name`], if any
+    ///
+    /// See [`WidgetExt::find_widget_by_name`].
+    fn find_widget_by_name_mut(&mut self, name: &str) -> Option<&mut dyn Widget> {
+        if self.widget_name() == name {
+            return Some(self.as_widget_mut());
+        }
+        // Find the index first: returning a mutable borrow from within the
+        // loop is not accepted by the borrow checker.
+        let index = (0..self.num_children()).find(|index| {
+            self.get_child(*index)
+                .and_then(|child| child.find_widget_by_name(name))
+                .is_some()
+        })?;
+        self.get_child_mut(index)
+            .and_then(|child| child.find_widget_by_name_mut(name))
+    }
+
+    /// Format the widget tree as an indented list, for debugging
+    ///
+    /// Each line shows a widget's [`WidgetExt::identify`], position and size;
+    /// children are indented below their parent, in index order. This is the
+    /// format used by the `print_heirarchy` option of
+    /// [`SolveCache::apply_rect`](crate::layout::SolveCache::apply_rect).
+    /// Widgets which have not been configured (with an invalid [`WidgetId`])
+    /// are shown as `Name#<unconfigured>`. Rects are zero before the first
+    /// call to [`Layout::set_rect`].
+    fn debug_tree(&self) -> String {
+        let tree = crate::layout::WidgetHeirarchy(self.as_widget(), 0).to_string();
+        tree.trim_start_matches('\n').to_string()
+    }
 }
 impl<W: Widget + ?Sized> WidgetExt for W {}
+
+/// Iterator over the children of a widget
+///
+/// This is returned by [`WidgetExt::children`].
+#[derive(Clone, Debug)]
+pub struct ChildIter<'a> {
+    widget: &'a dyn Widget,
+    index: usize,
+}
+
+impl<'a> Iterator for ChildIter<'a> {
+    type Item = &'a dyn Widget;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.widget.num_children() {
+            let index = self.index;
+            self.index += 1;
+            if let Some(child) = self.widget.get_child(index) {
+                return Some(child);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.widget.num_children().saturating_sub(self.index);
+        (0, Some(len))
+    }
+}
@@ -536,6 +536,50 @@ impl WidgetId {
             Variant::Slice(path) => PathIter::Slice(path.iter().cloned()),
         }
     }
+
+    /// Convert to a path string
+    ///
+    /// The result is the sequence of child keys from the root (window),
+    /// formatted as decimal numbers separated by `.` (e.g. `"0.2.1"`); the
+    /// root itself is represented by the empty string. This representation is
+    /// stable and may be saved (e.g. to persist the last focused widget) then
+    /// restored with [`Self::from_path_string`].
+    ///
+    /// Note that an identifier is only meaningful within a configured widget
+    /// tree of the same structure: a restored identifier may refer to a
+    /// different widget or none at all if the UI has changed. Callers should
+    /// check the result, e.g. via [`crate::WidgetExt::find_widget`].
+    ///
+    /// Panics if `self` is invalid.
+    pub fn to_path_string(&self) -> String {
+        let mut s = String::new();
+        for (i, key) in self.iter_path().enumerate() {
+            if i > 0 {
+                s.push('.');
+            }
+            s.push_str(&key.to_string());
+        }
+        s
+    }
+
+    /// Parse a path string
+    ///
+    /// This is the inverse of [`Self::to_path_string`]. Returns `None` if the
+    /// string is not a valid path.
+    pub fn from_path_string(s: &str) -> Option<Self> {
+        let mut id = WidgetId::ROOT;
+        if s.is_empty() {
+            return Some(id);
+        }
+        for part in s.split('.') {
+            // Note: usize::from_str accepts a leading '+'; we do not
+            if !part.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            id = id.make_child(part.parse().ok()?);
+        }
+        Some(id)
+    }
 }
 
 impl PartialEq for WidgetId {
@@ -678,6 +722,33 @@ mod test {
         assert!(d1 != WidgetId::ROOT);
     }
 
+    #[test]
+    fn test_path_string() {
+        fn test(seq: &[usize], s: &str) {
+            let mut id = WidgetId::ROOT;
+            for key in seq {
+                id = id.make_child(*key);
+            }
+            assert_eq!(id.to_path_string(), s);
+            let id2 = WidgetId::from_path_string(s).unwrap();
+            assert_eq!(id2, id);
+            assert_eq!(id2.iter_path().collect::<Vec<_>>(), seq);
+        }
+
+        test(&[], "");
+        test(&[0], "0");
+        test(&[3, 0, 15], "3.0.15");
+        test(&[1, 139, 0, 0, 7], "1.139.0.0.7");
+        test(&[1 << 20, 2], "1048576.2");
+        test(&[usize::MAX], &usize::MAX.to_string());
+
+        assert_eq!(WidgetId::from_path_string("1..2"), None);
+        assert_eq!(WidgetId::from_path_string("1."), None);
+        assert_eq!(WidgetId::from_path_string("+1"), None);
+        assert_eq!(WidgetId::from_path_string("a.1"), None);
+        assert_eq!(WidgetId::from_path_string("#1"), None);
+    }
+
     #[test]
     #[should_panic]
     fn test_partial_eq_invalid_1() {
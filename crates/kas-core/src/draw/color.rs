@@ -34,6 +34,18 @@ impl Rgba {
     pub const WHITE: Self = Self::grey(1.0);
     /// Opaque black
     pub const BLACK: Self = Self::grey(0.0);
+    /// Opaque red
+    pub const RED: Self = Self::rgb(1.0, 0.0, 0.0);
+    /// Opaque green
+    pub const GREEN: Self = Self::rgb(0.0, 1.0, 0.0);
+    /// Opaque blue
+    pub const BLUE: Self = Self::rgb(0.0, 0.0, 1.0);
+    /// Opaque yellow
+    pub const YELLOW: Self = Self::rgb(1.0, 1.0, 0.0);
+    /// Opaque cyan
+    pub const CYAN: Self = Self::rgb(0.0, 1.0, 1.0);
+    /// Opaque magenta
+    pub const MAGENTA: Self = Self::rgb(1.0, 0.0, 1.0);
 
     /// Construct from R-G-B-A components
     ///
@@ -59,6 +71,33 @@ impl Rgba {
         Self::rgba(s, s, s, a)
     }
 
+    /// Replace the alpha component
+    ///
+    /// E.g. `Rgba::RED.with_alpha(0.5)` is translucent red.
+    #[inline]
+    #[must_use]
+    pub const fn with_alpha(self, a: f32) -> Self {
+        Self::rgba(self.r, self.g, self.b, a)
+    }
+
+    /// Parse from a hex string
+    ///
+    /// Accepts `#RGB`, `#RRGGBB` and `#RRGGBBAA` (the `#` is optional). Input
+    /// is interpreted as sRGB (with linear alpha) and converted to linear; see
+    /// [`Rgba8Srgb`]. Equivalent to `s.parse()`.
+    ///
+    /// ```
+    /// use kas_core::draw::color::Rgba;
+    ///
+    /// assert_eq!(Rgba::from_hex("#000"), Ok(Rgba::BLACK));
+    /// assert_eq!(Rgba::from_hex("#00000000"), Ok(Rgba::TRANSPARENT));
+    /// assert!(Rgba::from_hex("#12345").is_err());
+    /// ```
+    #[inline]
+    pub fn from_hex(s: &str) -> Result<Self, ParseError> {
+        s.parse()
+    }
+
     /// Get the sum of the three colour components
     pub fn sum(self) -> f32 {
         self.r + self.g + self.b
@@ -128,6 +167,18 @@ impl Rgb {
     pub const WHITE: Self = Self::grey(1.0);
     /// Opaque black
     pub const BLACK: Self = Self::grey(0.0);
+    /// Opaque red
+    pub const RED: Self = Self::rgb(1.0, 0.0, 0.0);
+    /// Opaque green
+    pub const GREEN: Self = Self::rgb(0.0, 1.0, 0.0);
+    /// Opaque blue
+    pub const BLUE: Self = Self::rgb(0.0, 0.0, 1.0);
+    /// Opaque yellow
+    pub const YELLOW: Self = Self::rgb(1.0, 1.0, 0.0);
+    /// Opaque cyan
+    pub const CYAN: Self = Self::rgb(0.0, 1.0, 1.0);
+    /// Opaque magenta
+    pub const MAGENTA: Self = Self::rgb(1.0, 0.0, 1.0);
 
     /// Construct from R-G-B components
     ///
@@ -141,6 +192,21 @@ impl Rgb {
         Self::rgb(s, s, s)
     }
 
+    /// Convert to [`Rgba`] with the given alpha component
+    #[inline]
+    pub const fn with_alpha(self, a: f32) -> Rgba {
+        Rgba::rgba(self.r, self.g, self.b, a)
+    }
+
+    /// Parse from a hex string
+    ///
+    /// Accepts the same formats as [`Rgba::from_hex`]; an alpha component, if
+    /// present, is discarded.
+    #[inline]
+    pub fn from_hex(s: &str) -> Result<Self, ParseError> {
+        s.parse()
+    }
+
     /// Get the sum of the three colour components
     pub fn sum(self) -> f32 {
         self.r + self.g + self.b
@@ -208,6 +274,10 @@ impl From<Rgb> for Rgba {
 /// let green: Rgba8Srgb = "00ff00".parse().unwrap();
 /// assert_eq!(green, Rgba8Srgb::rgb(0, 255, 0));
 ///
+/// // Three digits are shorthand, with each digit repeated
+/// let yellow: Rgba8Srgb = "#FF0".parse().unwrap();
+/// assert_eq!(yellow, Rgba8Srgb::rgb(255, 255, 0));
+///
 /// // An optional fourth byte is interpreted as alpha component
 /// let translucent_blue: Rgba8Srgb = "0000FF7F".parse().unwrap();
 /// assert_eq!(translucent_blue, Rgba8Srgb::rgba(0, 0, 255, 127));
@@ -278,10 +348,11 @@ impl From<[u8; 4]> for Rgba8Srgb {
     }
 }
 
-#[derive(Copy, Clone, Debug, Error)]
+/// Error type for colour parsing
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
 pub enum ParseError {
     /// Incorrect input length
-    #[error("input has unexpected length (expected optional `#` then 6 or 8 bytes")]
+    #[error("input has unexpected length (expected optional `#` then 3, 6 or 8 bytes)")]
     Length,
     /// Invalid hex byte
     #[error("input byte is not a valid hex byte (expected 0-9, a-f or A-F)")]
@@ -294,16 +365,14 @@ pub enum ParseError {
 ///
 /// -   optional `#` prefix
 /// -   upper and lower case hex digits
-/// -   six (RGB) or eight (RGBA) digits
+/// -   three (short-hand RGB), six (RGB) or eight (RGBA) digits
 impl std::str::FromStr for Rgba8Srgb {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut s = s.as_bytes();
-        if s[0] == b'#' {
-            s = &s[1..];
-        }
-        if s.len() != 6 && s.len() != 8 {
+        let s = s.as_bytes();
+        let s = s.strip_prefix(b"#").unwrap_or(s);
+        if s.len() != 3 && s.len() != 6 && s.len() != 8 {
             return Err(ParseError::Length);
         }
 
@@ -323,6 +392,13 @@ impl std::str::FromStr for Rgba8Srgb {
             Ok(val(s[0])? << 4 | val(s[1])?)
         }
 
+        if s.len() == 3 {
+            let r = val(s[0])? * 0x11;
+            let g = val(s[1])? * 0x11;
+            let b = val(s[2])? * 0x11;
+            return Ok(Rgba8Srgb([r, g, b, 0xFF]));
+        }
+
         let r = byte(&s[0..2])?;
         let g = byte(&s[2..4])?;
         let b = byte(&s[4..6])?;
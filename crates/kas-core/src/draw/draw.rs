@@ -8,8 +8,10 @@
 use super::{color::Rgba, AnimationState};
 #[allow(unused)]
 use super::{DrawRounded, DrawRoundedImpl};
-use super::{DrawSharedImpl, ImageId, PassId, PassType, SharedState};
+use super::{DrawSharedImpl, GlyphSpacer, ImageId, PassId, PassType, SharedState};
+use crate::dir::Direction;
 use crate::geom::{Offset, Quad, Rect, Vec2};
+use crate::layout::Margins;
 #[allow(unused)]
 use crate::text::TextApi;
 use crate::text::{Effect, TextDisplay};
@@ -88,6 +90,17 @@ impl<'a, DS: DrawSharedImpl> DrawIface<'a, DS> {
         }
     }
 
+    /// Reborrow with a depth for subsequent draw operations
+    ///
+    /// This does not create a new pass. See [`PassId::with_depth`].
+    pub fn with_depth(&mut self, depth: f32) -> DrawIface<DS> {
+        DrawIface {
+            draw: &mut *self.draw,
+            shared: &mut *self.shared,
+            pass: self.pass.with_depth(depth),
+        }
+    }
+
     /// Add a draw pass
     ///
     /// Adds a new draw pass. Passes affect draw order (operations in new passes
@@ -111,6 +124,18 @@ impl<'a, DS: DrawSharedImpl> DrawIface<'a, DS> {
             pass,
         }
     }
+
+    /// Add a draw pass with a scale transform
+    ///
+    /// See [`DrawImpl::new_transform_pass`].
+    pub fn new_transform_pass(&mut self, scale: f32, offset: Offset) -> DrawIface<DS> {
+        let pass = self.draw.new_transform_pass(self.pass, scale, offset);
+        DrawIface {
+            draw: &mut *self.draw,
+            shared: &mut *self.shared,
+            pass,
+        }
+    }
 }
 
 /// Base drawing interface for [`DrawIface`]
@@ -188,15 +213,69 @@ pub trait Draw {
     /// The frame is defined by the area inside `outer` and not inside `inner`.
     fn frame(&mut self, outer: Quad, inner: Quad, col: Rgba);
 
+    /// Draw a rectangle with a linear colour gradient
+    ///
+    /// Colour `from` is used on the edge of `rect` at which `dir` starts (e.g.
+    /// the left edge given [`Direction::Right`]) and `to` on the opposite edge.
+    /// Colours are interpolated linearly (as [`Rgba`] values, thus in linear
+    /// light space). Nothing is drawn when `rect` has zero size.
+    ///
+    /// Note: this is drawn at the same stage as [`Self::rect`].
+    fn rect_gradient(&mut self, rect: Quad, from: Rgba, to: Rgba, dir: Direction);
+
     /// Draw the image in the given `rect`
     fn image(&mut self, id: ImageId, rect: Quad);
 
+    /// Draw the image in the given `rect` as a nine-patch
+    ///
+    /// The image is divided into a 3×3 grid by `insets`, measured in image
+    /// pixels. Corners are drawn at a fixed size (`insets` multiplied by
+    /// `scale`), edges are stretched along one axis and the centre along both.
+    ///
+    /// If the scaled insets exceed the size of `rect` on an axis, the insets on
+    /// that axis are reduced proportionally (each axis independently).
+    /// Insets larger than the image are clamped to the image size.
+    fn image_nine_patch(&mut self, id: ImageId, rect: Quad, insets: Margins, scale: f32);
+
+    /// Draw the image tiled over the given `rect`
+    ///
+    /// The image is drawn repeatedly at size `tile`, starting from the
+    /// top-left corner of `rect`. Tiles at the right and bottom edges are
+    /// clipped to `rect` (showing only the corresponding part of the image).
+    /// If either component of `tile` is not positive, the image is stretched
+    /// over `rect` instead.
+    ///
+    /// Implementations may bound the number of tiles drawn, enlarging tiles
+    /// (preserving aspect ratio) where `tile` is very small relative to `rect`.
+    fn image_tiled(&mut self, id: ImageId, rect: Quad, tile: Vec2);
+
     /// Draw text with a colour
     ///
     /// It is required to call [`TextDisplay::prepare`] or [`TextApi::prepare`]
     /// prior to this method to select a font, font size and perform layout.
     fn text(&mut self, pos: Vec2, text: &TextDisplay, col: Rgba);
 
+    /// Draw text with a colour, effects and spacing adjustments
+    ///
+    /// Glyphs and effects are positioned as adjusted by `spacer`, which should
+    /// be constructed for the same `text`. Otherwise this is equivalent to
+    /// [`Draw::text_col_effects`]. The default implementation ignores
+    /// `spacer`.
+    ///
+    /// It is required to call [`TextDisplay::prepare`] or [`TextApi::prepare`]
+    /// prior to this method to select a font, font size and perform layout.
+    fn text_spaced(
+        &mut self,
+        pos: Vec2,
+        text: &TextDisplay,
+        col: Rgba,
+        effects: &[Effect<()>],
+        spacer: GlyphSpacer,
+    ) {
+        let _ = spacer;
+        self.text_col_effects(pos, text, col, effects);
+    }
+
     /// Draw text with a single color and effects
     ///
     /// The effects list does not contain colour information, but may contain
@@ -262,17 +341,45 @@ impl<'a, DS: DrawSharedImpl> Draw for DrawIface<'a, DS> {
     fn frame(&mut self, outer: Quad, inner: Quad, col: Rgba) {
         self.draw.frame(self.pass, outer, inner, col);
     }
+    fn rect_gradient(&mut self, rect: Quad, from: Rgba, to: Rgba, dir: Direction) {
+        self.draw.rect_gradient(self.pass, rect, from, to, dir);
+    }
 
     fn image(&mut self, id: ImageId, rect: Quad) {
         self.shared.draw.draw_image(self.draw, self.pass, id, rect);
     }
 
+    fn image_nine_patch(&mut self, id: ImageId, rect: Quad, insets: Margins, scale: f32) {
+        self.shared
+            .draw
+            .draw_image_nine_patch(self.draw, self.pass, id, rect, insets, scale);
+    }
+
+    fn image_tiled(&mut self, id: ImageId, rect: Quad, tile: Vec2) {
+        self.shared
+            .draw
+            .draw_image_tiled(self.draw, self.pass, id, rect, tile);
+    }
+
     fn text(&mut self, pos: Vec2, text: &TextDisplay, col: Rgba) {
         self.shared
             .draw
             .draw_text(self.draw, self.pass, pos, text, col);
     }
 
+    fn text_spaced(
+        &mut self,
+        pos: Vec2,
+        text: &TextDisplay,
+        col: Rgba,
+        effects: &[Effect<()>],
+        spacer: GlyphSpacer,
+    ) {
+        self.shared
+            .draw
+            .draw_text_spaced(self.draw, self.pass, pos, text, col, effects, spacer);
+    }
+
     fn text_col_effects(
         &mut self,
         pos: Vec2,
@@ -353,6 +460,16 @@ pub trait DrawImpl: Any {
         class: PassType,
     ) -> PassId;
 
+    /// Add a draw pass with a scale transform
+    ///
+    /// The new pass is derived from `parent_pass` and has the same clip rect.
+    /// A point `p` drawn in the new pass is drawn at `p * scale - offset` in
+    /// the parent's coordinate system. All draw operations are affected,
+    /// including text, which is rastered at the effective (scaled) size.
+    ///
+    /// Requires `scale > 0.0`.
+    fn new_transform_pass(&mut self, parent_pass: PassId, scale: f32, offset: Offset) -> PassId;
+
     /// Get drawable rect for a draw `pass`
     ///
     /// The result is in the current target's coordinate system, thus normally
@@ -367,4 +484,9 @@ pub trait DrawImpl: Any {
 
     /// Draw a frame of uniform colour
     fn frame(&mut self, pass: PassId, outer: Quad, inner: Quad, col: Rgba);
+
+    /// Draw a rectangle with a linear colour gradient
+    ///
+    /// See [`Draw::rect_gradient`].
+    fn rect_gradient(&mut self, pass: PassId, rect: Quad, from: Rgba, to: Rgba, dir: Direction);
 }
@@ -6,7 +6,7 @@
 //! Drawing APIs — draw rounded
 
 use super::color::Rgba;
-use super::{Draw, DrawIface, DrawImpl, DrawSharedImpl, PassId};
+use super::{Draw, DrawIface, DrawImpl, DrawSharedImpl, PassId, PathBuilder};
 use crate::geom::{Quad, Vec2};
 
 /// Extension over [`Draw`] for rounded shapes
@@ -32,6 +32,20 @@ pub trait DrawRounded: Draw {
     /// painted, while `1.0` will result in a zero-width line on the outer edge.
     fn circle(&mut self, rect: Quad, inner_radius: f32, col: Rgba);
 
+    /// Draw an arc (a pie slice or segment of a ring) of uniform colour
+    ///
+    /// This draws the part of [`Self::circle`] between `start_angle` and
+    /// `end_angle` (radians). Angles are measured from the positive x-axis
+    /// (rightwards) and increase clockwise on screen (since y points down).
+    /// For ovals, angles are measured on the virtual unit circle (i.e. before
+    /// scaling to `rect`).
+    ///
+    /// The arc sweeps from the lesser angle to the greater; where the two
+    /// angles differ by at least `2π` a full circle is drawn.
+    /// The `inner_radius` is as for [`Self::circle`]: `0.0` draws a solid pie
+    /// slice.
+    fn arc(&mut self, rect: Quad, start_angle: f32, end_angle: f32, inner_radius: f32, col: Rgba);
+
     /// Draw a circle or oval with two colours
     ///
     /// More generally, this shape is an axis-aligned oval which may be hollow.
@@ -64,6 +78,21 @@ pub trait DrawRounded: Draw {
     /// Note: this is drawn *before* other drawables, allowing it to be used
     /// for shadows without masking.
     fn rounded_frame_2col(&mut self, outer: Quad, inner: Quad, c1: Rgba, c2: Rgba);
+
+    /// Draw a rectangle with rounded corners and uniform colour
+    ///
+    /// Each corner is a quarter-circle with its own radius, given in the order
+    /// top-left, top-right, bottom-right, bottom-left. Each radius is clamped
+    /// to half the smaller dimension of `rect`; a radius of zero results in a
+    /// sharp corner.
+    fn rounded_rect(&mut self, rect: Quad, radii: [f32; 4], col: Rgba);
+
+    /// Fill a vector path with uniform colour
+    ///
+    /// Regions are filled according to [`PathBuilder::fill_rule`]. Edges of
+    /// the path are not anti-aliased, thus features much smaller than a pixel
+    /// may appear jagged or vanish.
+    fn path(&mut self, path: &PathBuilder, col: Rgba);
 }
 
 impl<'a, DS: DrawSharedImpl> DrawRounded for DrawIface<'a, DS>
@@ -79,6 +108,11 @@ where
         self.draw.circle(self.pass, rect, inner_radius, col);
     }
     #[inline]
+    fn arc(&mut self, rect: Quad, start_angle: f32, end_angle: f32, inner_radius: f32, col: Rgba) {
+        self.draw
+            .arc(self.pass, rect, start_angle, end_angle, inner_radius, col);
+    }
+    #[inline]
     fn circle_2col(&mut self, rect: Quad, col1: Rgba, col2: Rgba) {
         self.draw.circle_2col(self.pass, rect, col1, col2);
     }
@@ -92,6 +126,14 @@ where
         self.draw
             .rounded_frame_2col(self.pass, outer, inner, c1, c2);
     }
+    #[inline]
+    fn rounded_rect(&mut self, rect: Quad, radii: [f32; 4], col: Rgba) {
+        self.draw.rounded_rect(self.pass, rect, radii, col);
+    }
+    #[inline]
+    fn path(&mut self, path: &PathBuilder, col: Rgba) {
+        self.draw.path(self.pass, path, col);
+    }
 }
 
 /// Drawing commands for rounded shapes
@@ -110,6 +152,19 @@ pub trait DrawRoundedImpl: DrawImpl {
     /// Draw a circle or oval of uniform colour
     fn circle(&mut self, pass: PassId, rect: Quad, inner_radius: f32, col: Rgba);
 
+    /// Draw an arc (pie slice) of uniform colour
+    ///
+    /// See [`DrawRounded::arc`].
+    fn arc(
+        &mut self,
+        pass: PassId,
+        rect: Quad,
+        start_angle: f32,
+        end_angle: f32,
+        inner_radius: f32,
+        col: Rgba,
+    );
+
     /// Draw a circle or oval with two colours
     fn circle_2col(&mut self, pass: PassId, rect: Quad, col1: Rgba, col2: Rgba);
 
@@ -118,4 +173,14 @@ pub trait DrawRoundedImpl: DrawImpl {
 
     /// Draw a frame with rounded corners with two colours
     fn rounded_frame_2col(&mut self, pass: PassId, outer: Quad, inner: Quad, c1: Rgba, c2: Rgba);
+
+    /// Draw a rectangle with rounded corners and uniform colour
+    ///
+    /// See [`DrawRounded::rounded_rect`].
+    fn rounded_rect(&mut self, pass: PassId, rect: Quad, radii: [f32; 4], col: Rgba);
+
+    /// Fill a vector path with uniform colour
+    ///
+    /// See [`DrawRounded::path`].
+    fn path(&mut self, pass: PassId, path: &PathBuilder, col: Rgba);
 }
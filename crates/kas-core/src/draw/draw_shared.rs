@@ -6,9 +6,10 @@
 //! Drawing APIs — shared draw state
 
 use super::color::Rgba;
-use super::{DrawImpl, PassId};
+use super::{DrawImpl, GlyphSpacer, PassId};
 use crate::cast::Cast;
 use crate::geom::{Quad, Size, Vec2};
+use crate::layout::Margins;
 use crate::text::{Effect, TextDisplay};
 use std::any::Any;
 use std::num::NonZeroU32;
@@ -168,6 +169,31 @@ pub trait DrawSharedImpl: Any {
     /// Draw the image in the given `rect`
     fn draw_image(&self, draw: &mut Self::Draw, pass: PassId, id: ImageId, rect: Quad);
 
+    /// Draw the image in the given `rect` as a nine-patch
+    ///
+    /// See [`crate::draw::Draw::image_nine_patch`].
+    fn draw_image_nine_patch(
+        &self,
+        draw: &mut Self::Draw,
+        pass: PassId,
+        id: ImageId,
+        rect: Quad,
+        insets: Margins,
+        scale: f32,
+    );
+
+    /// Draw the image tiled over the given `rect`
+    ///
+    /// See [`crate::draw::Draw::image_tiled`].
+    fn draw_image_tiled(
+        &self,
+        draw: &mut Self::Draw,
+        pass: PassId,
+        id: ImageId,
+        rect: Quad,
+        tile: Vec2,
+    );
+
     /// Draw text with a colour
     fn draw_text(
         &mut self,
@@ -178,6 +204,25 @@ pub trait DrawSharedImpl: Any {
         col: Rgba,
     );
 
+    /// Draw text with a colour, effects and spacing adjustments
+    ///
+    /// See [`crate::draw::Draw::text_spaced`]. The default implementation
+    /// ignores `spacer`.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_text_spaced(
+        &mut self,
+        draw: &mut Self::Draw,
+        pass: PassId,
+        pos: Vec2,
+        text: &TextDisplay,
+        col: Rgba,
+        effects: &[Effect<()>],
+        spacer: GlyphSpacer,
+    ) {
+        let _ = spacer;
+        self.draw_text_col_effects(draw, pass, pos, text, col, effects);
+    }
+
     /// Draw text with a colour and effects
     ///
     /// The effects list does not contain colour information, but may contain
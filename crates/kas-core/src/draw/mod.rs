@@ -34,6 +34,9 @@
 //! 3.  Rounded or other partially-transparent primitives (e.g. [`DrawRounded::circle`])
 //! 4.  Custom draw routines (`CustomPipe`)
 //! 5.  Text
+//!
+//! Shaded primitives within a pass may additionally be layered by depth; see
+//! [`PassId::with_depth`].
 
 pub mod color;
 
@@ -41,6 +44,8 @@ pub mod color;
 mod draw;
 mod draw_rounded;
 mod draw_shared;
+mod path;
+mod spacing;
 
 use crate::cast::Cast;
 #[allow(unused)]
@@ -50,6 +55,8 @@ pub use draw::{Draw, DrawIface, DrawImpl};
 pub use draw_rounded::{DrawRounded, DrawRoundedImpl};
 pub use draw_shared::{AllocError, ImageFormat, ImageHandle, ImageId};
 pub use draw_shared::{DrawShared, DrawSharedImpl, SharedState};
+pub use path::{FillRule, PathBuilder};
+pub use spacing::{GlyphSpacer, TextSpacing};
 use std::time::Instant;
 
 /// Animation status
@@ -80,9 +87,10 @@ impl AnimationState {
 
 /// Draw pass identifier
 ///
-/// This is a numerical identifier for the draw pass (see [`DrawIface::new_pass`]).
+/// This is a numerical identifier for the draw pass (see [`DrawIface::new_pass`])
+/// together with a depth (see [`PassId::with_depth`]).
 #[derive(Copy, Clone)]
-pub struct PassId(u32);
+pub struct PassId(u32, f32);
 
 impl PassId {
     /// Construct a new pass from a `u32` identifier
@@ -90,7 +98,7 @@ impl PassId {
     #[cfg_attr(doc_cfg, doc(cfg(internal_doc)))]
     #[inline]
     pub const fn new(n: u32) -> Self {
-        PassId(n)
+        PassId(n, 0.0)
     }
 
     /// The pass number
@@ -103,10 +111,32 @@ impl PassId {
 
     /// The depth value
     ///
-    /// This is a historical left-over and always returns 0.0.
+    /// This is 0.0 unless set by [`PassId::with_depth`].
     #[inline]
     pub fn depth(self) -> f32 {
-        0.0
+        self.1
+    }
+
+    /// Set the depth value
+    ///
+    /// Depth is clamped to the range `0.0..=1.0` (NaN maps to 0.0). Within a
+    /// pass, shaded primitives with greater depth appear in front of those
+    /// with lesser depth, regardless of submission order. Depth is supported
+    /// by the shell's shaded pipelines (with `kas-wgpu`: [`Draw::rect`],
+    /// [`Draw::frame`] and `DrawShaded` primitives); other operations (e.g.
+    /// images, [`DrawRounded`] primitives and text) ignore depth and are drawn
+    /// in batch order (see [module docs](self)). To layer other content, use
+    /// a new pass (see [`DrawIface::new_pass`]).
+    ///
+    /// Partially-transparent (anti-aliased) edges of a primitive may hide
+    /// primitives of lesser depth submitted after it. Where edges overlap,
+    /// prefer to submit lesser depths first.
+    ///
+    /// See also [`DrawIface::with_depth`].
+    #[inline]
+    #[must_use]
+    pub fn with_depth(self, depth: f32) -> Self {
+        PassId(self.0, depth.max(0.0).min(1.0))
     }
 }
 
@@ -0,0 +1,350 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE-APACHE file or at:
+//     https://www.apache.org/licenses/LICENSE-2.0
+
+//! Vector paths
+
+use crate::geom::Vec2;
+use std::cmp::Ordering;
+
+/// Maximum distance (in pixels) between a curve and its flattened form
+const TOLERANCE: f32 = 0.1;
+/// Maximum number of line segments used to approximate one curve
+const MAX_CURVE_SEGMENTS: f32 = 64.0;
+
+/// Rule used to determine which regions of a path are filled
+///
+/// The two rules only differ for self-intersecting or nested paths. The
+/// *winding number* of a point is the number of times the path travels
+/// around that point (counting anti-clockwise as negative).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FillRule {
+    /// Fill where the winding number is not zero
+    ///
+    /// Nested sub-paths with the same direction are filled; those drawn in
+    /// the opposite direction cut holes.
+    NonZero,
+    /// Fill where the winding number is odd
+    ///
+    /// Nested sub-paths alternate between filled and holes regardless of
+    /// direction.
+    EvenOdd,
+}
+
+impl Default for FillRule {
+    fn default() -> Self {
+        FillRule::NonZero
+    }
+}
+
+/// A vector path, suitable for drawing scalable icons
+///
+/// A path consists of one or more sub-paths, each started by
+/// [`PathBuilder::move_to`] and continued with straight lines
+/// ([`PathBuilder::line_to`]) and cubic Bézier curves
+/// ([`PathBuilder::cubic_to`]). For filling, every sub-path is implicitly
+/// closed.
+///
+/// Coordinates are in pixels, using the same coordinate space as [`Quad`].
+///
+/// [`Quad`]: crate::geom::Quad
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PathBuilder {
+    points: Vec<Vec2>,
+    // Start index (within points) of each sub-path
+    starts: Vec<usize>,
+    fill_rule: FillRule,
+}
+
+impl PathBuilder {
+    /// Construct an empty path
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the fill rule (default: [`FillRule::NonZero`])
+    #[inline]
+    #[must_use]
+    pub fn with_fill_rule(mut self, rule: FillRule) -> Self {
+        self.fill_rule = rule;
+        self
+    }
+
+    /// Get the fill rule
+    #[inline]
+    pub fn fill_rule(&self) -> FillRule {
+        self.fill_rule
+    }
+
+    /// True if the path contains no points
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Remove all points, retaining the fill rule
+    pub fn clear(&mut self) {
+        self.points.clear();
+        self.starts.clear();
+    }
+
+    /// Start a new sub-path at `p`
+    pub fn move_to(&mut self, p: Vec2) {
+        let len = self.points.len();
+        if len > 0 && self.starts.last() == Some(&(len - 1)) {
+            // Previous sub-path is empty: replace its start point
+            self.points.pop();
+        } else {
+            self.starts.push(self.points.len());
+        }
+        self.points.push(p);
+    }
+
+    /// Add a straight line from the current point to `p`
+    ///
+    /// If there is no current sub-path, this acts like [`Self::move_to`].
+    pub fn line_to(&mut self, p: Vec2) {
+        if self.points.is_empty() {
+            return self.move_to(p);
+        }
+        self.points.push(p);
+    }
+
+    /// Add a cubic Bézier curve from the current point to `p`
+    ///
+    /// Control points are `c1` and `c2`. The curve is approximated by line
+    /// segments deviating from the true curve by at most a tenth of a pixel.
+    ///
+    /// If there is no current sub-path, this acts like [`Self::move_to`].
+    pub fn cubic_to(&mut self, c1: Vec2, c2: Vec2, p: Vec2) {
+        let p0 = match self.points.last() {
+            Some(p0) => *p0,
+            None => return self.move_to(p),
+        };
+
+        // Subdivision count from the maximum second difference of the control
+        // polygon; this bounds the flattening error.
+        let d1 = (p0 - c1 * 2.0 + c2).max_abs_comp();
+        let d2 = (c1 - c2 * 2.0 + p).max_abs_comp();
+        let dd = d1.max(d2) * std::f32::consts::SQRT_2;
+        let n = (0.75 * dd / TOLERANCE).sqrt().ceil();
+        let n = if n.is_finite() {
+            n.clamp(1.0, MAX_CURVE_SEGMENTS)
+        } else {
+            1.0
+        };
+
+        let n = n as u32;
+        for i in 1..n {
+            let t = i as f32 / n as f32;
+            let s = 1.0 - t;
+            let q = p0 * (s * s * s)
+                + c1 * (3.0 * s * s * t)
+                + c2 * (3.0 * s * t * t)
+                + p * (t * t * t);
+            self.points.push(q);
+        }
+        self.points.push(p);
+    }
+
+    /// Close the current sub-path
+    ///
+    /// A new sub-path is started at the start point of the closed sub-path.
+    /// (Since filled sub-paths are closed implicitly, this is only required
+    /// where a path continues from that point.)
+    pub fn close(&mut self) {
+        if let Some(start) = self.starts.last() {
+            let p = self.points[*start];
+            self.move_to(p);
+        }
+    }
+
+    /// Tessellate the filled region into triangles
+    ///
+    /// The region is decomposed into horizontal bands with breaks at each
+    /// vertex and each self-intersection; within each band the filled spans
+    /// are trapezoids, each emitted as two triangles.
+    ///
+    /// The result is exact with respect to the flattened path; edges are not
+    /// anti-aliased. Paths with non-finite coordinates yield no triangles.
+    ///
+    /// Cost is `O(b × n)` for `n` edges and `b` bands. Finding
+    /// self-intersections only compares edges whose vertical extents overlap,
+    /// but is `O(n²)` in the worst case (e.g. many long, nearly vertical
+    /// edges). Paths should therefore be kept reasonably simple; complex
+    /// shapes are better split into multiple paths.
+    pub fn tessellate(&self) -> Vec<[Vec2; 3]> {
+        // Edges as (upper point, lower point, direction)
+        let mut edges = Vec::with_capacity(self.points.len());
+        let mut ys = Vec::with_capacity(self.points.len());
+        for (i, start) in self.starts.iter().cloned().enumerate() {
+            let end = self.starts.get(i + 1).cloned().unwrap_or(self.points.len());
+            let points = &self.points[start..end];
+            for (j, p0) in points.iter().cloned().enumerate() {
+                let p1 = points[(j + 1) % points.len()];
+                if !(p0.0.is_finite() && p0.1.is_finite()) {
+                    return vec![];
+                }
+                ys.push(p0.1);
+                if p0.1 < p1.1 {
+                    edges.push((p0, p1, 1));
+                } else if p1.1 < p0.1 {
+                    edges.push((p1, p0, -1));
+                }
+            }
+        }
+
+        let x_at = |(a, b, _): (Vec2, Vec2, i32), y: f32| -> f32 {
+            a.0 + (b.0 - a.0) * ((y - a.1) / (b.1 - a.1))
+        };
+
+        // Self-intersections must also be band breaks. With edges sorted by
+        // upper point, only those starting above the lower end of `e` may
+        // intersect it.
+        edges.sort_by(|a, b| cmp_finite(&a.0 .1, &b.0 .1));
+        for (i, e) in edges.iter().enumerate() {
+            for f in &edges[i + 1..] {
+                if f.0 .1 >= e.1 .1 {
+                    break;
+                }
+                let y0 = e.0 .1.max(f.0 .1);
+                let y1 = e.1 .1.min(f.1 .1);
+                if y1 <= y0 {
+                    continue;
+                }
+                let d0 = x_at(*e, y0) - x_at(*f, y0);
+                let d1 = x_at(*e, y1) - x_at(*f, y1);
+                if (d0 < 0.0 && d1 > 0.0) || (d0 > 0.0 && d1 < 0.0) {
+                    ys.push(y0 + (y1 - y0) * (d0 / (d0 - d1)));
+                }
+            }
+        }
+        // Intersections of huge coordinates may overflow
+        ys.retain(|y| y.is_finite());
+        ys.sort_by(cmp_finite);
+        ys.dedup();
+
+        let mut triangles = vec![];
+        let mut crossings = vec![];
+        for band in ys.windows(2) {
+            let (y0, y1) = (band[0], band[1]);
+            let ym = 0.5 * (y0 + y1);
+
+            crossings.clear();
+            for e in edges.iter().cloned() {
+                if e.0 .1 <= y0 && y1 <= e.1 .1 {
+                    let x = x_at(e, ym);
+                    if x.is_finite() {
+                        crossings.push((x, x_at(e, y0), x_at(e, y1), e.2));
+                    }
+                }
+            }
+            crossings.sort_by(|a, b| cmp_finite(&a.0, &b.0));
+
+            let mut winding = 0;
+            let mut left = None;
+            for c in crossings.iter() {
+                winding += c.3;
+                let inside = match self.fill_rule {
+                    FillRule::NonZero => winding != 0,
+                    FillRule::EvenOdd => winding % 2 != 0,
+                };
+                match (left, inside) {
+                    (None, true) => left = Some(c),
+                    (Some(l), false) => {
+                        let p0 = Vec2(l.1, y0);
+                        let p1 = Vec2(c.1, y0);
+                        let p2 = Vec2(c.2, y1);
+                        let p3 = Vec2(l.2, y1);
+                        triangles.push([p0, p1, p2]);
+                        triangles.push([p0, p2, p3]);
+                        left = None;
+                    }
+                    _ => (),
+                }
+            }
+        }
+        triangles
+    }
+}
+
+// Compare values known to be finite (thus never NaN)
+fn cmp_finite(a: &f32, b: &f32) -> Ordering {
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn area(triangles: &[[Vec2; 3]]) -> f32 {
+        triangles
+            .iter()
+            .map(|[a, b, c]| {
+                let (u, v) = (*b - *a, *c - *a);
+                0.5 * (u.0 * v.1 - u.1 * v.0).abs()
+            })
+            .sum()
+    }
+
+    fn square(path: &mut PathBuilder, a: f32, b: f32, clockwise: bool) {
+        path.move_to(Vec2(a, a));
+        if clockwise {
+            path.line_to(Vec2(b, a));
+            path.line_to(Vec2(b, b));
+            path.line_to(Vec2(a, b));
+        } else {
+            path.line_to(Vec2(a, b));
+            path.line_to(Vec2(b, b));
+            path.line_to(Vec2(b, a));
+        }
+        path.close();
+    }
+
+    #[test]
+    fn fill_rules() {
+        let mut path = PathBuilder::new();
+        square(&mut path, 0.0, 10.0, true);
+        square(&mut path, 2.0, 8.0, true);
+        assert_eq!(area(&path.tessellate()), 100.0);
+
+        let path = path.with_fill_rule(FillRule::EvenOdd);
+        assert_eq!(area(&path.tessellate()), 64.0);
+
+        let mut path = PathBuilder::new();
+        square(&mut path, 0.0, 10.0, true);
+        square(&mut path, 2.0, 8.0, false);
+        assert_eq!(area(&path.tessellate()), 64.0);
+    }
+
+    #[test]
+    fn self_intersecting() {
+        // A "bow-tie": two triangles meeting at (5, 5)
+        let mut path = PathBuilder::new();
+        path.move_to(Vec2(0.0, 0.0));
+        path.line_to(Vec2(10.0, 10.0));
+        path.line_to(Vec2(10.0, 0.0));
+        path.line_to(Vec2(0.0, 10.0));
+        assert_eq!(area(&path.tessellate()), 50.0);
+    }
+
+    #[test]
+    fn non_finite() {
+        let mut path = PathBuilder::new();
+        path.move_to(Vec2(0.0, 0.0));
+        path.line_to(Vec2(f32::NAN, 10.0));
+        path.line_to(Vec2(10.0, 0.0));
+        assert!(path.tessellate().is_empty());
+
+        // Intermediate values overflow; this must not panic
+        let big = f32::MAX;
+        let mut path = PathBuilder::new();
+        path.move_to(Vec2(-big, -big));
+        path.line_to(Vec2(big, big));
+        path.line_to(Vec2(big, -big));
+        path.line_to(Vec2(-big, big));
+        let _ = path.tessellate();
+    }
+}
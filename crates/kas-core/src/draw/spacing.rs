@@ -0,0 +1,282 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE-APACHE file or at:
+//     https://www.apache.org/licenses/LICENSE-2.0
+
+//! Text spacing adjustments
+
+use crate::geom::Vec2;
+use crate::text::{Align, TextDisplay};
+
+/// Line height and letter spacing adjustments
+///
+/// These adjust glyph positions relative to those computed by text layout
+/// (see [`GlyphSpacer`]). The default value makes no adjustment.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextSpacing {
+    /// Line height, as a multiple of the font's line height
+    pub line_height: f32,
+    /// Extra space between glyphs (tracking), in em
+    ///
+    /// One em is the font size in pixels. May be negative.
+    pub letter_spacing: f32,
+}
+
+impl Default for TextSpacing {
+    fn default() -> Self {
+        TextSpacing {
+            line_height: 1.0,
+            letter_spacing: 0.0,
+        }
+    }
+}
+
+impl TextSpacing {
+    /// True if this makes no adjustment
+    #[inline]
+    pub fn is_default(&self) -> bool {
+        *self == TextSpacing::default()
+    }
+}
+
+/// Positions glyphs according to a [`TextSpacing`]
+///
+/// Lines are moved away from (or towards) the first line, and the `n`-th
+/// glyph of each line is moved right by the letter spacing of the glyphs
+/// before it. Each line is thus widened; to fit, text should be laid out with
+/// its width bound reduced by [`GlyphSpacer::extra_width`]. Lines are shifted
+/// according to their horizontal alignment such that aligned edges remain
+/// aligned within the original bound.
+#[derive(Clone, Debug)]
+pub struct GlyphSpacer {
+    line_height: f32,
+    extra: f32,
+    lines: Vec<Line>,
+    line: usize,
+    glyph: usize,
+}
+
+#[derive(Clone, Debug)]
+struct Line {
+    baseline: f32,
+    shift: f32,
+    // Unadjusted x position and offset of each glyph
+    glyphs: Vec<(f32, f32)>,
+}
+
+impl Line {
+    fn extra(&self) -> f32 {
+        self.glyphs.last().map(|g| g.1).unwrap_or(0.0)
+    }
+
+    // Offset of the last glyph positioned before `x` (or at `x` if inclusive)
+    fn offset(&self, x: f32, inclusive: bool) -> f32 {
+        let mut offset = 0.0;
+        for (gx, off) in self.glyphs.iter() {
+            if *gx < x || (inclusive && *gx == x) {
+                offset = *off;
+            }
+        }
+        self.shift + offset
+    }
+}
+
+impl GlyphSpacer {
+    /// Construct from glyph positions
+    ///
+    /// `glyphs` yields the size (`dpem`) and position of each glyph in the
+    /// order yielded by [`TextDisplay::glyphs`]. A new line is detected when
+    /// the baseline changes. `align` is the horizontal alignment of the text.
+    pub fn new(
+        spacing: TextSpacing,
+        align: Align,
+        glyphs: impl IntoIterator<Item = (f32, Vec2)>,
+    ) -> Self {
+        let mut lines: Vec<Line> = vec![];
+        let mut tracking = 0.0;
+        for (dpem, pos) in glyphs {
+            match lines.last_mut() {
+                Some(line) if line.baseline == pos.1 => {
+                    let offset = line.extra() + tracking;
+                    line.glyphs.push((pos.0, offset));
+                }
+                _ => lines.push(Line {
+                    baseline: pos.1,
+                    shift: 0.0,
+                    glyphs: vec![(pos.0, 0.0)],
+                }),
+            }
+            tracking = spacing.letter_spacing * dpem;
+        }
+
+        let extra = lines.iter().map(Line::extra).fold(0.0, f32::max);
+        let factor = match align {
+            Align::Center => 0.5,
+            Align::BR => 1.0,
+            Align::Default | Align::TL | Align::Stretch => 0.0,
+        };
+        for line in lines.iter_mut() {
+            line.shift = factor * (extra - line.extra());
+        }
+
+        GlyphSpacer {
+            line_height: spacing.line_height,
+            extra,
+            lines,
+            line: 0,
+            glyph: 0,
+        }
+    }
+
+    /// Construct for a prepared `text`
+    pub fn for_text(spacing: TextSpacing, align: Align, text: &TextDisplay) -> Self {
+        let mut glyphs = vec![];
+        let _ = text.glyphs(|_, dpem, glyph| glyphs.push((dpem, glyph.position.into())));
+        Self::new(spacing, align, glyphs)
+    }
+
+    /// The width added to the widest line
+    ///
+    /// This is never negative.
+    pub fn extra_width(&self) -> f32 {
+        self.extra
+    }
+
+    fn y(&self, y: f32) -> f32 {
+        let first = self.lines.first().map(|line| line.baseline).unwrap_or(0.0);
+        first + (y - first) * self.line_height
+    }
+
+    /// Adjust the position `pos` of the next glyph
+    ///
+    /// Glyphs must be passed in the order used on construction.
+    pub fn position(&mut self, pos: Vec2) -> Vec2 {
+        while let Some(line) = self.lines.get(self.line) {
+            if line.baseline == pos.1 && self.glyph < line.glyphs.len() {
+                break;
+            }
+            self.line += 1;
+            self.glyph = 0;
+        }
+        let x = match self.lines.get(self.line) {
+            Some(line) => {
+                self.glyph += 1;
+                pos.0 + line.shift + line.glyphs[self.glyph - 1].1
+            }
+            None => pos.0,
+        };
+        Vec2(x, self.y(pos.1))
+    }
+
+    /// Adjust an effect (e.g. underline) spanning `x1..x2` at height `y`
+    ///
+    /// The effect is assigned to the line with the nearest baseline. Returns
+    /// the adjusted `(x1, x2, y)`.
+    pub fn effect(&self, x1: f32, x2: f32, y: f32) -> (f32, f32, f32) {
+        let nearest = self.lines.iter().min_by(|a, b| {
+            let (a, b) = ((a.baseline - y).abs(), (b.baseline - y).abs());
+            a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+        });
+        match nearest {
+            Some(line) => {
+                let y = y + self.y(line.baseline) - line.baseline;
+                (x1 + line.offset(x1, true), x2 + line.offset(x2, false), y)
+            }
+            None => (x1, x2, y),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cast::Cast;
+
+    const SPACING: TextSpacing = TextSpacing {
+        line_height: 1.5,
+        letter_spacing: 0.25,
+    };
+
+    // Glyphs 8px apart at 16px/em; each line is `(x, baseline, count)`
+    fn glyphs(lines: &[(f32, f32, usize)]) -> Vec<(f32, Vec2)> {
+        let mut glyphs = vec![];
+        for (x, y, n) in lines {
+            for i in 0..*n {
+                let i: f32 = i.cast();
+                glyphs.push((16.0, Vec2(x + 8.0 * i, *y)));
+            }
+        }
+        glyphs
+    }
+
+    fn positions(mut spacer: GlyphSpacer, glyphs: &[(f32, Vec2)]) -> Vec<Vec2> {
+        glyphs.iter().map(|g| spacer.position(g.1)).collect()
+    }
+
+    #[test]
+    fn default_spacing_is_identity() {
+        let glyphs = glyphs(&[(0.0, 12.0, 2), (0.0, 28.0, 1)]);
+        let spacer = GlyphSpacer::new(TextSpacing::default(), Align::BR, glyphs.clone());
+        assert_eq!(spacer.extra_width(), 0.0);
+        let expected: Vec<_> = glyphs.iter().map(|g| g.1).collect();
+        assert_eq!(positions(spacer, &glyphs), expected);
+    }
+
+    #[test]
+    fn line_height_and_letter_spacing() {
+        let glyphs = glyphs(&[(0.0, 12.0, 3), (0.0, 28.0, 2), (0.0, 44.0, 1)]);
+        let spacer = GlyphSpacer::new(SPACING, Align::TL, glyphs.clone());
+        // 4px tracking per glyph (0.25 × 16)
+        assert_eq!(spacer.extra_width(), 8.0);
+        #[rustfmt::skip]
+        let expected = [
+            Vec2(0.0, 12.0), Vec2(12.0, 12.0), Vec2(24.0, 12.0),
+            // Tracking restarts; baseline moves from 28 to 36
+            Vec2(0.0, 36.0), Vec2(12.0, 36.0),
+            Vec2(0.0, 60.0),
+        ];
+        assert_eq!(positions(spacer, &glyphs), expected);
+    }
+
+    #[test]
+    fn wrapped_lines() {
+        // One source line wrapped as 4 + 2 glyphs: width is added per
+        // wrapped line, not per source line
+        let glyphs = glyphs(&[(0.0, 12.0, 4), (0.0, 28.0, 2)]);
+        let spacer = GlyphSpacer::new(SPACING, Align::TL, glyphs.clone());
+        assert_eq!(spacer.extra_width(), 12.0);
+        let pos = positions(spacer, &glyphs);
+        assert_eq!(pos[3], Vec2(36.0, 12.0));
+        assert_eq!(pos[5], Vec2(12.0, 36.0));
+    }
+
+    #[test]
+    fn aligned_lines() {
+        // Right-aligned to x=40 (line widths 24 and 8): right edges remain
+        // aligned, at 40 + extra_width
+        let glyphs = glyphs(&[(16.0, 12.0, 3), (32.0, 28.0, 1)]);
+        let spacer = GlyphSpacer::new(SPACING, Align::BR, glyphs.clone());
+        assert_eq!(spacer.extra_width(), 8.0);
+        let pos = positions(spacer, &glyphs);
+        assert_eq!(pos[2].0 + 8.0, 48.0);
+        assert_eq!(pos[3].0 + 8.0, 48.0);
+
+        // Centred about x=20: centres move by half of extra_width
+        let glyphs = self::glyphs(&[(8.0, 12.0, 3), (16.0, 28.0, 1)]);
+        let spacer = GlyphSpacer::new(SPACING, Align::Center, glyphs.clone());
+        let pos = positions(spacer, &glyphs);
+        assert_eq!((pos[0].0 + pos[2].0 + 8.0) / 2.0, 24.0);
+        assert_eq!((pos[3].0 + pos[3].0 + 8.0) / 2.0, 24.0);
+    }
+
+    #[test]
+    fn effects() {
+        let glyphs = glyphs(&[(0.0, 12.0, 3), (0.0, 28.0, 2)]);
+        let spacer = GlyphSpacer::new(SPACING, Align::TL, glyphs);
+        // Underline of the second and third glyphs, below the first baseline
+        assert_eq!(spacer.effect(8.0, 24.0, 14.0), (12.0, 32.0, 14.0));
+        // Underline of the first glyph of the second line
+        assert_eq!(spacer.effect(0.0, 8.0, 30.0), (0.0, 8.0, 38.0));
+    }
+}
@@ -5,6 +5,8 @@
 
 //! Event handling components
 
+#[allow(unused)]
+use super::EventState; // for doc-links
 use super::ScrollDelta::{LineDelta, PixelDelta};
 use super::{Command, CursorIcon, Event, EventMgr, PressSource, Response, Scroll};
 use crate::cast::traits::*;
@@ -241,19 +243,15 @@ impl ScrollComponent {
                     Command::End => self.max_offset,
                     cmd => {
                         let delta = match cmd {
-                            Command::Left => LineDelta(-1.0, 0.0),
-                            Command::Right => LineDelta(1.0, 0.0),
+                            Command::Left => LineDelta(1.0, 0.0),
+                            Command::Right => LineDelta(-1.0, 0.0),
                             Command::Up => LineDelta(0.0, 1.0),
                             Command::Down => LineDelta(0.0, -1.0),
                             Command::PageUp => PixelDelta(Offset(0, window_rect.size.1 / 2)),
                             Command::PageDown => PixelDelta(Offset(0, -(window_rect.size.1 / 2))),
                             _ => return (false, Response::Unused),
                         };
-                        let delta = match delta {
-                            LineDelta(x, y) => mgr.config().scroll_distance((-x, y), None),
-                            PixelDelta(d) => d,
-                        };
-                        self.offset - delta
+                        self.offset - delta.to_offset(mgr.config(), None)
                     }
                 };
                 let action = self.set_offset(offset);
@@ -264,10 +262,7 @@ impl ScrollComponent {
                 mgr.set_scroll(Scroll::Rect(window_rect));
             }
             Event::Scroll(delta) => {
-                let delta = match delta {
-                    LineDelta(x, y) => mgr.config().scroll_distance((-x, y), None),
-                    PixelDelta(d) => d,
-                };
+                let delta = delta.to_offset(mgr.config(), None);
                 moved = self.scroll_by_delta(mgr, delta);
             }
             Event::PressStart { source, coord, .. }
@@ -453,3 +448,120 @@ impl TextInput {
         }
     }
 }
+
+/// Keyboard type-ahead search
+///
+/// Containers may use this to move focus to the next item whose label starts
+/// with text typed by the user (see [`Event::ReceivedCharacter`]; this input
+/// must be requested via [`EventState::request_type_ahead`]). Characters
+/// are accumulated while each is typed within [`TypeAhead::TIMEOUT`] of the
+/// previous; after this timeout the search text is reset.
+///
+/// Matching is case-insensitive. Typing the same character repeatedly cycles
+/// through items starting with that character. Searches start from the
+/// current item and wrap from the last item back to the first.
+#[derive(Clone, Debug, Default)]
+pub struct TypeAhead {
+    text: String,
+    last: Option<Instant>,
+}
+
+impl TypeAhead {
+    /// Maximum delay between characters for these to be combined
+    pub const TIMEOUT: Duration = Duration::from_millis(1000);
+
+    /// Handle a typed character
+    ///
+    /// Inputs:
+    ///
+    /// -   `c`: the typed character
+    /// -   `from`: index of the current item (e.g. that with navigation focus)
+    /// -   `len`: number of items
+    /// -   `label`: label of item `index`, if any
+    ///
+    /// Returns the index of the matching item, if any.
+    pub fn search<'a, F>(
+        &mut self,
+        c: char,
+        from: Option<usize>,
+        len: usize,
+        label: F,
+    ) -> Option<usize>
+    where
+        F: Fn(usize) -> Option<&'a str>,
+    {
+        self.search_at(Instant::now(), c, from, len, label)
+    }
+
+    fn search_at<'a, F>(
+        &mut self,
+        now: Instant,
+        c: char,
+        from: Option<usize>,
+        len: usize,
+        label: F,
+    ) -> Option<usize>
+    where
+        F: Fn(usize) -> Option<&'a str>,
+    {
+        if self
+            .last
+            .map(|last| now - last > Self::TIMEOUT)
+            .unwrap_or(true)
+        {
+            self.text.clear();
+        }
+        if self.text.is_empty() && c.is_whitespace() {
+            return None;
+        }
+        self.last = Some(now);
+        self.text.extend(c.to_lowercase());
+
+        let mut chars = self.text.chars();
+        let first = chars.next()?;
+        let (prefix, start) = if chars.all(|c| c == first) {
+            // Cycle through items starting with this character
+            let prefix = &self.text[..first.len_utf8()];
+            (prefix, from.map(|index| index + 1).unwrap_or(0))
+        } else {
+            (self.text.as_str(), from.unwrap_or(0))
+        };
+
+        (0..len).map(|i| (start + i) % len).find(|index| {
+            label(*index)
+                .map(|s| s.to_lowercase().starts_with(prefix))
+                .unwrap_or(false)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn type_ahead() {
+        let labels = ["Apple", "banana", "Blueberry", "cherry", "apricot"];
+        let label = |index: usize| labels.get(index).cloned();
+        let len = labels.len();
+        let mut ta = TypeAhead::default();
+        let t0 = Instant::now();
+        let ms = |n| t0 + Duration::from_millis(n);
+
+        // Repeated characters cycle, wrapping at the end
+        assert_eq!(ta.search_at(ms(0), 'a', None, len, label), Some(0));
+        assert_eq!(ta.search_at(ms(100), 'a', Some(0), len, label), Some(4));
+        assert_eq!(ta.search_at(ms(200), 'A', Some(4), len, label), Some(0));
+
+        // After the timeout, the search text is reset
+        let t = 200 + 1100;
+        assert_eq!(ta.search_at(ms(t), 'b', Some(0), len, label), Some(1));
+        // Longer prefixes search from the current item
+        assert_eq!(ta.search_at(ms(t + 100), 'l', Some(1), len, label), Some(2));
+        assert_eq!(ta.search_at(ms(t + 200), 'x', Some(2), len, label), None);
+
+        let t = t + 2000;
+        assert_eq!(ta.search_at(ms(t), ' ', Some(2), len, label), None);
+        assert_eq!(ta.search_at(ms(t), 'c', Some(2), len, label), Some(3));
+    }
+}
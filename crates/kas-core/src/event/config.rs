@@ -22,7 +22,9 @@ use std::time::Duration;
 /// This is serializable (using `feature = "config"`) with the following fields:
 ///
 /// > `menu_delay_ms`: `u32` (milliseconds) \
+/// > `tooltip_delay_ms`: `u32` (milliseconds) \
 /// > `touch_select_delay_ms`: `u32` (milliseconds) \
+/// > `double_click_ms`: `u32` (milliseconds) \
 /// > `scroll_flick_timeout_ms`: `u32` (milliseconds) \
 /// > `scroll_flick_mul`: `f32` (unitless, applied each second) \
 /// > `scroll_flick_sub`: `f32` (pixels per second) \
@@ -34,15 +36,31 @@ use std::time::Duration;
 /// > `shortcuts`: [`Shortcuts`]
 ///
 /// For descriptions of configuration effects, see [`WindowConfig`] methods.
+///
+/// Configuration may be changed at run-time via [`Config::update`] (for
+/// example, through [`EventState::update_config`]); windows detect the change
+/// and recompute derived values. Changes made by assigning fields directly
+/// are not detected.
+///
+/// [`EventState::update_config`]: super::EventState::update_config
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "config", derive(Serialize, Deserialize))]
 pub struct Config {
+    #[cfg_attr(feature = "config", serde(skip))]
+    version: u64,
+
     #[cfg_attr(feature = "config", serde(default = "defaults::menu_delay_ms"))]
     pub menu_delay_ms: u32,
 
+    #[cfg_attr(feature = "config", serde(default = "defaults::tooltip_delay_ms"))]
+    pub tooltip_delay_ms: u32,
+
     #[cfg_attr(feature = "config", serde(default = "defaults::touch_select_delay_ms"))]
     pub touch_select_delay_ms: u32,
 
+    #[cfg_attr(feature = "config", serde(default = "defaults::double_click_ms"))]
+    pub double_click_ms: u32,
+
     #[cfg_attr(feature = "config", serde(default = "defaults::scroll_lines"))]
     pub scroll_lines: f32,
 
@@ -78,8 +96,11 @@ pub struct Config {
 impl Default for Config {
     fn default() -> Self {
         Config {
+            version: 0,
             menu_delay_ms: defaults::menu_delay_ms(),
+            tooltip_delay_ms: defaults::tooltip_delay_ms(),
             touch_select_delay_ms: defaults::touch_select_delay_ms(),
+            double_click_ms: defaults::double_click_ms(),
             scroll_lines: defaults::scroll_lines(),
             scroll_flick_timeout_ms: defaults::scroll_flick_timeout_ms(),
             scroll_flick_mul: defaults::scroll_flick_mul(),
@@ -98,6 +119,8 @@ impl Default for Config {
 #[derive(Clone, Debug)]
 pub struct WindowConfig {
     config: Rc<RefCell<Config>>,
+    version: u64,
+    scale_factor: f32,
     scroll_dist: f32,
     scroll_flick_sub: f32,
     pan_dist_thresh: f32,
@@ -110,6 +133,8 @@ impl WindowConfig {
     pub fn new(config: Rc<RefCell<Config>>, scale_factor: f32) -> Self {
         let mut w = WindowConfig {
             config,
+            version: 0,
+            scale_factor,
             scroll_dist: f32::NAN,
             scroll_flick_sub: f32::NAN,
             pan_dist_thresh: f32::NAN,
@@ -122,8 +147,35 @@ impl WindowConfig {
     #[cfg_attr(not(feature = "internal_doc"), doc(hidden))]
     #[cfg_attr(doc_cfg, doc(cfg(internal_doc)))]
     pub fn set_scale_factor(&mut self, scale_factor: f32) {
+        self.scale_factor = scale_factor;
+        self.recompute();
+    }
+
+    /// Recompute derived values if the base config has changed
+    #[cfg_attr(not(feature = "internal_doc"), doc(hidden))]
+    #[cfg_attr(doc_cfg, doc(cfg(internal_doc)))]
+    #[inline]
+    pub fn refresh(&mut self) {
+        if self.config.borrow().version != self.version {
+            self.recompute();
+        }
+    }
+
+    /// Update the base config
+    ///
+    /// See [`Config::update`]. Derived values of this window's config are
+    /// recomputed immediately; other windows sharing the base config do so
+    /// on their next event.
+    pub(crate) fn update_base<F: FnOnce(&mut Config)>(&mut self, f: F) {
+        self.config.borrow_mut().update(f);
+        self.recompute();
+    }
+
+    fn recompute(&mut self) {
         let base = self.config.borrow();
+        let scale_factor = self.scale_factor;
         const LINE_HEIGHT: f32 = 19.0; // TODO: maybe we shouldn't assume this?
+        self.version = base.version;
         self.scroll_dist = base.scroll_lines * LINE_HEIGHT;
         self.scroll_flick_sub = base.scroll_flick_sub * scale_factor;
         self.pan_dist_thresh = base.pan_dist_thresh * scale_factor;
@@ -135,12 +187,27 @@ impl WindowConfig {
         Duration::from_millis(self.config.borrow().menu_delay_ms.cast())
     }
 
+    /// Delay before showing a tooltip while the mouse cursor rests on a widget
+    #[inline]
+    pub fn tooltip_delay(&self) -> Duration {
+        Duration::from_millis(self.config.borrow().tooltip_delay_ms.cast())
+    }
+
     /// Delay before switching from panning to (text) selection mode
     #[inline]
     pub fn touch_select_delay(&self) -> Duration {
         Duration::from_millis(self.config.borrow().touch_select_delay_ms.cast())
     }
 
+    /// Maximum delay between clicks for these to count as a multi-click
+    ///
+    /// Subsequent clicks with the same mouse button within this time increase
+    /// the repetition count (e.g. double-click).
+    #[inline]
+    pub fn double_click_timeout(&self) -> Duration {
+        Duration::from_millis(self.config.borrow().double_click_ms.cast())
+    }
+
     /// Get distance in pixels to scroll due to mouse wheel
     ///
     /// Calculates scroll distance from `(horiz, vert)` lines.
@@ -228,10 +295,30 @@ impl WindowConfig {
 
 /// Other functions
 impl Config {
+    /// Change the config
+    ///
+    /// Calls `f` to modify the config, then marks the config as changed
+    /// (see [`Self::is_dirty`] and [`Self::version`]).
+    pub fn update<F: FnOnce(&mut Self)>(&mut self, f: F) {
+        f(self);
+        self.version = self.version.wrapping_add(1);
+    }
+
     /// Has the config ever been updated?
+    ///
+    /// This is true after any call to [`Self::update`].
     #[inline]
     pub fn is_dirty(&self) -> bool {
-        false // current code never updates config
+        self.version != 0
+    }
+
+    /// Get the version
+    ///
+    /// This is incremented by each call to [`Self::update`]; windows compare
+    /// it to detect changes.
+    #[inline]
+    pub fn version(&self) -> u64 {
+        self.version
     }
 }
 
@@ -276,9 +363,15 @@ mod defaults {
     pub fn menu_delay_ms() -> u32 {
         250
     }
+    pub fn tooltip_delay_ms() -> u32 {
+        800
+    }
     pub fn touch_select_delay_ms() -> u32 {
         1000
     }
+    pub fn double_click_ms() -> u32 {
+        1000
+    }
     pub fn scroll_lines() -> f32 {
         3.0
     }
@@ -314,3 +407,28 @@ mod defaults {
         true
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn update_shared_config() {
+        let config = Rc::new(RefCell::new(Config::default()));
+        assert!(!config.borrow().is_dirty());
+        let mut w1 = WindowConfig::new(config.clone(), 1.0);
+        let mut w2 = WindowConfig::new(config.clone(), 2.0);
+        assert_eq!(w2.pan_dist_thresh(), 2.0 * defaults::pan_dist_thresh());
+
+        w1.update_base(|c| c.pan_dist_thresh = 5.0);
+        assert!(config.borrow().is_dirty());
+        assert_eq!(w1.pan_dist_thresh(), 5.0);
+        // w2 still has the old derived value until refreshed
+        assert_eq!(w2.pan_dist_thresh(), 2.0 * defaults::pan_dist_thresh());
+        w2.refresh();
+        assert_eq!(w2.pan_dist_thresh(), 10.0);
+
+        w2.set_scale_factor(1.5);
+        assert_eq!(w2.pan_dist_thresh(), 7.5);
+    }
+}
@@ -84,6 +84,17 @@ impl Shortcuts {
                 (VK::Down, Command::NavDown),
             ];
             map.extend(shortcuts.iter().cloned());
+
+            // Shift + Alt
+            let modifiers = ModifiersState::SHIFT | ModifiersState::ALT;
+            let map = self.map.entry(modifiers).or_insert_with(Default::default);
+            let shortcuts = [
+                (VK::Left, Command::MoveLeft),
+                (VK::Right, Command::MoveRight),
+                (VK::Up, Command::MoveUp),
+                (VK::Down, Command::MoveDown),
+            ];
+            map.extend(shortcuts.iter().cloned());
         }
         #[cfg(target_os = "macos")]
         {
@@ -196,6 +207,13 @@ impl Shortcuts {
             let modifiers = ModifiersState::ALT | CMD;
             let map = self.map.entry(modifiers).or_insert_with(Default::default);
             map.insert(VK::W, Command::Exit);
+            let shortcuts = [
+                (VK::Left, Command::MoveLeft),
+                (VK::Right, Command::MoveRight),
+                (VK::Up, Command::MoveUp),
+                (VK::Down, Command::MoveDown),
+            ];
+            map.extend(shortcuts.iter().cloned());
         }
     }
 
@@ -378,3 +396,42 @@ impl<'de> Deserialize<'de> for Shortcuts {
         d.deserialize_map(ShortcutsVisitor)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn move_commands_do_not_replace_alt_arrows() {
+        use VirtualKeyCode as VK;
+        let shortcuts = Shortcuts::platform_defaults();
+        let alt = ModifiersState::ALT;
+        #[cfg(not(target_os = "macos"))]
+        {
+            assert_eq!(shortcuts.get(alt, VK::Left), Some(Command::NavPrev));
+            assert_eq!(shortcuts.get(alt, VK::Right), Some(Command::NavNext));
+            assert_eq!(shortcuts.get(alt, VK::Up), Some(Command::NavParent));
+            assert_eq!(shortcuts.get(alt, VK::Down), Some(Command::NavDown));
+        }
+        #[cfg(target_os = "macos")]
+        {
+            assert_eq!(shortcuts.get(alt, VK::Left), Some(Command::WordLeft));
+            assert_eq!(shortcuts.get(alt, VK::Up), None);
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        let modifiers = ModifiersState::SHIFT | alt;
+        #[cfg(target_os = "macos")]
+        let modifiers = ModifiersState::LOGO | alt;
+        let keys = [VK::Left, VK::Right, VK::Up, VK::Down];
+        let cmds = [
+            Command::MoveLeft,
+            Command::MoveRight,
+            Command::MoveUp,
+            Command::MoveDown,
+        ];
+        for (key, cmd) in keys.into_iter().zip(cmds) {
+            assert_eq!(shortcuts.get(modifiers, key), Some(cmd));
+        }
+    }
+}
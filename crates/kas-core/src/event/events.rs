@@ -8,6 +8,7 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::config::WindowConfig;
 #[allow(unused)]
 use super::{EventMgr, EventState, GrabMode, Response}; // for doc-links
 use super::{MouseButton, UpdateId, VirtualKeyCode};
@@ -43,10 +44,17 @@ pub enum Event {
     LostSelFocus,
     /// Widget receives a character of text input
     ///
-    /// This is only received by a widget with character focus (see
-    /// [`EventState::request_char_focus`]). There is no overlap with
-    /// [`Event::Command`]: key presses result in at most one of these events
-    /// being sent to a widget.
+    /// This is received by the widget with character focus (see
+    /// [`EventState::request_char_focus`]), if any. Otherwise, if no modifier
+    /// key (other than <kbd>Shift</kbd>) is held, this is sent to the
+    /// innermost ancestor of the navigation focus which requested type-ahead
+    /// text (see [`EventState::request_type_ahead`]), if any; containers may
+    /// use this for type-ahead search (see [`components::TypeAhead`]).
+    ///
+    /// There is no overlap with [`Event::Command`]: key presses result in at
+    /// most one of these events being sent to a widget.
+    ///
+    /// [`components::TypeAhead`]: super::components::TypeAhead
     ReceivedCharacter(char),
     /// A mouse or touchpad scroll event
     Scroll(ScrollDelta),
@@ -199,6 +207,50 @@ pub enum Event {
     /// automatically sets `Scroll::Rect(widget.rect())` to
     /// [`EventMgr::set_scroll`] and considers the event used.
     NavFocus(bool),
+    /// A drag-and-drop payload is over this widget
+    ///
+    /// This event is sent to the widget under the mouse cursor each time the
+    /// cursor moves during a drag operation (see [`EventMgr::start_drag`]).
+    /// The payload may be inspected via [`EventState::drag_payload`].
+    /// The widget should return [`Response::Used`] if it would accept a drop.
+    ///
+    /// `source` is the widget which started the drag.
+    DragOver { source: WidgetId, coord: Coord },
+    /// A drag-and-drop payload left this widget
+    ///
+    /// This is sent to a widget which used the last [`Event::DragOver`] when
+    /// the cursor moves elsewhere or when the drag is cancelled.
+    DragLeave,
+    /// A drag-and-drop payload was dropped on this widget
+    ///
+    /// The payload may be taken via [`EventMgr::take_drag_payload`]. The widget
+    /// should return [`Response::Used`] if the drop was accepted.
+    DragDrop { source: WidgetId, coord: Coord },
+    /// End of a drag-and-drop operation
+    ///
+    /// This is sent to the widget which called [`EventMgr::start_drag`] once
+    /// the operation finishes. `accepted` is true only if a target used the
+    /// [`Event::DragDrop`] event; it is false if the drag was cancelled (e.g.
+    /// via the Escape key) or dropped outside of any accepting target.
+    DragEnd { accepted: bool },
+    /// The mouse cursor entered this widget
+    ///
+    /// Sent when this widget becomes the hovered widget, i.e. the widget found
+    /// by [`crate::Layout::find_id`] at the mouse cursor's position. This is
+    /// followed by [`Event::MouseLeave`] when the cursor moves to another
+    /// widget or leaves the window.
+    ///
+    /// While a mouse grab is active (see [`EventMgr::grab_press`]) these
+    /// events are deferred: on release of the grab, the widget last notified
+    /// receives [`Event::MouseLeave`] and the widget under the cursor receives
+    /// [`Event::MouseEnter`] (only if these differ).
+    ///
+    /// These events are also sent to disabled widgets.
+    MouseEnter,
+    /// The mouse cursor left this widget
+    ///
+    /// See [`Event::MouseEnter`].
+    MouseLeave,
 }
 
 impl std::ops::Add<Offset> for Event {
@@ -223,6 +275,12 @@ impl std::ops::AddAssign<Offset> for Event {
             Event::PressEnd { coord, .. } => {
                 *coord += offset;
             }
+            Event::DragOver { coord, .. } => {
+                *coord += offset;
+            }
+            Event::DragDrop { coord, .. } => {
+                *coord += offset;
+            }
             _ => (),
         }
     }
@@ -241,9 +299,27 @@ impl Event {
         mgr: &mut EventMgr,
         id: WidgetId,
         f: F,
+    ) -> Response {
+        self.on_activate_repeated(mgr, id, |mgr, _| f(mgr))
+    }
+
+    /// Call `f` on any "activation" event, passing the repetition count
+    ///
+    /// This is identical to [`Event::on_activate`] except that `f` is passed
+    /// the number of repetitions: 1 for a single click, 2 for a double click,
+    /// etc. (see [`PressSource::repetitions`]). Each click of a double click
+    /// is reported: first with 1, then with 2.
+    ///
+    /// Activation via `Event::Command` always reports 1. Touch events also
+    /// always report 1: double-tap is not currently detected.
+    pub fn on_activate_repeated<F: FnOnce(&mut EventMgr, u32) -> Response>(
+        self,
+        mgr: &mut EventMgr,
+        id: WidgetId,
+        f: F,
     ) -> Response {
         match self {
-            Event::Command(cmd) if cmd.is_activate() => f(mgr),
+            Event::Command(cmd) if cmd.is_activate() => f(mgr, 1),
             Event::PressStart { source, coord, .. } if source.is_primary() => {
                 mgr.grab_press(id, source, coord, GrabMode::Grab, None);
                 Response::Used
@@ -254,8 +330,11 @@ impl Event {
                 Response::Used
             }
             Event::PressEnd {
-                end_id, success, ..
-            } if success && id == end_id => f(mgr),
+                source,
+                end_id,
+                success,
+                ..
+            } if success && id == end_id => f(mgr, source.repetitions()),
             Event::PressEnd { .. } => Response::Used,
             _ => Response::Unused,
         }
@@ -274,6 +353,9 @@ impl Event {
             PressStart { .. } | PressMove { .. } | PressEnd { .. } => false,
             TimerUpdate(_) | Update { .. } | PopupRemoved(_) => true,
             NavFocus(_) => false,
+            DragOver { .. } | DragDrop { .. } => false,
+            DragLeave | DragEnd { .. } => true,
+            MouseEnter | MouseLeave => true,
         }
     }
 }
@@ -421,6 +503,24 @@ pub enum Command {
     /// This is an opposite to `NavParent`, and will mostly not be used.
     NavDown,
 
+    /// Move the focused item up (e.g. to reorder a list)
+    ///
+    /// Unlike most commands, this is sent to the widget with navigation focus
+    /// even while <kbd>Alt</kbd> is held.
+    MoveUp,
+    /// Move the focused item down
+    ///
+    /// See [`Command::MoveUp`].
+    MoveDown,
+    /// Move the focused item left
+    ///
+    /// See [`Command::MoveUp`].
+    MoveLeft,
+    /// Move the focused item right
+    ///
+    /// See [`Command::MoveUp`].
+    MoveRight,
+
     /// Open a new tab
     TabNew,
     /// Navigate to next tab
@@ -511,6 +611,57 @@ impl Command {
             _ => None,
         }
     }
+
+    /// Convert a direction to an arrow key
+    ///
+    /// This is the inverse of [`Self::as_direction`].
+    pub fn from_direction(dir: Direction) -> Self {
+        match dir {
+            Direction::Left => Command::Left,
+            Direction::Right => Command::Right,
+            Direction::Up => Command::Up,
+            Direction::Down => Command::Down,
+        }
+    }
+
+    /// True for navigation commands
+    ///
+    /// This includes arrow keys, <kbd>Home</kbd>/<kbd>End</kbd> (also
+    /// [`Self::DocHome`] and [`Self::DocEnd`]),
+    /// <kbd>PageUp</kbd>/<kbd>PageDown</kbd> and the `Nav*` commands (e.g.
+    /// [`Self::NavNext`]).
+    pub fn is_navigation(self) -> bool {
+        use Command::*;
+        matches!(
+            self,
+            Left | Right
+                | Up
+                | Down
+                | Home
+                | End
+                | DocHome
+                | DocEnd
+                | PageUp
+                | PageDown
+                | NavNext
+                | NavPrev
+                | NavParent
+                | NavDown
+        )
+    }
+
+    /// Convert item-move commands to a direction
+    ///
+    /// This matches [`Self::MoveUp`] and similar.
+    pub fn as_move_direction(self) -> Option<Direction> {
+        match self {
+            Command::MoveLeft => Some(Direction::Left),
+            Command::MoveRight => Some(Direction::Right),
+            Command::MoveUp => Some(Direction::Up),
+            Command::MoveDown => Some(Direction::Down),
+            _ => None,
+        }
+    }
 }
 
 /// Source of `EventChild::Press`
@@ -563,6 +714,9 @@ impl PressSource {
 }
 
 /// Type used by [`Event::Scroll`]
+///
+/// On both axes, positive values move content right / down, thus reducing the
+/// scroll offset. Use [`ScrollDelta::to_offset`] to convert to pixels.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ScrollDelta {
     /// Scroll a given number of lines
@@ -570,3 +724,57 @@ pub enum ScrollDelta {
     /// Scroll a given number of pixels
     PixelDelta(Offset),
 }
+
+impl ScrollDelta {
+    /// Convert to a pixel offset
+    ///
+    /// `PixelDelta` is returned unchanged. `LineDelta` is converted using
+    /// [`WindowConfig::scroll_distance`]; if `line_height` is provided, scroll
+    /// distance is based on this value.
+    pub fn to_offset(&self, config: &WindowConfig, line_height: Option<f32>) -> Offset {
+        match *self {
+            ScrollDelta::LineDelta(x, y) => config.scroll_distance((x, y), line_height),
+            ScrollDelta::PixelDelta(offset) => offset,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn command_direction() {
+        for dir in [
+            Direction::Left,
+            Direction::Right,
+            Direction::Up,
+            Direction::Down,
+        ] {
+            let cmd = Command::from_direction(dir);
+            assert_eq!(cmd.as_direction(), Some(dir));
+            assert!(cmd.is_navigation());
+        }
+    }
+
+    #[test]
+    fn command_is_navigation() {
+        use Command::*;
+        let nav = [
+            Left, Right, Up, Down, Home, End, DocHome, DocEnd, PageUp, PageDown, NavNext, NavPrev,
+            NavParent, NavDown,
+        ];
+        for cmd in nav {
+            assert!(cmd.is_navigation(), "{:?}", cmd);
+        }
+
+        let not_nav = [
+            Escape, Activate, Return, Space, Tab, ViewUp, ViewDown, WordLeft, WordRight, Delete,
+            DelBack, SelectAll, Cut, Copy, Paste, MoveUp, MoveDown, MoveLeft, MoveRight, TabNext,
+            TabPrev, Menu, Close,
+        ];
+        for cmd in not_nav {
+            assert!(!cmd.is_navigation(), "{:?}", cmd);
+        }
+    }
+}
@@ -13,21 +13,59 @@ use log::{trace, warn};
 use smallvec::SmallVec;
 use std::any::Any;
 use std::cell::RefCell;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::future::Future;
 use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
 use std::rc::Rc;
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
 use std::time::Instant;
 use std::u16;
 
 use super::config::WindowConfig;
 use super::*;
 use crate::cast::Cast;
-use crate::geom::{Coord, Offset};
+use crate::dir::Direction;
+use crate::geom::{Coord, Offset, Rect};
 use crate::{ShellWindow, TkAction, Widget, WidgetExt, WidgetId, WindowId};
 
 mod mgr_pub;
 mod mgr_shell;
 
+/// Counts of requested [`TkAction`]s
+///
+/// Each call to [`EventState::send_action`] (including via `*mgr |= action`
+/// and [`EventState::redraw`]) increments each matching counter once, even if
+/// the requested action was already pending. This is intended for diagnosing
+/// widgets requesting excessive updates; see [`EventState::action_stats`].
+#[cfg(feature = "action_stats")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "action_stats")))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ActionStats {
+    /// Requests including [`TkAction::REDRAW`] or [`TkAction::REGION_MOVED`]
+    pub redraw: u32,
+    /// Requests including [`TkAction::RESIZE`] or [`TkAction::SET_SIZE`]
+    pub resize: u32,
+    /// Requests including [`TkAction::RECONFIGURE`]
+    pub reconfigure: u32,
+}
+
+#[cfg(feature = "action_stats")]
+impl ActionStats {
+    fn count(&mut self, action: TkAction) {
+        if action.intersects(TkAction::REDRAW | TkAction::REGION_MOVED) {
+            self.redraw += 1;
+        }
+        if action.intersects(TkAction::RESIZE | TkAction::SET_SIZE) {
+            self.resize += 1;
+        }
+        if action.contains(TkAction::RECONFIGURE) {
+            self.reconfigure += 1;
+        }
+    }
+}
+
 /// Controls the types of events delivered by [`EventMgr::grab_press`]
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum GrabMode {
@@ -115,12 +153,32 @@ struct PanGrab {
     coords: [(Coord, Coord); MAX_PAN_GRABS],
 }
 
+#[derive(Debug)]
+struct DragState {
+    source: WidgetId,
+    payload: Box<dyn Any>,
+    icon: CursorIcon,
+    // The widget under the cursor, if it used the last Event::DragOver
+    target: Option<WidgetId>,
+}
+
 #[derive(Clone, Debug)]
-#[allow(clippy::enum_variant_names)] // they all happen to be about Focus
 enum Pending {
     LostCharFocus(WidgetId),
     LostSelFocus(WidgetId),
     SetNavFocus(WidgetId, bool),
+    DragLeave(WidgetId),
+    DragEnd(WidgetId),
+    MouseEnter(WidgetId),
+    MouseLeave(WidgetId),
+}
+
+// Region of the window requiring a redraw
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Damage {
+    None,
+    Region(Rect),
+    Full,
 }
 
 type AccelLayer = (bool, HashMap<VirtualKeyCode, WidgetId>);
@@ -154,6 +212,8 @@ pub struct EventState {
     nav_focus: Option<WidgetId>,
     nav_fallback: Option<WidgetId>,
     hover: Option<WidgetId>,
+    // Widget last sent Event::MouseEnter (may lag behind hover during grabs)
+    hover_notified: Option<WidgetId>,
     hover_icon: CursorIcon,
     key_depress: LinearMap<u32, WidgetId>,
     last_mouse_coord: Coord,
@@ -163,12 +223,34 @@ pub struct EventState {
     mouse_grab: Option<MouseGrab>,
     touch_grab: SmallVec<[TouchGrab; 8]>,
     pan_grab: SmallVec<[PanGrab; 4]>,
+    drag: Option<DragState>,
+    // Payload of a drag being dropped; only set while sending Event::DragDrop
+    drop_payload: Option<Box<dyn Any>>,
     accel_layers: BTreeMap<WidgetId, AccelLayer>,
+    // Widgets requesting type-ahead text (see request_type_ahead)
+    type_ahead: BTreeSet<WidgetId>,
     // For each: (WindowId of popup, popup descriptor, old nav focus)
     popups: SmallVec<[(WindowId, crate::Popup, Option<WidgetId>); 16]>,
     popup_removed: SmallVec<[(WidgetId, WindowId); 16]>,
+    // Futures whose result is pushed as a message from the widget on completion
+    fut_messages: Vec<(AsyncId, WidgetId, MsgFuture)>,
+    next_async_id: u64,
+    workers: WorkerPool,
     time_updates: Vec<(Instant, WidgetId, u64)>,
     pending: SmallVec<[Pending; 8]>,
+    damage: Damage,
+    // Tooltips: host widget (registered by the root widget), pending (time,
+    // owner), pop-up of the tooltip being shown, its text and the mouse
+    // position when shown
+    tooltip_host: Option<WidgetId>,
+    tooltip_pending: Option<(Instant, WidgetId)>,
+    tooltip_shown: Option<WindowId>,
+    tooltip_text: String,
+    tooltip_coord: Coord,
+    #[cfg(feature = "action_stats")]
+    action_stats: ActionStats,
+    #[cfg(feature = "action_stats")]
+    last_action_stats: ActionStats,
     #[cfg_attr(not(feature = "internal_doc"), doc(hidden))]
     #[cfg_attr(doc_cfg, doc(cfg(internal_doc)))]
     pub action: TkAction,
@@ -176,6 +258,22 @@ pub struct EventState {
 
 /// internals
 impl EventState {
+    // Queue Event::MouseLeave / MouseEnter if the hovered widget has changed
+    // since last notified. This is deferred while a mouse grab is active.
+    fn notify_hover(&mut self) {
+        if self.mouse_grab.is_some() || self.hover_notified == self.hover {
+            return;
+        }
+
+        // Note: pending events are handled in reverse order
+        if let Some(id) = self.hover.clone() {
+            self.pending.push(Pending::MouseEnter(id));
+        }
+        if let Some(id) = std::mem::replace(&mut self.hover_notified, self.hover.clone()) {
+            self.pending.push(Pending::MouseLeave(id));
+        }
+    }
+
     #[inline]
     fn char_focus(&self) -> Option<WidgetId> {
         if self.char_focus {
@@ -310,6 +408,32 @@ impl EventState {
         None
     }
 
+    // Drop futures pushed on behalf of widget `id`
+    pub(crate) fn drop_futures_of(&mut self, id: &WidgetId) {
+        self.fut_messages.retain(|(_, fut_id, _)| {
+            let keep = fut_id != id;
+            if !keep {
+                trace!("EventMgr: dropping future of replaced widget {id}");
+            }
+            keep
+        });
+    }
+
+    // Index of the top-most modal pop-up, if any
+    fn modal_index(&self) -> Option<usize> {
+        self.popups.iter().rposition(|(_, popup, _)| popup.modal)
+    }
+
+    // True if `id` is outside of the top-most modal pop-up (and those above)
+    fn is_blocked_by_modal(&self, id: &WidgetId) -> bool {
+        match self.modal_index() {
+            Some(index) => !self.popups[index..]
+                .iter()
+                .any(|(_, popup, _)| popup.id.is_ancestor_of(id)),
+            None => false,
+        }
+    }
+
     fn clear_char_focus(&mut self) {
         trace!("EventMgr::clear_char_focus");
         if let Some(id) = self.char_focus() {
@@ -381,6 +505,118 @@ impl Message {
     }
 }
 
+/// Identifier of a future pushed via [`EventMgr::push_async`]
+///
+/// Identifiers are unique within a window. See [`EventState::cancel_async`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AsyncId(u64);
+
+// A future yielding a message; see EventMgr::push_async
+struct MsgFuture(Pin<Box<dyn Future<Output = Message>>>);
+impl std::fmt::Debug for MsgFuture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MsgFuture")
+    }
+}
+
+// Result of a computation on another thread; see EventMgr::push_spawn
+struct ThreadResult<M> {
+    result: Option<M>,
+    waker: Option<Waker>,
+}
+
+// A future resolving to the result of a computation on another thread
+struct ThreadFuture<M>(Arc<Mutex<ThreadResult<M>>>);
+impl<M> Future for ThreadFuture<M> {
+    type Output = M;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<M> {
+        let mut state = self.0.lock().unwrap();
+        if let Some(result) = state.result.take() {
+            Poll::Ready(result)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+// Maximum number of worker threads per window; see EventMgr::push_spawn
+const MAX_WORKERS: usize = 4;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+#[derive(Default)]
+struct WorkerQueue {
+    jobs: VecDeque<Job>,
+    idle: usize,
+    closed: bool,
+}
+
+// A pool of at most MAX_WORKERS threads, spawned on demand
+//
+// Queued jobs are discarded when the pool is dropped.
+#[derive(Default)]
+struct WorkerPool {
+    queue: Arc<(Mutex<WorkerQueue>, Condvar)>,
+    threads: usize,
+}
+
+impl std::fmt::Debug for WorkerPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WorkerPool")
+            .field("threads", &self.threads)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WorkerPool {
+    fn spawn(&mut self, job: Job) {
+        let (lock, cvar) = &*self.queue;
+        let mut queue = lock.lock().unwrap();
+        queue.jobs.push_back(job);
+        if queue.jobs.len() <= queue.idle {
+            cvar.notify_one();
+        } else if self.threads < MAX_WORKERS {
+            self.threads += 1;
+            let queue = self.queue.clone();
+            std::thread::spawn(move || Self::worker(&queue));
+        }
+        // Otherwise the job waits for a busy worker
+    }
+
+    fn worker(queue: &(Mutex<WorkerQueue>, Condvar)) {
+        let (lock, cvar) = queue;
+        let mut guard = lock.lock().unwrap();
+        loop {
+            if let Some(job) = guard.jobs.pop_front() {
+                drop(guard);
+                // A panicking job must not take down the worker
+                if std::panic::catch_unwind(std::panic::AssertUnwindSafe(job)).is_err() {
+                    warn!("EventMgr: a job spawned via push_spawn panicked");
+                }
+                guard = lock.lock().unwrap();
+            } else if guard.closed {
+                return;
+            } else {
+                guard.idle += 1;
+                guard = cvar.wait(guard).unwrap();
+                guard.idle -= 1;
+            }
+        }
+    }
+}
+
+impl Drop for WorkerPool {
+    fn drop(&mut self) {
+        let (lock, cvar) = &*self.queue;
+        let mut queue = lock.lock().unwrap();
+        queue.jobs.clear();
+        queue.closed = true;
+        cvar.notify_all();
+    }
+}
+
 /// Manager of event-handling and toolkit actions
 ///
 /// An `EventMgr` is in fact a handle around [`EventState`] and [`ShellWindow`]
@@ -425,7 +661,27 @@ impl<'a> Drop for EventMgr<'a> {
 
 /// Internal methods
 impl<'a> EventMgr<'a> {
-    fn set_hover(&mut self, widget: &dyn Widget, w_id: Option<WidgetId>) {
+    // Send a typed character to the char focus or type-ahead target, if any
+    fn received_char(&mut self, widget: &mut dyn Widget, c: char) {
+        let event = Event::ReceivedCharacter(c);
+        if let Some(id) = self.state.char_focus() {
+            self.send_event(widget, id, event);
+        } else if let Some(nav) = self.state.nav_focus.as_ref() {
+            let mods = self.state.modifiers;
+            if mods.ctrl() || mods.alt() || mods.logo() {
+                return;
+            }
+            let root = &WidgetId::ROOT;
+            let target = (self.state.type_ahead.range(root..=nav).rev())
+                .find(|id| id.is_ancestor_of(nav))
+                .cloned();
+            if let Some(id) = target {
+                self.send_event(widget, id, event);
+            }
+        }
+    }
+
+    fn set_hover(&mut self, widget: &dyn Widget, w_id: Option<WidgetId>, coord: Coord) {
         if self.state.hover != w_id {
             trace!("EventMgr: hover = {:?}", w_id);
             if let Some(id) = self.state.hover.take() {
@@ -438,24 +694,78 @@ impl<'a> EventMgr<'a> {
                 }
             }
             self.state.hover = w_id.clone();
+            self.state.notify_hover();
+            self.reset_tooltip(widget);
 
-            if let Some(id) = w_id {
-                let mut icon = Default::default();
-                if !self.is_disabled(&id) {
-                    if let Some(w) = widget.find_widget(&id) {
+            if let Some(id) = w_id.as_ref() {
+                if !self.is_disabled(id) {
+                    if let Some(w) = widget.find_widget(id) {
                         if w.hover_highlight() {
-                            self.redraw(id);
+                            self.redraw(id.clone());
                         }
-                        icon = w.cursor_icon();
                     }
                 }
-                if icon != self.state.hover_icon {
-                    self.state.hover_icon = icon;
-                    if self.state.mouse_grab.is_none() {
-                        self.shell.set_cursor_icon(icon);
-                    }
+            }
+        }
+
+        // The icon may depend on the coordinate, thus is updated on each move
+        if let Some(id) = w_id {
+            let mut icon = Default::default();
+            if !self.is_disabled(&id) {
+                if let Some((w, coord)) = find_widget_coord(widget, &id, coord) {
+                    icon = w.cursor_icon_at(coord);
                 }
             }
+            if icon != self.state.hover_icon {
+                self.state.hover_icon = icon;
+                if self.state.mouse_grab.is_none() {
+                    self.shell.set_cursor_icon(icon);
+                }
+            }
+        }
+    }
+
+    fn hide_tooltip(&mut self) {
+        self.state.tooltip_pending = None;
+        if let Some(wid) = self.state.tooltip_shown.take() {
+            trace!("EventMgr: hide tooltip");
+            self.shell.close_window(wid);
+        }
+    }
+
+    // Hide any tooltip, then schedule a tooltip for the hovered widget
+    fn reset_tooltip(&mut self, widget: &dyn Widget) {
+        self.hide_tooltip();
+        if self.state.tooltip_host.is_none()
+            || self.state.mouse_grab.is_some()
+            || !self.state.popups.is_empty()
+        {
+            return;
+        }
+
+        let hover = match self.state.hover.clone() {
+            Some(id) => id,
+            None => return,
+        };
+        if let Some(w) = find_tooltip(widget, &hover) {
+            if w.tooltip_when_disabled() || !self.is_disabled(w.id_ref()) {
+                let time = Instant::now() + self.state.config.tooltip_delay();
+                self.state.tooltip_pending = Some((time, w.id()));
+            }
+        }
+    }
+
+    fn show_tooltip(&mut self, widget: &dyn Widget, id: WidgetId) {
+        let host = match self.state.tooltip_host.clone() {
+            Some(host) => host,
+            None => return,
+        };
+        if let Some(text) = widget.find_widget(&id).and_then(|w| w.tooltip()) {
+            trace!("EventMgr: show tooltip for {}", id);
+            self.state.tooltip_text = text.to_string();
+            self.state.tooltip_coord = self.state.last_mouse_coord;
+            let popup = crate::Popup::new(host, id, Direction::Down);
+            self.state.tooltip_shown = self.shell.add_popup(popup);
         }
     }
 
@@ -469,6 +779,10 @@ impl<'a> EventMgr<'a> {
 
         use VirtualKeyCode as VK;
 
+        if vkey == VK::Escape && self.cancel_drag() {
+            return;
+        }
+
         let opt_command = self
             .state
             .config
@@ -477,7 +791,9 @@ impl<'a> EventMgr<'a> {
         if let Some(cmd) = opt_command {
             let mut targets = vec![];
             let mut send = |_self: &mut Self, id: WidgetId, cmd| -> bool {
-                if !targets.contains(&id) {
+                if _self.state.is_blocked_by_modal(&id) {
+                    false
+                } else if !targets.contains(&id) {
                     let used = _self.send_event(widget, id.clone(), Event::Command(cmd));
                     if used {
                         _self.add_key_depress(scancode, id.clone());
@@ -497,7 +813,7 @@ impl<'a> EventMgr<'a> {
                 }
             }
 
-            if !self.state.modifiers.alt() {
+            if !self.state.modifiers.alt() || cmd.as_move_direction().is_some() {
                 if let Some(id) = self.state.nav_focus.clone() {
                     if send(self, id, cmd) {
                         return;
@@ -527,11 +843,16 @@ impl<'a> EventMgr<'a> {
         }
 
         // Next priority goes to accelerator keys when Alt is held or alt_bypass is true
+        // Layers below the top-most modal pop-up are excluded.
+        let (start, root) = match self.state.modal_index() {
+            Some(index) => (index, None),
+            None => (0, Some(widget.id())),
+        };
         let mut target = None;
         let mut n = 0;
-        for (i, id) in (self.state.popups.iter().rev())
+        for (i, id) in (self.state.popups[start..].iter().rev())
             .map(|(_, popup, _)| popup.parent.clone())
-            .chain(std::iter::once(widget.id()))
+            .chain(root)
             .enumerate()
         {
             if let Some(layer) = self.state.accel_layers.get(&id) {
@@ -577,9 +898,13 @@ impl<'a> EventMgr<'a> {
     }
 
     // Clears mouse grab and pan grab, resets cursor and redraws
+    //
+    // Any active drag is cancelled.
     fn remove_mouse_grab(&mut self) -> Option<MouseGrab> {
         if let Some(grab) = self.state.mouse_grab.take() {
             trace!("EventMgr: end mouse grab by {}", grab.start_id);
+            self.cancel_drag();
+            self.state.notify_hover();
             self.shell.set_cursor_icon(self.state.hover_icon);
             self.send_action(TkAction::REDRAW); // redraw(..)
             self.state.remove_pan_grab(grab.pan_grab);
@@ -637,6 +962,53 @@ impl<'a> EventMgr<'a> {
         response
     }
 
+    // Deliver a message as if pushed by widget `id` while handling an event
+    fn replay_msg(&mut self, widget: &mut dyn Widget, id: WidgetId, msg: Message) {
+        if let Some(index) = widget.find_child_index(&id) {
+            if let Some(w) = widget.get_child_mut(index) {
+                self.replay_msg(w, id, msg);
+            } else {
+                warn!(
+                    "Widget {} found index {index} for {id}, but child not found",
+                    widget.identify()
+                );
+            }
+
+            if self.has_msg() {
+                widget.handle_message(self, index);
+            }
+        } else if id == widget.id_ref() {
+            self.messages.push(msg);
+        } else {
+            warn!("Widget {} cannot find path to {id}", widget.identify());
+        }
+    }
+
+    // Poll pending futures, delivering the results of those which complete
+    //
+    // Futures belonging to widgets which no longer exist are dropped.
+    fn poll_futures(&mut self, widget: &mut dyn Widget) {
+        let waker = self.shell.waker();
+        let mut cx = Context::from_waker(&waker);
+        let mut i = 0;
+        while i < self.state.fut_messages.len() {
+            let (_, id, fut) = &mut self.state.fut_messages[i];
+            if widget.find_widget(id).is_none() {
+                trace!("EventMgr: dropping future of removed widget {id}");
+                self.state.fut_messages.remove(i);
+                continue;
+            }
+
+            match fut.0.as_mut().poll(&mut cx) {
+                Poll::Pending => i += 1,
+                Poll::Ready(msg) => {
+                    let (_, id, _) = self.state.fut_messages.remove(i);
+                    self.replay_msg(widget, id, msg);
+                }
+            }
+        }
+    }
+
     // Traverse widget tree by recursive call, broadcasting
     fn send_all(&mut self, widget: &mut dyn Widget, event: Event) -> usize {
         let child_event = event.clone() + widget.translation();
@@ -656,13 +1028,117 @@ impl<'a> EventMgr<'a> {
         self.send(widget, id, event) == Response::Used
     }
 
+    // Update the drag target given the widget under the mouse cursor
+    fn drag_move(&mut self, widget: &mut dyn Widget, cur_id: Option<WidgetId>, coord: Coord) {
+        let (source, old_target) = match self.state.drag.as_mut() {
+            Some(drag) if drag.target != cur_id => (drag.source.clone(), drag.target.take()),
+            Some(drag) => (drag.source.clone(), None),
+            None => return,
+        };
+
+        // Only a target which accepted the payload is told that it left
+        if let Some(id) = old_target {
+            self.send_event(widget, id, Event::DragLeave);
+        }
+        let accepted = match cur_id.clone() {
+            Some(id) => self.send_event(widget, id, Event::DragOver { source, coord }),
+            None => false,
+        };
+
+        // The handler may have cancelled the drag
+        if let Some(drag) = self.state.drag.as_mut() {
+            drag.target = if accepted { cur_id } else { None };
+            let icon = if accepted {
+                drag.icon
+            } else {
+                CursorIcon::NoDrop
+            };
+            self.shell.set_cursor_icon(icon);
+        }
+    }
+
+    // End the drag (if any), dropping the payload on success
+    fn end_drag(&mut self, widget: &mut dyn Widget, success: bool, coord: Coord) {
+        let drag = match self.state.drag.take() {
+            Some(drag) => drag,
+            None => return,
+        };
+        trace!("EventMgr: end drag by {}", drag.source);
+
+        let mut accepted = false;
+        if let Some(id) = drag.target {
+            if success {
+                self.state.drop_payload = Some(drag.payload);
+                let source = drag.source.clone();
+                accepted = self.send_event(widget, id, Event::DragDrop { source, coord });
+                self.state.drop_payload = None;
+            } else {
+                self.send_event(widget, id, Event::DragLeave);
+            }
+        }
+        self.send_event(widget, drag.source, Event::DragEnd { accepted });
+    }
+
+    // Cancel the drag (if any), returning true if one was active
+    fn cancel_drag(&mut self) -> bool {
+        if let Some(drag) = self.state.drag.take() {
+            trace!("EventMgr: cancel drag by {}", drag.source);
+            // Note: pending events are handled in reverse order
+            self.state.pending.push(Pending::DragEnd(drag.source));
+            if let Some(id) = drag.target {
+                self.state.pending.push(Pending::DragLeave(id));
+            }
+            self.shell.set_cursor_icon(self.state.hover_icon);
+            true
+        } else {
+            false
+        }
+    }
+
+    // Start a press (mouse click or touch) on `start_id`
+    //
+    // Presses outside of a modal pop-up are swallowed. If `nav_focus`, the
+    // pressed widget receives navigation focus (if it supports this).
+    fn start_press(
+        &mut self,
+        widget: &mut dyn Widget,
+        source: PressSource,
+        start_id: Option<WidgetId>,
+        coord: Coord,
+        nav_focus: bool,
+    ) {
+        let start_id = start_id.filter(|id| !self.state.is_blocked_by_modal(id));
+        if let Some(id) = start_id.as_ref().filter(|_| nav_focus) {
+            if let Some(w) = widget.find_widget(id) {
+                if w.key_nav() {
+                    self.set_nav_focus(w.id(), false);
+                }
+            }
+        }
+
+        let event = Event::PressStart {
+            source,
+            start_id: start_id.clone(),
+            coord,
+        };
+        self.send_popup_first(widget, start_id, event);
+    }
+
     fn send_popup_first(&mut self, widget: &mut dyn Widget, id: Option<WidgetId>, event: Event) {
-        while let Some((wid, parent)) = self
+        while let Some((wid, parent, modal)) = self
             .state
             .popups
             .last()
-            .map(|(wid, p, _)| (*wid, p.parent.clone()))
+            .map(|(wid, p, _)| (*wid, p.parent.clone(), p.modal))
         {
+            if modal {
+                // A modal pop-up is not closed; events outside are swallowed
+                if id.as_ref().map(|id| self.state.is_blocked_by_modal(id)) == Some(true) {
+                    trace!("Blocked by modal pop-up: {:?}", event);
+                    return;
+                }
+                break;
+            }
             trace!("Send to popup parent: {}: {:?}", parent, event);
             match self.send(widget, parent, event.clone()) {
                 Response::Unused => (),
@@ -675,3 +1151,31 @@ impl<'a> EventMgr<'a> {
         }
     }
 }
+
+// Find the innermost widget on the path to `id` which has a tooltip
+fn find_tooltip<'b>(widget: &'b dyn Widget, id: &WidgetId) -> Option<&'b dyn Widget> {
+    let inner = widget
+        .find_child_index(id)
+        .and_then(|index| widget.get_child(index))
+        .and_then(|child| find_tooltip(child, id));
+    inner.or_else(|| (widget.tooltip().is_some() && widget.is_ancestor_of(id)).then(|| widget))
+}
+
+// Find widget `id`, translating `coord` into its coordinate space
+fn find_widget_coord<'w>(
+    widget: &'w dyn Widget,
+    id: &WidgetId,
+    coord: Coord,
+) -> Option<(&'w dyn Widget, Coord)> {
+    match widget.find_child_index(id) {
+        Some(i) => {
+            let coord = coord + widget.translation();
+            find_widget_coord(widget.get_child(i)?, id, coord)
+        }
+        None if widget.eq_id(id) => Some((widget, coord)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test;
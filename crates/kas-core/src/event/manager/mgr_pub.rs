@@ -5,18 +5,22 @@
 
 //! Event manager — public API
 
-use log::{debug, trace};
+use log::{debug, trace, warn};
+use std::any::Any;
+use std::collections::hash_map::Entry;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use std::u16;
 
 use super::*;
 use crate::cast::Conv;
 use crate::draw::DrawShared;
-use crate::geom::{Coord, Offset, Vec2};
+use crate::geom::{Coord, Offset, Rect, Vec2};
 use crate::layout::SetRectMgr;
 use crate::theme::{SizeMgr, ThemeControl};
 #[allow(unused)]
-use crate::Widget; // for doc-links
+use crate::{Layout, Widget}; // for doc-links
 use crate::{TkAction, WidgetExt, WidgetId, WindowId};
 
 impl<'a> std::ops::BitOrAssign<TkAction> for EventMgr<'a> {
@@ -63,12 +67,27 @@ impl EventState {
     }
 
     /// Get whether the widget is under the mouse cursor
+    ///
+    /// This is true when `w_id` is the widget under the mouse cursor, as
+    /// reported by [`Layout::find_id`], and no mouse grab is active (during a
+    /// grab, e.g. while dragging, no widget is considered hovered).
+    ///
+    /// Widgets may use this (and [`Self::is_depressed`]) from
+    /// [`Layout::draw`] instead of tracking hover state themselves.
     #[inline]
     pub fn is_hovered(&self, w_id: &WidgetId) -> bool {
         self.mouse_grab.is_none() && *w_id == self.hover
     }
 
     /// Check whether the given widget is visually depressed
+    ///
+    /// This is true when any of the following apply:
+    ///
+    /// -   a key (e.g. <kbd>Space</kbd> or an accelerator key) activating
+    ///     `w_id` is held
+    /// -   a mouse grab or any touch grab with `depress = Some(w_id)` is
+    ///     active (see [`EventMgr::grab_press`])
+    /// -   `w_id` is the parent of an open pop-up (e.g. a menu)
     pub fn is_depressed(&self, w_id: &WidgetId) -> bool {
         for (_, id) in &self.key_depress {
             if *id == w_id {
@@ -96,9 +115,29 @@ impl EventState {
         false
     }
 
+    /// Get whether a drag-and-drop operation is in progress
+    ///
+    /// See [`EventMgr::start_drag`].
+    #[inline]
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Access the payload of the current drag-and-drop operation
+    ///
+    /// This is available while handling [`Event::DragOver`] and
+    /// [`Event::DragDrop`] (unless already taken), allowing a target to check
+    /// the payload's type before accepting it.
+    pub fn drag_payload(&self) -> Option<&dyn Any> {
+        match self.drag.as_ref() {
+            Some(drag) => Some(&*drag.payload),
+            None => self.drop_payload.as_deref(),
+        }
+    }
+
     /// Check whether a widget is disabled
     ///
-    /// A widget is disabled if any ancestor is.
+    /// A widget is disabled if any ancestor is (see [`Self::set_disabled`]).
     #[inline]
     pub fn is_disabled(&self, w_id: &WidgetId) -> bool {
         // TODO(opt): we should be able to use binary search here
@@ -111,6 +150,13 @@ impl EventState {
     }
 
     /// Get the current modifier state
+    ///
+    /// This is updated before dispatch of any event following a change in
+    /// modifier state, thus when handling e.g. [`Event::Scroll`] or
+    /// [`Event::PressStart`] it reflects the keys held at the time of that
+    /// event. Modifiers are cleared when the window loses focus.
+    ///
+    /// This is accessible from [`EventMgr`] (via `Deref`).
     #[inline]
     pub fn modifiers(&self) -> ModifiersState {
         self.modifiers
@@ -122,6 +168,15 @@ impl EventState {
         &self.config
     }
 
+    /// Change event-handling configuration
+    ///
+    /// Configuration is shared between windows: changes apply to all windows
+    /// (others recompute derived values on their next event). The config is
+    /// marked as dirty, thus may be saved by the shell on exit.
+    pub fn update_config<F: FnOnce(&mut Config)>(&mut self, f: F) {
+        self.config.update_base(f);
+    }
+
     /// Is mouse panning enabled?
     #[inline]
     pub fn config_enable_pan(&self, source: PressSource) -> bool {
@@ -148,10 +203,27 @@ impl EventState {
 
     /// Set/unset a widget as disabled
     ///
-    /// Disabled status applies to all descendants and blocks reception of
-    /// events ([`Response::Unused`] is returned automatically when the
-    /// recipient or any ancestor is disabled).
+    /// Disabled status is inherited: it applies to the widget with this `id`
+    /// and all its descendants ([`Self::is_disabled`] checks ancestors).
+    /// A redraw is requested; themes draw disabled widgets and descendants
+    /// in a disabled style.
+    ///
+    /// Effects on disabled widgets (including descendants):
+    ///
+    /// -   Events (except those where [`Event::pass_when_disabled`]) are not
+    ///     delivered: [`EventMgr::send`] redirects the event to the disabled
+    ///     ancestor, returning [`Response::Unused`] without calling any
+    ///     handler, thus unused-event handling continues from its parent
+    /// -   Hit-testing ([`Layout::find_id`]) is unaffected, thus a disabled
+    ///     widget still occludes widgets below it; hover highlighting and
+    ///     custom cursor icons are however suppressed
+    /// -   Keyboard navigation skips disabled widgets; if the navigation focus
+    ///     is within the newly disabled widget, it is cleared
     pub fn set_disabled(&mut self, w_id: WidgetId, state: bool) {
+        if state && matches!(&self.nav_focus, Some(id) if w_id.is_ancestor_of(id)) {
+            self.clear_nav_focus();
+        }
+
         for (i, id) in self.disabled.iter().enumerate() {
             if w_id == id {
                 if !state {
@@ -212,17 +284,63 @@ impl EventState {
         self.time_updates.sort_by(|a, b| b.0.cmp(&a.0)); // reverse sort
     }
 
+    /// Cancel scheduled updates
+    ///
+    /// Removes all pending updates scheduled via [`Self::update_on_timer`]
+    /// matching this `w_id` and `payload`. Returns true if any update was
+    /// cancelled.
+    ///
+    /// Animations (e.g. smooth scrolling or a spinner) should use this when
+    /// stopping early; otherwise the widget will still receive the pending
+    /// [`Event::TimerUpdate`].
+    pub fn cancel_timer_updates(&mut self, w_id: &WidgetId, payload: u64) -> bool {
+        let len = self.time_updates.len();
+        // NOTE: retain preserves order, thus time_updates remains sorted
+        self.time_updates
+            .retain(|row| !(row.1 == *w_id && row.2 == payload));
+        let cancelled = self.time_updates.len() < len;
+        if cancelled {
+            trace!(
+                "EventMgr::cancel_timer_updates: cancelled updates for {} with payload {}",
+                w_id,
+                payload
+            );
+        }
+        cancelled
+    }
+
     /// Notify that a widget must be redrawn
     ///
-    /// Currently the entire window is redrawn on any redraw request and the
-    /// [`WidgetId`] is ignored. In the future partial redraws may be used.
+    /// This causes the entire window to be redrawn: the [`WidgetId`] is
+    /// ignored. Where the affected region is known, [`Self::redraw_rect`]
+    /// allows the shell to limit rendering to that region.
     #[inline]
     pub fn redraw(&mut self, _id: WidgetId) {
-        // Theoretically, notifying by WidgetId allows selective redrawing
-        // (damage events). This is not yet implemented.
         self.send_action(TkAction::REDRAW);
     }
 
+    /// Notify that a region of the window must be redrawn
+    ///
+    /// The `rect` is in window coordinates: widgets within a scrolled region
+    /// or pop-up must translate their own coordinates. Everything drawn
+    /// within the region is redrawn, including e.g. pop-ups overlapping it.
+    ///
+    /// Regions from multiple calls are combined into their bounding rect. Any
+    /// other action (e.g. via [`Self::redraw`] or [`Self::send_action`],
+    /// including those caused by scrolling or resizing) causes the whole
+    /// window to be redrawn. Shells are not required to support partial
+    /// redraws; see [`EventState::take_damage`].
+    pub fn redraw_rect(&mut self, rect: Rect) {
+        #[cfg(feature = "action_stats")]
+        self.action_stats.count(TkAction::REDRAW);
+        self.action |= TkAction::REDRAW;
+        self.damage = match self.damage {
+            Damage::None => Damage::Region(rect),
+            Damage::Region(r) => Damage::Region(r.union(&rect)),
+            Damage::Full => Damage::Full,
+        };
+    }
+
     /// Notify that a [`TkAction`] action should happen
     ///
     /// This causes the given action to happen after event handling.
@@ -234,9 +352,26 @@ impl EventState {
     /// affect the UI after a reconfigure action.
     #[inline]
     pub fn send_action(&mut self, action: TkAction) {
+        #[cfg(feature = "action_stats")]
+        self.action_stats.count(action);
+        if !action.is_empty() {
+            self.damage = Damage::Full;
+        }
         self.action |= action;
     }
 
+    /// Get counts of actions requested during the last update cycle
+    ///
+    /// Counts cover all actions requested since the previous call to
+    /// [`EventState::update`] (thus, approximately, per frame). Requests made
+    /// during the current cycle are not included.
+    #[cfg(feature = "action_stats")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "action_stats")))]
+    #[inline]
+    pub fn action_stats(&self) -> ActionStats {
+        self.last_action_stats
+    }
+
     /// Attempts to set a fallback to receive [`Event::Command`]
     ///
     /// In case a navigation key is pressed (see [`Command`]) but no widget has
@@ -306,17 +441,44 @@ impl EventState {
     /// pop-up, the key is only active when that pop-up is open.
     /// See [`EventState::new_accel_layer`].
     ///
+    /// If a key is already bound to another widget within the same layer, the
+    /// first binding is kept and a warning is logged.
+    ///
     /// This should only be called from [`Widget::configure`].
     // TODO(type safety): consider only implementing on ConfigureManager
-    #[inline]
     pub fn add_accel_keys(&mut self, id: &WidgetId, keys: &[VirtualKeyCode]) {
         if let Some(layer) = self.accel_layer_for_id(id) {
             for key in keys {
-                layer.1.insert(*key, id.clone());
+                match layer.1.entry(*key) {
+                    Entry::Occupied(entry) => {
+                        if entry.get() != id {
+                            warn!(
+                                "add_accel_keys: key {key:?} for {id} conflicts with existing binding for {}",
+                                entry.get()
+                            );
+                        }
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert(id.clone());
+                    }
+                }
             }
         }
     }
 
+    /// Request type-ahead text input
+    ///
+    /// While no widget has char focus (see [`Self::request_char_focus`]) and
+    /// `id` or a descendant has navigation focus, text typed without
+    /// modifiers (other than <kbd>Shift</kbd>) is sent to `id` via
+    /// [`Event::ReceivedCharacter`]. If multiple such widgets are ancestors of
+    /// the navigation focus, text is sent to the innermost.
+    ///
+    /// This should only be called from [`Widget::configure`].
+    pub fn request_type_ahead(&mut self, id: WidgetId) {
+        self.type_ahead.insert(id);
+    }
+
     /// Request character-input focus
     ///
     /// Returns true on success or when the widget already had char focus.
@@ -446,6 +608,19 @@ impl EventState {
             self.pending.push(Pending::SetNavFocus(id, key_focus));
         }
     }
+
+    /// Cancel a future pushed via [`EventMgr::push_async`] or [`EventMgr::push_spawn`]
+    ///
+    /// The future is dropped and its result will not be delivered. A
+    /// computation started by [`EventMgr::push_spawn`] is not interrupted
+    /// (unless it has not started yet), but its result is discarded.
+    ///
+    /// Returns true if the future was found (i.e. it had not completed).
+    pub fn cancel_async(&mut self, id: AsyncId) -> bool {
+        let len = self.fut_messages.len();
+        self.fut_messages.retain(|(fut_id, _, _)| *fut_id != id);
+        self.fut_messages.len() < len
+    }
 }
 
 /// Public API
@@ -475,6 +650,72 @@ impl<'a> EventMgr<'a> {
         self.send_recurse(widget, id, disabled, event)
     }
 
+    /// Send a [`Command`] to a widget
+    ///
+    /// This delivers [`Event::Command`] to the widget `id` exactly as when the
+    /// widget has key focus and a key bound to `cmd` is pressed (but without
+    /// fallback to parents or the navigation fallback). This may be used to
+    /// drive a UI from scripts or integration tests.
+    ///
+    /// If `id` does not identify a widget under `widget`, this does nothing
+    /// and returns [`Response::Unused`].
+    pub fn send_command(
+        &mut self,
+        widget: &mut dyn Widget,
+        id: WidgetId,
+        cmd: Command,
+    ) -> Response {
+        if widget.find_widget(&id).is_none() {
+            trace!("EventMgr::send_command: target {id} not found");
+            return Response::Unused;
+        }
+        self.send(widget, id, Event::Command(cmd))
+    }
+
+    /// Simulate a mouse click at `coord`
+    ///
+    /// This delivers [`Event::PressStart`] to the widget under `coord` (found
+    /// via [`Layout::find_id`]) exactly as for a left-button mouse press,
+    /// including navigation focus and blocking by modal pop-ups. If some
+    /// widget then takes a grab (see [`Self::grab_press`]), the grab is
+    /// released immediately, delivering [`Event::PressEnd`] with
+    /// `success = true`; thus activation events fire as for a real click.
+    /// This may be used to drive a UI from integration tests without a
+    /// display.
+    ///
+    /// Returns false (doing nothing) if no widget is found at `coord` or if a
+    /// mouse grab is already active.
+    pub fn simulate_click(&mut self, widget: &mut dyn Widget, coord: Coord) -> bool {
+        if self.state.mouse_grab.is_some() {
+            warn!("EventMgr::simulate_click: mouse grab already active");
+            return false;
+        }
+        let start_id = match widget.find_id(coord) {
+            Some(id) => id,
+            None => {
+                trace!("EventMgr::simulate_click: no widget at {:?}", coord);
+                return false;
+            }
+        };
+
+        let source = PressSource::Mouse(MouseButton::Left, 1);
+        let nav_focus = self.state.config.mouse_nav_focus();
+        self.start_press(widget, source, Some(start_id), coord, nav_focus);
+
+        if let Some(grab) = self.remove_mouse_grab() {
+            if grab.mode == GrabMode::Grab {
+                let event = Event::PressEnd {
+                    source,
+                    end_id: widget.find_id(coord),
+                    coord,
+                    success: true,
+                };
+                self.send_event(widget, grab.start_id, event);
+            }
+        }
+        true
+    }
+
     /// Push a message to the stack
     pub fn push_msg<M: Debug + 'static>(&mut self, msg: M) {
         self.push_boxed_msg(Box::new(msg));
@@ -485,6 +726,66 @@ impl<'a> EventMgr<'a> {
         self.messages.push(Message::new(msg));
     }
 
+    /// Asynchronously push a message to the stack via a [`Future`]
+    ///
+    /// The future is polled on the UI thread after event handling and each
+    /// time its [`std::task::Waker`] is woken (wakers may be used from any
+    /// thread). Once complete, the result is pushed to the message stack as if
+    /// by widget `id` while handling an event; thus it may be handled by any
+    /// ancestor of `id` via [`Widget::handle_message`].
+    ///
+    /// The future is dropped (cancelled) if widget `id` is removed from the
+    /// window before the future completes, or if a new widget is configured
+    /// with the same `id` (thus results are never delivered to a widget which
+    /// merely reuses the identifier of the requesting widget). It may also be
+    /// cancelled explicitly via [`EventState::cancel_async`] using the
+    /// returned [`AsyncId`].
+    ///
+    /// Since the future is polled on the UI thread, polling should be cheap.
+    /// Long-running computations should instead use [`Self::push_spawn`].
+    pub fn push_async<Fut, M>(&mut self, id: WidgetId, fut: Fut) -> AsyncId
+    where
+        Fut: Future<Output = M> + 'static,
+        M: Debug + 'static,
+    {
+        let fut = Box::pin(async move { Message::new(Box::new(fut.await)) });
+        let async_id = AsyncId(self.state.next_async_id);
+        self.state.next_async_id += 1;
+        self.state.fut_messages.push((async_id, id, MsgFuture(fut)));
+        async_id
+    }
+
+    /// Run a computation on a worker thread, pushing the result as a message
+    ///
+    /// The closure `f` is run on a worker thread. Each window uses a pool of
+    /// at most four worker threads, spawned on demand; when all are busy, `f`
+    /// is queued. Its result is sent back to the UI thread and pushed to the
+    /// message stack as if by widget `id`, exactly as for
+    /// [`Self::push_async`] (including cancellation).
+    ///
+    /// If `f` panics, no message is pushed. Jobs which have not started are
+    /// discarded when the window is closed.
+    pub fn push_spawn<F, M>(&mut self, id: WidgetId, f: F) -> AsyncId
+    where
+        F: FnOnce() -> M + Send + 'static,
+        M: Debug + Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(ThreadResult {
+            result: None,
+            waker: None,
+        }));
+        let thread_state = state.clone();
+        self.state.workers.spawn(Box::new(move || {
+            let result = f();
+            let mut state = thread_state.lock().unwrap();
+            state.result = Some(result);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }));
+        self.push_async(id, ThreadFuture(state))
+    }
+
     /// True if the message stack is non-empty
     pub fn has_msg(&self) -> bool {
         !self.messages.is_empty()
@@ -530,6 +831,11 @@ impl<'a> EventMgr<'a> {
     ///
     /// It is recommended to call [`EventState::set_nav_focus`] after this method.
     ///
+    /// If [`crate::Popup::modal`] is true, interaction outside of the pop-up
+    /// is blocked until it is closed; see [`crate::Popup`]. Tab navigation
+    /// is always restricted to the top-most pop-up, wrapping at its
+    /// boundaries.
+    ///
     /// A pop-up may be closed by calling [`EventMgr::close_window`] with
     /// the [`WindowId`] returned by this method.
     ///
@@ -548,6 +854,7 @@ impl<'a> EventMgr<'a> {
             // Don't restore old nav focus: assume new focus will be set by new popup
         }
 
+        let modal = popup.modal;
         let opt_id = self.shell.add_popup(popup.clone());
         if let Some(id) = opt_id {
             self.state
@@ -555,6 +862,9 @@ impl<'a> EventMgr<'a> {
                 .push((id, popup, self.state.nav_focus.clone()));
         }
         self.clear_nav_focus();
+        if modal {
+            self.clear_char_focus();
+        }
         opt_id
     }
 
@@ -624,19 +934,35 @@ impl<'a> EventMgr<'a> {
 
     /// Attempt to get clipboard contents
     ///
-    /// In case of failure, paste actions will simply fail. The implementation
-    /// may wish to log an appropriate warning message.
+    /// Returns `None` when the clipboard is unavailable (e.g. the shell was
+    /// built without clipboard support) or when its contents are not valid
+    /// UTF-8 text. In case of failure, paste actions will simply fail. The
+    /// implementation may wish to log an appropriate warning message.
     #[inline]
     pub fn get_clipboard(&mut self) -> Option<String> {
         self.shell.get_clipboard()
     }
 
     /// Attempt to set clipboard contents
+    ///
+    /// This is a no-op when the clipboard is unavailable. Failure is logged
+    /// but not otherwise reported.
     #[inline]
     pub fn set_clipboard(&mut self, content: String) {
         self.shell.set_clipboard(content)
     }
 
+    /// Open a URL in the system's default handler (e.g. a web browser)
+    ///
+    /// This does not block. Failure is logged but not otherwise reported.
+    /// Shells may refuse URLs with schemes other than `http`, `https` and
+    /// `mailto`.
+    #[inline]
+    pub fn open_url(&mut self, url: &str) {
+        debug!("open_url: {url}");
+        self.shell.open_url(url);
+    }
+
     /// Adjust the theme
     #[inline]
     pub fn adjust_theme<F: FnMut(&mut dyn ThemeControl) -> TkAction>(&mut self, mut f: F) {
@@ -802,6 +1128,55 @@ impl<'a> EventMgr<'a> {
         }
     }
 
+    /// Start a drag-and-drop operation
+    ///
+    /// This only succeeds if widget `id` has an active mouse-grab with mode
+    /// [`GrabMode::Grab`] (see [`EventMgr::grab_press`]); typically this is
+    /// called while handling [`Event::PressMove`]. Returns true on success.
+    ///
+    /// While the drag is active, the widget under the mouse cursor receives
+    /// [`Event::DragOver`] on each movement. A widget using this event is the
+    /// drop target: it receives [`Event::DragLeave`] when the cursor moves
+    /// elsewhere. The cursor is set to `icon` while over a drop target,
+    /// otherwise to [`CursorIcon::NoDrop`].
+    ///
+    /// On release of the mouse button, the drop target (if any) receives
+    /// [`Event::DragDrop`] and may take the payload via
+    /// [`EventMgr::take_drag_payload`]. Pressing <kbd>Escape</kbd> or
+    /// otherwise ending the mouse-grab cancels the drag. In all cases, widget
+    /// `id` finally receives [`Event::DragEnd`].
+    pub fn start_drag(&mut self, id: WidgetId, payload: Box<dyn Any>, icon: CursorIcon) -> bool {
+        match self.state.mouse_grab {
+            Some(ref grab) if grab.start_id == id && grab.mode == GrabMode::Grab => (),
+            _ => {
+                warn!("EventMgr::start_drag: {} does not have a mouse grab", id);
+                return false;
+            }
+        }
+        if self.state.drag.is_some() {
+            warn!("EventMgr::start_drag: a drag is already in progress");
+            return false;
+        }
+
+        trace!("EventMgr: start drag by {}", id);
+        self.state.drag = Some(DragState {
+            source: id,
+            payload,
+            icon,
+            target: None,
+        });
+        self.shell.set_cursor_icon(CursorIcon::NoDrop);
+        true
+    }
+
+    /// Take the payload of a drag-and-drop operation
+    ///
+    /// This returns the payload only while handling [`Event::DragDrop`].
+    /// A widget which takes the payload should return [`Response::Used`].
+    pub fn take_drag_payload(&mut self) -> Option<Box<dyn Any>> {
+        self.state.drop_payload.take()
+    }
+
     /// Advance the keyboard navigation focus
     ///
     /// If some widget currently has nav focus, this will give focus to the next
@@ -834,73 +1209,6 @@ impl<'a> EventMgr<'a> {
         // processing, we can push directly to self.state.action.
         self.state.send_action(TkAction::REDRAW);
 
-        fn nav(
-            mgr: &mut SetRectMgr,
-            widget: &mut dyn Widget,
-            focus: Option<&WidgetId>,
-            rev: bool,
-        ) -> Option<WidgetId> {
-            if mgr.ev_state().is_disabled(widget.id_ref()) {
-                return None;
-            }
-
-            let mut child = focus.and_then(|id| widget.find_child_index(id));
-
-            if !rev {
-                if let Some(index) = child {
-                    if let Some(id) = widget
-                        .get_child_mut(index)
-                        .and_then(|w| nav(mgr, w, focus, rev))
-                    {
-                        return Some(id);
-                    }
-                } else if !widget.eq_id(focus) && widget.key_nav() {
-                    return Some(widget.id());
-                }
-
-                loop {
-                    if let Some(index) = widget.spatial_nav(mgr, rev, child) {
-                        if let Some(id) = widget
-                            .get_child_mut(index)
-                            .and_then(|w| nav(mgr, w, focus, rev))
-                        {
-                            return Some(id);
-                        }
-                        child = Some(index);
-                    } else {
-                        return None;
-                    }
-                }
-            } else {
-                if let Some(index) = child {
-                    if let Some(id) = widget
-                        .get_child_mut(index)
-                        .and_then(|w| nav(mgr, w, focus, rev))
-                    {
-                        return Some(id);
-                    }
-                }
-
-                loop {
-                    if let Some(index) = widget.spatial_nav(mgr, rev, child) {
-                        if let Some(id) = widget
-                            .get_child_mut(index)
-                            .and_then(|w| nav(mgr, w, focus, rev))
-                        {
-                            return Some(id);
-                        }
-                        child = Some(index);
-                    } else {
-                        return if !widget.eq_id(focus) && widget.key_nav() {
-                            Some(widget.id())
-                        } else {
-                            None
-                        };
-                    }
-                }
-            }
-        }
-
         // Whether to restart from the beginning on failure
         let restart = self.state.nav_focus.is_some();
 
@@ -929,4 +1237,99 @@ impl<'a> EventMgr<'a> {
             false
         }
     }
+
+    /// Give navigation focus to the first (or last) widget under `widget`
+    ///
+    /// This is like [`Self::next_nav_focus`] except that it ignores any
+    /// existing navigation focus, selecting the first (or last, if `reverse`)
+    /// descendant of `widget` (possibly `widget` itself) for which
+    /// [`Widget::key_nav`] returns true. This may be used e.g. to focus the
+    /// first entry of a list after populating it.
+    ///
+    /// Returns true on success. If no navigable widget is found, this does
+    /// nothing and returns false.
+    pub fn nav_focus_first(
+        &mut self,
+        widget: &mut dyn Widget,
+        reverse: bool,
+        key_focus: bool,
+    ) -> bool {
+        let mut opt_id = None;
+        self.set_rect_mgr(|mgr| opt_id = nav(mgr, widget, None, reverse));
+
+        if let Some(id) = opt_id {
+            self.set_nav_focus(id, key_focus);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+// Find the next key-navigable widget under `widget` after `focus`
+fn nav(
+    mgr: &mut SetRectMgr,
+    widget: &mut dyn Widget,
+    focus: Option<&WidgetId>,
+    rev: bool,
+) -> Option<WidgetId> {
+    if mgr.ev_state().is_disabled(widget.id_ref()) {
+        return None;
+    }
+
+    let mut child = focus.and_then(|id| widget.find_child_index(id));
+
+    if !rev {
+        if let Some(index) = child {
+            if let Some(id) = widget
+                .get_child_mut(index)
+                .and_then(|w| nav(mgr, w, focus, rev))
+            {
+                return Some(id);
+            }
+        } else if !widget.eq_id(focus) && widget.key_nav() {
+            return Some(widget.id());
+        }
+
+        loop {
+            if let Some(index) = widget.spatial_nav(mgr, rev, child) {
+                if let Some(id) = widget
+                    .get_child_mut(index)
+                    .and_then(|w| nav(mgr, w, focus, rev))
+                {
+                    return Some(id);
+                }
+                child = Some(index);
+            } else {
+                return None;
+            }
+        }
+    } else {
+        if let Some(index) = child {
+            if let Some(id) = widget
+                .get_child_mut(index)
+                .and_then(|w| nav(mgr, w, focus, rev))
+            {
+                return Some(id);
+            }
+        }
+
+        loop {
+            if let Some(index) = widget.spatial_nav(mgr, rev, child) {
+                if let Some(id) = widget
+                    .get_child_mut(index)
+                    .and_then(|w| nav(mgr, w, focus, rev))
+                {
+                    return Some(id);
+                }
+                child = Some(index);
+            } else {
+                return if !widget.eq_id(focus) && widget.key_nav() {
+                    Some(widget.id())
+                } else {
+                    None
+                };
+            }
+        }
+    }
 }
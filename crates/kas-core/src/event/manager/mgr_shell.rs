@@ -7,17 +7,14 @@
 
 use log::*;
 use smallvec::SmallVec;
-use std::time::{Duration, Instant};
+use std::time::Instant;
 
 use super::*;
 use crate::cast::traits::*;
-use crate::geom::{Coord, DVec2};
+use crate::geom::{Coord, DVec2, Offset, Rect};
 use crate::layout::SetRectMgr;
 use crate::{ShellWindow, TkAction, Widget, WidgetId};
 
-// TODO: this should be configurable or derived from the system
-const DOUBLE_CLICK_TIMEOUT: Duration = Duration::from_secs(1);
-
 const FAKE_MOUSE_BUTTON: MouseButton = MouseButton::Other(0);
 
 /// Shell API
@@ -37,6 +34,7 @@ impl EventState {
             nav_focus: None,
             nav_fallback: None,
             hover: None,
+            hover_notified: None,
             hover_icon: CursorIcon::Default,
             key_depress: Default::default(),
             last_mouse_coord: Coord::ZERO,
@@ -46,15 +44,47 @@ impl EventState {
             mouse_grab: None,
             touch_grab: Default::default(),
             pan_grab: SmallVec::new(),
+            drag: None,
+            drop_payload: None,
             accel_layers: Default::default(),
+            type_ahead: Default::default(),
             popups: Default::default(),
             popup_removed: Default::default(),
+            fut_messages: vec![],
+            next_async_id: 0,
+            workers: Default::default(),
             time_updates: vec![],
             pending: SmallVec::new(),
+            damage: Damage::None,
+            tooltip_host: None,
+            tooltip_pending: None,
+            tooltip_shown: None,
+            tooltip_text: String::new(),
+            tooltip_coord: Coord::ZERO,
+            #[cfg(feature = "action_stats")]
+            action_stats: Default::default(),
+            #[cfg(feature = "action_stats")]
+            last_action_stats: Default::default(),
             action: TkAction::empty(),
         }
     }
 
+    /// Take the damaged region of the window
+    ///
+    /// Returns the union of regions passed to [`EventState::redraw_rect`]
+    /// since the last call, or `None` if the whole window must be redrawn.
+    /// The latter is the case if any other action was requested (including
+    /// via [`EventState::redraw`]) or if no region was recorded.
+    ///
+    /// Note that this only accounts for redraws requested through the
+    /// [`EventState`]; the shell must track its own causes (e.g. resizing).
+    pub fn take_damage(&mut self) -> Option<Rect> {
+        match std::mem::replace(&mut self.damage, Damage::None) {
+            Damage::Region(rect) => Some(rect),
+            Damage::None | Damage::Full => None,
+        }
+    }
+
     /// Update scale factor
     pub fn set_scale_factor(&mut self, scale_factor: f32) {
         self.config.set_scale_factor(scale_factor);
@@ -75,6 +105,7 @@ impl EventState {
 
         // These are recreated during configure:
         self.accel_layers.clear();
+        self.type_ahead.clear();
         self.nav_fallback = None;
 
         self.new_accel_layer(WidgetId::ROOT, false);
@@ -84,8 +115,11 @@ impl EventState {
             mgr.configure(WidgetId::ROOT, widget);
         });
 
-        let hover = widget.find_id(self.last_mouse_coord);
-        self.with(shell, |mgr| mgr.set_hover(widget, hover));
+        let coord = self.last_mouse_coord;
+        let hover = widget
+            .find_id(coord)
+            .filter(|id| !self.is_blocked_by_modal(id));
+        self.with(shell, |mgr| mgr.set_hover(widget, hover, coord));
     }
 
     /// Update the widgets under the cursor and touch events
@@ -94,8 +128,11 @@ impl EventState {
         // Note: redraw is already implied.
 
         // Update hovered widget
-        let hover = widget.find_id(self.last_mouse_coord);
-        self.with(shell, |mgr| mgr.set_hover(widget, hover));
+        let coord = self.last_mouse_coord;
+        let hover = widget
+            .find_id(coord)
+            .filter(|id| !self.is_blocked_by_modal(id));
+        self.with(shell, |mgr| mgr.set_hover(widget, hover, coord));
 
         for grab in self.touch_grab.iter_mut() {
             grab.cur_id = widget.find_id(grab.coord);
@@ -104,7 +141,33 @@ impl EventState {
 
     /// Get the next resume time
     pub fn next_resume(&self) -> Option<Instant> {
-        self.time_updates.last().map(|time| time.0)
+        let timer = self.time_updates.last().map(|time| time.0);
+        let tooltip = self.tooltip_pending.as_ref().map(|pending| pending.0);
+        match (timer, tooltip) {
+            (Some(t1), Some(t2)) => Some(t1.min(t2)),
+            (t1, t2) => t1.or(t2),
+        }
+    }
+
+    /// Register the widget used to display tooltips
+    ///
+    /// This should be called by the root widget on configure. Tooltips are
+    /// shown by opening this widget as a pop-up (see [`ShellWindow::add_popup`]);
+    /// its text should be set from [`Self::tooltip_text`] on opening.
+    pub fn set_tooltip_host(&mut self, id: WidgetId) {
+        self.tooltip_host = Some(id);
+    }
+
+    /// Get the text of the current tooltip
+    pub fn tooltip_text(&self) -> &str {
+        &self.tooltip_text
+    }
+
+    /// Get the mouse position at which the current tooltip was requested
+    ///
+    /// The tooltip should be placed next to this position.
+    pub fn tooltip_coord(&self) -> Coord {
+        self.tooltip_coord
     }
 
     /// Construct a [`EventMgr`] referring to this state
@@ -196,6 +259,8 @@ impl EventState {
             }
         }
 
+        mgr.poll_futures(widget);
+
         // Warning: infinite loops are possible here if widgets always queue a
         // new pending event when evaluating one of these:
         while let Some(item) = mgr.state.pending.pop() {
@@ -204,6 +269,10 @@ impl EventState {
                 Pending::LostCharFocus(id) => (id, Event::LostCharFocus),
                 Pending::LostSelFocus(id) => (id, Event::LostSelFocus),
                 Pending::SetNavFocus(id, key_focus) => (id, Event::NavFocus(key_focus)),
+                Pending::DragLeave(id) => (id, Event::DragLeave),
+                Pending::DragEnd(id) => (id, Event::DragEnd { accepted: false }),
+                Pending::MouseEnter(id) => (id, Event::MouseEnter),
+                Pending::MouseLeave(id) => (id, Event::MouseLeave),
             };
             mgr.send_event(widget, id, event);
         }
@@ -212,6 +281,15 @@ impl EventState {
         drop(mgr);
         let action = action | self.action;
         self.action = TkAction::empty();
+
+        #[cfg(feature = "action_stats")]
+        {
+            self.last_action_stats = std::mem::take(&mut self.action_stats);
+            if self.last_action_stats != ActionStats::default() {
+                trace!("EventMgr::update: {:?}", self.last_action_stats);
+            }
+        }
+
         action
     }
 }
@@ -222,8 +300,16 @@ impl EventState {
 impl<'a> EventMgr<'a> {
     /// Update widgets due to timer
     pub fn update_timer(&mut self, widget: &mut dyn Widget) {
+        self.state.config.refresh();
         let now = Instant::now();
 
+        if let Some((time, id)) = self.state.tooltip_pending.clone() {
+            if time <= now {
+                self.state.tooltip_pending = None;
+                self.show_tooltip(widget, id);
+            }
+        }
+
         // assumption: time_updates are sorted in reverse order
         while !self.state.time_updates.is_empty() {
             if self.state.time_updates.last().unwrap().0 > now {
@@ -257,6 +343,9 @@ impl<'a> EventMgr<'a> {
     pub fn handle_winit(&mut self, widget: &mut dyn Widget, event: winit::event::WindowEvent) {
         use winit::event::{ElementState, MouseScrollDelta, TouchPhase, WindowEvent::*};
 
+        // The base config may have been changed via another window
+        self.state.config.refresh();
+
         match event {
             CloseRequested => self.send_action(TkAction::CLOSE),
             /* Not yet supported: see #98
@@ -265,14 +354,11 @@ impl<'a> EventMgr<'a> {
             HoveredFileCancelled => ,
             */
             ReceivedCharacter(c) => {
-                if let Some(id) = self.state.char_focus() {
-                    // Filter out control codes (Unicode 5.11). These may be
-                    // generated from combinations such as Ctrl+C by some other
-                    // layer. We use our own shortcut system instead.
-                    if c >= '\x20' && !('\x7f'..='\u{9f}').contains(&c) {
-                        let event = Event::ReceivedCharacter(c);
-                        self.send_event(widget, id, event);
-                    }
+                // Filter out control codes (Unicode 5.11). These may be
+                // generated from combinations such as Ctrl+C by some other
+                // layer. We use our own shortcut system instead.
+                if c >= '\x20' && !('\x7f'..='\u{9f}').contains(&c) {
+                    self.received_char(widget, c);
                 }
             }
             Focused(state) => {
@@ -281,10 +367,19 @@ impl<'a> EventMgr<'a> {
                     // Required to restart theme animations
                     self.state.send_action(TkAction::REDRAW);
                 } else {
-                    // Window focus lost: close all popups
-                    while let Some(id) = self.state.popups.last().map(|(id, _, _)| *id) {
+                    // Window focus lost: close all popups above any modal popup
+                    while let Some((id, _)) = (self.state.popups.last())
+                        .map(|(id, p, _)| (*id, p.modal))
+                        .filter(|(_, modal)| !modal)
+                    {
                         self.close_window(id, true);
                     }
+                    // We will not be notified of modifier changes while
+                    // unfocused, so do not report stale modifiers
+                    if self.state.modifiers.alt() {
+                        self.state.send_action(TkAction::REDRAW);
+                    }
+                    self.state.modifiers = ModifiersState::empty();
                 }
             }
             KeyboardInput {
@@ -293,6 +388,7 @@ impl<'a> EventMgr<'a> {
                 ..
             } => {
                 if input.state == ElementState::Pressed && !is_synthetic {
+                    self.hide_tooltip();
                     if let Some(vkey) = input.virtual_keycode {
                         self.start_key_event(widget, vkey, input.scancode);
                     }
@@ -312,9 +408,16 @@ impl<'a> EventMgr<'a> {
                 let coord = position.cast_approx();
 
                 // Update hovered widget
-                let cur_id = widget.find_id(coord);
+                let cur_id = widget
+                    .find_id(coord)
+                    .filter(|id| !self.state.is_blocked_by_modal(id));
                 let delta = coord - self.state.last_mouse_coord;
-                self.set_hover(widget, cur_id.clone());
+                self.set_hover(widget, cur_id.clone(), coord);
+                if delta != Offset::ZERO {
+                    // Any movement hides the tooltip and restarts the timer
+                    self.reset_tooltip(widget);
+                }
+                self.drag_move(widget, cur_id.clone(), coord);
 
                 if let Some(grab) = self.state.mouse_grab.as_mut() {
                     if grab.mode == GrabMode::Grab {
@@ -326,7 +429,9 @@ impl<'a> EventMgr<'a> {
                     {
                         pan.coords[usize::conv(grab.pan_grab.1)].1 = coord;
                     }
-                } else if let Some(id) = self.state.popups.last().map(|(_, p, _)| p.parent.clone())
+                } else if let Some(id) = (self.state.popups.last())
+                    .filter(|(_, p, _)| !p.modal)
+                    .map(|(_, p, _)| p.parent.clone())
                 {
                     let source = PressSource::Mouse(FAKE_MOUSE_BUTTON, 0);
                     let event = Event::PressMove {
@@ -350,10 +455,11 @@ impl<'a> EventMgr<'a> {
                     // If there's a mouse grab, we will continue to receive
                     // coordinates; if not, set a fake coordinate off the window
                     self.state.last_mouse_coord = Coord(-1, -1);
-                    self.set_hover(widget, None);
+                    self.set_hover(widget, None, Coord(-1, -1));
                 }
             }
             MouseWheel { delta, .. } => {
+                self.hide_tooltip();
                 if let Some((id, event)) = self.mouse_grab().and_then(|g| g.flush_move()) {
                     self.send_event(widget, id, event);
                 }
@@ -374,6 +480,7 @@ impl<'a> EventMgr<'a> {
                 }
             }
             MouseInput { state, button, .. } => {
+                self.hide_tooltip();
                 if let Some((id, event)) = self.mouse_grab().and_then(|g| g.flush_move()) {
                     self.send_event(widget, id, event);
                 }
@@ -388,9 +495,10 @@ impl<'a> EventMgr<'a> {
                         self.state.last_click_repetitions = 0;
                     }
                     self.state.last_click_repetitions += 1;
-                    self.state.last_click_timeout = now + DOUBLE_CLICK_TIMEOUT;
+                    self.state.last_click_timeout = now + self.state.config.double_click_timeout();
                 }
 
+                self.end_drag(widget, state == ElementState::Released, coord);
                 if let Some(grab) = self.remove_mouse_grab() {
                     if grab.mode == GrabMode::Grab {
                         // Mouse grab active: send events there
@@ -407,24 +515,11 @@ impl<'a> EventMgr<'a> {
                 }
 
                 if state == ElementState::Pressed {
-                    if let Some(start_id) = self.state.hover.clone() {
-                        // No mouse grab but have a hover target
-                        if self.state.config.mouse_nav_focus() {
-                            if let Some(w) = widget.find_widget(&start_id) {
-                                if w.key_nav() {
-                                    self.set_nav_focus(w.id(), false);
-                                }
-                            }
-                        }
-                    }
-
+                    // No mouse grab but may have a hover target
                     let source = PressSource::Mouse(button, self.state.last_click_repetitions);
-                    let event = Event::PressStart {
-                        source,
-                        start_id: self.state.hover.clone(),
-                        coord,
-                    };
-                    self.send_popup_first(widget, self.state.hover.clone(), event);
+                    let start_id = self.state.hover.clone();
+                    let nav_focus = self.state.config.mouse_nav_focus();
+                    self.start_press(widget, source, start_id, coord, nav_focus);
                 }
             }
             // TouchpadPressure { pressure: f32, stage: i64, },
@@ -435,21 +530,9 @@ impl<'a> EventMgr<'a> {
                 match touch.phase {
                     TouchPhase::Started => {
                         let start_id = widget.find_id(coord);
-                        if let Some(id) = start_id.as_ref() {
-                            if self.state.config.touch_nav_focus() {
-                                if let Some(w) = widget.find_widget(id) {
-                                    if w.key_nav() {
-                                        self.set_nav_focus(w.id(), false);
-                                    }
-                                }
-                            }
-
-                            let event = Event::PressStart {
-                                source,
-                                start_id: start_id.clone(),
-                                coord,
-                            };
-                            self.send_popup_first(widget, start_id, event);
+                        if start_id.is_some() {
+                            let nav_focus = self.state.config.touch_nav_focus();
+                            self.start_press(widget, source, start_id, coord, nav_focus);
                         }
                     }
                     TouchPhase::Moved => {
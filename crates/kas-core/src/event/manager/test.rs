@@ -0,0 +1,701 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE-APACHE file or at:
+//     https://www.apache.org/licenses/LICENSE-2.0
+
+//! Event manager tests
+//!
+//! These tests inspect private [`EventState`] fields, thus use a minimal shell
+//! of their own (the `kas-widgets` test harness is not available here).
+
+use super::*;
+use crate::cast::Conv;
+use crate::draw::{AllocError, DrawShared, ImageFormat, ImageHandle};
+use crate::geom::{Rect, Size, Vec2};
+use crate::layout::{AxisInfo, FrameRules, Margins, SetRectMgr, SizeRules, SolveCache};
+use crate::text::{Align, TextApi};
+use crate::theme::ThemeControl;
+use crate::theme::{DrawMgr, FrameStyle, MarkStyle, SizeHandle, SizeMgr, TextClass};
+use crate::{Layout, Popup};
+use kas_macros::impl_scope;
+use std::num::NonZeroU32;
+
+struct TestSize;
+
+impl SizeHandle for TestSize {
+    fn scale_factor(&self) -> f32 {
+        1.0
+    }
+    fn pixels_from_points(&self, pt: f32) -> f32 {
+        pt
+    }
+    fn pixels_from_em(&self, em: f32) -> f32 {
+        16.0 * em
+    }
+    fn frame(&self, _: FrameStyle, _: bool) -> FrameRules {
+        FrameRules::new_sym(0, 0, 0)
+    }
+    fn separator(&self) -> Size {
+        Size::ZERO
+    }
+    fn inner_margin(&self) -> Size {
+        Size::ZERO
+    }
+    fn outer_margins(&self) -> Margins {
+        Margins::ZERO
+    }
+    fn text_margins(&self) -> Margins {
+        Margins::ZERO
+    }
+    fn line_height(&self, _: TextClass) -> i32 {
+        16
+    }
+    fn text_bound(&self, _: &mut dyn TextApi, _: TextClass, _: AxisInfo) -> SizeRules {
+        SizeRules::fixed(16, (0, 0))
+    }
+    fn text_set_size(
+        &self,
+        _: &mut dyn TextApi,
+        _: TextClass,
+        size: Size,
+        _: (Align, Align),
+    ) -> Vec2 {
+        size.cast()
+    }
+    fn checkbox(&self) -> Size {
+        Size::ZERO
+    }
+    fn radiobox(&self) -> Size {
+        Size::ZERO
+    }
+    fn mark(&self, _: MarkStyle, _: bool) -> SizeRules {
+        SizeRules::EMPTY
+    }
+    fn scrollbar(&self) -> (Size, i32) {
+        (Size::ZERO, 0)
+    }
+    fn slider(&self) -> (Size, i32) {
+        (Size::ZERO, 0)
+    }
+    fn progress_bar(&self) -> Size {
+        Size::ZERO
+    }
+}
+
+struct TestDrawShared;
+
+impl DrawShared for TestDrawShared {
+    fn image_alloc(&mut self, _: (u32, u32)) -> Result<ImageHandle, AllocError> {
+        Err(AllocError)
+    }
+    fn image_upload(&mut self, _: &ImageHandle, _: &[u8], _: ImageFormat) {}
+    fn image_free(&mut self, _: ImageHandle) {}
+    fn image_size(&self, _: &ImageHandle) -> Option<Size> {
+        None
+    }
+}
+
+struct TestShell {
+    size: TestSize,
+    shared: TestDrawShared,
+    next_id: u32,
+}
+
+impl ShellWindow for TestShell {
+    fn add_popup(&mut self, _: Popup) -> Option<WindowId> {
+        self.next_id += 1;
+        NonZeroU32::new(self.next_id).map(WindowId::new)
+    }
+    fn add_window(&mut self, _: Box<dyn crate::Window>) -> WindowId {
+        // Windows are not shown; return a fresh identifier
+        self.next_id += 1;
+        WindowId::new(NonZeroU32::new(self.next_id).unwrap())
+    }
+    fn close_window(&mut self, _: WindowId) {}
+    fn trigger_update(&mut self, _: UpdateId, _: u64) {}
+    fn get_clipboard(&mut self) -> Option<String> {
+        None
+    }
+    fn set_clipboard(&mut self, _: String) {}
+    fn adjust_theme(&mut self, _: &mut dyn FnMut(&mut dyn ThemeControl) -> TkAction) {}
+    fn size_and_draw_shared(
+        &mut self,
+        f: &mut dyn FnMut(&mut dyn SizeHandle, &mut dyn DrawShared),
+    ) {
+        f(&mut self.size, &mut self.shared);
+    }
+    fn set_cursor_icon(&mut self, _: CursorIcon) {}
+}
+
+impl_scope! {
+    #[derive(Debug, Default)]
+    #[widget {
+        key_nav = true;
+    }]
+    struct Leaf {
+        core: widget_core!(),
+        presses: usize,
+        clicks: usize,
+        // If true, grab presses (allowing a drag)
+        grab: bool,
+        // If true, do not accept dragged payloads
+        reject_drag: bool,
+        drag_log: Vec<&'static str>,
+        dropped: Option<u32>,
+        // May be shared between leaves to record the order of delivery
+        hover_log: Rc<RefCell<Vec<(WidgetId, &'static str)>>>,
+        chars: String,
+    }
+
+    impl Layout for Self {
+        fn size_rules(&mut self, _: SizeMgr, _: AxisInfo) -> SizeRules {
+            SizeRules::fixed(10, (0, 0))
+        }
+
+        fn draw(&mut self, _: DrawMgr) {}
+    }
+
+    impl Widget for Self {
+        fn handle_event(&mut self, mgr: &mut EventMgr, event: Event) -> Response {
+            match event {
+                Event::PressStart { source, coord, .. } => {
+                    self.presses += 1;
+                    if self.grab {
+                        mgr.grab_press(self.id(), source, coord, GrabMode::Grab, None);
+                    }
+                    Response::Used
+                }
+                Event::PressEnd { success, .. } => {
+                    self.clicks += usize::from(success);
+                    Response::Used
+                }
+                Event::DragOver { .. } => {
+                    self.drag_log.push("over");
+                    match self.reject_drag {
+                        false => Response::Used,
+                        true => Response::Unused,
+                    }
+                }
+                Event::DragLeave => {
+                    self.drag_log.push("leave");
+                    Response::Used
+                }
+                Event::DragDrop { .. } => {
+                    self.drag_log.push("drop");
+                    let payload = mgr.take_drag_payload();
+                    self.dropped = payload.and_then(|p| p.downcast().ok()).map(|b| *b);
+                    Response::Used
+                }
+                Event::DragEnd { accepted } => {
+                    self.drag_log.push(if accepted { "accepted" } else { "end" });
+                    Response::Used
+                }
+                Event::MouseEnter => {
+                    self.hover_log.borrow_mut().push((self.id(), "enter"));
+                    Response::Used
+                }
+                Event::MouseLeave => {
+                    self.hover_log.borrow_mut().push((self.id(), "leave"));
+                    Response::Used
+                }
+                Event::ReceivedCharacter(c) => {
+                    self.chars.push(c);
+                    Response::Used
+                }
+                _ => Response::Unused,
+            }
+        }
+    }
+}
+
+impl_scope! {
+    #[derive(Debug, Default)]
+    #[widget {
+        layout = row: [self.a, self.b];
+    }]
+    struct Dialog {
+        core: widget_core!(),
+        #[widget]
+        a: Leaf,
+        #[widget]
+        b: Leaf,
+        chars: String,
+    }
+
+    impl Widget for Self {
+        fn handle_event(&mut self, _: &mut EventMgr, event: Event) -> Response {
+            match event {
+                Event::ReceivedCharacter(c) => {
+                    self.chars.push(c);
+                    Response::Used
+                }
+                _ => Response::Unused,
+            }
+        }
+    }
+}
+
+impl_scope! {
+    // A window with two leaves and a (pop-up) dialog, recording messages
+    #[derive(Debug, Default)]
+    #[widget {
+        layout = row: [self.a, self.b];
+    }]
+    struct TestWindow {
+        core: widget_core!(),
+        #[widget]
+        a: Leaf,
+        #[widget]
+        b: Leaf,
+        #[widget]
+        dialog: Dialog,
+        received: Vec<u32>,
+        chars: String,
+    }
+
+    impl Widget for Self {
+        fn handle_event(&mut self, _: &mut EventMgr, event: Event) -> Response {
+            match event {
+                Event::ReceivedCharacter(c) => {
+                    self.chars.push(c);
+                    Response::Used
+                }
+                _ => Response::Unused,
+            }
+        }
+
+        fn handle_message(&mut self, mgr: &mut EventMgr, _: usize) {
+            if let Some(msg) = mgr.try_pop_msg() {
+                self.received.push(msg);
+            }
+        }
+    }
+}
+
+struct Harness {
+    shell: TestShell,
+    state: EventState,
+    window: TestWindow,
+}
+
+impl Harness {
+    fn new() -> Self {
+        let mut shell = TestShell {
+            size: TestSize,
+            shared: TestDrawShared,
+            next_id: 0,
+        };
+        let config = Rc::new(RefCell::new(Config::default()));
+        let mut state = EventState::new(config, 1.0);
+        let mut window = TestWindow::default();
+        state.full_configure(&mut shell, &mut window);
+
+        Harness {
+            shell,
+            state,
+            window,
+        }
+    }
+
+    // Focus `window.a`, then open `window.dialog` as a modal pop-up with
+    // focus on `window.dialog.a`
+    fn with_modal() -> Self {
+        let mut h = Harness::new();
+        h.with(|mgr, w| {
+            mgr.set_nav_focus(w.a.id(), true);
+            let popup = Popup::new(w.dialog.id(), w.id(), Direction::Down).with_modal(true);
+            assert!(mgr.add_popup(popup).is_some());
+            assert!(mgr.next_nav_focus(w, false, true));
+        });
+        assert_eq!(h.state.nav_focus(), Some(h.window.dialog.a.id_ref()));
+        h
+    }
+
+    // Solve layout: leaves `a` and `b` are each 10×10, side by side
+    fn layout(&mut self) {
+        let size = &self.shell.size;
+        let mut cache = SolveCache::find_constraints(&mut self.window, SizeMgr::new(size));
+        let shared = &mut self.shell.shared;
+        let mut mgr = SetRectMgr::new(size, shared, &mut self.state);
+        let rect = Rect::new(Coord::ZERO, Size(20, 10));
+        cache.apply_rect(&mut self.window, &mut mgr, rect, false, false);
+    }
+
+    // Start a drag of `7u32` from `window.a`
+    fn start_drag(&mut self) {
+        self.window.a.grab = true;
+        let id = self.window.a.id();
+        self.press(id.clone());
+        self.with(|mgr, _| {
+            assert!(mgr.start_drag(id, Box::new(7u32), CursorIcon::Move));
+            assert!(mgr.is_dragging());
+        });
+    }
+
+    fn with(&mut self, f: impl FnOnce(&mut EventMgr, &mut TestWindow)) {
+        let window = &mut self.window;
+        self.state.with(&mut self.shell, |mgr| f(mgr, window));
+    }
+
+    fn press(&mut self, id: WidgetId) {
+        self.with(|mgr, window| {
+            let source = PressSource::Mouse(MouseButton::Left, 1);
+            mgr.start_press(window, source, Some(id), Coord::ZERO, true);
+        });
+    }
+
+    fn hover(&mut self, id: Option<WidgetId>) {
+        self.with(|mgr, window| mgr.set_hover(window, id, Coord::ZERO));
+    }
+
+    // Returns a log shared by `window.a` and `window.b`
+    fn share_hover_log(&mut self) -> Rc<RefCell<Vec<(WidgetId, &'static str)>>> {
+        self.window.b.hover_log = self.window.a.hover_log.clone();
+        self.window.a.hover_log.clone()
+    }
+
+    fn key(&mut self, vkey: VirtualKeyCode) {
+        self.with(|mgr, window| mgr.start_key_event(window, vkey, 0));
+    }
+
+    fn update(&mut self) {
+        let _ = self.state.update(&mut self.shell, &mut self.window);
+    }
+
+    // Update until `n` messages have been received (or panic on timeout)
+    fn update_until_received(&mut self, n: usize) {
+        for _ in 0..1000 {
+            self.update();
+            if self.window.received.len() >= n {
+                return;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(2));
+        }
+        panic!("timeout: received {:?}", self.window.received);
+    }
+}
+
+#[test]
+fn modal_swallows_outside_press() {
+    let mut h = Harness::with_modal();
+    let outside = h.window.b.id();
+    h.press(outside);
+    assert_eq!(h.window.b.presses, 0);
+    // Neither focus nor the pop-up are affected
+    assert_eq!(h.state.nav_focus(), Some(h.window.dialog.a.id_ref()));
+    assert_eq!(h.state.popups.len(), 1);
+
+    let inside = h.window.dialog.b.id();
+    h.press(inside);
+    assert_eq!(h.window.dialog.b.presses, 1);
+    assert_eq!(h.state.nav_focus(), Some(h.window.dialog.b.id_ref()));
+    assert_eq!(h.state.popups.len(), 1);
+}
+
+#[test]
+fn modal_traps_focus() {
+    let mut h = Harness::with_modal();
+    let (a, b) = (h.window.dialog.a.id(), h.window.dialog.b.id());
+    for expected in [&b, &a, &b] {
+        h.key(VirtualKeyCode::Tab);
+        assert_eq!(h.state.nav_focus(), Some(expected));
+    }
+}
+
+#[test]
+fn modal_escape_closes_and_restores_focus() {
+    let mut h = Harness::with_modal();
+    h.key(VirtualKeyCode::Escape);
+    assert!(h.state.popups.is_empty());
+    assert_eq!(h.state.nav_focus(), Some(h.window.a.id_ref()));
+
+    // Input outside is no longer blocked
+    let id = h.window.b.id();
+    h.press(id);
+    assert_eq!(h.window.b.presses, 1);
+}
+
+#[test]
+fn modal_close_restores_focus() {
+    let mut h = Harness::with_modal();
+    let popup_id = h.state.popups[0].0;
+    h.with(|mgr, _| mgr.close_window(popup_id, true));
+    assert!(h.state.popups.is_empty());
+    assert_eq!(h.state.nav_focus(), Some(h.window.a.id_ref()));
+}
+
+#[test]
+fn push_async_delivers_message() {
+    let mut h = Harness::new();
+    let id = h.window.a.id();
+    h.with(|mgr, _| {
+        mgr.push_async(id, async { 7u32 });
+    });
+    h.update();
+    assert_eq!(h.window.received, [7]);
+
+    // Each future is delivered only once
+    h.update();
+    assert_eq!(h.window.received, [7]);
+}
+
+#[test]
+fn cancel_async() {
+    let mut h = Harness::new();
+    let id = h.window.a.id();
+    let mut async_ids = vec![];
+    h.with(|mgr, _| {
+        async_ids.push(mgr.push_async(id.clone(), async { 1u32 }));
+        async_ids.push(mgr.push_async(id, async { 2u32 }));
+    });
+    assert_ne!(async_ids[0], async_ids[1]);
+
+    assert!(h.state.cancel_async(async_ids[0]));
+    assert!(!h.state.cancel_async(async_ids[0]));
+    h.update();
+    assert_eq!(h.window.received, [2]);
+    assert!(!h.state.cancel_async(async_ids[1]));
+}
+
+#[test]
+fn async_result_not_delivered_to_replacement() {
+    let mut h = Harness::new();
+    let id = h.window.a.id();
+    h.with(|mgr, w| {
+        mgr.push_async(id.clone(), async { 1u32 });
+
+        // Replace widget `a` with a new widget using the same id
+        w.a = Leaf::default();
+        mgr.set_rect_mgr(|mgr| mgr.configure(id.clone(), &mut w.a));
+        mgr.push_async(id.clone(), async { 2u32 });
+    });
+    h.update();
+    assert_eq!(h.window.received, [2]);
+}
+
+#[test]
+fn push_spawn_uses_bounded_pool() {
+    let mut h = Harness::new();
+    let id = h.window.b.id();
+    let n = 3 * MAX_WORKERS;
+    h.with(|mgr, _| {
+        for i in 0..n {
+            mgr.push_spawn(id.clone(), move || {
+                std::thread::sleep(std::time::Duration::from_millis(5));
+                u32::conv(i)
+            });
+        }
+    });
+    assert!(h.state.workers.threads <= MAX_WORKERS);
+
+    h.update_until_received(n);
+    h.window.received.sort_unstable();
+    assert_eq!(h.window.received, (0..n).map(u32::conv).collect::<Vec<_>>());
+    assert!(h.state.workers.threads <= MAX_WORKERS);
+}
+
+#[test]
+fn drag_and_drop() {
+    let mut h = Harness::new();
+    h.start_drag();
+    let b = h.window.b.id();
+    h.with(|mgr, w| {
+        mgr.drag_move(w, Some(b.clone()), Coord(15, 5));
+        mgr.drag_move(w, Some(b), Coord(16, 5));
+        mgr.end_drag(w, true, Coord(16, 5));
+    });
+    assert!(!h.state.is_dragging());
+    assert_eq!(h.window.b.drag_log, ["over", "over", "drop"]);
+    assert_eq!(h.window.b.dropped, Some(7));
+    assert_eq!(h.window.a.drag_log, ["accepted"]);
+    assert!(h.state.drag_payload().is_none());
+}
+
+#[test]
+fn drag_dropped_outside() {
+    let mut h = Harness::new();
+    h.start_drag();
+    let b = h.window.b.id();
+    h.with(|mgr, w| {
+        mgr.drag_move(w, Some(b), Coord(15, 5));
+        mgr.drag_move(w, None, Coord(30, 5));
+        mgr.end_drag(w, true, Coord(30, 5));
+    });
+    assert_eq!(h.window.b.drag_log, ["over", "leave"]);
+    assert_eq!(h.window.b.dropped, None);
+    assert_eq!(h.window.a.drag_log, ["end"]);
+}
+
+#[test]
+fn drag_rejected_by_target() {
+    let mut h = Harness::new();
+    h.window.b.reject_drag = true;
+    h.start_drag();
+    let b = h.window.b.id();
+    h.with(|mgr, w| {
+        mgr.drag_move(w, Some(b.clone()), Coord(15, 5));
+        mgr.drag_move(w, None, Coord(30, 5));
+        mgr.drag_move(w, Some(b), Coord(15, 5));
+        mgr.end_drag(w, true, Coord(15, 5));
+    });
+    assert_eq!(h.window.b.drag_log, ["over", "over"]);
+    assert_eq!(h.window.b.dropped, None);
+    assert_eq!(h.window.a.drag_log, ["end"]);
+}
+
+#[test]
+fn drag_cancelled_by_escape() {
+    let mut h = Harness::new();
+    h.start_drag();
+    let b = h.window.b.id();
+    h.with(|mgr, w| mgr.drag_move(w, Some(b), Coord(15, 5)));
+    assert!(h.state.drag_payload().is_some());
+    h.key(VirtualKeyCode::Escape);
+    assert!(!h.state.is_dragging());
+    h.update();
+    assert_eq!(h.window.b.drag_log, ["over", "leave"]);
+    assert_eq!(h.window.b.dropped, None);
+    assert_eq!(h.window.a.drag_log, ["end"]);
+}
+
+#[test]
+fn drag_requires_grab() {
+    let mut h = Harness::new();
+    let id = h.window.a.id();
+    h.with(|mgr, _| assert!(!mgr.start_drag(id, Box::new(()), CursorIcon::Move)));
+    assert!(!h.state.is_dragging());
+}
+
+#[test]
+fn simulate_click() {
+    let mut h = Harness::new();
+    h.layout();
+    h.window.b.grab = true;
+    h.with(|mgr, w| assert!(mgr.simulate_click(w, Coord(15, 5))));
+    assert_eq!((h.window.a.presses, h.window.b.presses), (0, 1));
+    assert_eq!(h.window.b.clicks, 1);
+    assert!(h.state.mouse_grab.is_none());
+    assert_eq!(h.state.nav_focus(), Some(h.window.b.id_ref()));
+
+    // Without a grab there is no PressEnd
+    h.with(|mgr, w| assert!(mgr.simulate_click(w, Coord(5, 5))));
+    assert_eq!((h.window.a.presses, h.window.a.clicks), (1, 0));
+
+    // No widget at this coordinate
+    h.with(|mgr, w| assert!(!mgr.simulate_click(w, Coord(25, 5))));
+    assert_eq!(h.window.b.presses, 1);
+}
+
+#[test]
+fn hover_leave_before_enter() {
+    let mut h = Harness::new();
+    let log = h.share_hover_log();
+    let (a, b) = (h.window.a.id(), h.window.b.id());
+
+    h.hover(Some(a.clone()));
+    h.update();
+    assert_eq!(*log.borrow(), [(a.clone(), "enter")]);
+
+    h.hover(Some(b.clone()));
+    h.update();
+    h.hover(None);
+    h.update();
+    let expected = [
+        (a.clone(), "enter"),
+        (a, "leave"),
+        (b.clone(), "enter"),
+        (b, "leave"),
+    ];
+    assert_eq!(*log.borrow(), expected);
+}
+
+#[test]
+fn hover_deferred_during_grab() {
+    let mut h = Harness::new();
+    let log = h.share_hover_log();
+    let (a, b) = (h.window.a.id(), h.window.b.id());
+    h.hover(Some(a.clone()));
+    h.window.a.grab = true;
+    h.press(a.clone());
+    assert!(h.state.mouse_grab.is_some());
+
+    // While grabbed, moving over `b` and back does not notify either
+    h.hover(Some(b.clone()));
+    h.update();
+    h.hover(Some(a.clone()));
+    h.hover(Some(b.clone()));
+    h.update();
+    assert_eq!(*log.borrow(), [(a.clone(), "enter")]);
+
+    // Releasing the grab delivers only the net change
+    h.with(|mgr, _| assert!(mgr.remove_mouse_grab().is_some()));
+    h.update();
+    let expected = [(a.clone(), "enter"), (a, "leave"), (b, "enter")];
+    assert_eq!(*log.borrow(), expected);
+}
+
+#[test]
+fn text_sent_only_on_request() {
+    let mut h = Harness::new();
+    let a = h.window.a.id();
+    h.with(|mgr, w| {
+        mgr.set_nav_focus(a, true);
+        mgr.received_char(w, 'x');
+    });
+    assert_eq!(h.window.a.chars, "");
+    assert_eq!(h.window.chars, "");
+
+    // Text is sent to the innermost requesting ancestor of the nav focus
+    let (window, dialog) = (h.window.id(), h.window.dialog.id());
+    let dialog_a = h.window.dialog.a.id();
+    h.with(|mgr, w| {
+        mgr.request_type_ahead(window);
+        mgr.request_type_ahead(dialog);
+        mgr.received_char(w, 'y');
+        mgr.set_nav_focus(dialog_a, true);
+        mgr.received_char(w, 'z');
+    });
+    assert_eq!(h.window.a.chars, "");
+    assert_eq!(h.window.chars, "y");
+    assert_eq!(h.window.dialog.a.chars, "");
+    assert_eq!(h.window.dialog.chars, "z");
+
+    // Char focus takes precedence
+    let b = h.window.b.id();
+    h.with(|mgr, w| {
+        assert!(mgr.request_char_focus(b));
+        mgr.received_char(w, 'c');
+    });
+    assert_eq!(h.window.b.chars, "c");
+    assert_eq!(h.window.chars, "y");
+    assert_eq!(h.window.dialog.chars, "z");
+}
+
+#[test]
+fn damage_regions() {
+    let mut h = Harness::new();
+    let _ = h.state.take_damage();
+    let rect = |x, y| Rect::new(Coord(x, y), Size(5, 5));
+
+    h.with(|mgr, _| {
+        mgr.redraw_rect(rect(0, 0));
+        mgr.redraw_rect(rect(10, 2));
+    });
+    assert!(h.state.action.contains(TkAction::REDRAW));
+    let union = Rect::new(Coord::ZERO, Size(15, 7));
+    assert_eq!(h.state.take_damage(), Some(union));
+    assert_eq!(h.state.take_damage(), None);
+
+    // Any other request causes a full redraw, regardless of order
+    let id = h.window.a.id();
+    h.with(|mgr, _| {
+        mgr.redraw_rect(rect(0, 0));
+        mgr.redraw(id);
+    });
+    assert_eq!(h.state.take_damage(), None);
+    h.with(|mgr, _| {
+        mgr.send_action(TkAction::SET_SIZE);
+        mgr.redraw_rect(rect(0, 0));
+    });
+    assert_eq!(h.state.take_damage(), None);
+}
@@ -78,7 +78,9 @@ pub use config::Config;
 #[cfg(not(feature = "winit"))]
 pub use enums::{CursorIcon, ModifiersState, MouseButton, VirtualKeyCode};
 pub use events::*;
-pub use manager::{EventMgr, EventState, GrabMode};
+#[cfg(feature = "action_stats")]
+pub use manager::ActionStats;
+pub use manager::{AsyncId, EventMgr, EventState, GrabMode};
 pub use response::{Response, Scroll};
 pub use update::UpdateId;
 
@@ -561,6 +561,12 @@ impl Rect {
         self.pos + self.size
     }
 
+    /// True if the rect has no area (size is not positive on some axis)
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        !self.size.gt(Size::ZERO)
+    }
+
     /// Check whether the given coordinate is contained within this rect
     #[inline]
     pub fn contains(&self, c: Coord) -> bool {
@@ -570,20 +576,49 @@ impl Rect {
             && c.1 < self.pos.1 + (self.size.1)
     }
 
+    /// Clamp a coordinate to lie within this rect
+    ///
+    /// The result satisfies [`Self::contains`] unless this rect is empty, in
+    /// which case [`Self::pos`] is returned.
+    #[inline]
+    pub fn clamp_coord(&self, c: Coord) -> Coord {
+        let max = (self.pos2() - Offset::splat(1)).max(self.pos);
+        c.max(self.pos).min(max)
+    }
+
     /// Calculate the intersection of two rects
+    ///
+    /// Returns `None` if the rects do not overlap, including when either is
+    /// empty or they merely touch.
     #[inline]
     pub fn intersection(&self, rhs: &Rect) -> Option<Rect> {
         let (l1, l2) = (self.pos, self.pos2());
         let (r1, r2) = (rhs.pos, rhs.pos2());
         let pos = l1.max(r1);
         let pos2 = l2.min(r2);
-        if pos.le(pos2) {
+        if pos.lt(pos2) {
             Some(Rect::new(pos, (pos2 - pos).cast()))
         } else {
             None
         }
     }
 
+    /// Calculate the smallest rect containing both `self` and `rhs`
+    ///
+    /// Empty rects are ignored (if both are empty, `self` is returned).
+    #[inline]
+    #[must_use = "method does not modify self but returns a new value"]
+    pub fn union(&self, rhs: &Rect) -> Rect {
+        if rhs.is_empty() {
+            return *self;
+        } else if self.is_empty() {
+            return *rhs;
+        }
+        let pos = self.pos.min(rhs.pos);
+        let pos2 = self.pos2().max(rhs.pos2());
+        Rect::new(pos, (pos2 - pos).cast())
+    }
+
     /// Shrink self in all directions by the given `n`
     #[inline]
     #[must_use = "method does not modify self but returns a new value"]
@@ -604,6 +639,19 @@ impl Rect {
         let size = self.size + Size::splat(n + n);
         Rect { pos, size }
     }
+
+    /// Inflate self in all directions by `by`
+    ///
+    /// Unlike [`Self::expand`], `by` may be negative, in which case this
+    /// shrinks the rect (as [`Self::shrink`]). The size is clamped to be
+    /// non-negative.
+    #[inline]
+    #[must_use = "method does not modify self but returns a new value"]
+    pub fn inflate(&self, by: i32) -> Rect {
+        let pos = self.pos - Offset::splat(by);
+        let size = Size(self.size.0 + by + by, self.size.1 + by + by).max(Size::ZERO);
+        Rect { pos, size }
+    }
 }
 
 impl std::ops::Add<Offset> for Rect {
@@ -679,3 +727,85 @@ mod winit_impls {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rect_inflate() {
+        let r = Rect::new(Coord(10, 20), Size(30, 40));
+        assert_eq!(r.inflate(5), r.expand(5));
+        assert_eq!(r.inflate(-5), r.shrink(5));
+        assert_eq!(r.inflate(0), r);
+        let r2 = r.inflate(-20);
+        assert_eq!(r2, Rect::new(Coord(30, 40), Size(0, 0)));
+        assert!(r2.is_empty());
+    }
+
+    #[test]
+    fn rect_intersection() {
+        let a = Rect::new(Coord(0, 0), Size(10, 10));
+        let b = Rect::new(Coord(5, -5), Size(10, 10));
+        let c = Rect::new(Coord(5, 0), Size(5, 5));
+        assert_eq!(a.intersection(&b), Some(c));
+        assert_eq!(b.intersection(&a), Some(c));
+
+        // Touching edges and empty rects do not intersect
+        let d = Rect::new(Coord(10, 0), Size(5, 5));
+        assert_eq!(a.intersection(&d), None);
+        let e = Rect::new(Coord(5, 5), Size::ZERO);
+        assert_eq!(a.intersection(&e), None);
+        assert_eq!(e.intersection(&e), None);
+    }
+
+    #[test]
+    fn rect_union() {
+        let a = Rect::new(Coord(0, 0), Size(10, 10));
+        let b = Rect::new(Coord(20, -5), Size(5, 5));
+        assert_eq!(a.union(&b), Rect::new(Coord(0, -5), Size(25, 15)));
+        assert_eq!(b.union(&a), a.union(&b));
+
+        // Empty rects are ignored
+        let e = Rect::new(Coord(-50, -50), Size(0, 10));
+        assert_eq!(a.union(&e), a);
+        assert_eq!(e.union(&a), a);
+        assert_eq!(e.union(&Rect::ZERO), e);
+    }
+
+    #[test]
+    fn rect_clamp_coord() {
+        let r = Rect::new(Coord(10, 20), Size(30, 40));
+        assert_eq!(r.clamp_coord(Coord(15, 25)), Coord(15, 25));
+        assert_eq!(r.clamp_coord(Coord(0, 100)), Coord(10, 59));
+        assert_eq!(r.clamp_coord(Coord(100, 0)), Coord(39, 20));
+        assert!(r.contains(r.clamp_coord(Coord::MAX)));
+        assert!(r.contains(r.clamp_coord(Coord::MIN)));
+
+        let e = Rect::new(Coord(10, 20), Size::ZERO);
+        assert_eq!(e.clamp_coord(Coord(50, 50)), Coord(10, 20));
+        assert_eq!(e.clamp_coord(Coord(0, 0)), Coord(10, 20));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn serde_round_trip() {
+        let coord = Coord(-3, 7);
+        let s = serde_json::to_string(&coord).unwrap();
+        assert_eq!(s, "[-3,7]");
+        assert_eq!(serde_json::from_str::<Coord>(&s).unwrap(), coord);
+
+        let offset = Offset(12, -1);
+        let s = serde_json::to_string(&offset).unwrap();
+        assert_eq!(serde_json::from_str::<Offset>(&s).unwrap(), offset);
+
+        let size = Size(640, 480);
+        let s = serde_json::to_string(&size).unwrap();
+        assert_eq!(serde_json::from_str::<Size>(&s).unwrap(), size);
+
+        let rect = Rect::new(coord, size);
+        let s = serde_json::to_string(&rect).unwrap();
+        assert_eq!(s, r#"{"pos":[-3,7],"size":[640,480]}"#);
+        assert_eq!(serde_json::from_str::<Rect>(&s).unwrap(), rect);
+    }
+}
@@ -29,10 +29,17 @@ pub use crate::text::Align;
 ///     .aligned_rect(pref_size, rect);
 /// // self.core.rect = rect;
 /// ```
+///
+/// ### Baseline alignment
+///
+/// If `baseline` is set, rows align the text baselines of children (see
+/// [`crate::Layout::baseline`]). Children not reporting a baseline are aligned
+/// using `vert` as usual. Other layouts ignore this hint.
 #[derive(Copy, Clone, Debug, Default)]
 pub struct AlignHints {
     pub horiz: Option<Align>,
     pub vert: Option<Align>,
+    pub baseline: bool,
 }
 
 impl AlignHints {
@@ -47,15 +54,29 @@ impl AlignHints {
 
     /// Construct with optional horiz. and vert. alignment
     pub const fn new(horiz: Option<Align>, vert: Option<Align>) -> Self {
-        Self { horiz, vert }
+        Self {
+            horiz,
+            vert,
+            baseline: false,
+        }
+    }
+
+    /// Enable baseline alignment, inline
+    #[must_use]
+    pub const fn with_baseline(mut self) -> Self {
+        self.baseline = true;
+        self
     }
 
     /// Combine two hints (first takes priority)
+    ///
+    /// Baseline alignment is enabled if enabled on either input.
     #[must_use = "method does not modify self but returns a new value"]
     pub fn combine(self, rhs: AlignHints) -> Self {
         Self {
             horiz: self.horiz.or(rhs.horiz),
             vert: self.vert.or(rhs.vert),
+            baseline: self.baseline || rhs.baseline,
         }
     }
 
@@ -84,6 +105,9 @@ pub struct CompleteAlignment {
 
 impl CompleteAlignment {
     /// Construct a rect of size `ideal` within `rect` using the given alignment
+    ///
+    /// On axes not using [`Align::Stretch`] the result is no larger than
+    /// `ideal`; on other axes it covers `rect`.
     pub fn aligned_rect(&self, ideal: Size, rect: Rect) -> Rect {
         let mut pos = rect.pos;
         let mut size = rect.size;
@@ -106,3 +130,37 @@ impl CompleteAlignment {
         Rect { pos, size }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::geom::Coord;
+
+    #[test]
+    fn aligned_rect_never_exceeds_ideal() {
+        let rect = Rect::new(Coord(10, 20), Size(100, 50));
+        let ideal = Size(30, 20);
+
+        let r = AlignHints::NONE
+            .complete(Align::Default, Align::TL)
+            .aligned_rect(ideal, rect);
+        assert_eq!(r, Rect::new(Coord(10, 20), ideal));
+
+        let r = AlignHints::CENTER
+            .complete(Align::Stretch, Align::Stretch)
+            .aligned_rect(ideal, rect);
+        assert_eq!(r, Rect::new(Coord(45, 35), ideal));
+
+        let r = AlignHints::new(Some(Align::BR), Some(Align::Stretch))
+            .complete(Align::Default, Align::Default)
+            .aligned_rect(ideal, rect);
+        assert_eq!(r, Rect::new(Coord(80, 20), Size(30, 50)));
+
+        // Less space than ideal: use what is available
+        let small = Rect::new(Coord(10, 20), Size(20, 10));
+        let r = AlignHints::CENTER
+            .complete(Align::Default, Align::Default)
+            .aligned_rect(ideal, small);
+        assert_eq!(r, small);
+    }
+}
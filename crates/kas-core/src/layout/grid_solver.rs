@@ -7,7 +7,7 @@
 
 use std::marker::PhantomData;
 
-use super::{Align, AlignHints, AxisInfo, SizeRules};
+use super::{Align, AlignHints, AxisInfo, SizeRules, Stretch};
 use super::{GridStorage, RowTemp, RulesSetter, RulesSolver};
 use crate::cast::{Cast, Conv};
 use crate::geom::{Coord, Offset, Rect, Size};
@@ -32,12 +32,108 @@ impl<T: Clone + Default> DefaultWithLen for Vec<T> {
 }
 
 /// Grid dimensions
+///
+/// Construct with [`GridDimensions::new`] (or [`Default`]), optionally
+/// followed by [`GridDimensions::with_col_weights`] and
+/// [`GridDimensions::with_row_weights`].
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub struct GridDimensions {
     pub cols: u32,
     pub col_spans: u32,
     pub rows: u32,
     pub row_spans: u32,
+    /// Relative weights used to distribute extra width between columns
+    ///
+    /// See [`GridDimensions::row_weights`].
+    pub col_weights: &'static [u32],
+    /// Relative weights used to distribute extra height between rows
+    ///
+    /// Space in excess of the ideal size is usually distributed equally
+    /// between rows with the highest [`Stretch`] priority. If weights are
+    /// given, that excess is instead distributed in proportion to the
+    /// weights of these rows. Rows without a weight (where the slice is
+    /// shorter than the number of rows) use weight 1. If the weights of
+    /// these rows sum to zero, equal distribution is used.
+    ///
+    /// Weights are applied by [`GridSetter`] and also by [`GridSolver`] when
+    /// solving for a fixed size on the other axis, thus both axes are weighted
+    /// consistently.
+    pub row_weights: &'static [u32],
+}
+
+impl GridDimensions {
+    /// Construct with the given numbers of columns, rows and spans
+    ///
+    /// No weights are used.
+    #[inline]
+    pub const fn new(cols: u32, col_spans: u32, rows: u32, row_spans: u32) -> Self {
+        GridDimensions {
+            cols,
+            col_spans,
+            rows,
+            row_spans,
+            col_weights: &[],
+            row_weights: &[],
+        }
+    }
+
+    /// Set column weights
+    ///
+    /// See [`GridDimensions::col_weights`].
+    #[inline]
+    #[must_use]
+    pub const fn with_col_weights(mut self, weights: &'static [u32]) -> Self {
+        self.col_weights = weights;
+        self
+    }
+
+    /// Set row weights
+    ///
+    /// See [`GridDimensions::row_weights`].
+    #[inline]
+    #[must_use]
+    pub const fn with_row_weights(mut self, weights: &'static [u32]) -> Self {
+        self.row_weights = weights;
+        self
+    }
+}
+
+/// Redistribute space in excess of the ideal size by `weights`
+///
+/// Only cells with the highest stretch priority (as used by
+/// [`SizeRules::solve_seq_total`]) are affected; the total is preserved.
+fn apply_weights(sizes: &mut [i32], rules: &[SizeRules], total: SizeRules, weights: &[u32]) {
+    if weights.is_empty() {
+        return;
+    }
+    let weight = |i: usize| u64::conv(weights.get(i).cloned().unwrap_or(1));
+    let highest_stretch = total.stretch().max(Stretch::Filler);
+    let is_target = |i: usize| rules[i].stretch() == highest_stretch;
+
+    let mut excess = 0;
+    let mut weight_sum = 0;
+    for i in (0..sizes.len()).filter(|i| is_target(*i)) {
+        excess += (sizes[i] - rules[i].ideal_size()).max(0);
+        weight_sum += weight(i);
+    }
+    if excess == 0 || weight_sum == 0 {
+        return;
+    }
+
+    let mut remaining = excess;
+    let mut first = None;
+    for i in (0..sizes.len()).filter(|i| is_target(*i)) {
+        let base = sizes[i].min(rules[i].ideal_size());
+        let share = i32::conv(u64::conv(excess) * weight(i) / weight_sum);
+        sizes[i] = base + share;
+        remaining -= share;
+        first = first.or(Some(i));
+    }
+    // Distribute rounding errors to the first target
+    if let Some(i) = first {
+        sizes[i] += remaining;
+    }
 }
 
 /// Per-child information
@@ -70,6 +166,8 @@ impl GridChildInfo {
 /// This implementation relies on the caller to provide storage for solver data.
 pub struct GridSolver<CSR, RSR, S: GridStorage> {
     axis: AxisInfo,
+    col_weights: &'static [u32],
+    row_weights: &'static [u32],
     col_spans: CSR,
     row_spans: RSR,
     next_col_span: usize,
@@ -93,6 +191,8 @@ impl<CSR: DefaultWithLen, RSR: DefaultWithLen, S: GridStorage> GridSolver<CSR, R
 
         let mut solver = GridSolver {
             axis,
+            col_weights: dim.col_weights,
+            row_weights: dim.row_weights,
             col_spans,
             row_spans,
             next_col_span: 0,
@@ -107,10 +207,16 @@ impl<CSR: DefaultWithLen, RSR: DefaultWithLen, S: GridStorage> GridSolver<CSR, R
         if self.axis.has_fixed {
             if self.axis.is_vertical() {
                 let (widths, rules) = storage.widths_and_rules();
-                SizeRules::solve_seq(widths, rules, self.axis.other_axis);
+                let total = SizeRules::sum(rules);
+                let target = self.axis.other_axis.min(total.max_size());
+                SizeRules::solve_seq_total(widths, rules, total, target);
+                apply_weights(widths, rules, total, self.col_weights);
             } else {
                 let (heights, rules) = storage.heights_and_rules();
-                SizeRules::solve_seq(heights, rules, self.axis.other_axis);
+                let total = SizeRules::sum(rules);
+                let target = self.axis.other_axis.min(total.max_size());
+                SizeRules::solve_seq_total(heights, rules, total, target);
+                apply_weights(heights, rules, total, self.row_weights);
             }
         }
 
@@ -300,6 +406,7 @@ impl<CT: RowTemp, RT: RowTemp, S: GridStorage> GridSetter<CT, RT, S> {
             }
 
             SizeRules::solve_seq_total(widths, rules, total, target);
+            apply_weights(widths, rules, total, dim.col_weights);
             for i in 1..w_offsets.as_mut().len() {
                 let i1 = i - 1;
                 let m1 = storage.width_rules()[i1].margins_i32().1;
@@ -327,6 +434,7 @@ impl<CT: RowTemp, RT: RowTemp, S: GridStorage> GridSetter<CT, RT, S> {
             }
 
             SizeRules::solve_seq_total(heights, rules, total, target);
+            apply_weights(heights, rules, total, dim.row_weights);
             for i in 1..h_offsets.as_mut().len() {
                 let i1 = i - 1;
                 let m1 = storage.height_rules()[i1].margins_i32().1;
@@ -368,3 +476,68 @@ impl<CT: RowTemp, RT: RowTemp, S: GridStorage> RulesSetter for GridSetter<CT, RT
         unimplemented!()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::layout::DynGridStorage;
+
+    fn solve(rules: &[SizeRules], weights: &[u32], target: i32) -> Vec<i32> {
+        let total = SizeRules::sum(rules);
+        let mut sizes = vec![0; rules.len()];
+        SizeRules::solve_seq_total(&mut sizes, rules, total, target);
+        apply_weights(&mut sizes, rules, total, weights);
+        sizes
+    }
+
+    #[test]
+    fn weights_distribute_excess() {
+        let rules = [SizeRules::new(0, 10, (0, 0), Stretch::High); 2];
+        assert_eq!(solve(&rules, &[], 80), vec![40, 40]);
+        assert_eq!(solve(&rules, &[1, 2], 80), vec![30, 50]);
+        assert_eq!(solve(&rules, &[3], 80), vec![55, 25]);
+        assert_eq!(solve(&rules, &[0, 0], 80), vec![40, 40]);
+    }
+
+    #[test]
+    fn weights_ignore_lower_stretch() {
+        let rules = [
+            SizeRules::new(0, 10, (0, 0), Stretch::None),
+            SizeRules::new(0, 10, (0, 0), Stretch::High),
+            SizeRules::new(0, 10, (0, 0), Stretch::High),
+        ];
+        assert_eq!(solve(&rules, &[5, 1, 3], 70), vec![10, 20, 40]);
+    }
+
+    #[test]
+    fn weights_apply_to_fixed_axis() {
+        type Solver = GridSolver<Vec<_>, Vec<_>, DynGridStorage>;
+        let dim = GridDimensions::new(2, 0, 1, 0).with_col_weights(&[1, 2]);
+        let rules = SizeRules::new(0, 10, (0, 0), Stretch::High);
+        let cells = [GridChildInfo::new(0, 0), GridChildInfo::new(1, 0)];
+        let mut storage = DynGridStorage::default();
+
+        let mut solver = Solver::new(AxisInfo::new(false, None), dim, &mut storage);
+        for info in cells {
+            solver.for_child(&mut storage, info, |_| rules);
+        }
+        let _ = solver.finish(&mut storage);
+
+        let mut widths = vec![];
+        let mut solver = Solver::new(AxisInfo::new(true, Some(80)), dim, &mut storage);
+        for info in cells {
+            solver.for_child(&mut storage, info, |axis| {
+                widths.push(axis.other().unwrap());
+                rules
+            });
+        }
+        let _ = solver.finish(&mut storage);
+        assert_eq!(widths, vec![30, 50]);
+
+        let rect = Rect::new(Coord::ZERO, Size(80, 10));
+        let mut setter =
+            GridSetter::<Vec<_>, Vec<_>, _>::new(rect, dim, AlignHints::NONE, &mut storage);
+        assert_eq!(setter.child_rect(&mut storage, cells[0]).size, Size(30, 10));
+        assert_eq!(setter.child_rect(&mut storage, cells[1]).size, Size(50, 10));
+    }
+}
@@ -63,33 +63,148 @@ pub use row_solver::{RowPositionSolver, RowSetter, RowSolver};
 pub use single_solver::{SingleSetter, SingleSolver};
 pub use size_rules::SizeRules;
 pub use size_types::*;
+pub(crate) use sizer::WidgetHeirarchy;
 pub use sizer::{solve_size_rules, RulesSetter, RulesSolver, SolveCache};
 pub use storage::*;
-pub use visitor::{FrameStorage, Visitor};
+pub use visitor::{BackgroundStorage, FrameStorage, Visitor};
+
+/// Which size(s) the caller of [`crate::Layout::size_rules`] is interested in
+///
+/// This is a hint, available via [`AxisInfo::target`]. Since [`SizeRules`]
+/// always includes both a minimum and an ideal size, widgets must return
+/// valid values for both regardless, but content-wrapping widgets (e.g. text)
+/// may use this hint to choose how to wrap content.
+///
+/// Containers pass the hint on to children unchanged.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SizeTarget {
+    /// Both minimum and ideal sizes are required (default)
+    ///
+    /// This is used when the other axis has its final size (if fixed).
+    Both,
+    /// The caller is measuring the ideal size
+    ///
+    /// This is used when measuring the height of a window given its ideal
+    /// width (see [`SolveCache::find_constraints`]); the window may later be
+    /// given a different width.
+    Ideal,
+    /// The caller is measuring the minimum size
+    ///
+    /// This is used when measuring the minimum height of a window given its
+    /// minimum width (see [`SolveCache::find_constraints`]); only the result's
+    /// minimum size is used.
+    Min,
+}
+
+impl Default for SizeTarget {
+    fn default() -> Self {
+        SizeTarget::Both
+    }
+}
 
 /// Information on which axis is being resized
 ///
-/// Also conveys the size of the other axis, if fixed.
+/// Also conveys the size of the other axis, if fixed, a [`SizeTarget`]
+/// hint and a [generation](Self::generation) token usable for caching.
 #[derive(Copy, Clone, Debug)]
 pub struct AxisInfo {
     vertical: bool,
     has_fixed: bool,
+    target: SizeTarget,
     other_axis: i32,
+    generation: u32,
+    baseline: bool,
 }
 
 impl AxisInfo {
     /// Construct with direction and an optional value for the other axis
     ///
+    /// The [`SizeTarget`] is [`SizeTarget::Both`] and the
+    /// [generation](Self::generation) is zero (unknown).
+    ///
     /// This method is *usually* not required by user code.
     #[inline]
     pub fn new(vertical: bool, fixed: Option<i32>) -> Self {
         AxisInfo {
             vertical,
             has_fixed: fixed.is_some(),
+            target: SizeTarget::Both,
             other_axis: fixed.unwrap_or(0),
+            generation: 0,
+            baseline: false,
         }
     }
 
+    /// Set the generation, inline
+    ///
+    /// Containers constructing a new `AxisInfo` for their children should
+    /// pass on the generation of their own `axis`.
+    #[inline]
+    #[must_use]
+    pub fn with_generation(mut self, generation: u32) -> Self {
+        self.generation = generation;
+        self
+    }
+
+    /// Get the generation token
+    ///
+    /// The layout solver (see [`SolveCache`]) assigns a new, unique,
+    /// non-zero generation each time size rules are solved from scratch:
+    /// when the window is configured, after [`TkAction::RESIZE`] and when
+    /// the scale factor or theme changes. A generation is never re-used.
+    ///
+    /// Thus, a widget with expensive [`crate::Layout::size_rules`] may cache
+    /// its result and return that when called again with equal generation,
+    /// [axis](Self::is_vertical), [other-axis size](Self::other) and
+    /// [target](Self::target), assuming that the widget's own content has not
+    /// changed since (a widget changing its content should trigger
+    /// [`TkAction::RESIZE`] anyway). Note that such a widget must still
+    /// re-apply any side effects of `size_rules` which depend on the
+    /// other-axis size. A generation of zero is "unknown" and must never match.
+    ///
+    /// [`TkAction::RESIZE`]: crate::TkAction::RESIZE
+    #[inline]
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    /// Set the [`SizeTarget`] hint, inline
+    #[inline]
+    #[must_use]
+    pub fn with_target(mut self, target: SizeTarget) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Get the [`SizeTarget`] hint
+    ///
+    /// Widgets may ignore this; see [`SizeTarget`].
+    #[inline]
+    pub fn target(&self) -> SizeTarget {
+        self.target
+    }
+
+    /// Set whether baseline alignment applies, inline
+    ///
+    /// This mirrors [`AlignHints::baseline`] for use by
+    /// [`crate::Layout::size_rules`]: rows aligning children on their text
+    /// baselines must reserve space for the resulting vertical offsets.
+    /// Horizontal rows consume this hint; it is not passed to their children.
+    #[inline]
+    #[must_use]
+    pub fn with_baseline(mut self, baseline: bool) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// True if baseline alignment applies
+    ///
+    /// See [`Self::with_baseline`].
+    #[inline]
+    pub fn align_baseline(&self) -> bool {
+        self.baseline
+    }
+
     /// True if the current axis is vertical
     #[inline]
     pub fn is_vertical(&self) -> bool {
@@ -168,6 +283,7 @@ pub trait AutoLayout {
     fn set_rect(&mut self, mgr: &mut SetRectMgr, rect: Rect, align: AlignHints);
     fn find_id(&mut self, coord: Coord) -> Option<WidgetId>;
     fn draw(&mut self, draw: DrawMgr);
+    fn baseline(&mut self) -> Option<i32>;
 }
 
 /// Manager available to [`Layout::set_rect`] and [`Widget::configure`]
@@ -221,6 +337,10 @@ impl<'a> SetRectMgr<'a> {
     /// the parent's id via [`WidgetId::make_child`].
     #[inline]
     pub fn configure(&mut self, id: WidgetId, widget: &mut dyn Widget) {
+        if widget.id_ref() != &id {
+            // This is a new widget (or was moved): drop futures of the old one
+            self.ev.drop_futures_of(&id);
+        }
         widget.pre_configure(self, id);
 
         for index in 0..widget.num_children() {
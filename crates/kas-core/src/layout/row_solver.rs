@@ -8,7 +8,7 @@
 use std::marker::PhantomData;
 use std::ops::Range;
 
-use super::{Align, AlignHints, AxisInfo, SizeRules};
+use super::{Align, AlignHints, AxisInfo, SetRectMgr, SizeRules, Stretch};
 use super::{RowStorage, RowTemp, RulesSetter, RulesSolver};
 use crate::dir::{Direction, Directional};
 use crate::geom::{Coord, Rect};
@@ -25,6 +25,9 @@ pub struct RowSolver<S: RowStorage> {
     axis_is_vertical: bool,
     axis_is_reversed: bool,
     rules: Option<SizeRules>,
+    // Max. ascent, max. descent below baseline for min and ideal sizes
+    baseline: Option<(i32, i32, i32)>,
+    last: SizeRules,
     _s: PhantomData<S>,
 }
 
@@ -36,10 +39,19 @@ impl<S: RowStorage> RowSolver<S> {
     /// - `axis`: `AxisInfo` instance passed into `size_rules`
     /// - `(dir, len)`: direction and number of items
     /// - `storage`: reference to persistent storage
-    pub fn new<D: Directional>(axis: AxisInfo, (dir, len): (D, usize), storage: &mut S) -> Self {
+    pub fn new<D: Directional>(
+        mut axis: AxisInfo,
+        (dir, len): (D, usize),
+        storage: &mut S,
+    ) -> Self {
         storage.set_dim(len);
 
         let axis_is_vertical = axis.is_vertical() ^ dir.is_vertical();
+        let baseline = axis.align_baseline() && axis.is_vertical() && dir.is_horizontal();
+        let baseline = baseline.then(|| (0, 0, 0));
+        if dir.is_horizontal() {
+            axis = axis.with_baseline(false);
+        }
 
         if axis.has_fixed && axis_is_vertical {
             let (widths, rules) = storage.widths_and_rules();
@@ -51,9 +63,31 @@ impl<S: RowStorage> RowSolver<S> {
             axis_is_vertical,
             axis_is_reversed: dir.is_reversed(),
             rules: None,
+            baseline,
+            last: SizeRules::EMPTY,
             _s: Default::default(),
         }
     }
+
+    /// Note the text baseline of the last child
+    ///
+    /// Rows which may be aligned using [`AlignHints::baseline`] should call
+    /// this after each call to [`RulesSolver::for_child`]. If baseline
+    /// alignment applies (see [`AxisInfo::align_baseline`]), `baseline` is
+    /// called and should return the child's
+    /// [`Layout::baseline`](crate::Layout::baseline). The vertical rules of the
+    /// row are then enlarged such that each child may be offset to align
+    /// baselines (see [`RowSetter::set_rects_on_baseline`]) without reducing
+    /// it below its own size rules.
+    pub fn note_baseline<F: FnOnce() -> Option<i32>>(&mut self, baseline: F) {
+        if let Some((ascent, min, ideal)) = self.baseline.as_mut() {
+            if let Some(baseline) = baseline() {
+                *ascent = (*ascent).max(baseline);
+                *min = (*min).max(self.last.min_size() - baseline);
+                *ideal = (*ideal).max(self.last.ideal_size() - baseline);
+            }
+        }
+    }
 }
 
 impl<S: RowStorage> RulesSolver for RowSolver<S> {
@@ -70,6 +104,7 @@ impl<S: RowStorage> RulesSolver for RowSolver<S> {
             self.axis.other_axis = storage.widths()[child_info];
         }
         let child_rules = child_rules(self.axis);
+        self.last = child_rules;
         if !self.axis_is_vertical {
             storage.rules()[child_info] = child_rules;
             if let Some(rules) = self.rules {
@@ -91,7 +126,15 @@ impl<S: RowStorage> RulesSolver for RowSolver<S> {
     }
 
     fn finish(self, _: &mut Self::Storage) -> SizeRules {
-        self.rules.unwrap_or(SizeRules::EMPTY)
+        let rules = self.rules.unwrap_or(SizeRules::EMPTY);
+        match self.baseline {
+            Some((ascent, min, ideal)) => {
+                let min = ascent + min;
+                let ideal = ascent + ideal;
+                rules.max(SizeRules::new(min, ideal, (0, 0), Stretch::None))
+            }
+            None => rules,
+        }
     }
 }
 
@@ -224,6 +267,55 @@ impl<D: Directional, T: RowTemp, S: RowStorage> RowSetter<D, T, S> {
         let (widths, rules) = storage.widths_and_rules();
         SizeRules::solve_seq(&mut widths[range.clone()], &rules[range], width);
     }
+
+    /// Set child rects, aligning text baselines
+    ///
+    /// This is an alternative to calling [`RulesSetter::child_rect`] for each
+    /// child, used to implement [`AlignHints::baseline`] for horizontal rows.
+    /// `set_rect` is passed `(mgr, index, rect, align)`; it should call
+    /// [`Layout::set_rect`](crate::Layout::set_rect) on the child then return
+    /// the child's [`Layout::baseline`](crate::Layout::baseline). It may be
+    /// called more than once per child.
+    ///
+    /// Children reporting a baseline are top-aligned, then offset such that
+    /// all baselines match. Other children are aligned using `align.vert`.
+    /// Space for these offsets is reserved by [`RowSolver::note_baseline`].
+    pub fn set_rects_on_baseline<F>(
+        &mut self,
+        storage: &mut S,
+        mgr: &mut SetRectMgr,
+        align: AlignHints,
+        mut set_rect: F,
+    ) where
+        F: FnMut(&mut SetRectMgr, usize, Rect, AlignHints) -> Option<i32>,
+    {
+        debug_assert!(self.direction.is_horizontal());
+        let align = AlignHints::new(align.horiz, align.vert);
+        let top = AlignHints::new(align.horiz, Some(Align::TL));
+
+        let len = storage.widths().len();
+        let mut baselines = Vec::with_capacity(len);
+        for index in 0..len {
+            let rect = self.child_rect(storage, index);
+            let baseline = set_rect(mgr, index, rect, top);
+            if baseline.is_none() {
+                set_rect(mgr, index, rect, align);
+            }
+            baselines.push(baseline);
+        }
+
+        if let Some(max) = baselines.iter().flatten().max().cloned() {
+            for (index, baseline) in baselines.into_iter().enumerate() {
+                if let Some(baseline) = baseline.filter(|b| *b < max) {
+                    let offset = max - baseline;
+                    let mut rect = self.child_rect(storage, index);
+                    rect.pos.1 += offset;
+                    rect.size.1 = (rect.size.1 - offset).max(0);
+                    set_rect(mgr, index, rect, top);
+                }
+            }
+        }
+    }
 }
 
 impl<D: Directional, T: RowTemp, S: RowStorage> RulesSetter for RowSetter<D, T, S> {
@@ -8,7 +8,7 @@
 use smallvec::SmallVec;
 use std::iter::Sum;
 
-use super::{Margins, Stretch};
+use super::{AxisInfo, Margins, Stretch};
 use crate::cast::{Cast, CastFloat, Conv};
 use crate::dir::Directional;
 use crate::geom::Size;
@@ -151,6 +151,32 @@ impl SizeRules {
         SizeRules::extract(dir, size, margin, Stretch::None)
     }
 
+    /// Construct rules maintaining an aspect ratio
+    ///
+    /// The `ratio` is `width / height` (for example, `1.618` for the golden
+    /// section). When the size of the other axis is fixed (see
+    /// [`AxisInfo::other`]), this returns a fixed size satisfying
+    /// `height = width / ratio` (or `width = height * ratio`), using the
+    /// margins of `rules`.
+    ///
+    /// Otherwise (usually the first pass, sizing the horizontal axis),
+    /// `rules` is returned unmodified; this should describe a sensible range
+    /// for the axis, e.g. via [`LogicalSize::to_rules`](super::LogicalSize::to_rules).
+    pub fn aspect_ratio(axis: AxisInfo, ratio: f32, rules: SizeRules) -> Self {
+        debug_assert!(ratio > 0.0);
+        match axis.other() {
+            Some(other) => {
+                let size = if axis.is_vertical() {
+                    f32::conv(other) / ratio
+                } else {
+                    f32::conv(other) * ratio
+                };
+                SizeRules::fixed(size.cast_nearest(), rules.m)
+            }
+            None => rules,
+        }
+    }
+
     /// Construct with custom rules
     ///
     /// Region size should meet the given `min`-imum size and has a given
@@ -168,12 +194,46 @@ impl SizeRules {
         }
     }
 
+    /// Construct from a `(min, ideal)` pair
+    ///
+    /// This is a shortcut to [`SizeRules::new`] with zero margins and
+    /// [`Stretch::None`], intended for tests and simple fixed-size widgets.
+    ///
+    /// Expected: `ideal >= min` (if not, ideal is clamped to min).
+    #[inline]
+    pub fn from_pair(pair: (i32, i32)) -> Self {
+        SizeRules::new(pair.0, pair.1, (0, 0), Stretch::None)
+    }
+
+    /// Get the `(min, ideal)` sizes as a pair
+    ///
+    /// Margins and stretch priority are discarded: converting back with
+    /// [`SizeRules::from_pair`] does not round-trip unless these are zero
+    /// and [`Stretch::None`] respectively.
+    #[inline]
+    pub fn as_pair(self) -> (i32, i32) {
+        (self.a, self.b)
+    }
+
     /// Set stretch factor, inline
     #[inline]
     pub fn with_stretch(self, stretch: Stretch) -> Self {
         Self::new(self.a, self.b, self.m, stretch)
     }
 
+    /// True if these rules request no space
+    ///
+    /// This is the case when both the minimum and ideal sizes are zero and the
+    /// stretch priority is [`Stretch::None`] (e.g. a collapsed widget).
+    /// Margins are ignored: an empty widget may be laid out without margins.
+    ///
+    /// Zero-size rules with a higher stretch priority (e.g. a `Filler`) are
+    /// *not* empty since such rules do want space, when available.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.a == 0 && self.b == 0 && self.stretch == Stretch::None
+    }
+
     /// Get the minimum size
     #[inline]
     pub fn min_size(self) -> i32 {
@@ -228,6 +288,18 @@ impl SizeRules {
         self.m = margins;
     }
 
+    /// Copy margins from `other`
+    ///
+    /// Returns `self` with margins replaced by those of `other`; other
+    /// properties are unchanged. This is useful when wrapping a child:
+    /// the wrapper's rules may keep the child's margins.
+    #[inline]
+    #[must_use]
+    pub fn with_margins_from(mut self, other: SizeRules) -> Self {
+        self.m = other.m;
+        self
+    }
+
     /// Set margins to max of own margins and given margins
     pub fn include_margins(&mut self, margins: (u16, u16)) {
         self.m.0 = self.m.0.max(margins.0);
@@ -235,6 +307,11 @@ impl SizeRules {
     }
 
     /// Use the maximum size of `self` and `rhs`.
+    ///
+    /// This is the appropriate combinator for overlapping (stacked) content,
+    /// e.g. a `float` layout: minimum size, ideal size, each margin and the
+    /// stretch priority are all the maximum of the two inputs (margins are
+    /// *not* summed). The operation is commutative and associative.
     #[inline]
     #[must_use = "method does not modify self but returns a new value"]
     pub fn max(self, rhs: Self) -> SizeRules {
@@ -274,6 +351,11 @@ impl SizeRules {
     ///
     /// Note also that appending [`SizeRules::EMPTY`] does include interior
     /// margins (those between `EMPTY` and the other rules) within the result.
+    ///
+    /// "Right of or below" refers to position on screen, not logical order:
+    /// for a layout with reversed [`Direction`](crate::dir::Direction) (`Left`
+    /// or `Up`), logically later elements are positioned before earlier ones
+    /// and should be added with [`Self::prepend`].
     pub fn append(&mut self, rhs: SizeRules) {
         let c: i32 = self.m.1.max(rhs.m.0).into();
         self.a += rhs.a + c;
@@ -282,6 +364,22 @@ impl SizeRules {
         self.stretch = self.stretch.max(rhs.stretch);
     }
 
+    /// Prepend the rules for `lhs` to self
+    ///
+    /// This implies that `lhs` rules concern an element to the left of or
+    /// above self; it is the mirror of [`Self::append`]. The margin between
+    /// `lhs` and self is the larger of the two adjacent margins, and the
+    /// leading margin of the result is that of `lhs`.
+    ///
+    /// `rules.prepend(lhs)` is equivalent to `rules = lhs.appended(rules)`.
+    pub fn prepend(&mut self, lhs: SizeRules) {
+        let c: i32 = lhs.m.1.max(self.m.0).into();
+        self.a += lhs.a + c;
+        self.b += lhs.b + c;
+        self.m.0 = lhs.m.0;
+        self.stretch = self.stretch.max(lhs.stretch);
+    }
+
     /// Return the rules for self appended by `rhs`
     ///
     ///
@@ -700,3 +798,136 @@ impl<'a> Sum<&'a Self> for SizeRules {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn max_is_associative() {
+        let a = SizeRules::new(10, 20, (1, 4), Stretch::None);
+        let b = SizeRules::new(15, 15, (3, 2), Stretch::Low);
+        let c = SizeRules::new(5, 30, (2, 0), Stretch::Filler);
+
+        assert_eq!(a.max(b).max(c), a.max(b.max(c)));
+        assert_eq!(a.max(b), b.max(a));
+        assert_eq!(
+            a.max(b).max(c),
+            SizeRules::new(15, 30, (3, 4), Stretch::Low)
+        );
+    }
+
+    #[test]
+    fn append_prepend_symmetric() {
+        let a = SizeRules::new(10, 20, (1, 4), Stretch::None);
+        let b = SizeRules::new(15, 15, (3, 2), Stretch::Low);
+        let c = SizeRules::new(5, 30, (6, 0), Stretch::Filler);
+
+        let mut r = b;
+        r.append(c);
+        r.prepend(a);
+        assert_eq!(r, a.appended(b).appended(c));
+        assert_eq!(r, SizeRules::sum(&[a, b, c]));
+        // Stretch is the maximum: Low > Filler > None
+        assert_eq!(r, SizeRules::new(40, 75, (1, 0), Stretch::Low));
+
+        let mut r2 = c;
+        r2.prepend(b);
+        r2.prepend(a);
+        assert_eq!(r2, r);
+    }
+
+    #[test]
+    fn max_combines_margins() {
+        let a = SizeRules::fixed(10, (4, 4));
+        let b = SizeRules::fixed(10, (6, 2));
+
+        let r = a.max(b);
+        assert_eq!(r.margins(), (6, 4));
+        assert_eq!(r.min_size(), 10);
+        assert_eq!(r.ideal_size(), 10);
+
+        let mut r2 = a;
+        r2.max_with(b);
+        assert_eq!(r, r2);
+    }
+
+    #[test]
+    fn aspect_ratio() {
+        let rules = SizeRules::new(100, 200, (2, 3), Stretch::High);
+
+        let r = SizeRules::aspect_ratio(AxisInfo::new(false, None), 2.0, rules);
+        assert_eq!(r, rules);
+
+        let r = SizeRules::aspect_ratio(AxisInfo::new(true, Some(300)), 2.0, rules);
+        assert_eq!(r, SizeRules::fixed(150, (2, 3)));
+
+        let r = SizeRules::aspect_ratio(AxisInfo::new(false, Some(150)), 2.0, rules);
+        assert_eq!(r, SizeRules::fixed(300, (2, 3)));
+    }
+
+    #[test]
+    fn with_margins_from() {
+        let a = SizeRules::new(10, 20, (1, 2), Stretch::High);
+        let b = SizeRules::new(5, 5, (7, 3), Stretch::None);
+
+        let r = a.with_margins_from(b);
+        assert_eq!(r.margins(), (7, 3));
+        assert_eq!(r.min_size(), 10);
+        assert_eq!(r.ideal_size(), 20);
+        assert_eq!(r.stretch(), Stretch::High);
+        assert_eq!(r, SizeRules::new(10, 20, (7, 3), Stretch::High));
+    }
+
+    #[test]
+    fn pair() {
+        let r = SizeRules::from_pair((10, 20));
+        assert_eq!(r, SizeRules::new(10, 20, (0, 0), Stretch::None));
+        assert_eq!(r.as_pair(), (10, 20));
+
+        assert_eq!(SizeRules::from_pair((10, 5)).as_pair(), (10, 10));
+
+        let r = SizeRules::new(5, 8, (2, 3), Stretch::High);
+        assert_eq!(r.as_pair(), (5, 8));
+        assert_eq!(
+            SizeRules::from_pair(r.as_pair()),
+            SizeRules::new(5, 8, (0, 0), Stretch::None)
+        );
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(SizeRules::EMPTY.is_empty());
+        assert!(SizeRules::fixed(0, (4, 4)).is_empty());
+        assert!(!SizeRules::fixed(1, (0, 0)).is_empty());
+        assert!(!SizeRules::new(0, 5, (0, 0), Stretch::None).is_empty());
+        assert!(!SizeRules::empty(Stretch::Filler).is_empty());
+    }
+
+    #[test]
+    fn solve_seq_maximize_fair_share() {
+        let rules = [
+            SizeRules::new(10, 20, (0, 0), Stretch::Maximize),
+            SizeRules::new(30, 40, (0, 0), Stretch::Maximize),
+            SizeRules::new(10, 10, (0, 0), Stretch::High),
+        ];
+
+        // Excess over the ideal (70) is shared equally by both Maximize items
+        let mut out = [0; 3];
+        SizeRules::solve_seq(&mut out, &rules, 170);
+        assert_eq!(out, [70, 90, 10]);
+
+        // Rounding error goes to the first
+        let mut out = [0; 3];
+        SizeRules::solve_seq(&mut out, &rules, 171);
+        assert_eq!(out, [71, 90, 10]);
+
+        // Growing then shrinking reverts to the same equal split
+        let mut out = [0; 3];
+        SizeRules::solve_seq(&mut out, &rules, 170);
+        SizeRules::solve_seq(&mut out, &rules, 250);
+        assert_eq!(out, [110, 130, 10]);
+        SizeRules::solve_seq(&mut out, &rules, 170);
+        assert_eq!(out, [70, 90, 10]);
+    }
+}
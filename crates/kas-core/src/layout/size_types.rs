@@ -10,6 +10,7 @@ use crate::cast::*;
 use crate::dir::Directional;
 use crate::geom::{Rect, Size, Vec2};
 use kas_macros::{impl_default, impl_scope};
+use std::ops::{Add, Mul, Sub};
 
 // for doc use
 #[allow(unused)]
@@ -64,6 +65,44 @@ impl LogicalSize {
     pub fn extract_scaled(self, dir: impl Directional, scale_factor: f32) -> i32 {
         (self.extract(dir) * scale_factor).cast_nearest()
     }
+
+    /// Return the minimum, componentwise
+    #[inline]
+    #[must_use = "method does not modify self but returns a new value"]
+    pub fn min(self, other: Self) -> Self {
+        LogicalSize(self.0.min(other.0), self.1.min(other.1))
+    }
+
+    /// Return the maximum, componentwise
+    #[inline]
+    #[must_use = "method does not modify self but returns a new value"]
+    pub fn max(self, other: Self) -> Self {
+        LogicalSize(self.0.max(other.0), self.1.max(other.1))
+    }
+}
+
+impl Add for LogicalSize {
+    type Output = LogicalSize;
+    #[inline]
+    fn add(self, rhs: LogicalSize) -> Self::Output {
+        LogicalSize(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl Sub for LogicalSize {
+    type Output = LogicalSize;
+    #[inline]
+    fn sub(self, rhs: LogicalSize) -> Self::Output {
+        LogicalSize(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl Mul<f32> for LogicalSize {
+    type Output = LogicalSize;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        LogicalSize(self.0 * rhs, self.1 * rhs)
+    }
 }
 
 impl From<(f32, f32)> for LogicalSize {
@@ -221,6 +260,16 @@ impl MarginSelector {
 ///
 /// Space is allocated based on priority, with extra space (beyond the minimum)
 /// shared between widgets in the highest priority class.
+///
+/// Space in excess of the ideal size is shared *equally* between all widgets
+/// of the highest priority present: each is enlarged by the same amount over
+/// its ideal size (any rounding remainder going to the first). Widgets of lower
+/// priority are not enlarged beyond their ideal size. This applies to any
+/// priority, including multiple [`Stretch::Maximize`] widgets in one row.
+///
+/// Note that the layout solver preserves existing sizes where these already
+/// satisfy the rules, thus the split may be uneven after a change to some
+/// widget's size rules (e.g. its ideal size) without a change in total size.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
 pub enum Stretch {
     /// Prefer no stretching
@@ -228,6 +277,10 @@ pub enum Stretch {
     /// This does not prevent stretching. In particular, if the widget is in a
     /// column or row with a larger widget, that larger width/height will be
     /// provided.
+    ///
+    /// Widgets positioning themselves with
+    /// [`super::CompleteAlignment::aligned_rect`] do not exceed their ideal
+    /// size given a non-stretch alignment (e.g. via [`AlignHints`]).
     None,
     /// Fill unwanted space
     ///
@@ -238,6 +291,9 @@ pub enum Stretch {
     /// Extra space is considered of high utility
     High,
     /// Greedily consume as much space as possible
+    ///
+    /// Multiple `Maximize` widgets in one row or column share the excess
+    /// space equally (as with other priorities).
     Maximize,
 }
 
@@ -274,6 +330,15 @@ impl_scope! {
         ///
         /// If is `None`, max size is limited to ideal size.
         pub stretch: Stretch,
+        /// If true, the image is tiled instead of scaled
+        ///
+        /// When tiling, [`Self::align_rect`] returns the whole available
+        /// area and the image is repeated across this at size
+        /// [`Self::tile_size`]; tiles at the right and bottom edges are
+        /// clipped. [`Self::fix_aspect`] is ignored.
+        ///
+        /// Default: `false`
+        pub tile: bool,
     }
 }
 
@@ -296,7 +361,14 @@ impl PixmapScaling {
     /// Constrains and aligns within `rect`
     ///
     /// The resulting size is then aligned using the `align` hints, defaulting to centered.
+    ///
+    /// If [`Self::tile`] is true, `rect` is returned unless [`Self::stretch`]
+    /// is `None`, in which case the size is limited to the ideal size.
     pub fn align_rect(&mut self, rect: Rect, align: AlignHints, scale_factor: f32) -> Rect {
+        if self.tile && self.stretch != Stretch::None {
+            return rect;
+        }
+
         let mut size = rect.size;
 
         if self.stretch == Stretch::None {
@@ -304,7 +376,7 @@ impl PixmapScaling {
             size = size.min(ideal);
         }
 
-        if self.fix_aspect {
+        if self.fix_aspect && !self.tile {
             let logical_size = Vec2::from(self.size);
             let Vec2(rw, rh) = Vec2::conv(size) / logical_size;
 
@@ -320,6 +392,14 @@ impl PixmapScaling {
             .complete(Align::Center, Align::Center)
             .aligned_rect(size, rect)
     }
+
+    /// Get the size of each tile, when tiling
+    ///
+    /// This is the ideal size: [`Self::size`] scaled by [`Self::ideal_factor`]
+    /// and `scale_factor`.
+    pub fn tile_size(&self, scale_factor: f32) -> Size {
+        self.size.to_physical(scale_factor * self.ideal_factor)
+    }
 }
 
 /// Frame size rules
@@ -426,3 +506,31 @@ impl FrameRules {
         (rules, offset, size)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn logical_size_arithmetic() {
+        let a = LogicalSize(10.0, 20.0);
+        let b = LogicalSize(4.0, 30.0);
+        assert_eq!(a + b, LogicalSize(14.0, 50.0));
+        assert_eq!(a - b, LogicalSize(6.0, -10.0));
+        assert_eq!(a * 1.5, LogicalSize(15.0, 30.0));
+        assert_eq!(a.min(b), LogicalSize(4.0, 20.0));
+        assert_eq!(a.max(b), LogicalSize(10.0, 30.0));
+    }
+
+    #[test]
+    fn logical_size_to_physical() {
+        let content = LogicalSize(100.0, 40.0);
+        let padding = LogicalSize(2.5, 2.5);
+        let scale_factor = 1.5;
+        let size = (content + padding * 2.0).to_physical(scale_factor);
+        let w = i32::conv_nearest((100.0 + 2.5 * 2.0) * scale_factor);
+        let h = i32::conv_nearest((40.0 + 2.5 * 2.0) * scale_factor);
+        assert_eq!(size, Size(w, h));
+        assert_eq!(size, Size(158, 68));
+    }
+}
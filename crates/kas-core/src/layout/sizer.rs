@@ -7,8 +7,9 @@
 
 use log::trace;
 use std::fmt;
+use std::sync::atomic::{AtomicU32, Ordering};
 
-use super::{AlignHints, AxisInfo, Margins, SetRectMgr, SizeRules};
+use super::{AlignHints, AxisInfo, Margins, SetRectMgr, SizeRules, SizeTarget};
 use crate::cast::Conv;
 use crate::geom::{Rect, Size};
 use crate::theme::SizeMgr;
@@ -60,6 +61,19 @@ pub trait RulesSetter {
     fn maximal_rect_of(&mut self, storage: &mut Self::Storage, index: Self::ChildInfo) -> Rect;
 }
 
+/// Get a new, unique, non-zero rules generation
+///
+/// See [`AxisInfo::generation`].
+fn next_generation() -> u32 {
+    static NEXT: AtomicU32 = AtomicU32::new(1);
+    loop {
+        let generation = NEXT.fetch_add(1, Ordering::Relaxed);
+        if generation != 0 {
+            return generation;
+        }
+    }
+}
+
 /// Solve size rules for a widget
 ///
 /// Automatic layout solving requires that a widget's `size_rules` method is
@@ -78,8 +92,11 @@ pub fn solve_size_rules<W: Widget + ?Sized>(
     x_size: Option<i32>,
     y_size: Option<i32>,
 ) {
-    widget.size_rules(size_mgr.re(), AxisInfo::new(false, y_size));
-    widget.size_rules(size_mgr.re(), AxisInfo::new(true, x_size));
+    let generation = next_generation();
+    let axis = AxisInfo::new(false, y_size).with_generation(generation);
+    widget.size_rules(size_mgr.re(), axis);
+    let axis = AxisInfo::new(true, x_size).with_generation(generation);
+    widget.size_rules(size_mgr.re(), axis);
 }
 
 /// Size solver
@@ -100,6 +117,7 @@ pub struct SolveCache {
     margins: Margins,
     refresh_rules: bool,
     last_width: i32,
+    generation: u32,
 }
 
 impl SolveCache {
@@ -134,10 +152,25 @@ impl SolveCache {
     pub fn find_constraints(widget: &mut dyn Widget, size_mgr: SizeMgr) -> Self {
         let start = std::time::Instant::now();
 
-        let w = widget.size_rules(size_mgr.re(), AxisInfo::new(false, None));
-        let h = widget.size_rules(size_mgr.re(), AxisInfo::new(true, Some(w.ideal_size())));
+        let generation = next_generation();
+        let axis = AxisInfo::new(false, None).with_generation(generation);
+        let w = widget.size_rules(size_mgr.re(), axis);
+        // Height is measured given the ideal width:
+        let axis = AxisInfo::new(true, Some(w.ideal_size()))
+            .with_target(SizeTarget::Ideal)
+            .with_generation(generation);
+        let h = widget.size_rules(size_mgr.re(), axis);
+        // Content may wrap onto more lines at the minimum width:
+        let mut min_height = h.min_size();
+        if w.min_size() < w.ideal_size() {
+            let axis = AxisInfo::new(true, Some(w.min_size()))
+                .with_target(SizeTarget::Min)
+                .with_generation(generation);
+            let h = widget.size_rules(size_mgr.re(), axis);
+            min_height = min_height.max(h.min_size());
+        }
 
-        let min = Size(w.min_size(), h.min_size());
+        let min = Size(w.min_size(), min_height);
         let ideal = Size(w.ideal_size(), h.ideal_size());
         let margins = Margins::hv(w.margins(), h.margins());
 
@@ -156,14 +189,16 @@ impl SolveCache {
             margins,
             refresh_rules,
             last_width,
+            generation,
         }
     }
 
     /// Force updating of size rules
     ///
-    /// This should be called whenever widget size rules have been changed. It
-    /// forces [`SolveCache::apply_rect`] to recompute these rules when next
-    /// called.
+    /// This should be called whenever widget size rules have been changed
+    /// (including due to a change in scale factor). It forces
+    /// [`SolveCache::apply_rect`] to recompute these rules when next called,
+    /// using a new [generation](AxisInfo::generation).
     pub fn invalidate_rule_cache(&mut self) {
         self.refresh_rules = true;
     }
@@ -197,13 +232,16 @@ impl SolveCache {
         // internal layout solving.
         if self.refresh_rules || width != self.last_width {
             if self.refresh_rules {
-                let w = widget.size_rules(mgr.size_mgr(), AxisInfo::new(false, None));
+                self.generation = next_generation();
+                let axis = AxisInfo::new(false, None).with_generation(self.generation);
+                let w = widget.size_rules(mgr.size_mgr(), axis);
                 self.min.0 = w.min_size();
                 self.ideal.0 = w.ideal_size();
                 self.margins.horiz = w.margins();
             }
 
-            let h = widget.size_rules(mgr.size_mgr(), AxisInfo::new(true, Some(width)));
+            let axis = AxisInfo::new(true, Some(width)).with_generation(self.generation);
+            let h = widget.size_rules(mgr.size_mgr(), axis);
             self.min.1 = h.min_size();
             self.ideal.1 = h.ideal_size();
             self.margins.vert = h.margins();
@@ -230,19 +268,26 @@ impl SolveCache {
     }
 }
 
-struct WidgetHeirarchy<'a>(&'a dyn Widget, usize);
+/// Formats a widget and its descendants, one per line
+///
+/// Each line begins with a line break. Widgets which have not been configured
+/// (with an invalid [`crate::WidgetId`]) are shown as `Name#<unconfigured>`.
+pub(crate) struct WidgetHeirarchy<'a>(pub &'a dyn Widget, pub usize);
 impl<'a> fmt::Display for WidgetHeirarchy<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        let len = 40 - 2 * self.1;
+        let len = 40usize.saturating_sub(2 * self.1);
         let trail = "| ".repeat(self.1);
         // Note: pre-format some items to ensure correct alignment
-        let identify = format!("{}", self.0.identify());
+        let identify = match self.0.id_ref().is_valid() {
+            true => format!("{}", self.0.identify()),
+            false => format!("{}#<unconfigured>", self.0.widget_name()),
+        };
         let pos = format!("{:?}", self.0.rect().pos);
         let size = self.0.rect().size;
         write!(f, "\n{trail}{identify:<len$}{pos:<20}{size:?}")?;
 
-        for i in 0..self.0.num_children() {
-            WidgetHeirarchy(self.0.get_child(i).unwrap(), self.1 + 1).fmt(f)?;
+        for child in self.0.children() {
+            WidgetHeirarchy(child, self.1 + 1).fmt(f)?;
         }
         Ok(())
     }
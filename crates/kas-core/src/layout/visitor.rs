@@ -8,12 +8,14 @@
 // Methods have to take `&mut self`
 #![allow(clippy::wrong_self_convention)]
 
-use super::{Align, AlignHints, AxisInfo, MarginSelector, SetRectMgr, SizeRules};
+use super::{Align, AlignHints, AxisInfo, LogicalSize, MarginSelector, Margins, SetRectMgr};
 use super::{DynRowStorage, RowPositionSolver, RowSetter, RowSolver, RowStorage};
 use super::{GridChildInfo, GridDimensions, GridSetter, GridSolver, GridStorage};
 use super::{RulesSetter, RulesSolver, Storage};
+use super::{SizeRules, Stretch};
+use crate::cast::Conv;
 use crate::draw::color::Rgb;
-use crate::geom::{Coord, Offset, Rect, Size};
+use crate::geom::{Coord, Offset, Quad, Rect, Size};
 use crate::theme::{Background, DrawMgr, FrameStyle, SizeMgr};
 use crate::WidgetId;
 use crate::{dir::Directional, dir::Directions, Layout, Widget};
@@ -47,10 +49,14 @@ enum LayoutType<'a> {
     AlignLayout(Box<Visitor<'a>>, AlignHints),
     /// Replace (some) margins
     Margins(Box<Visitor<'a>>, Directions, MarginSelector),
+    /// Reserve a minimum size (logical pixels)
+    Reserve(Box<Visitor<'a>>, LogicalSize),
     /// Frame around content
     Frame(Box<Visitor<'a>>, &'a mut FrameStorage, FrameStyle),
-    /// Button frame around content
-    Button(Box<Visitor<'a>>, &'a mut FrameStorage, Option<Rgb>),
+    /// Button frame around content (base, hover and pressed colors)
+    Button(Box<Visitor<'a>>, &'a mut FrameStorage, [Option<Rgb>; 3]),
+    /// Background fill behind content
+    Background(Box<Visitor<'a>>, &'a mut BackgroundStorage, Rgb),
 }
 
 impl<'a> Default for Visitor<'a> {
@@ -72,6 +78,17 @@ impl<'a> Visitor<'a> {
         Visitor { layout }
     }
 
+    /// Construct a single-item layout over an optional widget
+    ///
+    /// This is equivalent to [`Self::single`] when `widget` is `Some`, and
+    /// to [`Self::none`] otherwise.
+    pub fn optional(widget: Option<&'a mut dyn Widget>) -> Self {
+        match widget {
+            Some(widget) => Visitor::single(widget),
+            None => Visitor::none(),
+        }
+    }
+
     /// Construct a single-item layout with alignment hints
     pub fn align_single(widget: &'a mut dyn Widget, hints: AlignHints) -> Self {
         let layout = LayoutType::AlignSingle(widget, hints);
@@ -102,8 +119,41 @@ impl<'a> Visitor<'a> {
     ///
     /// Generates a button frame containing the child node. Mouse/touch input
     /// on the button reports input to `self`, not to the child node.
-    pub fn button(data: &'a mut FrameStorage, child: Self, color: Option<Rgb>) -> Self {
-        let layout = LayoutType::Button(Box::new(child), data, color);
+    ///
+    /// The background uses `color` if given, or the theme's default.
+    /// Optionally, `hover` and `pressed` replace this color while the button
+    /// is hovered or depressed respectively (pressed takes precedence); the
+    /// theme may still adjust the color to indicate state. Where these are
+    /// `None`, the theme's state-derived tint of the base color is used.
+    pub fn button(
+        data: &'a mut FrameStorage,
+        child: Self,
+        color: Option<Rgb>,
+        hover: Option<Rgb>,
+        pressed: Option<Rgb>,
+    ) -> Self {
+        let layout = LayoutType::Button(Box::new(child), data, [color, hover, pressed]);
+        Visitor { layout }
+    }
+
+    /// Fill the background behind a sub-layout
+    ///
+    /// The filled rect is that assigned to the child, expanded by half of the
+    /// child's margins on each side. Where adjacent cells have equal margins,
+    /// backgrounds thus meet without gaps or overlap.
+    pub fn background(data: &'a mut BackgroundStorage, child: Self, color: Rgb) -> Self {
+        let layout = LayoutType::Background(Box::new(child), data, color);
+        Visitor { layout }
+    }
+
+    /// Reserve a minimum size for some sub-layout
+    ///
+    /// The `size` is in logical pixels and is scaled by the window's scale
+    /// factor. The child's size rules are enlarged (via [`SizeRules::max`])
+    /// to at least this minimum. A component of `0.0` leaves that axis
+    /// unconstrained.
+    pub fn reserve(child: Self, size: LogicalSize) -> Self {
+        let layout = LayoutType::Reserve(Box::new(child), size);
         Visitor { layout }
     }
 
@@ -185,7 +235,10 @@ impl<'a> Visitor<'a> {
             LayoutType::BoxComponent(component) => component.size_rules(mgr, axis),
             LayoutType::Single(child) => child.size_rules(mgr, axis),
             LayoutType::AlignSingle(child, _) => child.size_rules(mgr, axis),
-            LayoutType::AlignLayout(layout, _) => layout.size_rules_(mgr, axis),
+            LayoutType::AlignLayout(layout, hints) => {
+                let axis = axis.with_baseline(axis.align_baseline() || hints.baseline);
+                layout.size_rules_(mgr, axis)
+            }
             LayoutType::Margins(child, dirs, margins) => {
                 let mut child_rules = child.size_rules_(mgr.re(), axis);
                 if dirs.intersects(Directions::from(axis)) {
@@ -201,6 +254,11 @@ impl<'a> Visitor<'a> {
                 }
                 child_rules
             }
+            LayoutType::Reserve(child, size) => {
+                let rules = child.size_rules_(mgr.re(), axis);
+                let min = size.extract_scaled(axis, mgr.scale_factor());
+                rules.max(SizeRules::new(min, min, (0, 0), Stretch::None))
+            }
             LayoutType::Frame(child, storage, style) => {
                 let child_rules = child.size_rules_(mgr.re(), axis);
                 storage.size_rules(mgr, axis, child_rules, *style)
@@ -209,6 +267,14 @@ impl<'a> Visitor<'a> {
                 let child_rules = child.size_rules_(mgr.re(), axis);
                 storage.size_rules(mgr, axis, child_rules, FrameStyle::Button)
             }
+            LayoutType::Background(child, storage, _) => {
+                let rules = child.size_rules_(mgr, axis);
+                match axis.is_horizontal() {
+                    true => storage.margins.horiz = rules.margins(),
+                    false => storage.margins.vert = rules.margins(),
+                }
+                rules
+            }
         }
     }
 
@@ -234,6 +300,7 @@ impl<'a> Visitor<'a> {
                 return layout.set_rect_(mgr, rect, align);
             }
             LayoutType::Margins(child, _, _) => return child.set_rect_(mgr, rect, align),
+            LayoutType::Reserve(child, _) => return child.set_rect_(mgr, rect, align),
             LayoutType::Frame(child, storage, _) => {
                 storage.rect = rect;
                 let child_rect = Rect {
@@ -253,6 +320,10 @@ impl<'a> Visitor<'a> {
                 };
                 child.set_rect_(mgr, child_rect, AlignHints::CENTER);
             }
+            LayoutType::Background(child, storage, _) => {
+                storage.rect = rect;
+                child.set_rect_(mgr, rect, align);
+            }
         }
         rect
     }
@@ -273,9 +344,34 @@ impl<'a> Visitor<'a> {
             LayoutType::Single(child) | LayoutType::AlignSingle(child, _) => child.find_id(coord),
             LayoutType::AlignLayout(layout, _) => layout.find_id_(coord),
             LayoutType::Margins(layout, _, _) => layout.find_id_(coord),
+            LayoutType::Reserve(layout, _) => layout.find_id_(coord),
             LayoutType::Frame(child, _, _) => child.find_id_(coord),
             // Buttons steal clicks, hence Button never returns ID of content
             LayoutType::Button(_, _, _) => None,
+            LayoutType::Background(child, _, _) => child.find_id_(coord),
+        }
+    }
+
+    /// Get the text baseline, if any
+    ///
+    /// See [`Layout::baseline`].
+    #[inline]
+    pub fn baseline(mut self) -> Option<i32> {
+        self.baseline_()
+    }
+    fn baseline_(&mut self) -> Option<i32> {
+        match &mut self.layout {
+            LayoutType::None => None,
+            LayoutType::Component(component) => component.baseline(),
+            LayoutType::BoxComponent(layout) => layout.baseline(),
+            LayoutType::Single(child) | LayoutType::AlignSingle(child, _) => child.baseline(),
+            LayoutType::AlignLayout(layout, _) => layout.baseline_(),
+            LayoutType::Margins(layout, _, _) => layout.baseline_(),
+            LayoutType::Reserve(layout, _) => layout.baseline_(),
+            LayoutType::Frame(child, storage, _) | LayoutType::Button(child, storage, _) => {
+                child.baseline_().map(|b| b + storage.offset.1)
+            }
+            LayoutType::Background(child, _, _) => child.baseline_(),
         }
     }
 
@@ -292,18 +388,31 @@ impl<'a> Visitor<'a> {
             LayoutType::Single(child) | LayoutType::AlignSingle(child, _) => draw.recurse(*child),
             LayoutType::AlignLayout(layout, _) => layout.draw_(draw),
             LayoutType::Margins(layout, _, _) => layout.draw_(draw),
+            LayoutType::Reserve(layout, _) => layout.draw_(draw),
             LayoutType::Frame(child, storage, style) => {
                 draw.frame(storage.rect, *style, Background::Default);
                 child.draw_(draw);
             }
-            LayoutType::Button(child, storage, color) => {
+            LayoutType::Button(child, storage, [color, hover, pressed]) => {
+                let id = draw.id().clone();
+                let ev = draw.ev_state();
+                let color = match (*hover, *pressed) {
+                    (_, Some(rgb)) if ev.is_depressed(&id) => Some(rgb),
+                    (Some(rgb), _) if ev.is_hovered(&id) => Some(rgb),
+                    _ => *color,
+                };
                 let bg = match color {
-                    Some(rgb) => Background::Rgb(*rgb),
+                    Some(rgb) => Background::Rgb(rgb),
                     None => Background::Default,
                 };
                 draw.frame(storage.rect, FrameStyle::Button, bg);
                 child.draw_(draw);
             }
+            LayoutType::Background(child, storage, color) => {
+                let rect = Quad::conv(storage.fill_rect());
+                draw.draw_device().rect(rect, (*color).into());
+                child.draw_(draw);
+            }
         }
     }
 }
@@ -322,8 +431,9 @@ where
     fn size_rules(&mut self, mgr: SizeMgr, axis: AxisInfo) -> SizeRules {
         let dim = (self.direction, self.children.len());
         let mut solver = RowSolver::new(axis, dim, self.data);
-        for (n, child) in (&mut self.children).enumerate() {
-            solver.for_child(self.data, n, |axis| child.size_rules(mgr.re(), axis));
+        for (n, mut child) in (&mut self.children).enumerate() {
+            solver.for_child(self.data, n, |axis| child.size_rules_(mgr.re(), axis));
+            solver.note_baseline(|| child.baseline_());
         }
         solver.finish(self.data)
     }
@@ -332,6 +442,15 @@ where
         let dim = (self.direction, self.children.len());
         let mut setter = RowSetter::<D, Vec<i32>, _>::new(rect, dim, align, self.data);
 
+        if align.baseline && self.direction.is_horizontal() {
+            let mut children: Vec<_> = (&mut self.children).collect();
+            setter.set_rects_on_baseline(self.data, mgr, align, |mgr, n, rect, align| {
+                children[n].set_rect_(mgr, rect, align);
+                children[n].baseline_()
+            });
+            return;
+        }
+
         for (n, child) in (&mut self.children).enumerate() {
             child.set_rect(mgr, setter.child_rect(self.data, n), align);
         }
@@ -403,6 +522,7 @@ impl<'a, W: Widget, D: Directional> Layout for Slice<'a, W, D> {
         let mut solver = RowSolver::new(axis, dim, self.data);
         for (n, child) in self.children.iter_mut().enumerate() {
             solver.for_child(self.data, n, |axis| child.size_rules(mgr.re(), axis));
+            solver.note_baseline(|| child.baseline());
         }
         solver.finish(self.data)
     }
@@ -411,6 +531,15 @@ impl<'a, W: Widget, D: Directional> Layout for Slice<'a, W, D> {
         let dim = (self.direction, self.children.len());
         let mut setter = RowSetter::<D, Vec<i32>, _>::new(rect, dim, align, self.data);
 
+        if align.baseline && self.direction.is_horizontal() {
+            let children = &mut *self.children;
+            setter.set_rects_on_baseline(self.data, mgr, align, |mgr, n, rect, align| {
+                children[n].set_rect(mgr, rect, align);
+                children[n].baseline()
+            });
+            return;
+        }
+
         for (n, child) in self.children.iter_mut().enumerate() {
             child.set_rect(mgr, setter.child_rect(self.data, n), align);
         }
@@ -467,6 +596,30 @@ where
     }
 }
 
+/// Layout storage for background fill
+#[derive(Clone, Default, Debug)]
+pub struct BackgroundStorage {
+    rect: Rect,
+    margins: Margins,
+}
+impl Storage for BackgroundStorage {
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+impl BackgroundStorage {
+    /// The filled rect: content rect expanded by half the content's margins
+    fn fill_rect(&self) -> Rect {
+        let half = |m: (u16, u16)| (i32::from(m.0 / 2), i32::from(m.1 - m.1 / 2));
+        let (l, r) = half(self.margins.horiz);
+        let (t, b) = half(self.margins.vert);
+        Rect {
+            pos: self.rect.pos - Offset(l, t),
+            size: self.rect.size + Size(l + r, t + b),
+        }
+    }
+}
+
 /// Layout storage for frame layout
 #[derive(Clone, Default, Debug)]
 pub struct FrameStorage {
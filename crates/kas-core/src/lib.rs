@@ -35,6 +35,7 @@ pub mod event;
 pub mod geom;
 pub mod layout;
 pub mod prelude;
+pub mod state;
 pub mod text;
 pub mod theme;
 pub mod updatable;
@@ -33,6 +33,8 @@ pub use crate::text::{EditableTextApi, Text, TextApi, TextApiExt};
 #[doc(no_inline)]
 pub use crate::theme::{DrawMgr, SizeMgr, ThemeControl};
 #[doc(no_inline)]
+pub use crate::AccessibleRole;
+#[doc(no_inline)]
 pub use crate::CoreData;
 #[doc(no_inline)]
 pub use crate::TkAction;
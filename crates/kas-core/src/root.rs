@@ -5,15 +5,20 @@
 
 //! Window widgets
 
+use crate::cast::CastFloat;
 use crate::dir::Directional;
 use crate::event::EventMgr;
 use crate::geom::{Coord, Rect, Size};
-use crate::layout::{self, AlignHints, AxisInfo, SetRectMgr, SizeRules};
-use crate::theme::{DrawMgr, SizeMgr};
-use crate::{Layout, TkAction, Widget, WidgetExt, WidgetId, Window, WindowId};
+use crate::layout::{self, Align, AlignHints, AxisInfo, SetRectMgr, SizeRules};
+use crate::text::Text;
+use crate::theme::{DrawMgr, SizeMgr, TextClass};
+use crate::{Layout, TkAction, Widget, WidgetCore, WidgetExt, WidgetId, Window, WindowId};
 use kas_macros::{autoimpl, impl_scope};
 use smallvec::SmallVec;
 
+// Nominal size of the mouse cursor (virtual pixels), used to place tooltips
+const TOOLTIP_CURSOR_SIZE: f32 = 16.0;
+
 impl_scope! {
     /// A support layer around a window
     #[cfg_attr(not(feature = "internal_doc"), doc(hidden))]
@@ -25,7 +30,11 @@ impl_scope! {
         core: widget_core!(),
         #[widget]
         w: Box<dyn Window>,
-        popups: SmallVec<[(WindowId, kas::Popup); 16]>,
+        #[widget]
+        tooltip: Tooltip,
+        // The bool records whether the pop-up was last placed before its
+        // parent (i.e. in the reversed direction)
+        popups: SmallVec<[(WindowId, kas::Popup, Option<bool>); 16]>,
     }
 
     impl Layout for RootWidget {
@@ -40,31 +49,46 @@ impl_scope! {
             self.w.set_rect(mgr, rect, align);
         }
 
+        fn draw(&mut self, mut draw: DrawMgr) {
+            draw.recurse(&mut self.w);
+            for (_, popup, _) in &self.popups {
+                // The tooltip is a child of self, not self.w
+                if popup.id == self.tooltip.id_ref() {
+                    let widget = &mut self.tooltip;
+                    draw.with_overlay(widget.rect(), |mut draw| {
+                        draw.recurse(widget);
+                    });
+                    continue;
+                }
+                if let Some(widget) = self.w.find_widget_mut(&popup.id) {
+                    draw.with_overlay(widget.rect(), |mut draw| {
+                        draw.recurse(widget);
+                    });
+                }
+            }
+        }
+
         fn find_id(&mut self, coord: Coord) -> Option<WidgetId> {
             if !self.core.rect.contains(coord) {
                 return None;
             }
             for popup in self.popups.iter_mut().rev() {
-                if let Some(id) = self
-                    .w
-                    .find_widget_mut(&popup.1.id)
-                    .and_then(|w| w.find_id(coord))
-                {
+                let widget = if popup.1.id == self.tooltip.id_ref() {
+                    Some(self.tooltip.as_widget_mut())
+                } else {
+                    self.w.find_widget_mut(&popup.1.id)
+                };
+                if let Some(id) = widget.and_then(|w| w.find_id(coord)) {
                     return Some(id);
                 }
             }
             self.w.find_id(coord).or_else(|| Some(self.id()))
         }
+    }
 
-        fn draw(&mut self, mut draw: DrawMgr) {
-            draw.recurse(&mut self.w);
-            for (_, popup) in &self.popups {
-                if let Some(widget) = self.w.find_widget_mut(&popup.id) {
-                    draw.with_overlay(widget.rect(), |mut draw| {
-                        draw.recurse(widget);
-                    });
-                }
-            }
+    impl Widget for RootWidget {
+        fn configure(&mut self, mgr: &mut SetRectMgr) {
+            mgr.set_tooltip_host(self.tooltip.id());
         }
     }
 }
@@ -75,6 +99,7 @@ impl RootWidget {
         RootWidget {
             core: Default::default(),
             w,
+            tooltip: Tooltip::new(),
             popups: Default::default(),
         }
     }
@@ -83,8 +108,14 @@ impl RootWidget {
     ///
     /// Each [`crate::Popup`] is assigned a [`WindowId`]; both are passed.
     pub fn add_popup(&mut self, mgr: &mut EventMgr, id: WindowId, popup: kas::Popup) {
+        if popup.id == self.tooltip.id_ref() {
+            // Tooltips replace each other
+            self.popups.retain(|(_, p, _)| p.id != popup.id);
+            let text = mgr.tooltip_text().to_string();
+            self.tooltip.label.text = Text::new_multi(text);
+        }
         let index = self.popups.len();
-        self.popups.push((id, popup));
+        self.popups.push((id, popup, None));
         mgr.set_rect_mgr(|mgr| self.resize_popup(mgr, index));
         mgr.send_action(TkAction::REDRAW);
     }
@@ -105,7 +136,9 @@ impl RootWidget {
     /// Resize popups
     ///
     /// This is called immediately after [`Layout::set_rect`] to resize
-    /// existing pop-ups.
+    /// existing pop-ups. Each pop-up remains on the side of its parent where
+    /// it was last placed as long as it fits there, thus repeated resizing
+    /// does not cause pop-ups to oscillate between sides.
     pub fn resize_popups(&mut self, mgr: &mut SetRectMgr) {
         for i in 0..self.popups.len() {
             self.resize_popup(mgr, i);
@@ -133,32 +166,52 @@ impl RootWidget {
         // Notation: p=point/coord, s=size, m=margin
         // r=window/root rect, c=anchor rect
         let r = self.core.rect;
-        let popup = &mut self.popups[index].1;
+        let (_, popup, placed_before) = &mut self.popups[index];
 
-        let c = find_rect(&self.w, popup.parent.clone()).unwrap();
-        let widget = self.w.find_widget_mut(&popup.id).unwrap();
+        let is_tooltip = popup.id == self.tooltip.id_ref();
+        let c = if is_tooltip {
+            // Anchor tooltips to the mouse cursor, using a nominal cursor size
+            let size = (TOOLTIP_CURSOR_SIZE * mgr.size_mgr().scale_factor()).cast_nearest();
+            Rect::new(mgr.tooltip_coord(), Size::splat(size))
+        } else {
+            find_rect(&self.w, popup.parent.clone()).unwrap()
+        };
+        let widget = if is_tooltip {
+            self.tooltip.as_widget_mut()
+        } else {
+            self.w.find_widget_mut(&popup.id).unwrap()
+        };
         let mut cache = layout::SolveCache::find_constraints(widget, mgr.size_mgr());
         let ideal = cache.ideal(false);
         let m = cache.margins();
 
-        let is_reversed = popup.direction.is_reversed();
-        let place_in = |rp, rs: i32, cp: i32, cs: i32, ideal, m: (u16, u16)| -> (i32, i32) {
+        // Prefer the side used previously, if any, to avoid oscillation
+        let prefer_before = placed_before.unwrap_or(popup.direction.is_reversed());
+        // Place along the popup's direction, flipping to the opposite side of
+        // the parent if there is insufficient space. If there is insufficient
+        // space on both sides, the larger side is used and the popup is
+        // clamped to the available size.
+        let place_in = |rp, rs: i32, cp: i32, cs: i32, ideal, m: (u16, u16)| {
             let m: (i32, i32) = (m.0.into(), m.1.into());
             let before: i32 = cp - (rp + m.1);
             let before = before.max(0);
             let after = (rs - (cs + before + m.0)).max(0);
-            if after >= ideal {
-                if is_reversed && before >= ideal {
-                    (cp - ideal - m.1, ideal)
-                } else {
-                    (cp + cs + m.0, ideal)
-                }
-            } else if before >= ideal {
-                (cp - ideal - m.1, ideal)
-            } else if before > after {
-                (rp, before)
+            let use_before = if prefer_before && before >= ideal {
+                true
+            } else if !prefer_before && after >= ideal {
+                false
+            } else if after >= ideal || before >= ideal {
+                before >= ideal
+            } else if before != after {
+                before > after
             } else {
-                (cp + cs + m.0, after)
+                prefer_before
+            };
+            if use_before {
+                let size = ideal.min(before);
+                (cp - size - m.1, size, true)
+            } else {
+                (cp + cs + m.0, ideal.min(after), false)
             }
         };
         let place_out = |rp, rs, cp: i32, cs, ideal: i32| -> (i32, i32) {
@@ -167,15 +220,69 @@ impl RootWidget {
             (pos, size)
         };
         let rect = if popup.direction.is_horizontal() {
-            let (x, w) = place_in(r.pos.0, r.size.0, c.pos.0, c.size.0, ideal.0, m.horiz);
+            let (x, w, before) = place_in(r.pos.0, r.size.0, c.pos.0, c.size.0, ideal.0, m.horiz);
             let (y, h) = place_out(r.pos.1, r.size.1, c.pos.1, c.size.1, ideal.1);
+            *placed_before = Some(before);
             Rect::new(Coord(x, y), Size::new(w, h))
         } else {
             let (x, w) = place_out(r.pos.0, r.size.0, c.pos.0, c.size.0, ideal.0);
-            let (y, h) = place_in(r.pos.1, r.size.1, c.pos.1, c.size.1, ideal.1, m.vert);
+            let (y, h, before) = place_in(r.pos.1, r.size.1, c.pos.1, c.size.1, ideal.1, m.vert);
+            *placed_before = Some(before);
             Rect::new(Coord(x, y), Size::new(w, h))
         };
 
         cache.apply_rect(widget, mgr, rect, false, true);
     }
 }
+
+impl_scope! {
+    /// A tooltip pop-up
+    ///
+    /// This is shown by the [`EventMgr`] via [`RootWidget::add_popup`].
+    #[derive(Clone, Debug)]
+    #[widget{
+        layout = frame(kas::theme::FrameStyle::Popup): self.label;
+    }]
+    struct Tooltip {
+        core: widget_core!(),
+        #[widget]
+        label: TooltipLabel,
+    }
+
+    impl Self {
+        fn new() -> Self {
+            Tooltip {
+                core: Default::default(),
+                label: TooltipLabel {
+                    core: Default::default(),
+                    text: Text::new_multi(String::new()),
+                },
+            }
+        }
+    }
+}
+
+impl_scope! {
+    #[derive(Clone, Debug)]
+    #[widget]
+    struct TooltipLabel {
+        core: widget_core!(),
+        text: Text<String>,
+    }
+
+    impl Layout for Self {
+        fn size_rules(&mut self, size_mgr: SizeMgr, axis: AxisInfo) -> SizeRules {
+            size_mgr.text_bound(&mut self.text, TextClass::Label(false), axis)
+        }
+
+        fn set_rect(&mut self, mgr: &mut SetRectMgr, rect: Rect, _: AlignHints) {
+            self.core.rect = rect;
+            let align = (Align::Default, Align::Center);
+            mgr.text_set_size(&mut self.text, TextClass::Label(false), rect.size, align);
+        }
+
+        fn draw(&mut self, mut draw: DrawMgr) {
+            draw.text(self.core.rect.pos, &self.text, TextClass::Label(false));
+        }
+    }
+}
@@ -0,0 +1,120 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE-APACHE file or at:
+//     https://www.apache.org/licenses/LICENSE-2.0
+
+//! Saving and restoring widget state
+//!
+//! Some widgets have state adjustable by the user which is not part of any
+//! application data model: scroll offsets, the active page of a stack,
+//! splitter positions, selected list items, etc. Such widgets may implement [`SaveState`] and
+//! return `Some(self)` from [`Widget::as_save_state`].
+//!
+//! [`SavedState::save`] collects state from all such widgets in a tree, and
+//! [`SavedState::load`] restores it. With feature `serde`, [`SavedState`]
+//! supports (de)serialization, thus may be written to a file.
+//!
+//! ### Keys
+//!
+//! Entries are keyed by the string form of each widget's [`WidgetId`] (for
+//! example, `#1a2`). Since a [`WidgetId`] is a path of child indices from the
+//! window root, keys are deterministic: they match across runs provided that
+//! the widget tree is constructed identically.
+//!
+//! ### Loading
+//!
+//! Entries whose key does not match any widget implementing [`SaveState`]
+//! (for example, state saved from an older version of the UI) are ignored.
+//! Widgets should likewise ignore values of an unexpected kind.
+//!
+//! Some state (notably scroll offsets) is clamped to the widget's current
+//! size, thus state should be loaded after the window has been sized.
+
+use crate::layout::SetRectMgr;
+use crate::{Widget, WidgetCore, WidgetExt, WidgetId};
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A saved state value
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StateValue {
+    /// An offset, e.g. a scroll position
+    Offset(i32, i32),
+    /// An index, e.g. the active page
+    Index(usize),
+    /// A list of offsets, e.g. splitter handle positions
+    Offsets(Vec<(i32, i32)>),
+    /// A set of children, e.g. the selected items of a list
+    ///
+    /// Each child is identified by the keys of its [`WidgetId`] path after
+    /// that of the saving widget (see [`WidgetId::iter_keys_after`]).
+    Keys(Vec<Vec<usize>>),
+}
+
+/// Widgets with saveable state
+///
+/// See [module documentation](self).
+pub trait SaveState {
+    /// Get the current state
+    ///
+    /// Returns `None` if there is nothing to save.
+    fn save_state(&self) -> Option<StateValue>;
+
+    /// Restore state
+    ///
+    /// Implementations should ignore `state` if it is of an unexpected kind
+    /// and should clamp out-of-range values.
+    fn load_state(&mut self, mgr: &mut SetRectMgr, state: &StateValue);
+}
+
+/// Saved state of a widget tree
+///
+/// See [module documentation](self).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct SavedState(pub BTreeMap<String, StateValue>);
+
+impl SavedState {
+    /// Collect state from `widget` and all descendants
+    pub fn save(widget: &mut dyn Widget) -> Self {
+        let mut state = SavedState::default();
+        state.save_from(widget);
+        state
+    }
+
+    /// Collect state from `widget` and all descendants, replacing any
+    /// existing entries with the same key
+    pub fn save_from(&mut self, widget: &mut dyn Widget) {
+        if let Some(value) = widget.as_save_state().and_then(|w| w.save_state()) {
+            self.0.insert(Self::key(widget.id_ref()), value);
+        }
+        widget.for_children_mut(|child| self.save_from(child));
+    }
+
+    /// Restore state to `widget` and all descendants
+    ///
+    /// Entries not matching any widget are ignored.
+    pub fn load(&self, mgr: &mut SetRectMgr, widget: &mut dyn Widget) {
+        if !self.0.is_empty() {
+            self.load_to(mgr, widget);
+        }
+    }
+
+    fn load_to(&self, mgr: &mut SetRectMgr, widget: &mut dyn Widget) {
+        if let Some(value) = self.0.get(&Self::key(widget.id_ref())) {
+            if let Some(w) = widget.as_save_state() {
+                w.load_state(mgr, value);
+            }
+        }
+        widget.for_children_mut(|child| self.load_to(mgr, child));
+    }
+
+    /// Get the key used for a widget
+    pub fn key(id: &WidgetId) -> String {
+        id.to_string()
+    }
+}
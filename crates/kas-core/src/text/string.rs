@@ -106,6 +106,45 @@ impl AccelString {
     pub fn text(&self) -> &str {
         &self.label
     }
+
+    /// Set the accelerator key (mnemonic), keeping the text
+    ///
+    /// This replaces all existing accelerator keys. The first occurrence of
+    /// `c` in the text (ignoring case) is underlined; if there is none, the
+    /// key is still bound but nothing is underlined (e.g. when the text is
+    /// translated but the mnemonic is not). `None` removes the mnemonic.
+    ///
+    /// Returns true when the key bindings changed, in which case widgets
+    /// using this string must be reconfigured (see [`Self::keys`]).
+    pub fn set_mnemonic(&mut self, c: Option<char>) -> bool {
+        let old_keys = std::mem::take(&mut self.keys);
+        self.effects.clear();
+
+        if let Some(c) = c {
+            self.keys = find_vkeys(c);
+            let found = self
+                .label
+                .char_indices()
+                .find(|(_, d)| d.to_lowercase().eq(c.to_lowercase()));
+            if let Some((pos, d)) = found {
+                self.effects.push(Effect {
+                    start: u32::conv(pos),
+                    flags: EffectFlags::UNDERLINE,
+                    aux: (),
+                });
+                let end = pos + d.len_utf8();
+                if end < self.label.len() {
+                    self.effects.push(Effect {
+                        start: u32::conv(end),
+                        flags: EffectFlags::empty(),
+                        aux: (),
+                    });
+                }
+            }
+        }
+
+        self.keys != old_keys
+    }
 }
 
 impl FormattableText for AccelString {
@@ -6,11 +6,13 @@
 //! "Handle" types used by themes
 
 use super::{FrameStyle, MarkStyle, SizeHandle, SizeMgr, TextClass};
+use crate::cast::Conv;
 use crate::dir::Direction;
-use crate::draw::{color::Rgb, Draw, DrawShared, ImageId, PassType};
+use crate::draw::color::{Rgb, Rgba};
+use crate::draw::{Draw, DrawShared, ImageId, PassType, PathBuilder};
 use crate::event::EventState;
-use crate::geom::{Coord, Offset, Rect};
-use crate::layout::SetRectMgr;
+use crate::geom::{Coord, Offset, Quad, Rect, Size, Vec2};
+use crate::layout::{Margins, SetRectMgr};
 use crate::macros::autoimpl;
 use crate::text::{TextApi, TextDisplay};
 use crate::{TkAction, Widget, WidgetExt, WidgetId};
@@ -81,9 +83,24 @@ impl<'a> DrawMgr<'a> {
     }
 
     /// Recurse drawing to a child
+    ///
+    /// If the child is disabled (but this widget is not), the theme's
+    /// disabled overlay (see [`Self::disabled_overlay`]; this may be disabled
+    /// by the theme) is drawn over the child. If the child has navigation
+    /// focus, the theme's focus ring (see [`Self::focus_ring`]) is drawn over
+    /// the child.
     #[inline]
     pub fn recurse(&mut self, child: &mut dyn Widget) {
         child.draw(self.re_id(child.id()));
+        let ev = self.h.components().2;
+        // The overlay covers all descendants, thus is only drawn once per
+        // disabled subtree
+        if ev.is_disabled(child.id_ref()) && !ev.is_disabled(&self.id) {
+            self.h.disabled_overlay(child.rect());
+        }
+        if self.h.components().2.has_nav_focus(child.id_ref()) {
+            self.h.focus_ring(child.rect());
+        }
     }
 
     /// Construct from a [`DrawMgr`] and [`EventState`]
@@ -93,6 +110,12 @@ impl<'a> DrawMgr<'a> {
         DrawMgr { h, id }
     }
 
+    /// Get the identifier of the widget being drawn
+    #[inline]
+    pub fn id(&self) -> &WidgetId {
+        &self.id
+    }
+
     /// Access event-management state
     pub fn ev_state(&mut self) -> &EventState {
         self.h.components().2
@@ -152,6 +175,29 @@ impl<'a> DrawMgr<'a> {
         );
     }
 
+    /// Draw to a new pass with a scale transform (e.g. for zooming)
+    ///
+    /// Within `f`, a point `p` is drawn at `p * scale - offset` (in the
+    /// current coordinate system). All draw operations are scaled, including
+    /// text, which is rastered at the effective (scaled) font size. The clip
+    /// rect is unchanged (but is reported in transformed coordinates by
+    /// [`Self::get_clip_rect`]).
+    ///
+    /// Event handling is not affected by this transform. For consistent
+    /// hit-testing, a widget drawing children via this method should apply
+    /// the inverse transform in [`crate::Layout::find_id`]: a `coord` maps to
+    /// `(coord + offset) / scale` within the transformed region. Note that
+    /// [`crate::Widget::translation`] cannot represent a scale, thus
+    /// coordinates passed to children in mouse/touch events are not scaled.
+    ///
+    /// Requires `scale > 0.0`.
+    pub fn with_transform<F: FnOnce(DrawMgr)>(&mut self, scale: f32, offset: Offset, f: F) {
+        debug_assert!(scale > 0.0 && scale.is_finite());
+        let id = self.id.clone();
+        self.h
+            .new_transform_pass(scale, offset, Box::new(|h| f(DrawMgr { h, id })));
+    }
+
     /// Draw to a new pass as an overlay (e.g. for pop-up menus)
     ///
     /// Adds a new draw pass of type [`PassType::Overlay`], with draw operations
@@ -201,12 +247,65 @@ impl<'a> DrawMgr<'a> {
         self.h.selection_box(rect);
     }
 
+    /// Draw a focus ring
+    ///
+    /// This is drawn automatically by [`Self::recurse`] over the child with
+    /// navigation focus; widgets drawing children by other means may call
+    /// this directly.
+    ///
+    /// The ring is drawn *inside* of `rect`, thus it is not clipped away by
+    /// a clip region tightly enclosing the widget. Themes may draw nothing
+    /// here (the default configuration of the included themes).
+    pub fn focus_ring(&mut self, rect: Rect) {
+        self.h.focus_ring(rect);
+    }
+
+    /// Draw a disabled overlay
+    ///
+    /// This greys out content within `rect`, drawing over all content of the
+    /// current pass (including text). It is drawn automatically by
+    /// [`Self::recurse`] over each child which is disabled (see
+    /// [`EventState::set_disabled`]) when the parent is not; widgets drawing
+    /// children by other means may call this directly.
+    ///
+    /// This is opt-in: themes draw nothing unless the overlay is enabled in
+    /// their configuration. Disabled widgets are in any case drawn using the
+    /// theme's disabled colours.
+    pub fn disabled_overlay(&mut self, rect: Rect) {
+        self.h.disabled_overlay(rect);
+    }
+
+    /// Draw a fade overlay
+    ///
+    /// This partially hides content within `rect` by drawing over it with the
+    /// theme's background colour. `amount` ranges from `0.0` (content is
+    /// fully visible) to `1.0` (content is fully hidden). This may be used to
+    /// fade content in or out.
+    ///
+    /// The overlay is drawn in a new pass, thus covers all content of the
+    /// current pass (including text), regardless of draw order.
+    pub fn fade_overlay(&mut self, rect: Rect, amount: f32) {
+        self.h.fade_overlay(rect, amount);
+    }
+
+    /// Draw a drop shadow for an element occupying `rect`
+    ///
+    /// The shadow extends outside of `rect` (by a theme-dependent amount, with
+    /// a theme-dependent offset) and should be drawn before the element
+    /// itself, which is expected to cover `rect`. Drawing is clipped to the
+    /// current pass as usual.
+    ///
+    /// Pop-ups drawn via [`Self::with_overlay`] receive a shadow automatically.
+    pub fn shadow(&mut self, rect: Rect) {
+        self.h.shadow(rect);
+    }
+
     /// Draw text
     ///
     /// [`SizeMgr::text_bound`] should be called prior to this method to
     /// select a font, font size and wrap options (based on the [`TextClass`]).
-    pub fn text(&mut self, pos: Coord, text: impl AsRef<TextDisplay>, class: TextClass) {
-        self.h.text(&self.id, pos, text.as_ref(), class);
+    pub fn text(&mut self, pos: Coord, text: &dyn TextApi, class: TextClass) {
+        self.h.text(&self.id, pos, text, class);
     }
 
     /// Draw text with effects
@@ -263,6 +362,18 @@ impl<'a> DrawMgr<'a> {
             .text_cursor(&self.id, pos, text.as_ref(), class, byte);
     }
 
+    /// Draw a text caret (insertion marker)
+    ///
+    /// This is a simpler alternative to [`Self::text_cursor`] for widgets not
+    /// using [`TextDisplay`]: a vertical line of the given `height` is drawn
+    /// down from `pos` (the top of the line).
+    ///
+    /// The caret blinks while the window has focus. It is drawn solid whenever
+    /// `pos` changes (e.g. while typing), resuming blinking after a delay.
+    pub fn caret(&mut self, pos: Coord, height: i32) {
+        self.h.caret(&self.id, pos, height);
+    }
+
     /// Draw UI element: checkbox
     ///
     /// The checkbox is a small, usually square, box with or without a check
@@ -298,6 +409,24 @@ impl<'a> DrawMgr<'a> {
             .scrollbar(&self.id, handle.id_ref(), track_rect, handle.rect(), dir);
     }
 
+    /// Draw UI element: overlay scrollbar
+    ///
+    /// This is a scrollbar drawn over content, fading in or out when
+    /// `visible` changes. `last_change` should be the time of the last change
+    /// to `visible`, or `None` to skip the fade.
+    pub fn scrollbar_overlay<W: Widget>(
+        &mut self,
+        track_rect: Rect,
+        handle: &W,
+        dir: Direction,
+        visible: bool,
+        last_change: Option<Instant>,
+    ) {
+        let (id2, h_rect) = (handle.id_ref(), handle.rect());
+        self.h
+            .scrollbar_overlay(&self.id, id2, track_rect, h_rect, dir, visible, last_change);
+    }
+
     /// Draw UI element: slider
     pub fn slider<W: Widget>(&mut self, track_rect: Rect, handle: &W, dir: Direction) {
         self.h
@@ -318,6 +447,85 @@ impl<'a> DrawMgr<'a> {
     pub fn image(&mut self, rect: Rect, id: ImageId) {
         self.h.image(id, rect);
     }
+
+    /// Draw an arc or pie slice
+    ///
+    /// The arc lies within the circle (or oval) inscribed in `rect`, between
+    /// `start_angle` and `end_angle` (radians, clockwise from the positive
+    /// x-axis). `inner_radius` is relative to the outer radius: `0.0` draws a
+    /// solid pie slice while larger values draw a segment of a ring, as used
+    /// by circular progress indicators. Where the angles differ by at least
+    /// `2π`, a full circle is drawn.
+    ///
+    /// Colour `col` is used as given; the theme does not apply any styling.
+    /// See [`crate::draw::DrawRounded::arc`].
+    pub fn arc(
+        &mut self,
+        rect: Quad,
+        start_angle: f32,
+        end_angle: f32,
+        inner_radius: f32,
+        col: Rgba,
+    ) {
+        self.h.arc(rect, start_angle, end_angle, inner_radius, col);
+    }
+
+    /// Draw a rectangle with rounded corners
+    ///
+    /// Corner radii are given in pixels in the order top-left, top-right,
+    /// bottom-right, bottom-left. Each radius is clamped to half the smaller
+    /// dimension of `rect`; a zero radius gives a sharp corner. This is
+    /// suitable for card-style backgrounds and speech bubbles.
+    ///
+    /// Colour `col` is used as given; the theme does not apply any styling.
+    /// See [`crate::draw::DrawRounded::rounded_rect`].
+    pub fn rounded_rect(&mut self, rect: Quad, radii: [f32; 4], col: Rgba) {
+        self.h.rounded_rect(rect, radii, col);
+    }
+
+    /// Fill a vector path
+    ///
+    /// Paths are resolution-independent, thus are suitable for drawing icons
+    /// which remain crisp at any scale factor. Coordinates are in pixels
+    /// (see [`crate::draw::PathBuilder`]).
+    ///
+    /// Colour `fill` is used as given; the theme does not apply any styling.
+    /// See [`crate::draw::DrawRounded::path`].
+    pub fn path(&mut self, path: &PathBuilder, fill: Rgba) {
+        self.h.path(path, fill);
+    }
+
+    /// Draw an image as a nine-patch
+    ///
+    /// Corners, of size `insets` (in image pixels) multiplied by the scale
+    /// factor, are drawn unstretched; edges and centre are stretched to fill
+    /// `rect`. See [`Draw::image_nine_patch`].
+    pub fn image_nine_patch(&mut self, rect: Rect, id: ImageId, insets: Margins) {
+        let scale = self.size_mgr().scale_factor();
+        self.h
+            .draw_device()
+            .image_nine_patch(id, Quad::conv(rect), insets, scale);
+    }
+
+    /// Draw an image tiled over `rect`
+    ///
+    /// Each tile has size `tile` (physical pixels). See [`Draw::image_tiled`].
+    pub fn image_tiled(&mut self, rect: Rect, id: ImageId, tile: Size) {
+        self.h
+            .draw_device()
+            .image_tiled(id, Quad::conv(rect), Vec2::conv(tile));
+    }
+
+    /// Fill `rect` with a linear colour gradient
+    ///
+    /// Colour `from` is used at the edge where `dir` starts and `to` at the
+    /// opposite edge. Unlike most methods here, this is not themed: colours
+    /// are used as given. See [`Draw::rect_gradient`].
+    pub fn rect_gradient(&mut self, rect: Rect, from: Rgba, to: Rgba, dir: Direction) {
+        self.h
+            .draw_device()
+            .rect_gradient(Quad::conv(rect), from, to, dir);
+    }
 }
 
 impl<'a> std::ops::BitOrAssign<TkAction> for DrawMgr<'a> {
@@ -355,6 +563,16 @@ pub trait DrawHandle {
         f: Box<dyn FnOnce(&mut dyn DrawHandle) + 'a>,
     );
 
+    /// Construct a new pass with a scale transform
+    ///
+    /// See [`DrawMgr::with_transform`].
+    fn new_transform_pass<'a>(
+        &mut self,
+        scale: f32,
+        offset: Offset,
+        f: Box<dyn FnOnce(&mut dyn DrawHandle) + 'a>,
+    );
+
     /// Target area for drawing
     ///
     /// Drawing is restricted to this [`Rect`]. Affected by [`Self::new_pass`].
@@ -376,11 +594,31 @@ pub trait DrawHandle {
     /// of size `inner_margin` that is expected to be present around this box.
     fn selection_box(&mut self, rect: Rect);
 
+    /// Draw a focus ring inside `rect`
+    ///
+    /// See [`DrawMgr::focus_ring`].
+    fn focus_ring(&mut self, rect: Rect);
+
+    /// Draw a disabled overlay over `rect`
+    ///
+    /// See [`DrawMgr::disabled_overlay`].
+    fn disabled_overlay(&mut self, rect: Rect);
+
+    /// Draw a fade overlay over `rect`
+    ///
+    /// See [`DrawMgr::fade_overlay`].
+    fn fade_overlay(&mut self, rect: Rect, amount: f32);
+
+    /// Draw a drop shadow for an element occupying `rect`
+    ///
+    /// See [`DrawMgr::shadow`].
+    fn shadow(&mut self, rect: Rect);
+
     /// Draw text
     ///
     /// [`SizeMgr::text_bound`] should be called prior to this method to
     /// select a font, font size and wrap options (based on the [`TextClass`]).
-    fn text(&mut self, id: &WidgetId, pos: Coord, text: &TextDisplay, class: TextClass);
+    fn text(&mut self, id: &WidgetId, pos: Coord, text: &dyn TextApi, class: TextClass);
 
     /// Draw text with effects
     ///
@@ -415,6 +653,11 @@ pub trait DrawHandle {
         byte: usize,
     );
 
+    /// Draw a text caret (insertion marker)
+    ///
+    /// See [`DrawMgr::caret`].
+    fn caret(&mut self, id: &WidgetId, pos: Coord, height: i32);
+
     /// Draw UI element: checkbox
     ///
     /// The checkbox is a small, usually square, box with or without a check
@@ -454,6 +697,23 @@ pub trait DrawHandle {
         dir: Direction,
     );
 
+    /// Draw UI element: overlay scrollbar
+    ///
+    /// As [`Self::scrollbar`], but drawn over content with opacity faded
+    /// according to `visible` and `last_change`. See
+    /// [`DrawMgr::scrollbar_overlay`].
+    #[allow(clippy::too_many_arguments)]
+    fn scrollbar_overlay(
+        &mut self,
+        id: &WidgetId,
+        id2: &WidgetId,
+        rect: Rect,
+        h_rect: Rect,
+        dir: Direction,
+        visible: bool,
+        last_change: Option<Instant>,
+    );
+
     /// Draw UI element: slider
     ///
     /// -   `id`: [`WidgetId`] of the bar
@@ -473,6 +733,21 @@ pub trait DrawHandle {
 
     /// Draw an image
     fn image(&mut self, id: ImageId, rect: Rect);
+
+    /// Draw an arc or pie slice
+    ///
+    /// See [`DrawMgr::arc`].
+    fn arc(&mut self, rect: Quad, start_angle: f32, end_angle: f32, inner_radius: f32, col: Rgba);
+
+    /// Draw a rectangle with rounded corners
+    ///
+    /// See [`DrawMgr::rounded_rect`].
+    fn rounded_rect(&mut self, rect: Quad, radii: [f32; 4], col: Rgba);
+
+    /// Fill a vector path
+    ///
+    /// See [`DrawMgr::path`].
+    fn path(&mut self, path: &PathBuilder, fill: Rgba);
 }
 
 #[cfg(test)]
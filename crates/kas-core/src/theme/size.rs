@@ -10,7 +10,7 @@ use std::ops::Deref;
 use super::{FrameStyle, MarkStyle, TextClass};
 use crate::dir::Directional;
 use crate::geom::{Size, Vec2};
-use crate::layout::{AxisInfo, FrameRules, Margins, SizeRules};
+use crate::layout::{AxisInfo, FrameRules, LogicalSize, Margins, SizeRules};
 use crate::macros::autoimpl;
 use crate::text::{Align, TextApi};
 #[allow(unused)]
@@ -71,11 +71,34 @@ impl<'a> SizeMgr<'a> {
         self.0.scale_factor()
     }
 
+    /// Get the screen resolution, in dots per inch
+    ///
+    /// This is derived from the scale factor, assuming the conventional 96 DPI
+    /// at a scale factor of 1. The actual physical resolution of the screen is
+    /// not considered.
+    ///
+    /// Before the window is first configured on some platforms, the scale
+    /// factor (and thus this value) may be a placeholder of 1.0; widgets are
+    /// resized when the correct value is known.
+    pub fn dpi(&self) -> f32 {
+        96.0 * self.scale_factor()
+    }
+
     /// Convert a size in virtual pixels to physical pixels
     pub fn pixels_from_virtual(&self, px: f32) -> f32 {
         px * self.scale_factor()
     }
 
+    /// Convert a logical size to physical pixels
+    ///
+    /// This uses the current [`Self::scale_factor`], rounding to the nearest
+    /// pixel. The result may change when the scale factor changes (see
+    /// [`Self::dpi`]); it should not be cached beyond the next call to
+    /// [`crate::Layout::size_rules`].
+    pub fn to_physical(&self, size: LogicalSize) -> Size {
+        size.to_physical(self.scale_factor())
+    }
+
     /// Convert a size in font Points to physical pixels
     pub fn pixels_from_points(&self, pt: f32) -> f32 {
         self.0.pixels_from_points(pt)
@@ -79,6 +79,16 @@ pub enum FrameStyle {
     InnerMargin,
     /// A frame for grouping content
     Frame,
+    /// A frame with a sunken (inset) bevel
+    ///
+    /// Suitable e.g. for status areas. Themes without shading may draw this
+    /// like [`FrameStyle::Frame`].
+    Inset,
+    /// A frame with a raised (outset) bevel
+    ///
+    /// Suitable e.g. for group boxes. Themes without shading may draw this
+    /// like [`FrameStyle::Frame`].
+    Outset,
     /// A frame around pop-ups
     Popup,
     /// Border around a pop-up menu entry
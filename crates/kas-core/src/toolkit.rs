@@ -17,6 +17,7 @@ use crate::event;
 use crate::event::UpdateId;
 use crate::theme::{SizeHandle, ThemeControl};
 use std::num::NonZeroU32;
+use std::task::{RawWaker, RawWakerVTable, Waker};
 
 /// Identifier for a window or pop-up
 ///
@@ -125,15 +126,46 @@ pub trait ShellWindow {
     /// windows, will receive an update.
     fn trigger_update(&mut self, id: UpdateId, payload: u64);
 
+    /// Get a waker for futures
+    ///
+    /// Waking this must (eventually) cause the window to be updated, which
+    /// polls futures pushed via [`EventMgr::push_async`]. The waker must be
+    /// usable from any thread.
+    ///
+    /// The default implementation returns a waker which does nothing; in this
+    /// case futures are only polled when the window is updated for some other
+    /// reason (e.g. after handling input).
+    ///
+    /// [`EventMgr::push_async`]: crate::event::EventMgr::push_async
+    fn waker(&self) -> Waker {
+        noop_waker()
+    }
+
     /// Attempt to get clipboard contents
     ///
-    /// In case of failure, paste actions will simply fail. The implementation
-    /// may wish to log an appropriate warning message.
+    /// Implementations must return `None` if the clipboard is unavailable or
+    /// does not contain UTF-8 text. In case of failure, paste actions will
+    /// simply fail. The implementation may wish to log an appropriate warning
+    /// message.
     fn get_clipboard(&mut self) -> Option<String>;
 
     /// Attempt to set clipboard contents
+    ///
+    /// Implementations should do nothing if the clipboard is unavailable.
     fn set_clipboard(&mut self, content: String);
 
+    /// Open a URL using the system's default handler (e.g. a web browser)
+    ///
+    /// This should not block. Failure (e.g. no browser available) should be
+    /// logged, not cause a panic. Implementations should refuse URLs with
+    /// schemes other than `http`, `https` and `mailto` and must not pass the
+    /// URL through a command shell.
+    ///
+    /// The default implementation only logs a warning.
+    fn open_url(&mut self, url: &str) {
+        log::warn!("open_url: not supported by this shell (url: {url})");
+    }
+
     /// Adjust the theme
     ///
     /// Note: theme adjustments apply to all windows, as does the [`TkAction`]
@@ -150,3 +182,15 @@ pub trait ShellWindow {
     /// Set the mouse cursor
     fn set_cursor_icon(&mut self, icon: event::CursorIcon);
 }
+
+// A waker which does nothing; see ShellWindow::waker
+fn noop_waker() -> Waker {
+    unsafe fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+    unsafe fn noop(_: *const ()) {}
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+    // SAFETY: the vtable functions ignore the (null) data pointer
+    unsafe { Waker::from_raw(clone(std::ptr::null())) }
+}
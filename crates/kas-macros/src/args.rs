@@ -14,12 +14,15 @@ use syn::token::{Brace, Colon, Comma, Eq, Paren, Semi};
 use syn::{braced, bracketed, parenthesized, parse_quote};
 use syn::{
     AttrStyle, Attribute, ConstParam, Expr, GenericParam, Generics, Ident, ItemImpl, Lifetime,
-    LifetimeDef, Member, Path, Token, Type, TypeParam, TypePath, TypeTraitObject, Visibility,
+    LifetimeDef, Member, Path, Token, Type, TypeParam, TypePath, TypeTraitObject, Variant,
+    Visibility,
 };
 
 #[derive(Debug)]
 pub struct Child {
     pub ident: Member,
+    /// True if the field has type `Option<W>`
+    pub optional: bool,
 }
 
 fn parse_impl(in_ident: Option<&Ident>, input: ParseStream) -> Result<ItemImpl> {
@@ -294,19 +297,35 @@ pub struct SingletonField {
     pub value: Option<Expr>,
 }
 
+#[derive(Debug)]
+pub enum SingletonItem {
+    Struct {
+        token: Token![struct],
+        style: StructStyle,
+        fields: Punctuated<SingletonField, Comma>,
+    },
+    Enum {
+        token: Token![enum],
+        brace_token: Brace,
+        variants: Punctuated<Variant, Comma>,
+        value: Expr,
+    },
+}
+
 #[derive(Debug)]
 pub struct ImplSingleton {
     pub attrs: Vec<Attribute>,
-    pub token: Token![struct],
     pub generics: Generics,
-    pub style: StructStyle,
-    pub fields: Punctuated<SingletonField, Comma>,
+    pub item: SingletonItem,
     pub impls: Vec<ItemImpl>,
 }
 
 impl Parse for ImplSingleton {
     fn parse(input: ParseStream) -> Result<Self> {
         let attrs = input.call(Attribute::parse_outer)?;
+        if input.peek(Token![enum]) {
+            return Self::parse_enum(attrs, input);
+        }
         let token = input.parse::<Token![struct]>()?;
 
         let mut generics = input.parse::<Generics>()?;
@@ -354,10 +373,62 @@ impl Parse for ImplSingleton {
 
         Ok(ImplSingleton {
             attrs,
-            token,
             generics,
-            style,
-            fields,
+            item: SingletonItem::Struct {
+                token,
+                style,
+                fields,
+            },
+            impls,
+        })
+    }
+}
+
+impl ImplSingleton {
+    fn parse_enum(attrs: Vec<Attribute>, input: ParseStream) -> Result<Self> {
+        let token = input.parse::<Token![enum]>()?;
+
+        let mut generics = input.parse::<Generics>()?;
+        if input.peek(Token![where]) {
+            generics.where_clause = Some(input.parse()?);
+        }
+
+        let content;
+        let brace_token = braced!(content in input);
+        let variants = content.parse_terminated(Variant::parse)?;
+
+        // The #[widget] rule applies to struct fields only
+        let widget_attrs = variants.iter().flat_map(|variant| {
+            let field_attrs = variant.fields.iter().flat_map(|field| field.attrs.iter());
+            variant.attrs.iter().chain(field_attrs)
+        });
+        for attr in widget_attrs {
+            if attr.path == parse_quote! { widget } {
+                return Err(Error::new(
+                    attr.span(),
+                    "#[widget] attribute is not supported on anonymous enums",
+                ));
+            }
+        }
+
+        let _: Eq = input.parse()?;
+        let value = input.parse()?;
+        let _: Semi = input.parse()?;
+
+        let mut impls = Vec::new();
+        while !input.is_empty() {
+            impls.push(parse_impl(None, input)?);
+        }
+
+        Ok(ImplSingleton {
+            attrs,
+            generics,
+            item: SingletonItem::Enum {
+                token,
+                brace_token,
+                variants,
+                value,
+            },
             impls,
         })
     }
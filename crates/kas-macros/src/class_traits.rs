@@ -173,6 +173,10 @@ impl ImplTrait for ImplSetAccel {
                 fn set_accel_string(&mut self, accel: AccelString) -> ::kas::TkAction {
                     self.#using.set_accel_string(accel)
                 }
+                #[inline]
+                fn set_mnemonic(&mut self, c: Option<char>) -> ::kas::TkAction {
+                    self.#using.set_mnemonic(c)
+                }
             })
         } else {
             Err(Error::RequireUsing)
@@ -3,7 +3,7 @@
 // You may obtain a copy of the License in the LICENSE-APACHE file or at:
 //     https://www.apache.org/licenses/LICENSE-2.0
 
-use crate::args::{ChildType, ImplSingleton, StructStyle};
+use crate::args::{ChildType, ImplSingleton, SingletonField, SingletonItem, StructStyle};
 use impl_tools_lib::{
     fields::{Field, Fields, FieldsNamed, FieldsUnnamed},
     Scope, ScopeItem,
@@ -14,11 +14,43 @@ use std::collections::HashMap;
 use std::fmt::Write;
 use syn::parse_quote;
 use syn::punctuated::Punctuated;
-use syn::token::Comma;
+use syn::token::{Brace, Comma};
 use syn::{visit_mut, ConstParam, GenericParam, Lifetime, LifetimeDef, TypeParam};
+use syn::{Attribute, Expr, Generics, ItemImpl, Token, Variant};
 use syn::{Ident, Member, Result, Type, TypePath, Visibility};
 
-pub(crate) fn impl_singleton(mut args: ImplSingleton) -> Result<TokenStream> {
+pub(crate) fn impl_singleton(args: ImplSingleton) -> Result<TokenStream> {
+    match args.item {
+        SingletonItem::Struct {
+            token,
+            style,
+            fields,
+        } => impl_struct(args.attrs, args.generics, token, style, fields, args.impls),
+        SingletonItem::Enum {
+            token,
+            brace_token,
+            variants,
+            value,
+        } => impl_enum(
+            args.attrs,
+            args.generics,
+            token,
+            brace_token,
+            variants,
+            value,
+            args.impls,
+        ),
+    }
+}
+
+fn impl_struct(
+    attrs: Vec<Attribute>,
+    mut generics: Generics,
+    token: Token![struct],
+    style: StructStyle,
+    args_fields: Punctuated<SingletonField, Comma>,
+    impls: Vec<ItemImpl>,
+) -> Result<TokenStream> {
     // Used to make fresh identifiers for generic types
     let mut name_buf = String::with_capacity(32);
     let mut make_ident = move |args: std::fmt::Arguments, span| -> Ident {
@@ -30,11 +62,11 @@ pub(crate) fn impl_singleton(mut args: ImplSingleton) -> Result<TokenStream> {
     let mut fields = Punctuated::<Field, Comma>::new();
     let mut field_val_toks = quote! {};
 
-    for (index, pair) in args.fields.into_pairs().enumerate() {
+    for (index, pair) in args_fields.into_pairs().enumerate() {
         let (field, opt_comma) = pair.into_tuple();
 
         let mut ident = field.ident.clone();
-        let mem = match args.style {
+        let mem = match style {
             StructStyle::Regular(_) => {
                 let id = ident.unwrap_or_else(|| {
                     make_ident(format_args!("_field{index}"), Span::call_site())
@@ -74,7 +106,7 @@ pub(crate) fn impl_singleton(mut args: ImplSingleton) -> Result<TokenStream> {
                     *ident = to.clone();
                     renames.0.insert(from, to);
                 }
-                args.generics.params.extend(gen_args);
+                generics.params.extend(gen_args);
 
                 impl visit_mut::VisitMut for RenameUnique {
                     fn visit_ident_mut(&mut self, ident: &mut Ident) {
@@ -93,9 +125,9 @@ pub(crate) fn impl_singleton(mut args: ImplSingleton) -> Result<TokenStream> {
                     if is_widget {
                         bound.bounds.push(parse_quote! { ::kas::Widget });
                     }
-                    args.generics.params.push(parse_quote! { #ty: #bound });
+                    generics.params.push(parse_quote! { #ty: #bound });
                 } else {
-                    args.generics.params.push(if is_widget {
+                    generics.params.push(if is_widget {
                         parse_quote! { #ty: ::kas::Widget }
                     } else {
                         parse_quote! { #ty }
@@ -128,7 +160,7 @@ pub(crate) fn impl_singleton(mut args: ImplSingleton) -> Result<TokenStream> {
         }
     }
 
-    let (fields, semi) = match args.style {
+    let (fields, semi) = match style {
         StructStyle::Unit(semi) => (Fields::Unit, Some(semi)),
         StructStyle::Regular(brace_token) => (
             Fields::Named(FieldsNamed {
@@ -146,19 +178,60 @@ pub(crate) fn impl_singleton(mut args: ImplSingleton) -> Result<TokenStream> {
         ),
     };
 
-    let mut scope = Scope {
-        attrs: args.attrs,
+    let scope = Scope {
+        attrs,
         vis: Visibility::Inherited,
         ident: parse_quote! { AnonWidget },
-        generics: args.generics,
-        item: ScopeItem::Struct {
-            token: args.token,
-            fields,
-        },
+        generics,
+        item: ScopeItem::Struct { token, fields },
         semi,
-        impls: args.impls,
+        impls,
+        generated: vec![],
+    };
+
+    Ok(expand(scope, quote! { AnonWidget { #field_val_toks } }))
+}
+
+fn impl_enum(
+    attrs: Vec<Attribute>,
+    generics: Generics,
+    token: Token![enum],
+    brace: Brace,
+    variants: Punctuated<Variant, Comma>,
+    mut value: Expr,
+    impls: Vec<ItemImpl>,
+) -> Result<TokenStream> {
+    // The initial value names variants via `Self`, which is not in scope
+    // where the value is constructed.
+    struct ReplaceSelf;
+    impl visit_mut::VisitMut for ReplaceSelf {
+        fn visit_ident_mut(&mut self, ident: &mut Ident) {
+            if ident == "Self" {
+                *ident = Ident::new("AnonWidget", ident.span());
+            }
+        }
+    }
+    visit_mut::visit_expr_mut(&mut ReplaceSelf, &mut value);
+
+    let scope = Scope {
+        attrs,
+        vis: Visibility::Inherited,
+        ident: parse_quote! { AnonWidget },
+        generics,
+        item: ScopeItem::Enum {
+            token,
+            brace,
+            variants,
+        },
+        semi: None,
+        impls,
         generated: vec![],
     };
+
+    Ok(expand(scope, quote! { #value }))
+}
+
+fn expand(mut scope: Scope, value: TokenStream) -> TokenStream {
     scope.apply_attrs(|path| {
         crate::IMPL_SCOPE_RULES
             .iter()
@@ -167,13 +240,9 @@ pub(crate) fn impl_singleton(mut args: ImplSingleton) -> Result<TokenStream> {
     });
     scope.expand_impl_self();
 
-    let toks = quote! { {
+    quote! { {
         #scope
 
-        AnonWidget {
-            #field_val_toks
-        }
-    } };
-
-    Ok(toks)
+        #value
+    } }
 }
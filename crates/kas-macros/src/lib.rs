@@ -25,6 +25,7 @@ mod args;
 mod class_traits;
 mod impl_singleton;
 mod make_layout;
+mod partial_eq;
 mod storage;
 mod widget;
 mod widget_index;
@@ -60,6 +61,7 @@ pub fn impl_default(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// | `Clone` | `::std::clone::Clone` | initialized with `Default::default()` | - |
 /// | `Debug` | `::std::fmt::Debug` | field is not printed | - |
 /// | `Default` | `::std::default::Default` | - | - |
+/// | `PartialEq` | `::std::cmp::PartialEq` | field is not compared | - |
 /// | `Deref` | `::std::ops::Deref` | - | deref target |
 /// | `DerefMut` | `::std::ops::DerefMut` | - | deref target |
 /// | `Storage` | `::kas::layout::Storage` | - | - |
@@ -71,6 +73,9 @@ pub fn impl_default(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// *Ignore:* trait supports ignoring fields (e.g. `#[autoimpl(Debug ignore self.foo)]`).
 ///
+/// `PartialEq` never compares a widget's core field (of type
+/// `widget_core!()`); other fields may be ignored explicitly.
+///
 /// *Using:* trait requires a named field to "use" (e.g. `#[autoimpl(Deref using self.foo)]`).
 ///
 /// ### Examples
@@ -99,6 +104,7 @@ pub fn autoimpl(attr: TokenStream, item: TokenStream) -> TokenStream {
                     .chain(class_traits::CLASS_IMPLS.iter())
                     .cloned()
                     .chain(once(&ImplClassTraits as &dyn ImplTrait))
+                    .chain(once(&partial_eq::ImplPartialEq as &dyn ImplTrait))
                     .chain(once(&storage::ImplStorage as &dyn ImplTrait))
                     .find(|impl_| impl_.path().matches_ident_or_path(path))
             };
@@ -180,8 +186,8 @@ pub fn impl_scope(input: TokenStream) -> TokenStream {
 /// `Widget::pre_configure`) and `rect: Rect` (usually assigned by
 /// `Widget::set_rect`). It may contain additional fields for layout data. The
 /// type supports `Debug`, `Default` and `Clone` (although `Clone` actually
-/// default-initializes all fields other than `rect` since clones of widgets
-/// must themselves be configured).
+/// default-initializes all fields, including `rect` and any layout storage,
+/// since clones of widgets must themselves be configured and sized).
 ///
 /// Assuming the deriving type is a `struct` or `tuple struct`, fields support
 /// the following attributes:
@@ -189,6 +195,16 @@ pub fn impl_scope(input: TokenStream) -> TokenStream {
 /// -   `#[widget]`: marks the field as a [`Widget`] to be configured, enumerated by
 ///     [`WidgetChildren`] and included by glob layouts
 ///
+/// A `#[widget]` field may have type `Option<W>` (where `W: Widget`), in which
+/// case the child is enumerated (and laid out, where used as a _Single_ layout
+/// item) only while the field is `Some`. Optional children are enumerated
+/// after all other children (including layout-defined ones), thus the
+/// indices of other children are stable. The index of an optional child
+/// (as given by [`widget_index!`]) depends on which preceding optional
+/// children are `Some` and is not a constant, thus it may not be used as a
+/// `match` pattern. After changing whether such a field is `Some`, the
+/// widget must be reconfigured (e.g. via `TkAction::RECONFIGURE`) and resized.
+///
 /// ## Layout
 ///
 /// Widget layout may be specified either by implementing the `size_rules`,
@@ -196,7 +212,7 @@ pub fn impl_scope(input: TokenStream) -> TokenStream {
 /// The latter accepts the following syntax:
 ///
 /// > _Layout_ :\
-/// > &nbsp;&nbsp; &nbsp;&nbsp; _Single_ | _List_ | _Slice_ | _Grid_ | _Float_ | _Align_ | _Frame_ | _Button_
+/// > &nbsp;&nbsp; &nbsp;&nbsp; _Single_ | _List_ | _Slice_ | _Grid_ | _Float_ | _Align_ | _Reserve_ | _Frame_ | _Button_
 /// >
 /// > _Single_ :\
 /// > &nbsp;&nbsp; `self` `.` _Member_ | _Expr_
@@ -214,10 +230,17 @@ pub fn impl_scope(input: TokenStream) -> TokenStream {
 /// > &nbsp;&nbsp; `left` | `right` | `up` | `down`
 /// >
 /// > _Grid_ :\
-/// > &nbsp;&nbsp; `grid` _Storage_? `:` `{` _GridCell_* `}`
+/// > &nbsp;&nbsp; `grid` ( `(` _GridWeights_ `)` )? _Storage_? `:` `{` _GridCell_* `}`\
+/// > &nbsp;&nbsp; | `grid` `(` `cols` `=` _LitInt_ ( `,` _GridWeights_ )? `)` _Storage_? `:` `{` ( _Layout_ `;` ) * `}`
+/// >
+/// > _GridWeights_ :\
+/// > &nbsp;&nbsp; _Weights_ ( `,` _Weights_ )?
+/// >
+/// > _Weights_ :\
+/// > &nbsp;&nbsp; ( `col_weights` | `row_weights` ) `=` `[` ( _LitInt_ `,`? ) * `]`
 /// >
 /// > _GridCell_ :\
-/// > &nbsp;&nbsp; _CellRange_ `,` _CellRange_ `:` _Layout_
+/// > &nbsp;&nbsp; _CellRange_ `,` _CellRange_ ( `(` `bg` `=` _Expr_ `)` )? `:` _Layout_ `;`
 /// >
 /// > _CellRange_ :\
 /// > &nbsp;&nbsp; _LitInt_ ( `..` `+`? _LitInt_ )?
@@ -229,13 +252,25 @@ pub fn impl_scope(input: TokenStream) -> TokenStream {
 /// > &nbsp;&nbsp; `align` `(` _AlignType_ ( `,` _AlignType_ )? `)` `:` _Layout_
 /// >
 /// > _AlignType_ :\
-/// > &nbsp;&nbsp; `default` | `center` | `stretch` | `top` | `bottom` | `left` | `right`
+/// > &nbsp;&nbsp; `default` | `center` | `stretch` | `top` | `bottom` | `left` | `right` | `baseline`
+/// >
+/// > _Reserve_ :\
+/// > &nbsp;&nbsp; `reserve` `(` _ReserveAxis_ ( `,` _ReserveAxis_ )? `)` `:` _Layout_
+/// >
+/// > _ReserveAxis_ :\
+/// > &nbsp;&nbsp; ( `width` | `height` ) `=` _LitInt_ | _LitFloat_
 /// >
 /// > _Frame_ :\
 /// > &nbsp;&nbsp; `frame` `(` _Style_ `)` _Storage_? `:` _Layout_
 /// >
 /// > _Button_ :\
-/// > &nbsp;&nbsp; `button` `(` _Color_ `)` ? _Storage_? `:` _Layout_
+/// > &nbsp;&nbsp; `button` ( `(` _ButtonColors_ `)` )? _Storage_? `:` _Layout_
+/// >
+/// > _ButtonColors_ :\
+/// > &nbsp;&nbsp; _Color_ ( `,` _StateColor_ )* | _StateColor_ ( `,` _StateColor_ )*
+/// >
+/// > _StateColor_ :\
+/// > &nbsp;&nbsp; ( `hover` | `pressed` ) `=` _Color_
 /// >
 /// > _Storage_ :\
 /// > &nbsp;&nbsp; `'` _Ident_
@@ -260,6 +295,15 @@ pub fn impl_scope(input: TokenStream) -> TokenStream {
 /// keywords are used, the first applies to the horizontal axis and the second
 /// to the vertical (thus `top, left` is invalid; use `left, top`). `default`
 /// forces content-default alignment when the widget would set alignment.
+/// For example, `align(stretch, default)` stretches horizontally only.
+/// `baseline` applies to the vertical axis only: rows align the text baselines
+/// of their children (see `AlignHints::baseline`), reserving vertical space
+/// for the resulting offsets.
+///
+/// _Reserve_ enforces a minimum size on its content, in logical pixels (thus
+/// scaled by the window's scale factor). Sizes may have a `px` suffix, e.g.
+/// `reserve(width = 100px, height = 20px): self.inner`. An axis which is not
+/// given is left unconstrained. See also `kas::widgets::adapter::Reserve`.
 ///
 /// _Slice_ is a variant of _List_ over a single struct field which supports
 /// `AsMut<W>` for some widget type `W`.
@@ -268,11 +312,30 @@ pub fn impl_scope(input: TokenStream) -> TokenStream {
 /// Contents are declared as a collection of cells. Cell location is specified
 /// like `0, 1` (that is, col=0, row=1) with spans specified like `0..2, 1`
 /// (thus cols={0, 1}, row=1) or `2..+2, 1` (cols={2,3}, row=1).
+/// Alternatively, `grid(cols = N)` auto-places cells (without coordinates) in
+/// order, filling each row of `N` columns before starting the next. Explicit
+/// coordinates (and thus spans) may not be used in this mode.
+/// Options within the parentheses may be given in any order.
+///
+/// Optionally, `col_weights = [1, 2]` and `row_weights = [..]` bias the
+/// distribution of space in excess of the ideal size: instead of sharing this
+/// equally between columns (rows) with the highest stretch priority, it is
+/// shared in proportion to their weights. Columns (rows) without a listed
+/// weight use weight 1; if all weights involved are zero, space is shared
+/// equally. See `kas::layout::GridDimensions`.
+///
+/// A cell with explicit coordinates may specify a background fill color (an
+/// expression of type `Rgb`), for example `0..2, 1 (bg = Rgb::grey(0.9)): ..`.
+/// The fill covers the whole cell span plus half the margins around the cell
+/// contents, thus adjacent filled cells meet without gaps.
 ///
 /// _Frame_ and _Button_ are two variants of the same thing: a button is a frame
 /// using `FrameStyle::Button`, but may optionally also have a color (a field of
 /// type `Option<Rgb>`). Additionally, a button automatically uses centered
-/// alignment of content.
+/// alignment of content. Colors used while the button is hovered or pressed
+/// may be overridden, e.g. `button(self.color, hover = self.hover_color)`
+/// (each an `Option<Rgb>`); where omitted, the theme derives these from the
+/// base color.
 ///
 /// Non-trivial layouts require a "storage" field within the generated
 /// `widget_core!()`. This storage field may be named via a "lifetime label"
@@ -327,6 +390,18 @@ pub fn impl_scope(input: TokenStream) -> TokenStream {
 /// };
 /// ```
 ///
+/// A two-column grid using auto-placement (`a`, `b` in the first row, `c`, `d`
+/// in the second):
+///
+/// ```ignore
+/// layout = grid(cols = 2): {
+///     self.a;
+///     self.b;
+///     self.c;
+///     self.d;
+/// };
+/// ```
+///
 /// ## Derive
 ///
 /// It is possible to derive from a field which is itself a widget, e.g.:
@@ -406,6 +481,29 @@ pub fn widget(_: TokenStream, item: TokenStream) -> TokenStream {
 /// As a special rule, any field using the `#[widget]` attribute and without a
 /// fixed type has the `::kas::Widget` trait bound applied.
 ///
+/// Alternatively, an anonymous enum may be created. Variants use standard
+/// syntax (types must be fixed) and an initial value must be given, naming
+/// the variant via `Self`. The `#[widget]` attribute is not supported here.
+/// ```
+/// use kas_macros::impl_singleton;
+/// fn main() {
+///     let mut light = impl_singleton! {
+///         enum { Red, Amber, Green(u32) } = Self::Green(30);
+///         impl Self {
+///             fn next(&mut self) {
+///                 *self = match self {
+///                     Self::Red => Self::Green(30),
+///                     Self::Amber => Self::Red,
+///                     Self::Green(_) => Self::Amber,
+///                 };
+///             }
+///         }
+///     };
+///     light.next();
+///     light.next();
+/// }
+/// ```
+///
 /// Refer to [examples](https://github.com/search?q=impl_singleton+repo%3Akas-gui%2Fkas+path%3Aexamples&type=Code) for usage.
 #[proc_macro_error]
 #[proc_macro]
@@ -425,6 +523,12 @@ pub fn impl_singleton(input: TokenStream) -> TokenStream {
 /// marked with the `#[widget]` attribute), then this expands to the child
 /// widget's index (as used by [`WidgetChildren`]). Otherwise, this is an error.
 ///
+/// Where `a` has type `Option<W>`, the index depends on which optional
+/// children are `Some`, hence this expands to a run-time expression (not a
+/// constant) evaluating to `usize::MAX` while `a` is `None`. Such an index
+/// cannot be used as a `match` pattern; compare with `==` instead, e.g.
+/// `i if i == widget_index![self.a] => ..`.
+///
 /// [`WidgetChildren`]: https://docs.rs/kas/0.11/kas/trait.WidgetChildren.html
 #[proc_macro_error]
 #[proc_macro]
@@ -29,10 +29,15 @@ mod kw {
     custom_keyword!(default);
     custom_keyword!(top);
     custom_keyword!(bottom);
+    custom_keyword!(baseline);
     custom_keyword!(aligned_column);
     custom_keyword!(aligned_row);
     custom_keyword!(float);
     custom_keyword!(margins);
+    custom_keyword!(bg);
+    custom_keyword!(reserve);
+    custom_keyword!(width);
+    custom_keyword!(height);
 }
 
 #[derive(Debug)]
@@ -50,6 +55,16 @@ impl Tree {
         }
     }
 
+    /// Mark uses of optional children (fields of type `Option<W>`)
+    ///
+    /// Layout items `self.member` (optionally with alignment) where `member`
+    /// is in `optional` are laid out only when the field is `Some`.
+    pub fn set_optional(&mut self, optional: &[Member]) {
+        if !optional.is_empty() {
+            self.0.set_optional(optional);
+        }
+    }
+
     pub fn generate(&self, core: &Member) -> Result<Toks> {
         self.0.generate(core)
     }
@@ -79,11 +94,15 @@ impl ToTokens for StorIdent {
 enum Layout {
     Align(Box<Layout>, AlignHints),
     AlignSingle(Expr, AlignHints),
+    AlignOptional(Expr, AlignHints),
     Margins(Box<Layout>, Directions, Toks),
+    Reserve(Box<Layout>, f32, f32),
     Single(Expr),
+    Optional(Expr),
     Widget(StorIdent, Expr),
     Frame(StorIdent, Box<Layout>, Expr),
-    Button(StorIdent, Box<Layout>, Expr),
+    Button(StorIdent, Box<Layout>, ButtonColors),
+    Background(StorIdent, Box<Layout>, Expr),
     List(StorIdent, Direction, Vec<Layout>),
     Float(Vec<Layout>),
     Slice(StorIdent, Direction, Expr),
@@ -118,9 +137,10 @@ enum Align {
     Center,
     BR,
     Stretch,
+    Baseline,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 struct AlignHints(Align, Align);
 
 #[derive(Debug, Default)]
@@ -129,6 +149,119 @@ struct GridDimensions {
     col_spans: u32,
     rows: u32,
     row_spans: u32,
+    col_weights: Vec<u32>,
+    row_weights: Vec<u32>,
+}
+
+/// Colors of `button(...)`
+#[derive(Debug)]
+struct ButtonColors {
+    base: Expr,
+    hover: Expr,
+    pressed: Expr,
+}
+
+impl Default for ButtonColors {
+    fn default() -> Self {
+        ButtonColors {
+            base: syn::parse_quote! { None },
+            hover: syn::parse_quote! { None },
+            pressed: syn::parse_quote! { None },
+        }
+    }
+}
+
+impl Parse for ButtonColors {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut colors = ButtonColors::default();
+        let (mut hover, mut pressed) = (false, false);
+
+        let is_named = |input: ParseStream| input.peek(Ident) && input.peek2(Token![=]);
+        if !input.is_empty() && !is_named(input) {
+            colors.base = input.parse()?;
+            if !input.is_empty() {
+                let _ = input.parse::<Token![,]>()?;
+            }
+        }
+
+        while !input.is_empty() {
+            let ident = input.parse::<Ident>()?;
+            let _ = input.parse::<Token![=]>()?;
+            let duplicate = if ident == "hover" {
+                colors.hover = input.parse()?;
+                std::mem::replace(&mut hover, true)
+            } else if ident == "pressed" {
+                colors.pressed = input.parse()?;
+                std::mem::replace(&mut pressed, true)
+            } else {
+                return Err(Error::new(ident.span(), "expected `hover` or `pressed`"));
+            };
+            if duplicate {
+                return Err(Error::new(ident.span(), "duplicate option"));
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            let _ = input.parse::<Token![,]>()?;
+        }
+        Ok(colors)
+    }
+}
+
+/// Options of `grid(...)`
+#[derive(Debug, Default)]
+struct GridOptions {
+    auto_cols: Option<u32>,
+    col_weights: Option<(Span, Vec<u32>)>,
+    row_weights: Option<(Span, Vec<u32>)>,
+}
+
+impl Parse for GridOptions {
+    fn parse(input: ParseStream) -> Result<Self> {
+        fn parse_weights(input: ParseStream) -> Result<(Span, Vec<u32>)> {
+            let inner;
+            let bracket = bracketed!(inner in input);
+            let list = inner.parse_terminated::<LitInt, Token![,]>(LitInt::parse)?;
+            let weights = list
+                .iter()
+                .map(|lit| lit.base10_parse())
+                .collect::<Result<_>>()?;
+            Ok((bracket.span, weights))
+        }
+
+        let mut options = GridOptions::default();
+        while !input.is_empty() {
+            let ident = input.parse::<Ident>()?;
+            let _ = input.parse::<Token![=]>()?;
+            let duplicate = if ident == "cols" {
+                let lit = input.parse::<LitInt>()?;
+                let cols: u32 = lit.base10_parse()?;
+                if cols == 0 {
+                    return Err(Error::new(lit.span(), "expected value > 0"));
+                }
+                options.auto_cols.replace(cols).is_some()
+            } else if ident == "col_weights" {
+                options.col_weights.replace(parse_weights(input)?).is_some()
+            } else if ident == "row_weights" {
+                options.row_weights.replace(parse_weights(input)?).is_some()
+            } else {
+                return Err(Error::new(
+                    ident.span(),
+                    "expected `cols`, `col_weights` or `row_weights`",
+                ));
+            };
+            if duplicate {
+                return Err(Error::new(ident.span(), "duplicate option"));
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            let _ = input.parse::<Token![,]>()?;
+        }
+        Ok(options)
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -276,6 +409,12 @@ impl Layout {
             let _ = input.parse::<Token![:]>()?;
             let layout = Layout::parse(input, gen)?;
             Ok(Layout::Margins(Box::new(layout), dirs, margins))
+        } else if lookahead.peek(kw::reserve) {
+            let _: kw::reserve = input.parse()?;
+            let (width, height) = parse_reserve(input)?;
+            let _: Token![:] = input.parse()?;
+            let layout = Layout::parse(input, gen)?;
+            Ok(Layout::Reserve(Box::new(layout), width, height))
         } else if lookahead.peek(Token![self]) {
             Ok(Layout::Single(input.parse()?))
         } else if lookahead.peek(kw::frame) {
@@ -293,16 +432,17 @@ impl Layout {
             Ok(Layout::Frame(stor, Box::new(layout), style))
         } else if lookahead.peek(kw::button) {
             let _: kw::button = input.parse()?;
-            let mut color: Expr = syn::parse_quote! { None };
-            if input.peek(syn::token::Paren) {
+            let colors = if input.peek(syn::token::Paren) {
                 let inner;
                 let _ = parenthesized!(inner in input);
-                color = inner.parse()?;
-            }
+                inner.parse()?
+            } else {
+                ButtonColors::default()
+            };
             let stor = gen.parse_or_next(input)?;
             let _: Token![:] = input.parse()?;
             let layout = Layout::parse(input, gen)?;
-            Ok(Layout::Button(stor, Box::new(layout), color))
+            Ok(Layout::Button(stor, Box::new(layout), colors))
         } else if lookahead.peek(kw::column) {
             let _: kw::column = input.parse()?;
             let dir = Direction::Down;
@@ -359,9 +499,15 @@ impl Layout {
             }
         } else if lookahead.peek(kw::grid) {
             let _: kw::grid = input.parse()?;
+            let mut options = GridOptions::default();
+            if input.peek(syn::token::Paren) {
+                let inner;
+                let _ = parenthesized!(inner in input);
+                options = inner.parse()?;
+            }
             let stor = gen.parse_or_next(input)?;
             let _: Token![:] = input.parse()?;
-            Ok(parse_grid(stor, input, gen)?)
+            Ok(parse_grid(stor, input, gen, options)?)
         } else if lookahead.peek(LitStr) {
             let stor = gen.next();
             Ok(Layout::Label(stor, input.parse()?))
@@ -408,6 +554,12 @@ impl Align {
             }
             let _: kw::bottom = inner.parse()?;
             Align::BR
+        } else if lookahead.peek(kw::baseline) {
+            if first {
+                return Ok(None);
+            }
+            let _: kw::baseline = inner.parse()?;
+            Align::Baseline
         } else if lookahead.peek(kw::left) && first {
             let _: kw::left = inner.parse()?;
             Align::TL
@@ -443,6 +595,60 @@ fn parse_align(input: ParseStream) -> Result<AlignHints> {
     }
 }
 
+/// Parse `(width = 100px, height = 20px)`; either axis may be omitted
+///
+/// Returns logical pixel sizes, with `0.0` for an omitted axis.
+fn parse_reserve(input: ParseStream) -> Result<(f32, f32)> {
+    let inner;
+    let _ = parenthesized!(inner in input);
+
+    let (mut width, mut height) = (None, None);
+    while !inner.is_empty() {
+        let lookahead = inner.lookahead1();
+        let target = if lookahead.peek(kw::width) {
+            let kw: kw::width = inner.parse()?;
+            (&mut width, kw.span)
+        } else if lookahead.peek(kw::height) {
+            let kw: kw::height = inner.parse()?;
+            (&mut height, kw.span)
+        } else {
+            return Err(lookahead.error());
+        };
+        if target.0.is_some() {
+            return Err(Error::new(target.1, "duplicate axis"));
+        }
+        let _: Token![=] = inner.parse()?;
+        *target.0 = Some(parse_logical_px(&inner)?);
+
+        if inner.is_empty() {
+            break;
+        }
+        let _: Token![,] = inner.parse()?;
+    }
+
+    if width.is_none() && height.is_none() {
+        return Err(inner.error("expected `width = ..` and/or `height = ..`"));
+    }
+    Ok((width.unwrap_or(0.0), height.unwrap_or(0.0)))
+}
+
+/// Parse a non-negative number with optional `px` suffix
+fn parse_logical_px(input: ParseStream) -> Result<f32> {
+    let lit: syn::Lit = input.parse()?;
+    let (value, suffix) = match &lit {
+        syn::Lit::Int(lit) => (lit.base10_parse::<f32>()?, lit.suffix()),
+        syn::Lit::Float(lit) => (lit.base10_parse::<f32>()?, lit.suffix()),
+        _ => return Err(Error::new(lit.span(), "expected size in logical pixels")),
+    };
+    if !(suffix.is_empty() || suffix == "px") {
+        return Err(Error::new(lit.span(), "expected suffix `px` or none"));
+    }
+    if !(value >= 0.0 && value.is_finite()) {
+        return Err(Error::new(lit.span(), "expected a non-negative size"));
+    }
+    Ok(value)
+}
+
 fn parse_layout_list(input: ParseStream, gen: &mut NameGenerator) -> Result<Vec<Layout>> {
     let inner;
     let _ = bracketed!(inner in input);
@@ -516,17 +722,57 @@ fn parse_grid_as_list_of_lists<KW: Parse>(
     Ok(Layout::Grid(stor, dim, cells))
 }
 
-fn parse_grid(stor: StorIdent, input: ParseStream, gen: &mut NameGenerator) -> Result<Layout> {
+fn parse_grid(
+    stor: StorIdent,
+    input: ParseStream,
+    gen: &mut NameGenerator,
+    options: GridOptions,
+) -> Result<Layout> {
     let inner;
     let _ = braced!(inner in input);
 
+    let auto_cols = options.auto_cols;
     let mut dim = GridDimensions::default();
+    if let Some(cols) = auto_cols {
+        dim.cols = cols;
+    }
     let mut cells = vec![];
+    let mut index = 0;
     while !inner.is_empty() {
-        let info = parse_cell_info(&inner)?;
+        let mut bg = None;
+        let info = if let Some(cols) = auto_cols {
+            if inner.peek(LitInt) {
+                return Err(Error::new(
+                    inner.span(),
+                    "explicit cell coordinates (and spans) are not allowed with `grid(cols = N)`",
+                ));
+            }
+            let info = CellInfo::new(index % cols, index / cols);
+            index += 1;
+            info
+        } else {
+            if !inner.peek(LitInt) {
+                return Err(Error::new(
+                    inner.span(),
+                    "expected cell coordinates `col, row:` (use `grid(cols = N)` for auto-placement)",
+                ));
+            }
+            let info = parse_cell_info(&inner)?;
+            if inner.peek(syn::token::Paren) {
+                let args;
+                let _ = parenthesized!(args in inner);
+                let _: kw::bg = args.parse()?;
+                let _: Token![=] = args.parse()?;
+                bg = Some(args.parse()?);
+            }
+            let _: Token![:] = inner.parse()?;
+            info
+        };
         dim.update(&info);
-        let _: Token![:] = inner.parse()?;
-        let layout = Layout::parse(&inner, gen)?;
+        let mut layout = Layout::parse(&inner, gen)?;
+        if let Some(color) = bg {
+            layout = Layout::Background(gen.next(), Box::new(layout), color);
+        }
         cells.push((info, layout));
 
         if inner.is_empty() {
@@ -536,6 +782,19 @@ fn parse_grid(stor: StorIdent, input: ParseStream, gen: &mut NameGenerator) -> R
         let _: Token![;] = inner.parse()?;
     }
 
+    if let Some((span, weights)) = options.col_weights {
+        if weights.len() > usize::try_from(dim.cols).unwrap() {
+            return Err(Error::new(span, "more column weights than columns"));
+        }
+        dim.col_weights = weights;
+    }
+    if let Some((span, weights)) = options.row_weights {
+        if weights.len() > usize::try_from(dim.rows).unwrap() {
+            return Err(Error::new(span, "more row weights than rows"));
+        }
+        dim.row_weights = weights;
+    }
+
     Ok(Layout::Grid(stor, dim, cells))
 }
 
@@ -579,6 +838,7 @@ impl ToTokens for AlignHints {
                 Align::Stretch => quote! { Some(layout::Align::Stretch) },
                 Align::TL => quote! { Some(layout::Align::TL) },
                 Align::BR => quote! { Some(layout::Align::BR) },
+                Align::Baseline => quote! { None },
             }
         }
         let horiz = align_toks(&self.0);
@@ -587,6 +847,9 @@ impl ToTokens for AlignHints {
         toks.append_all(quote! {
             layout::AlignHints::new(#horiz, #vert)
         });
+        if self.1 == Align::Baseline {
+            toks.append_all(quote! { .with_baseline() });
+        }
     }
 }
 
@@ -606,22 +869,63 @@ impl ToTokens for GridDimensions {
     fn to_tokens(&self, toks: &mut Toks) {
         let (cols, rows) = (self.cols, self.rows);
         let (col_spans, row_spans) = (self.col_spans, self.row_spans);
-        toks.append_all(quote! { layout::GridDimensions {
-            cols: #cols,
-            col_spans: #col_spans,
-            rows: #rows,
-            row_spans: #row_spans,
-        } });
+        let (col_weights, row_weights) = (&self.col_weights, &self.row_weights);
+        toks.append_all(quote! {
+            layout::GridDimensions::new(#cols, #col_spans, #rows, #row_spans)
+        });
+        if !col_weights.is_empty() {
+            toks.append_all(quote! { .with_col_weights(&[#(#col_weights),*]) });
+        }
+        if !row_weights.is_empty() {
+            toks.append_all(quote! { .with_row_weights(&[#(#row_weights),*]) });
+        }
     }
 }
 
 impl Layout {
+    fn set_optional(&mut self, optional: &[Member]) {
+        let is_optional = |expr: &Expr| match expr {
+            Expr::Field(field) => {
+                matches!(&*field.base, Expr::Path(path) if path.path.is_ident("self"))
+                    && optional.contains(&field.member)
+            }
+            _ => false,
+        };
+
+        match self {
+            Layout::Align(layout, _)
+            | Layout::Margins(layout, _, _)
+            | Layout::Reserve(layout, _, _)
+            | Layout::Frame(_, layout, _)
+            | Layout::Button(_, layout, _)
+            | Layout::Background(_, layout, _) => layout.set_optional(optional),
+            Layout::List(_, _, vec) | Layout::Float(vec) => {
+                for item in vec {
+                    item.set_optional(optional);
+                }
+            }
+            Layout::Grid(_, _, cells) => {
+                for (_, layout) in cells {
+                    layout.set_optional(optional);
+                }
+            }
+            Layout::Single(expr) if is_optional(expr) => {
+                *self = Layout::Optional(expr.clone());
+            }
+            Layout::AlignSingle(expr, align) if is_optional(expr) => {
+                *self = Layout::AlignOptional(expr.clone(), *align);
+            }
+            _ => (),
+        }
+    }
+
     fn append_fields(&self, ty_toks: &mut Toks, def_toks: &mut Toks, children: &mut Vec<Toks>) {
         match self {
-            Layout::Align(layout, _) => {
+            Layout::Align(layout, _) | Layout::Reserve(layout, _, _) => {
                 layout.append_fields(ty_toks, def_toks, children);
             }
-            Layout::AlignSingle(..) | Layout::Margins(..) | Layout::Single(_) => (),
+            Layout::AlignSingle(..) | Layout::AlignOptional(..) => (),
+            Layout::Margins(..) | Layout::Single(_) | Layout::Optional(_) => (),
             Layout::Widget(stor, expr) => {
                 children.push(stor.to_token_stream());
                 stor.to_tokens(ty_toks);
@@ -636,6 +940,13 @@ impl Layout {
                 def_toks.append_all(quote! { : Default::default(), });
                 layout.append_fields(ty_toks, def_toks, children);
             }
+            Layout::Background(stor, layout, _) => {
+                stor.to_tokens(ty_toks);
+                ty_toks.append_all(quote! { : ::kas::layout::BackgroundStorage, });
+                stor.to_tokens(def_toks);
+                def_toks.append_all(quote! { : Default::default(), });
+                layout.append_fields(ty_toks, def_toks, children);
+            }
             Layout::List(stor, _, vec) => {
                 stor.to_tokens(ty_toks);
                 stor.to_tokens(def_toks);
@@ -696,6 +1007,14 @@ impl Layout {
             Layout::AlignSingle(expr, align) => {
                 quote! { layout::Visitor::align_single(&mut (#expr), #align) }
             }
+            Layout::AlignOptional(expr, align) => quote! {
+                layout::Visitor::align(
+                    layout::Visitor::optional(
+                        (#expr).as_mut().map(|w| w as &mut dyn ::kas::Widget)
+                    ),
+                    #align,
+                )
+            },
             Layout::Margins(layout, dirs, selector) => {
                 let inner = layout.generate(core)?;
                 quote! { layout::Visitor::margins(
@@ -704,9 +1023,19 @@ impl Layout {
                     layout::MarginSelector::#selector,
                 ) }
             }
+            Layout::Reserve(layout, width, height) => {
+                let inner = layout.generate(core)?;
+                quote! { layout::Visitor::reserve(
+                    #inner,
+                    layout::LogicalSize(#width, #height),
+                ) }
+            }
             Layout::Single(expr) => quote! {
                 layout::Visitor::single(&mut (#expr))
             },
+            Layout::Optional(expr) => quote! {
+                layout::Visitor::optional((#expr).as_mut().map(|w| w as &mut dyn ::kas::Widget))
+            },
             Layout::Widget(stor, _) => quote! {
                 layout::Visitor::single(&mut self.#core.#stor)
             },
@@ -716,10 +1045,17 @@ impl Layout {
                     layout::Visitor::frame(&mut self.#core.#stor, #inner, #style)
                 }
             }
-            Layout::Button(stor, layout, color) => {
+            Layout::Button(stor, layout, colors) => {
                 let inner = layout.generate(core)?;
+                let (base, hover, pressed) = (&colors.base, &colors.hover, &colors.pressed);
                 quote! {
-                    layout::Visitor::button(&mut self.#core.#stor, #inner, #color)
+                    layout::Visitor::button(&mut self.#core.#stor, #inner, #base, #hover, #pressed)
+                }
+            }
+            Layout::Background(stor, layout, color) => {
+                let inner = layout.generate(core)?;
+                quote! {
+                    layout::Visitor::background(&mut self.#core.#stor, #inner, #color)
                 }
             }
             Layout::List(stor, dir, list) => {
@@ -771,3 +1107,113 @@ impl Layout {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use syn::parse::Parser;
+
+    #[test]
+    fn align_two_axes() {
+        let kws = [
+            (
+                "default",
+                Align::Default,
+                quote! { Some(layout::Align::Default) },
+            ),
+            (
+                "center",
+                Align::Center,
+                quote! { Some(layout::Align::Center) },
+            ),
+            (
+                "stretch",
+                Align::Stretch,
+                quote! { Some(layout::Align::Stretch) },
+            ),
+        ];
+        for (h, h_align, h_toks) in &kws {
+            for (v, v_align, v_toks) in &kws {
+                let s = format!("({h}, {v})");
+                let hints = parse_align.parse_str(&s).unwrap();
+                assert_eq!(hints, AlignHints(*h_align, *v_align), "align{s}");
+
+                let expected = quote! { layout::AlignHints::new(#h_toks, #v_toks) };
+                assert_eq!(
+                    hints.to_token_stream().to_string(),
+                    expected.to_string(),
+                    "align{s}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn align_single() {
+        let hints = parse_align.parse_str("(stretch)").unwrap();
+        assert_eq!(hints, AlignHints(Align::Stretch, Align::Stretch));
+        let hints = parse_align.parse_str("(left)").unwrap();
+        assert_eq!(hints, AlignHints(Align::TL, Align::None));
+        let hints = parse_align.parse_str("(bottom)").unwrap();
+        assert_eq!(hints, AlignHints(Align::None, Align::BR));
+        assert!(parse_align.parse_str("(top, left)").is_err());
+    }
+
+    #[test]
+    fn align_baseline() {
+        let hints = parse_align.parse_str("(baseline)").unwrap();
+        assert_eq!(hints, AlignHints(Align::None, Align::Baseline));
+        let expected = quote! { layout::AlignHints::new(None, None).with_baseline() };
+        assert_eq!(hints.to_token_stream().to_string(), expected.to_string());
+
+        let hints = parse_align.parse_str("(center, baseline)").unwrap();
+        assert_eq!(hints, AlignHints(Align::Center, Align::Baseline));
+        assert!(parse_align.parse_str("(baseline, center)").is_err());
+    }
+
+    #[test]
+    fn reserve() {
+        let tree: Tree = syn::parse_str("reserve(width = 100px, height = 20.5): self.a").unwrap();
+        let core: Member = syn::parse_str("core").unwrap();
+        let toks = tree.generate(&core).unwrap().to_string();
+        assert!(toks.contains("layout :: LogicalSize (100f32 , 20.5f32)"));
+
+        let tree: Tree = syn::parse_str("reserve(height = 20px): self.a").unwrap();
+        let toks = tree.generate(&core).unwrap().to_string();
+        assert!(toks.contains("layout :: LogicalSize (0f32 , 20f32)"));
+
+        assert!(syn::parse_str::<Tree>("reserve(): self.a").is_err());
+        assert!(syn::parse_str::<Tree>("reserve(width = 1em): self.a").is_err());
+        assert!(syn::parse_str::<Tree>("reserve(width = 1, width = 2): self.a").is_err());
+    }
+
+    #[test]
+    fn grid_cell_bg() {
+        let tree: Tree =
+            syn::parse_str("grid: { 0..2, 1 (bg = Rgb::grey(0.9)): self.a; 0, 0: self.b }")
+                .unwrap();
+        let mut children = vec![];
+        let (ty_toks, _) = tree.storage_fields(&mut children).unwrap();
+        assert!(ty_toks
+            .to_string()
+            .contains(":: kas :: layout :: BackgroundStorage"));
+        let core: Member = syn::parse_str("core").unwrap();
+        let toks = tree.generate(&core).unwrap().to_string();
+        assert_eq!(toks.matches("layout :: Visitor :: background").count(), 1);
+
+        assert!(syn::parse_str::<Tree>("grid: { 0, 0 (fg = 1): self.a }").is_err());
+        assert!(syn::parse_str::<Tree>("grid(cols = 1): { self.a (bg = x): self.b }").is_err());
+    }
+    #[test]
+    fn optional_children() {
+        let mut tree: Tree =
+            syn::parse_str("row: [self.a, align(center): self.b, frame: self.c]").unwrap();
+        let optional: Vec<Member> =
+            vec![syn::parse_str("b").unwrap(), syn::parse_str("c").unwrap()];
+        tree.set_optional(&optional);
+        let core: Member = syn::parse_str("core").unwrap();
+        let toks = tree.generate(&core).unwrap().to_string();
+        assert_eq!(toks.matches("layout :: Visitor :: single").count(), 1);
+        assert_eq!(toks.matches("layout :: Visitor :: optional").count(), 2);
+    }
+}
@@ -0,0 +1,71 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE-APACHE file or at:
+//     https://www.apache.org/licenses/LICENSE-2.0
+
+use impl_tools_lib::autoimpl::{ImplArgs, ImplTrait, Result};
+use impl_tools_lib::SimplePath;
+use proc_macro2::TokenStream;
+use quote::{quote, TokenStreamExt};
+use syn::{Fields, Index, ItemStruct, Type};
+
+pub struct ImplPartialEq;
+impl ImplTrait for ImplPartialEq {
+    fn path(&self) -> SimplePath {
+        SimplePath::new(&["", "std", "cmp", "PartialEq"])
+    }
+
+    fn support_ignore(&self) -> bool {
+        true
+    }
+
+    fn support_using(&self) -> bool {
+        false
+    }
+
+    fn struct_items(&self, item: &ItemStruct, args: &ImplArgs) -> Result<TokenStream> {
+        let mut inner = quote! { true };
+        match &item.fields {
+            Fields::Named(fields) => {
+                for field in fields.named.iter() {
+                    let ident = field.ident.as_ref().unwrap();
+                    if !args.ignore_named(ident) && !is_core_type(&field.ty) {
+                        inner.append_all(quote! { && self.#ident == other.#ident });
+                    }
+                }
+            }
+            Fields::Unnamed(fields) => {
+                for (i, field) in fields.unnamed.iter().enumerate() {
+                    let index = Index::from(i);
+                    if !args.ignore_unnamed(&index) && !is_core_type(&field.ty) {
+                        inner.append_all(quote! { && self.#index == other.#index });
+                    }
+                }
+            }
+            Fields::Unit => (),
+        }
+
+        Ok(quote! {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                #inner
+            }
+        })
+    }
+}
+
+// True for the type of a widget's core field: `widget_core!()` or the type it
+// is replaced with by `#[widget]`
+fn is_core_type(ty: &Type) -> bool {
+    match ty {
+        Type::Macro(mac) => mac.mac.path.is_ident("widget_core"),
+        Type::Path(path) if path.qself.is_none() => match path.path.segments.last() {
+            Some(seg) => {
+                let name = seg.ident.to_string();
+                name == "CoreData" || (name.starts_with("Kas") && name.ends_with("GeneratedCore"))
+            }
+            None => false,
+        },
+        _ => false,
+    }
+}
@@ -12,6 +12,22 @@ use quote::{quote, TokenStreamExt};
 use syn::spanned::Spanned;
 use syn::{parse2, parse_quote, Error, Ident, ImplItem, Index, ItemImpl, Member, Result, Type};
 
+/// True if `ty` is (syntactically) `Option<T>`
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .map(|seg| {
+                seg.ident == "Option"
+                    && matches!(seg.arguments, syn::PathArguments::AngleBracketed(_))
+            })
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
 fn member(index: usize, ident: Option<Ident>) -> Member {
     match ident {
         None => Member::Unnamed(Index {
@@ -102,10 +118,9 @@ pub fn widget(mut args: WidgetArgs, scope: &mut Scope) -> Result<()> {
 
                     impl Clone for #core_type {
                         fn clone(&self) -> Self {
-                            #core_type {
-                                rect: self.rect,
-                                .. #core_type::default()
-                            }
+                            // Layout storage is only valid for the widget's
+                            // current rect; reset both together.
+                            #core_type::default()
                         }
                     }
                 });
@@ -127,7 +142,8 @@ pub fn widget(mut args: WidgetArgs, scope: &mut Scope) -> Result<()> {
                     return Err(Error::new(attr.tokens.span(), "unexpected token"));
                 }
                 let ident = member(i, field.ident.clone());
-                children.push(Child { ident });
+                let optional = is_option(&field.ty);
+                children.push(Child { ident, optional });
             } else {
                 other_attrs.push(attr);
             }
@@ -135,7 +151,7 @@ pub fn widget(mut args: WidgetArgs, scope: &mut Scope) -> Result<()> {
         field.attrs = other_attrs;
     }
 
-    crate::widget_index::visit_impls(&children, &mut scope.impls);
+    crate::widget_index::visit_impls(&children, layout_children.len(), &mut scope.impls);
 
     for (index, impl_) in scope.impls.iter().enumerate() {
         if let Some((_, ref path, _)) = impl_.trait_ {
@@ -288,6 +304,10 @@ pub fn widget(mut args: WidgetArgs, scope: &mut Scope) -> Result<()> {
                     ) {
                         self.#inner.draw(draw);
                     }
+                    #[inline]
+                    fn baseline(&mut self) -> Option<i32> {
+                        self.#inner.baseline()
+                    }
                 }
             });
         }
@@ -315,6 +335,13 @@ pub fn widget(mut args: WidgetArgs, scope: &mut Scope) -> Result<()> {
                     fn cursor_icon(&self) -> ::kas::event::CursorIcon {
                         self.#inner.cursor_icon()
                     }
+                    #[inline]
+                    fn cursor_icon_at(
+                        &self,
+                        coord: ::kas::geom::Coord,
+                    ) -> ::kas::event::CursorIcon {
+                        self.#inner.cursor_icon_at(coord)
+                    }
                 }
             });
             scope.generated.push(quote! {
@@ -340,6 +367,22 @@ pub fn widget(mut args: WidgetArgs, scope: &mut Scope) -> Result<()> {
                     #key_nav
                     #hover_highlight
                     #cursor_icon
+                    #[inline]
+                    fn tooltip(&self) -> Option<&str> {
+                        self.#inner.tooltip()
+                    }
+                    #[inline]
+                    fn tooltip_when_disabled(&self) -> bool {
+                        self.#inner.tooltip_when_disabled()
+                    }
+                    #[inline]
+                    fn accessible_role(&self) -> ::kas::AccessibleRole {
+                        self.#inner.accessible_role()
+                    }
+                    #[inline]
+                    fn accessible_label(&self) -> Option<String> {
+                        self.#inner.accessible_label()
+                    }
 
                     #[inline]
                     fn translation(&self) -> ::kas::geom::Offset {
@@ -388,6 +431,12 @@ pub fn widget(mut args: WidgetArgs, scope: &mut Scope) -> Result<()> {
                     ) {
                         self.#inner.handle_scroll(mgr, scroll);
                     }
+                    #[inline]
+                    fn as_save_state(
+                        &mut self,
+                    ) -> Option<&mut dyn ::kas::state::SaveState> {
+                        self.#inner.as_save_state()
+                    }
                 }
             });
         }
@@ -397,40 +446,73 @@ pub fn widget(mut args: WidgetArgs, scope: &mut Scope) -> Result<()> {
 
     let core = core_data.unwrap();
 
+    // Optional children are enumerated last (see widget_index::visit_impls)
+    let optional: Vec<_> = children
+        .iter()
+        .filter(|child| child.optional)
+        .map(|child| child.ident.clone())
+        .collect();
+
     if impl_widget_children {
-        let mut count = children.len();
+        let mut count = 0;
 
         let mut get_rules = quote! {};
         let mut get_mut_rules = quote! {};
-        for (i, child) in children.iter().enumerate() {
+        for child in children.iter().filter(|child| !child.optional) {
             let ident = &child.ident;
-            get_rules.append_all(quote! { #i => Some(&self.#ident), });
-            get_mut_rules.append_all(quote! { #i => Some(&mut self.#ident), });
+            get_rules.append_all(quote! { #count => Some(&self.#ident), });
+            get_mut_rules.append_all(quote! { #count => Some(&mut self.#ident), });
+            count += 1;
         }
-        for (i, path) in layout_children.iter().enumerate() {
-            let index = count + i;
-            get_rules.append_all(quote! { #index => Some(&self.#core.#path), });
-            get_mut_rules.append_all(quote! { #index => Some(&mut self.#core.#path), });
+        for path in layout_children.iter() {
+            get_rules.append_all(quote! { #count => Some(&self.#core.#path), });
+            get_mut_rules.append_all(quote! { #count => Some(&mut self.#core.#path), });
+            count += 1;
         }
-        count += layout_children.len();
+
+        let (get_opt, get_mut_opt) = if optional.is_empty() {
+            (quote! { None }, quote! { None })
+        } else {
+            let get_opt = quote! {
+                let mut _i = _index - #count;
+                #(if let Some(w) = self.#optional.as_ref() {
+                    if _i == 0 {
+                        return Some(w);
+                    }
+                    _i -= 1;
+                })*
+                None
+            };
+            let get_mut_opt = quote! {
+                let mut _i = _index - #count;
+                #(if let Some(w) = self.#optional.as_mut() {
+                    if _i == 0 {
+                        return Some(w);
+                    }
+                    _i -= 1;
+                })*
+                None
+            };
+            (get_opt, get_mut_opt)
+        };
 
         scope.generated.push(quote! {
             impl #impl_generics ::kas::WidgetChildren
                 for #name #ty_generics #where_clause
             {
                 fn num_children(&self) -> usize {
-                    #count
+                    #count #(+ (self.#optional.is_some() as usize))*
                 }
                 fn get_child(&self, _index: usize) -> Option<&dyn ::kas::Widget> {
                     match _index {
                         #get_rules
-                        _ => None
+                        _ => { #get_opt }
                     }
                 }
                 fn get_child_mut(&mut self, _index: usize) -> Option<&mut dyn ::kas::Widget> {
                     match _index {
                         #get_mut_rules
-                        _ => None
+                        _ => { #get_mut_opt }
                     }
                 }
             }
@@ -444,7 +526,9 @@ pub fn widget(mut args: WidgetArgs, scope: &mut Scope) -> Result<()> {
         self.rect().contains(coord).then(|| self.id())
     };
     let mut fn_draw = None;
-    if let Some(layout) = args.layout.take() {
+    let mut fn_baseline = None;
+    if let Some(mut layout) = args.layout.take() {
+        layout.set_optional(&optional);
         let layout = layout.generate(&core)?;
         scope.generated.push(quote! {
             impl #impl_generics ::kas::layout::AutoLayout
@@ -482,6 +566,11 @@ pub fn widget(mut args: WidgetArgs, scope: &mut Scope) -> Result<()> {
                     use ::kas::{WidgetCore, layout};
                     (#layout).draw(draw);
                 }
+
+                fn baseline(&mut self) -> Option<i32> {
+                    use ::kas::{WidgetCore, layout};
+                    (#layout).baseline()
+                }
             }
         });
 
@@ -505,6 +594,11 @@ pub fn widget(mut args: WidgetArgs, scope: &mut Scope) -> Result<()> {
                 <Self as ::kas::layout::AutoLayout>::draw(self, draw);
             }
         });
+        fn_baseline = Some(quote! {
+            fn baseline(&mut self) -> Option<i32> {
+                <Self as ::kas::layout::AutoLayout>::baseline(self)
+            }
+        });
     }
     let fn_set_rect = quote! {
         fn set_rect(
@@ -547,6 +641,11 @@ pub fn widget(mut args: WidgetArgs, scope: &mut Scope) -> Result<()> {
                 layout_impl.items.push(parse2(method)?);
             }
         }
+        if let Some(method) = fn_baseline {
+            if !has_method(layout_impl, "baseline") {
+                layout_impl.items.push(parse2(method)?);
+            }
+        }
     } else if let Some(fn_size_rules) = fn_size_rules {
         scope.generated.push(quote! {
             impl #impl_generics ::kas::Layout for #name #ty_generics #where_clause {
@@ -554,6 +653,7 @@ pub fn widget(mut args: WidgetArgs, scope: &mut Scope) -> Result<()> {
                 #fn_set_rect
                 #fn_find_id
                 #fn_draw
+                #fn_baseline
             }
         });
     }
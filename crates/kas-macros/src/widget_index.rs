@@ -25,6 +25,12 @@ impl Parse for BaseInput {
             if input.is_empty() {
                 return Ok(Self);
             }
+        } else if input.peek(Token![if]) {
+            // Okay: expanded internally for an optional child
+            let _ = input.parse::<syn::ExprIf>()?;
+            if input.is_empty() {
+                return Ok(Self);
+            }
         } else if input.peek(kw::error_emitted) {
             // An error was already emitted by the visitor
             let _ = input.parse::<kw::error_emitted>()?;
@@ -54,6 +60,7 @@ impl Parse for WidgetInput {
 
 struct Visitor<'a> {
     children: &'a [Child],
+    num_layout_children: usize,
 }
 impl<'a> VisitMut for Visitor<'a> {
     fn visit_macro_mut(&mut self, node: &mut syn::Macro) {
@@ -72,11 +79,32 @@ impl<'a> VisitMut for Visitor<'a> {
                 }
             };
 
-            for (i, child) in self.children.iter().enumerate() {
+            // Index of child: non-optional children come first (in order),
+            // then layout children, then optional children (where `Some`).
+            let mut index = 0;
+            for child in self.children.iter().filter(|child| !child.optional) {
                 if args.ident == child.ident {
-                    node.tokens = parse_quote! { #i };
+                    node.tokens = parse_quote! { #index };
+                    return;
+                }
+                index += 1;
+            }
+            index += self.num_layout_children;
+            let mut prev = vec![];
+            for child in self.children.iter().filter(|child| child.optional) {
+                let ident = &child.ident;
+                if args.ident == *ident {
+                    // The index is only valid while the child is `Some`
+                    node.tokens = parse_quote! {
+                        if self.#ident.is_some() {
+                            #index #(+ (self.#prev.is_some() as usize))*
+                        } else {
+                            usize::MAX
+                        }
+                    };
                     return;
                 }
+                prev.push(ident);
             }
 
             emit_error!(args.ident.span(), "does not match any child widget");
@@ -88,8 +116,11 @@ impl<'a> VisitMut for Visitor<'a> {
     }
 }
 
-pub fn visit_impls(children: &[Child], impls: &mut [syn::ItemImpl]) {
-    let mut obj = Visitor { children };
+pub fn visit_impls(children: &[Child], num_layout_children: usize, impls: &mut [syn::ItemImpl]) {
+    let mut obj = Visitor {
+        children,
+        num_layout_children,
+    };
 
     for impl_ in impls {
         obj.visit_item_impl_mut(impl_);
@@ -6,6 +6,7 @@
 //! Animation helpers
 
 use kas::draw::DrawImpl;
+use kas::geom::Coord;
 use kas::WidgetId;
 use std::marker::PhantomData;
 use std::time::{Duration, Instant};
@@ -37,7 +38,7 @@ impl<D> AnimState<D> {
             now,
             text_cursor: TextCursor {
                 widget: 0,
-                byte: 0,
+                key: 0,
                 state: false,
                 time: now,
             },
@@ -61,7 +62,7 @@ impl<D> AnimState<D> {
 #[derive(Clone, Copy, Debug)]
 struct TextCursor {
     widget: u64,
-    byte: usize,
+    key: u64,
     state: bool,
     time: Instant,
 }
@@ -70,8 +71,24 @@ impl<D: DrawImpl> AnimState<D> {
     ///
     /// Assumption: only one widget may draw a text cursor at any time.
     pub fn text_cursor(&mut self, draw: &mut D, id: &WidgetId, byte: usize) -> bool {
+        self.blink(draw, id, byte as u64)
+    }
+
+    /// Flashing caret: return true to draw
+    ///
+    /// As [`Self::text_cursor`], but keyed on position.
+    pub fn caret(&mut self, draw: &mut D, id: &WidgetId, pos: Coord) -> bool {
+        self.blink(
+            draw,
+            id,
+            (u64::from(pos.0 as u32) << 32) | u64::from(pos.1 as u32),
+        )
+    }
+
+    // Blink state is reset (to visible) whenever `key` changes
+    fn blink(&mut self, draw: &mut D, id: &WidgetId, key: u64) -> bool {
         let entry = &mut self.text_cursor;
-        if entry.widget == id.as_u64() && entry.byte == byte {
+        if entry.widget == id.as_u64() && entry.key == key {
             if entry.time < self.now {
                 entry.state = !entry.state;
                 entry.time += self.c.cursor_blink_rate;
@@ -80,7 +97,7 @@ impl<D: DrawImpl> AnimState<D> {
             entry.state
         } else {
             entry.widget = id.as_u64();
-            entry.byte = byte;
+            entry.key = key;
             entry.state = true;
             entry.time = self.now + self.c.cursor_blink_rate;
             draw.animate_at(entry.time);
@@ -6,6 +6,7 @@
 //! Theme configuration
 
 use crate::{ColorsSrgb, ThemeConfig};
+use kas::draw::{color::Rgba8Srgb, TextSpacing};
 use kas::text::fonts::{fonts, AddMode, FontSelector};
 use kas::theme::TextClass;
 use kas::TkAction;
@@ -41,6 +42,10 @@ pub struct Config {
     #[cfg_attr(feature = "config", serde(default))]
     fonts: BTreeMap<TextClass, FontSelector<'static>>,
 
+    /// Line height and letter spacing overrides, per text class
+    #[cfg_attr(feature = "config", serde(default))]
+    text_spacing: BTreeMap<TextClass, TextSpacing>,
+
     /// Text cursor blink rate: delay between switching states
     #[cfg_attr(feature = "config", serde(default = "defaults::cursor_blink_rate_ms"))]
     cursor_blink_rate_ms: u32,
@@ -52,6 +57,18 @@ pub struct Config {
     /// Text glyph rastering settings
     #[cfg_attr(feature = "config", serde(default))]
     raster: RasterConfig,
+
+    /// Width of the focus ring, in logical pixels (zero to disable)
+    #[cfg_attr(feature = "config", serde(default))]
+    focus_ring_width: f32,
+
+    /// Colour of the focus ring (default: the scheme's `nav_focus` colour)
+    #[cfg_attr(feature = "config", serde(default))]
+    focus_ring_color: Option<Rgba8Srgb>,
+
+    /// Draw an overlay over disabled widgets (default: false)
+    #[cfg_attr(feature = "config", serde(default))]
+    disabled_overlay: bool,
 }
 
 impl Default for Config {
@@ -63,9 +80,13 @@ impl Default for Config {
             color_schemes: defaults::color_schemes(),
             font_aliases: Default::default(),
             fonts: defaults::fonts(),
+            text_spacing: Default::default(),
             cursor_blink_rate_ms: defaults::cursor_blink_rate_ms(),
             transition_fade_ms: defaults::transition_fade_ms(),
             raster: Default::default(),
+            focus_ring_width: 0.0,
+            focus_ring_color: None,
+            disabled_overlay: false,
         }
     }
 }
@@ -168,6 +189,23 @@ impl Config {
         self.fonts.iter()
     }
 
+    /// Get the line height and letter spacing used for text of `class`
+    ///
+    /// These adjust glyph positions after text layout (see
+    /// [`kas::draw::GlyphSpacer`]). They are not applied to
+    /// [`TextClass::Edit`] or [`TextClass::LabelScroll`] since cursor
+    /// placement and selection use unadjusted positions.
+    #[inline]
+    pub fn text_spacing(&self, class: TextClass) -> TextSpacing {
+        self.text_spacing.get(&class).cloned().unwrap_or_default()
+    }
+
+    /// Get an iterator over line height and letter spacing overrides
+    #[inline]
+    pub fn iter_text_spacing(&self) -> impl Iterator<Item = (&TextClass, &TextSpacing)> {
+        self.text_spacing.iter()
+    }
+
     /// Get the cursor blink rate (delay)
     #[inline]
     pub fn cursor_blink_rate(&self) -> Duration {
@@ -179,6 +217,32 @@ impl Config {
     pub fn transition_fade_duration(&self) -> Duration {
         Duration::from_millis(self.transition_fade_ms as u64)
     }
+
+    /// Width of the focus ring drawn over the widget with navigation focus
+    ///
+    /// Units: logical pixels. Zero (the default) disables the focus ring.
+    #[inline]
+    pub fn focus_ring_width(&self) -> f32 {
+        self.focus_ring_width
+    }
+
+    /// Colour of the focus ring
+    ///
+    /// If `None`, the colour scheme's `nav_focus` colour is used.
+    #[inline]
+    pub fn focus_ring_color(&self) -> Option<Rgba8Srgb> {
+        self.focus_ring_color
+    }
+
+    /// Whether an overlay is drawn over disabled widgets
+    ///
+    /// Disabled widgets are always drawn using disabled colours; if enabled,
+    /// an overlay greys out the whole widget in addition (this includes
+    /// content such as images). Default: false.
+    #[inline]
+    pub fn disabled_overlay(&self) -> bool {
+        self.disabled_overlay
+    }
 }
 
 /// Setters
@@ -194,6 +258,34 @@ impl Config {
         self.dirty = true;
         self.active_scheme = scheme.to_string();
     }
+
+    /// Set line height and letter spacing for text of `class`
+    pub fn set_text_spacing(&mut self, class: TextClass, spacing: TextSpacing) {
+        self.dirty = true;
+        if spacing.is_default() {
+            self.text_spacing.remove(&class);
+        } else {
+            self.text_spacing.insert(class, spacing);
+        }
+    }
+
+    /// Set focus ring width (logical pixels; zero to disable)
+    pub fn set_focus_ring_width(&mut self, width: f32) {
+        self.dirty = true;
+        self.focus_ring_width = width;
+    }
+
+    /// Set focus ring colour (`None` to use the scheme's `nav_focus` colour)
+    pub fn set_focus_ring_color(&mut self, col: Option<Rgba8Srgb>) {
+        self.dirty = true;
+        self.focus_ring_color = col;
+    }
+
+    /// Enable or disable the overlay drawn over disabled widgets
+    pub fn set_disabled_overlay(&mut self, enable: bool) {
+        self.dirty = true;
+        self.disabled_overlay = enable;
+    }
 }
 
 /// Other functions
@@ -201,8 +293,14 @@ impl Config {
     /// Currently this is just "set". Later, maybe some type of merge.
     #[allow(clippy::float_cmp)]
     pub fn apply_config(&mut self, other: &Config) -> TkAction {
-        let action = if self.font_size != other.font_size {
+        let action = if self.font_size != other.font_size || self.text_spacing != other.text_spacing
+        {
             TkAction::RESIZE | TkAction::THEME_UPDATE
+        } else if self.focus_ring_width != other.focus_ring_width
+            || self.focus_ring_color != other.focus_ring_color
+            || self.disabled_overlay != other.disabled_overlay
+        {
+            TkAction::THEME_UPDATE
         } else if self != other {
             TkAction::REDRAW
         } else {
@@ -13,6 +13,7 @@ use std::rc::Rc;
 use crate::anim::AnimState;
 use kas::cast::traits::*;
 use kas::dir::Directional;
+use kas::draw::{color::Rgba, GlyphSpacer, TextSpacing};
 use kas::geom::{Size, Vec2};
 use kas::layout::{AxisInfo, FrameRules, Margins, SizeRules, Stretch};
 use kas::text::{fonts::FontId, Align, TextApi, TextApiExt};
@@ -132,6 +133,14 @@ pub struct Window<D> {
     pub dims: Dimensions,
     pub fonts: Rc<LinearMap<TextClass, FontId>>,
     pub anim: AnimState<D>,
+    /// Width of focus ring (pixels); zero disables
+    pub focus_ring_width: f32,
+    /// Colour of focus ring; if `None`, use `nav_focus`
+    pub focus_ring_col: Option<Rgba>,
+    /// Whether to draw an overlay over disabled widgets
+    pub disabled_overlay: bool,
+    /// Line height and letter spacing overrides
+    pub text_spacing: LinearMap<TextClass, TextSpacing>,
 }
 
 impl<D> Window<D> {
@@ -145,12 +154,83 @@ impl<D> Window<D> {
             dims: Dimensions::new(dims, config.font_size(), scale_factor),
             fonts,
             anim: AnimState::new(config),
+            focus_ring_width: config.focus_ring_width() * scale_factor,
+            focus_ring_col: config.focus_ring_color().map(|col| col.into()),
+            disabled_overlay: config.disabled_overlay(),
+            text_spacing: spacing_overrides(config),
         }
     }
 
     pub fn update(&mut self, dims: &Parameters, config: &crate::Config, scale_factor: f32) {
         self.dims = Dimensions::new(dims, config.font_size(), scale_factor);
+        self.focus_ring_width = config.focus_ring_width() * scale_factor;
+        self.focus_ring_col = config.focus_ring_color().map(|col| col.into());
+        self.disabled_overlay = config.disabled_overlay();
+        self.text_spacing = spacing_overrides(config);
     }
+
+    /// Line height and letter spacing used for text of `class`
+    pub fn text_spacing(&self, class: TextClass) -> TextSpacing {
+        self.text_spacing.get(&class).cloned().unwrap_or_default()
+    }
+
+    /// A [`GlyphSpacer`] for prepared `text` of `class`
+    ///
+    /// Returns `None` when text of this `class` is not adjusted.
+    pub fn glyph_spacer(&self, text: &dyn TextApi, class: TextClass) -> Option<GlyphSpacer> {
+        let spacing = self.text_spacing(class);
+        if spacing.is_default() {
+            return None;
+        }
+        let align = text.env().align.0;
+        Some(GlyphSpacer::for_text(spacing, align, text.display()))
+    }
+
+    // Re-layout `text` such that it fits its bounds after spacing adjustments
+    //
+    // Takes and returns the size required by text layout.
+    fn spaced_layout(
+        &self,
+        text: &mut dyn TextApi,
+        class: TextClass,
+        mut required: kas::text::Vec2,
+    ) -> kas::text::Vec2 {
+        let mut spacer = match self.glyph_spacer(text, class) {
+            Some(spacer) => spacer,
+            None => return required,
+        };
+
+        // Letter spacing widens lines, thus we reduce the width available to
+        // text layout. This may re-wrap lines, changing the width required,
+        // hence we iterate (but not indefinitely).
+        let bounds = text.env().bounds;
+        let mut reduction = 0.0;
+        for _ in 0..3 {
+            if !bounds.0.is_finite() || spacer.extra_width() <= reduction {
+                break;
+            }
+            reduction = spacer.extra_width();
+            let bounds = kas::text::Vec2(bounds.0 - reduction, bounds.1);
+            required = text.update_env(|env| env.set_bounds(bounds));
+            spacer = self.glyph_spacer(text, class).unwrap();
+        }
+        required.0 += spacer.extra_width();
+
+        // All lines but the first move by (line_height - 1) lines
+        let spacing = self.text_spacing(class);
+        let line_height: f32 = self.dims.line_height.cast();
+        required.1 += (spacing.line_height - 1.0) * (required.1 - line_height).max(0.0);
+        required
+    }
+}
+
+// Spacing overrides are not applied to text supporting selection
+fn spacing_overrides(config: &crate::Config) -> LinearMap<TextClass, TextSpacing> {
+    config
+        .iter_text_spacing()
+        .filter(|(class, _)| !matches!(class, TextClass::Edit(_) | TextClass::LabelScroll))
+        .map(|(class, spacing)| (*class, *spacing))
+        .collect()
 }
 
 impl<D: 'static> crate::Window for Window<D> {
@@ -180,7 +260,9 @@ impl<D: 'static> SizeHandle for Window<D> {
         let inner = self.dims.inner_margin.into();
         match style {
             FrameStyle::InnerMargin => FrameRules::new_sym(0, 0, 0),
-            FrameStyle::Frame => FrameRules::new_sym(self.dims.frame, 0, 0),
+            FrameStyle::Frame | FrameStyle::Inset | FrameStyle::Outset => {
+                FrameRules::new_sym(self.dims.frame, 0, 0)
+            }
             FrameStyle::Popup => FrameRules::new_sym(self.dims.popup_frame, 0, 0),
             FrameStyle::MenuEntry => FrameRules::new_sym(self.dims.menu_frame, inner, 0),
             FrameStyle::NavFocus => FrameRules::new_sym(self.dims.inner_margin.into(), 0, 0),
@@ -208,8 +290,13 @@ impl<D: 'static> SizeHandle for Window<D> {
         Margins::hv_splat(self.dims.text_margin)
     }
 
-    fn line_height(&self, _: TextClass) -> i32 {
-        self.dims.line_height
+    fn line_height(&self, class: TextClass) -> i32 {
+        let spacing = self.text_spacing(class);
+        if spacing.is_default() {
+            return self.dims.line_height;
+        }
+        let line_height: f32 = self.dims.line_height.cast();
+        i32::conv_ceil(line_height * spacing.line_height)
     }
 
     fn text_bound(&self, text: &mut dyn TextApi, class: TextClass, axis: AxisInfo) -> SizeRules {
@@ -248,6 +335,7 @@ impl<D: 'static> SizeHandle for Window<D> {
             env.set_align((Align::TL, Align::TL)); // force top-left alignment for sizing
             env.set_wrap(class.multi_line());
         });
+        let required = self.spaced_layout(text, class, required);
 
         if axis.is_horizontal() {
             let min = self.dims.min_line_length;
@@ -270,7 +358,7 @@ impl<D: 'static> SizeHandle for Window<D> {
         } else {
             let bound = i32::conv_ceil(required.1);
             let min = match class {
-                _ if class.single_line() => self.dims.line_height,
+                _ if class.single_line() => self.line_height(class),
                 TextClass::Label(true) | TextClass::AccelLabel(true) => bound,
                 TextClass::LabelScroll => bound.min(self.dims.line_height * 3),
                 TextClass::Edit(true) => self.dims.line_height * 3,
@@ -293,7 +381,7 @@ impl<D: 'static> SizeHandle for Window<D> {
         align: (Align, Align),
     ) -> Vec2 {
         // TODO(opt): we don't always need to do this work
-        text.update_env(|env| {
+        let required = text.update_env(|env| {
             if let Some(font_id) = self.fonts.get(&class).cloned() {
                 env.set_font_id(font_id);
             }
@@ -303,8 +391,8 @@ impl<D: 'static> SizeHandle for Window<D> {
             env.set_bounds(size.cast());
             env.set_align(align);
             env.set_wrap(class.multi_line());
-        })
-        .into()
+        });
+        self.spaced_layout(text, class, required).into()
     }
 
     fn checkbox(&self) -> Size {
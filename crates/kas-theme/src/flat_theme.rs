@@ -281,8 +281,7 @@ where
             } else {
                 Rgba::BLACK
             };
-            let mut col2 = col1;
-            col2.a = 0.0;
+            let col2 = col1.with_alpha(0.0);
             self.draw
                 .rounded_frame_2col(shadow_outer, inner, col1, col2);
         }
@@ -295,6 +294,32 @@ where
         inner
     }
 
+    // Outer bound of the drop shadow around `rect`
+    fn shadow_quad(&self, rect: Rect) -> Quad {
+        let mut shadow = Quad::conv(rect);
+        shadow.a += self.w.dims.shadow_a * SHADOW_POPUP;
+        shadow.b += self.w.dims.shadow_b * SHADOW_POPUP;
+        shadow
+    }
+
+    /// Draw the background colour over `rect` with opacity `amount`
+    ///
+    /// This uses a new pass, thus is drawn over all content of the current
+    /// pass (including text).
+    fn overlay_rect(&mut self, rect: Rect, amount: f32) {
+        let col = self.cols.background;
+        let col = col.with_alpha(amount.clamp(0.0, 1.0) * col.a);
+        let mut draw = self.draw.new_pass(rect, Offset::ZERO, PassType::Clip);
+        draw.rect(Quad::conv(rect), col);
+    }
+
+    fn draw_shadow(&mut self, rect: Rect) {
+        let outer = self.shadow_quad(rect);
+        let inner = Quad::conv(rect).shrink(self.w.dims.frame as f32);
+        self.draw
+            .rounded_frame_2col(outer, inner, Rgba::BLACK, Rgba::TRANSPARENT);
+    }
+
     fn edit_box(&mut self, id: &WidgetId, outer: Quad, bg: Background) {
         let state = InputState::new_except_depress(self.ev, id);
         let col_bg = self.cols.from_edit_bg(bg, state);
@@ -323,8 +348,7 @@ where
             let outer = Quad::from_coords(a + sa, b + sb);
             let inner = Quad::from_coords(a, b);
             let col1 = if self.cols.is_dark { col } else { Rgba::BLACK };
-            let mut col2 = col1;
-            col2.a = 0.0;
+            let col2 = col1.with_alpha(0.0);
             self.draw.rounded_frame_2col(outer, inner, col1, col2);
 
             self.draw.rounded_line(a, b, r, col);
@@ -351,24 +375,34 @@ where
         class: PassType,
         f: Box<dyn FnOnce(&mut dyn theme::DrawHandle) + 'b>,
     ) {
-        let mut shadow = Default::default();
         let mut outer_rect = inner_rect;
         if class == PassType::Overlay {
-            shadow = Quad::conv(inner_rect);
-            shadow.a += self.w.dims.shadow_a * SHADOW_POPUP;
-            shadow.b += self.w.dims.shadow_b * SHADOW_POPUP;
+            let shadow = self.shadow_quad(inner_rect);
             let a = Coord::conv_floor(shadow.a);
             let b = Coord::conv_ceil(shadow.b);
             outer_rect = Rect::new(a, (b - a).cast());
         }
-        let mut draw = self.draw.new_pass(outer_rect, offset, class);
+        let draw = self.draw.new_pass(outer_rect, offset, class);
 
+        let mut handle = DrawHandle {
+            draw,
+            ev: self.ev,
+            w: self.w,
+            cols: self.cols,
+        };
         if class == PassType::Overlay {
-            shadow += offset.cast();
-            let inner = Quad::conv(inner_rect + offset).shrink(self.w.dims.frame as f32);
-            draw.rounded_frame_2col(shadow, inner, Rgba::BLACK, Rgba::TRANSPARENT);
+            handle.draw_shadow(inner_rect + offset);
         }
+        f(&mut handle);
+    }
 
+    fn new_transform_pass<'b>(
+        &mut self,
+        scale: f32,
+        offset: Offset,
+        f: Box<dyn FnOnce(&mut dyn theme::DrawHandle) + 'b>,
+    ) {
+        let draw = self.draw.new_transform_pass(scale, offset);
         let mut handle = DrawHandle {
             draw,
             ev: self.ev,
@@ -386,7 +420,7 @@ where
         let outer = Quad::conv(rect);
         match style {
             FrameStyle::InnerMargin => (),
-            FrameStyle::Frame => {
+            FrameStyle::Frame | FrameStyle::Inset | FrameStyle::Outset => {
                 let inner = outer.shrink(self.w.dims.frame as f32);
                 self.draw
                     .rounded_frame(outer, inner, BG_SHRINK_FACTOR, self.cols.frame);
@@ -444,13 +478,42 @@ where
         self.draw.frame(outer, inner, col);
     }
 
-    fn text(&mut self, id: &WidgetId, pos: Coord, text: &TextDisplay, _: TextClass) {
+    fn focus_ring(&mut self, rect: Rect) {
+        let outer = Quad::conv(rect);
+        let width = self.w.focus_ring_width.min(0.5 * outer.size().min_comp());
+        if width > 0.0 {
+            let inner = outer.shrink(width);
+            let col = self.w.focus_ring_col.unwrap_or(self.cols.nav_focus);
+            self.draw.frame(outer, inner, col);
+        }
+    }
+
+    fn disabled_overlay(&mut self, rect: Rect) {
+        if self.w.disabled_overlay {
+            self.overlay_rect(rect, 0.5);
+        }
+    }
+
+    fn fade_overlay(&mut self, rect: Rect, amount: f32) {
+        self.overlay_rect(rect, amount);
+    }
+
+    fn shadow(&mut self, rect: Rect) {
+        self.draw_shadow(rect);
+    }
+
+    fn text(&mut self, id: &WidgetId, pos: Coord, text: &dyn TextApi, class: TextClass) {
         let col = if self.ev.is_disabled(id) {
             self.cols.text_disabled
         } else {
             self.cols.text
         };
-        self.draw.text(pos.cast(), text, col);
+        if let Some(spacer) = self.w.glyph_spacer(text, class) {
+            self.draw
+                .text_spaced(pos.cast(), text.display(), col, &[], spacer);
+        } else {
+            self.draw.text(pos.cast(), text.display(), col);
+        }
     }
 
     fn text_effects(&mut self, id: &WidgetId, pos: Coord, text: &dyn TextApi, class: TextClass) {
@@ -460,7 +523,15 @@ where
         } else {
             self.cols.text
         };
-        if class.is_accel() && !self.ev.show_accel_labels() {
+        if let Some(spacer) = self.w.glyph_spacer(text, class) {
+            let effects: &[Effect<()>] = if class.is_accel() && !self.ev.show_accel_labels() {
+                &[]
+            } else {
+                text.effect_tokens()
+            };
+            self.draw
+                .text_spaced(pos, text.display(), col, effects, spacer);
+        } else if class.is_accel() && !self.ev.show_accel_labels() {
             self.draw.text(pos, text.display(), col);
         } else {
             self.draw
@@ -556,6 +627,18 @@ where
         }
     }
 
+    fn caret(&mut self, id: &WidgetId, pos: Coord, height: i32) {
+        if self.ev.window_has_focus() && !self.w.anim.caret(self.draw.draw, id, pos) {
+            return;
+        }
+
+        let width = self.w.dims.mark_line;
+        let p1 = Vec2::conv(pos);
+        let p2 = p1 + Vec2(width, height.cast());
+        self.draw
+            .rect(Quad::from_coords(p1, p2), self.cols.nav_focus);
+    }
+
     fn checkbox(&mut self, id: &WidgetId, rect: Rect, checked: bool, last_change: Option<Instant>) {
         let anim_fade = 1.0 - self.w.anim.fade_bool(self.draw.draw, checked, last_change);
 
@@ -604,8 +687,7 @@ where
             b = b * mult;
             let shadow_outer = Quad::from_coords(a + outer.a, b + outer.b);
             let col1 = if self.cols.is_dark { col } else { Rgba::BLACK };
-            let mut col2 = col1;
-            col2.a = 0.0;
+            let col2 = col1.with_alpha(0.0);
             self.draw.circle_2col(shadow_outer, col1, col2);
         }
 
@@ -670,12 +752,36 @@ where
         }
     }
 
-    fn scrollbar(&mut self, id: &WidgetId, id2: &WidgetId, rect: Rect, h_rect: Rect, _: Direction) {
+    fn scrollbar(
+        &mut self,
+        id: &WidgetId,
+        id2: &WidgetId,
+        rect: Rect,
+        h_rect: Rect,
+        dir: Direction,
+    ) {
+        self.scrollbar_overlay(id, id2, rect, h_rect, dir, true, None);
+    }
+
+    fn scrollbar_overlay(
+        &mut self,
+        id: &WidgetId,
+        id2: &WidgetId,
+        rect: Rect,
+        h_rect: Rect,
+        _: Direction,
+        visible: bool,
+        last_change: Option<Instant>,
+    ) {
+        let alpha = self.w.anim.fade_bool(self.draw.draw, visible, last_change);
+        if alpha <= 0.0 {
+            return;
+        }
+
         // track
         let outer = Quad::conv(rect);
         let inner = outer.shrink(outer.size().min_comp() / 2.0);
-        let mut col = self.cols.frame;
-        col.a = 0.5; // HACK
+        let col = self.cols.frame.with_alpha(0.5 * alpha); // HACK
         self.draw.rounded_frame(outer, inner, 0.0, col);
 
         // handle
@@ -684,7 +790,8 @@ where
         let outer = outer.shrink(r);
         let inner = outer.shrink(3.0 * r);
         let state = InputState::new2(self.ev, id, id2);
-        let col = self.cols.accent_soft_state(state);
+        let mut col = self.cols.accent_soft_state(state);
+        col.a *= alpha;
         self.draw.rounded_frame(outer, inner, 0.0, col);
     }
 
@@ -747,8 +854,7 @@ where
             b = b * mult;
             let shadow_outer = Quad::from_coords(a + outer.a, b + outer.b);
             let col1 = if self.cols.is_dark { col } else { Rgba::BLACK };
-            let mut col2 = col1;
-            col2.a = 0.0;
+            let col2 = col1.with_alpha(0.0);
             self.draw.circle_2col(shadow_outer, col1, col2);
         }
 
@@ -775,4 +881,17 @@ where
         let rect = Quad::conv(rect);
         self.draw.image(id, rect);
     }
+
+    fn arc(&mut self, rect: Quad, start_angle: f32, end_angle: f32, inner_radius: f32, col: Rgba) {
+        self.draw
+            .arc(rect, start_angle, end_angle, inner_radius, col);
+    }
+
+    fn rounded_rect(&mut self, rect: Quad, radii: [f32; 4], col: Rgba) {
+        self.draw.rounded_rect(rect, radii, col);
+    }
+
+    fn path(&mut self, path: &PathBuilder, fill: Rgba) {
+        self.draw.path(path, fill);
+    }
 }
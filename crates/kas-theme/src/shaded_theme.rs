@@ -199,6 +199,21 @@ where
         }
     }
 
+    // Outer bound of the drop shadow around `rect`
+    fn shadow_quad(&self, rect: Rect) -> Quad {
+        let mut shadow = Quad::conv(rect);
+        shadow.a += self.w.dims.shadow_a;
+        shadow.b += self.w.dims.shadow_b;
+        shadow
+    }
+
+    fn draw_shadow(&mut self, rect: Rect) {
+        let outer = self.shadow_quad(rect);
+        let inner = Quad::conv(rect);
+        self.draw
+            .rounded_frame_2col(outer, inner, Rgba::BLACK, Rgba::TRANSPARENT);
+    }
+
     /// Draw an edit box with optional navigation highlight.
     /// Return the inner rect.
     fn draw_edit_box(&mut self, outer: Rect, bg_col: Rgba, nav_focus: bool) -> Quad {
@@ -258,24 +273,34 @@ where
         class: PassType,
         f: Box<dyn FnOnce(&mut dyn theme::DrawHandle) + 'b>,
     ) {
-        let mut shadow = Default::default();
         let mut outer_rect = inner_rect;
         if class == PassType::Overlay {
-            shadow = Quad::conv(inner_rect);
-            shadow.a += self.w.dims.shadow_a;
-            shadow.b += self.w.dims.shadow_b;
+            let shadow = self.shadow_quad(inner_rect);
             let a = Coord::conv_floor(shadow.a);
             let b = Coord::conv_ceil(shadow.b);
             outer_rect = Rect::new(a, (b - a).cast());
         }
-        let mut draw = self.draw.new_pass(outer_rect, offset, class);
+        let draw = self.draw.new_pass(outer_rect, offset, class);
 
+        let mut handle = DrawHandle {
+            draw,
+            ev: self.ev,
+            w: self.w,
+            cols: self.cols,
+        };
         if class == PassType::Overlay {
-            shadow += offset.cast();
-            let inner = Quad::conv(inner_rect + offset);
-            draw.rounded_frame_2col(shadow, inner, Rgba::BLACK, Rgba::TRANSPARENT);
+            handle.draw_shadow(inner_rect + offset);
         }
+        f(&mut handle);
+    }
 
+    fn new_transform_pass<'b>(
+        &mut self,
+        scale: f32,
+        offset: Offset,
+        f: Box<dyn FnOnce(&mut dyn theme::DrawHandle) + 'b>,
+    ) {
+        let draw = self.draw.new_transform_pass(scale, offset);
         let mut handle = DrawHandle {
             draw,
             ev: self.ev,
@@ -298,6 +323,16 @@ where
                 let col = self.cols.background;
                 self.draw.shaded_round_frame(outer, inner, norm, col);
             }
+            FrameStyle::Inset | FrameStyle::Outset => {
+                let outer = Quad::conv(rect);
+                let inner = outer.shrink(self.w.dims.frame as f32);
+                let norm = match style {
+                    FrameStyle::Inset => (-0.6, 0.0),
+                    _ => (0.0, 0.6),
+                };
+                let col = self.cols.background;
+                self.draw.shaded_round_frame(outer, inner, norm, col);
+            }
             FrameStyle::Popup => {
                 let outer = Quad::conv(rect);
                 self.draw.rect(outer, self.cols.background);
@@ -345,7 +380,23 @@ where
         self.as_flat().selection_box(rect);
     }
 
-    fn text(&mut self, id: &WidgetId, pos: Coord, text: &TextDisplay, class: TextClass) {
+    fn focus_ring(&mut self, rect: Rect) {
+        self.as_flat().focus_ring(rect);
+    }
+
+    fn disabled_overlay(&mut self, rect: Rect) {
+        self.as_flat().disabled_overlay(rect);
+    }
+
+    fn fade_overlay(&mut self, rect: Rect, amount: f32) {
+        self.as_flat().fade_overlay(rect, amount);
+    }
+
+    fn shadow(&mut self, rect: Rect) {
+        self.draw_shadow(rect);
+    }
+
+    fn text(&mut self, id: &WidgetId, pos: Coord, text: &dyn TextApi, class: TextClass) {
         self.as_flat().text(id, pos, text, class);
     }
 
@@ -376,6 +427,10 @@ where
         self.as_flat().text_cursor(id, pos, text, class, byte);
     }
 
+    fn caret(&mut self, id: &WidgetId, pos: Coord, height: i32) {
+        self.as_flat().caret(id, pos, height);
+    }
+
     fn checkbox(&mut self, id: &WidgetId, rect: Rect, checked: bool, last_change: Option<Instant>) {
         let state = InputState::new_all(self.ev, id);
         let anim_fade = 1.0 - self.w.anim.fade_bool(self.draw.draw, checked, last_change);
@@ -425,6 +480,21 @@ where
         self.draw_handle(h_rect, state);
     }
 
+    fn scrollbar_overlay(
+        &mut self,
+        id: &WidgetId,
+        id2: &WidgetId,
+        rect: Rect,
+        h_rect: Rect,
+        dir: Direction,
+        visible: bool,
+        last_change: Option<Instant>,
+    ) {
+        // Shading does not blend well over content; use the flat style
+        self.as_flat()
+            .scrollbar_overlay(id, id2, rect, h_rect, dir, visible, last_change);
+    }
+
     fn slider(&mut self, id: &WidgetId, id2: &WidgetId, rect: Rect, h_rect: Rect, dir: Direction) {
         // track
         let mut outer = Quad::conv(rect);
@@ -463,4 +533,17 @@ where
     fn image(&mut self, id: ImageId, rect: Rect) {
         self.as_flat().image(id, rect);
     }
+
+    fn arc(&mut self, rect: Quad, start_angle: f32, end_angle: f32, inner_radius: f32, col: Rgba) {
+        self.as_flat()
+            .arc(rect, start_angle, end_angle, inner_radius, col);
+    }
+
+    fn rounded_rect(&mut self, rect: Quad, radii: [f32; 4], col: Rgba) {
+        self.as_flat().rounded_rect(rect, radii, col);
+    }
+
+    fn path(&mut self, path: &PathBuilder, fill: Rgba) {
+        self.as_flat().path(path, fill);
+    }
 }
@@ -0,0 +1,278 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE-APACHE file or at:
+//     https://www.apache.org/licenses/LICENSE-2.0
+
+//! Blit pipeline
+//!
+//! Windows are rendered to a persistent [`Target`] texture, allowing rendering
+//! to be limited to a damaged region. This pipeline copies the target to the
+//! frame and clears the damaged region. Unlike other pipelines, output
+//! replaces (does not blend with) existing content.
+
+use super::ShaderManager;
+use kas::cast::Conv;
+use kas::geom::{Coord, Rect, Size, Vec2};
+use std::mem::size_of;
+use std::num::NonZeroU32;
+
+/// Screen and texture coordinates
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+struct Instance {
+    a: Vec2,
+    b: Vec2,
+    ta: Vec2,
+    tb: Vec2,
+}
+unsafe impl bytemuck::Zeroable for Instance {}
+unsafe impl bytemuck::Pod for Instance {}
+
+impl Instance {
+    /// Map the whole texture to `rect`
+    fn new(rect: Rect) -> Self {
+        Instance {
+            a: Vec2::conv(rect.pos),
+            b: Vec2::conv(rect.pos2()),
+            ta: Vec2::ZERO,
+            tb: Vec2::ONE,
+        }
+    }
+}
+
+/// A persistent render target
+pub struct Target {
+    pub view: wgpu::TextureView,
+    bg: wgpu::BindGroup,
+}
+
+/// A pipeline for copying a [`Target`] to the frame
+pub struct Pipeline {
+    bg_tex_layout: wgpu::BindGroupLayout,
+    render_pipeline: wgpu::RenderPipeline,
+    sampler: wgpu::Sampler,
+    instances: wgpu::Buffer,
+    clear_tex: wgpu::Texture,
+    clear_bg: wgpu::BindGroup,
+}
+
+impl Pipeline {
+    /// Construct
+    pub fn new(
+        device: &wgpu::Device,
+        shaders: &ShaderManager,
+        bgl_common: &wgpu::BindGroupLayout,
+    ) -> Self {
+        let bg_tex_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("blit texture bind group layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler {
+                        filtering: false,
+                        comparison: false,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("blit pipeline layout"),
+            bind_group_layouts: &[bgl_common, &bg_tex_layout],
+            push_constant_ranges: &[],
+        });
+
+        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("blit render pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shaders.vert_image,
+                entry_point: "main",
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: size_of::<Instance>() as wgpu::BufferAddress,
+                    step_mode: wgpu::VertexStepMode::Instance,
+                    attributes: &wgpu::vertex_attr_array![
+                        0 => Float32x2,
+                        1 => Float32x2,
+                        2 => Float32x2,
+                        3 => Float32x2,
+                    ],
+                }],
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Cw,
+                cull_mode: Some(wgpu::Face::Back), // not required
+                clamp_depth: false,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                conservative: false,
+            },
+            depth_stencil: None,
+            multisample: Default::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &shaders.frag_image,
+                entry_point: "main",
+                targets: &[wgpu::ColorTargetState {
+                    format: super::RENDER_TEX_FORMAT,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                }],
+            }),
+        });
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("blit sampler"),
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let instances = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("blit instances"),
+            size: (2 * size_of::<Instance>()) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        // A single texel of the clear colour, stored linearly
+        let clear_tex = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("blit clear colour"),
+            size: wgpu::Extent3d {
+                width: 1,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba32Float,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        });
+        let view = clear_tex.create_view(&wgpu::TextureViewDescriptor::default());
+        let clear_bg = Self::bind_group(device, &bg_tex_layout, &sampler, &view);
+
+        Pipeline {
+            bg_tex_layout,
+            render_pipeline,
+            sampler,
+            instances,
+            clear_tex,
+            clear_bg,
+        }
+    }
+
+    fn bind_group(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        sampler: &wgpu::Sampler,
+        view: &wgpu::TextureView,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("blit texture bind group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(sampler),
+                },
+            ],
+        })
+    }
+
+    /// Construct a render target with the given `size`
+    pub fn new_target(&self, device: &wgpu::Device, size: Size) -> Target {
+        let tex = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("window render target"),
+            size: wgpu::Extent3d {
+                width: u32::conv(size.0.max(1)),
+                height: u32::conv(size.1.max(1)),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: super::RENDER_TEX_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+        });
+        let view = tex.create_view(&wgpu::TextureViewDescriptor::default());
+        let bg = Self::bind_group(device, &self.bg_tex_layout, &self.sampler, &view);
+        Target { view, bg }
+    }
+
+    /// Prepare for a frame of `size` with `damage` cleared to `color`
+    pub fn prepare(&self, queue: &wgpu::Queue, size: Size, damage: Rect, color: wgpu::Color) {
+        let frame = Rect::new(Coord::ZERO, size);
+        let instances = [Instance::new(frame), Instance::new(damage)];
+        queue.write_buffer(&self.instances, 0, bytemuck::cast_slice(&instances));
+
+        let color = [
+            color.r as f32,
+            color.g as f32,
+            color.b as f32,
+            color.a as f32,
+        ];
+        queue.write_texture(
+            wgpu::ImageCopyTexture {
+                texture: &self.clear_tex,
+                mip_level: 0,
+                origin: wgpu::Origin3d { x: 0, y: 0, z: 0 },
+                aspect: wgpu::TextureAspect::All,
+            },
+            bytemuck::cast_slice(&color),
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: NonZeroU32::new(16), // 4 × f32
+                rows_per_image: NonZeroU32::new(1),
+            },
+            wgpu::Extent3d {
+                width: 1,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+        );
+    }
+
+    /// Clear the damaged region to the clear colour
+    ///
+    /// Uses values from the last call to [`Self::prepare`].
+    pub fn clear<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, bg_common: &'a wgpu::BindGroup) {
+        rpass.set_pipeline(&self.render_pipeline);
+        rpass.set_bind_group(0, bg_common, &[]);
+        rpass.set_bind_group(1, &self.clear_bg, &[]);
+        rpass.set_vertex_buffer(0, self.instances.slice(..));
+        rpass.draw(0..4, 1..2);
+    }
+
+    /// Copy `target` to the frame
+    ///
+    /// Uses values from the last call to [`Self::prepare`].
+    pub fn blit<'a>(
+        &'a self,
+        target: &'a Target,
+        rpass: &mut wgpu::RenderPass<'a>,
+        bg_common: &'a wgpu::BindGroup,
+    ) {
+        rpass.set_pipeline(&self.render_pipeline);
+        rpass.set_bind_group(0, bg_common, &[]);
+        rpass.set_bind_group(1, &target.bg, &[]);
+        rpass.set_vertex_buffer(0, self.instances.slice(..));
+        rpass.draw(0..4, 0..1);
+    }
+}
@@ -5,7 +5,9 @@
 
 //! Common pipeline parts
 
-use kas::cast::Conv;
+use kas::cast::{Cast, Conv};
+use kas::draw::PassId;
+use kas::geom::Vec2;
 use kas::macros::autoimpl;
 use std::mem::size_of;
 use std::num::NonZeroU64;
@@ -15,8 +17,11 @@ use std::ops::Range;
 #[derive(Clone, Debug)]
 struct PassData<V: bytemuck::Pod> {
     vertices: Vec<V>,
+    // Start vertex and depth of each run of vertices after the first (at depth 0)
+    depths: Vec<(u32, f32)>,
     count: u32,
     data_range: Range<u64>,
+    runs: Vec<(u32, f32)>,
 }
 
 /// Per-window state
@@ -45,6 +50,8 @@ impl<V: bytemuck::Pod> Window<V> {
             None => {
                 for pass in self.passes.iter_mut() {
                     pass.count = 0;
+                    pass.depths.clear();
+                    pass.runs.clear();
                 }
                 return;
             }
@@ -85,6 +92,8 @@ impl<V: bytemuck::Pod> Window<V> {
                     .copy_from_slice(bytemuck::cast_slice(&pass.vertices));
 
                 pass.vertices.clear();
+                std::mem::swap(&mut pass.runs, &mut pass.depths);
+                pass.depths.clear();
                 pass.count = len;
                 pass.data_range = byte_offset..byte_end;
                 byte_offset = byte_end;
@@ -113,14 +122,110 @@ impl<V: bytemuck::Pod> Window<V> {
         }
     }
 
+    /// True if any vertices of `pass` were written with non-zero depth
+    ///
+    /// This reflects the last call to [`Self::write_buffers`].
+    pub fn uses_depth(&self, pass: usize) -> bool {
+        self.passes
+            .get(pass)
+            .map(|pass| !pass.runs.is_empty())
+            .unwrap_or(false)
+    }
+
+    /// Enqueue render commands, setting depth via the viewport
+    ///
+    /// Shaders output depth 0, which the viewport maps to the depth of each
+    /// run of vertices. The viewport covers `size`, the size of the frame.
+    pub fn render_with_depth<'a>(
+        &'a self,
+        pass: usize,
+        rpass: &mut wgpu::RenderPass<'a>,
+        pipeline: &'a wgpu::RenderPipeline,
+        bg_common: &'a wgpu::BindGroup,
+        size: Vec2,
+    ) {
+        if let Some(buffer) = self.buffer.as_ref() {
+            if let Some(pass) = self.passes.get(pass) {
+                if pass.data_range.is_empty() {
+                    return;
+                }
+                rpass.set_pipeline(pipeline);
+                rpass.set_bind_group(0, bg_common, &[]);
+                rpass.set_vertex_buffer(0, buffer.slice(pass.data_range.clone()));
+
+                for (range, depth) in depth_runs(&pass.runs, pass.count) {
+                    rpass.set_viewport(0.0, 0.0, size.0, size.1, depth, depth);
+                    rpass.draw(range, 0..1);
+                }
+                rpass.set_viewport(0.0, 0.0, size.0, size.1, 0.0, 1.0);
+            }
+        }
+    }
+
     pub fn add_vertices(&mut self, pass: usize, slice: &[V]) {
+        self.add_vertices_with_depth(PassId::new(pass.cast()), slice);
+    }
+
+    /// Add vertices at the depth of `pass`
+    #[allow(clippy::float_cmp)]
+    pub fn add_vertices_with_depth(&mut self, pass: PassId, slice: &[V]) {
         debug_assert_eq!(slice.len() % 3, 0);
 
+        let (pass, depth) = (pass.pass(), pass.depth());
         if self.passes.len() <= pass {
             // We only need one more, but no harm in adding extra
             self.passes.resize(pass + 8, Default::default());
         }
 
-        self.passes[pass].vertices.extend_from_slice(slice);
+        let data = &mut self.passes[pass];
+        let last = data.depths.last().map(|run| run.1).unwrap_or(0.0);
+        if depth != last {
+            data.depths.push((u32::conv(data.vertices.len()), depth));
+        }
+        data.vertices.extend_from_slice(slice);
+    }
+}
+
+// Split `0..count` vertices into non-empty runs of equal depth, given the
+// start vertex and depth of each run after the first (at depth 0)
+fn depth_runs(runs: &[(u32, f32)], count: u32) -> impl Iterator<Item = (Range<u32>, f32)> + '_ {
+    let starts = std::iter::once((0, 0.0)).chain(runs.iter().cloned());
+    let ends = runs.iter().map(|run| run.0).chain(std::iter::once(count));
+    starts
+        .zip(ends)
+        .filter(|((start, _), end)| start < end)
+        .map(|((start, depth), end)| (start..end, depth))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn depth_transitions_are_recorded() {
+        let mut window = Window::<[f32; 2]>::default();
+        let tri = [[0.0; 2]; 3];
+        window.add_vertices(0, &tri);
+        let pass = PassId::new(0).with_depth(0.5);
+        window.add_vertices_with_depth(pass, &tri);
+        window.add_vertices_with_depth(pass, &tri);
+        window.add_vertices(0, &tri);
+        window.add_vertices(1, &tri);
+
+        assert_eq!(window.passes[0].depths, [(3, 0.5), (9, 0.0)]);
+        assert!(window.passes[1].depths.is_empty());
+    }
+
+    #[test]
+    fn depth_runs_cover_all_vertices() {
+        let runs = |runs: &[(u32, f32)], count| depth_runs(runs, count).collect::<Vec<_>>();
+        assert_eq!(runs(&[], 6), [(0..6, 0.0)]);
+        assert!(runs(&[], 0).is_empty());
+
+        let expected = [(0..3, 0.0), (3..9, 0.5), (9..12, 0.0)];
+        assert_eq!(runs(&[(3, 0.5), (9, 0.0)], 12), expected);
+
+        // Empty runs (e.g. at the start) are skipped
+        assert_eq!(runs(&[(0, 1.0), (6, 1.0)], 6), [(0..6, 1.0)]);
     }
 }
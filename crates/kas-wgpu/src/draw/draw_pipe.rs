@@ -10,9 +10,11 @@ use wgpu::util::DeviceExt;
 
 use super::*;
 use kas::cast::traits::*;
+use kas::dir::Direction;
 use kas::draw::color::Rgba;
 use kas::draw::*;
-use kas::geom::{Quad, Rect, Size, Vec2};
+use kas::geom::{Coord, Quad, Rect, Size, Vec2};
+use kas::layout::Margins;
 use kas::text::{Effect, TextDisplay};
 use kas_theme::DrawShadedImpl;
 
@@ -76,6 +78,7 @@ impl<C: CustomPipe> DrawPipe<C> {
         let shaded_round = shaded_round::Pipeline::new(&device, &shaders, &bgl_common);
         let flat_round = flat_round::Pipeline::new(&device, &shaders, &bgl_common);
         let round_2col = round_2col::Pipeline::new(&device, &shaders, &bgl_common);
+        let blit = blit::Pipeline::new(&device, &shaders, &bgl_common);
         let custom = custom.build(&device, &bgl_common, RENDER_TEX_FORMAT);
         let text = text_pipe::Pipeline::new(&device, &shaders, &bgl_common, raster_config);
 
@@ -92,6 +95,7 @@ impl<C: CustomPipe> DrawPipe<C> {
             shaded_round,
             flat_round,
             round_2col,
+            blit,
             custom,
             text,
         }
@@ -112,12 +116,16 @@ impl<C: CustomPipe> DrawPipe<C> {
             round_2col: Default::default(),
             custom,
             text: Default::default(),
+            depth: None,
+            target: None,
         }
     }
 
     /// Process window resize
     pub fn resize(&self, window: &mut DrawWindow<C::Window>, size: Size) {
-        window.clip_regions[0].0.size = size;
+        window.clip_regions[0].rect.size = size;
+        window.depth = None;
+        window.target = None;
 
         let vsize = Vec2::conv(size);
         let off = vsize * -0.5;
@@ -131,21 +139,23 @@ impl<C: CustomPipe> DrawPipe<C> {
     }
 
     /// Render batched draw instructions via `rpass`
+    ///
+    /// If `damage` is `Some(rect)`, only this region is re-rendered; all
+    /// other content is kept from the previous frame. Otherwise (or if there
+    /// is no previous frame), the whole window is rendered.
     pub fn render(
         &mut self,
         window: &mut DrawWindow<C::Window>,
         frame_view: &wgpu::TextureView,
         clear_color: wgpu::Color,
+        damage: Option<Rect>,
     ) {
         // Update all bind groups. We use a separate bind group for each clip
         // region and update on each render, although they don't always change.
         // NOTE: we could use push constants instead.
-        let mut scale = window.scale;
-        let base_offset = (scale[0], scale[1]);
+        let base_scale = window.scale;
         for (region, bg) in window.clip_regions.iter().zip(self.bg_common.iter()) {
-            let offset = Vec2::conv(region.1);
-            scale[0] = base_offset.0 - offset.0;
-            scale[1] = base_offset.1 - offset.1;
+            let scale = region.scale_uniform(base_scale);
             self.queue
                 .write_buffer(&bg.0, 0, bytemuck::cast_slice(&scale));
         }
@@ -155,9 +165,7 @@ impl<C: CustomPipe> DrawPipe<C> {
             let (bgl_common, light_norm_buf) = (&self.bgl_common, &self.light_norm_buf);
             self.bg_common
                 .extend(window.clip_regions[bg_len..].iter().map(|region| {
-                    let offset = Vec2::conv(region.1);
-                    scale[0] = base_offset.0 - offset.0;
-                    scale[1] = base_offset.1 - offset.1;
+                    let scale = region.scale_uniform(base_scale);
                     let scale_buf = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
                         label: Some("scale_buf"),
                         contents: bytemuck::cast_slice(&scale),
@@ -225,34 +233,153 @@ impl<C: CustomPipe> DrawPipe<C> {
             .text
             .write_buffers(&self.device, &mut self.staging_belt, &mut encoder);
 
+        let frame_size = window.clip_regions[0].rect.size;
+        let frame_rect = Rect::new(Coord::ZERO, frame_size);
+        let full_redraw = window.target.is_none() || damage.is_none();
+        if window.target.is_none() {
+            window.target = Some(self.blit.new_target(&self.device, frame_size));
+        }
+        let target = window.target.as_ref().unwrap();
+        // The region to re-render, if any
+        let damage = match damage {
+            Some(rect) if !full_redraw => rect.intersection(&frame_rect),
+            _ => Some(frame_rect),
+        };
+        self.blit.prepare(
+            &self.queue,
+            frame_size,
+            damage.unwrap_or(Rect::ZERO),
+            clear_color,
+        );
+
         let mut color_attachments = [wgpu::RenderPassColorAttachment {
-            view: frame_view,
+            view: &target.view,
             resolve_target: None,
             ops: wgpu::Operations {
-                load: wgpu::LoadOp::Clear(clear_color),
+                load: match full_redraw {
+                    true => wgpu::LoadOp::Clear(clear_color),
+                    false => wgpu::LoadOp::Load,
+                },
                 store: true,
             },
         }];
 
+        fn begin_pass<'a>(
+            encoder: &'a mut wgpu::CommandEncoder,
+            color_attachments: &'a [wgpu::RenderPassColorAttachment<'a>],
+            depth_stencil_attachment: Option<wgpu::RenderPassDepthStencilAttachment<'a>>,
+            rect: Rect,
+        ) -> wgpu::RenderPass<'a> {
+            let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("kas-wgpu render pass"),
+                color_attachments,
+                depth_stencil_attachment,
+            });
+            rpass.set_scissor_rect(
+                rect.pos.0.cast(),
+                rect.pos.1.cast(),
+                rect.size.0.cast(),
+                rect.size.1.cast(),
+            );
+            rpass
+        }
+
+        if let Some(rect) = damage.filter(|_| !full_redraw) {
+            // LoadOp::Clear would clear the whole target, thus we draw instead
+            let mut rpass = begin_pass(&mut encoder, &color_attachments, None, rect);
+            self.blit.clear(&mut rpass, &self.bg_common[0].1);
+        }
+
+        let uses_depth =
+            |pass| window.shaded_square.uses_depth(pass) || window.shaded_round.uses_depth(pass);
+        if window.depth.is_none() && (0..window.clip_regions.len()).any(uses_depth) {
+            let tex = self.device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("depth buffer"),
+                size: wgpu::Extent3d {
+                    width: frame_size.0.cast(),
+                    height: frame_size.1.cast(),
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: DEPTH_FORMAT,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            });
+            window.depth = Some(tex.create_view(&wgpu::TextureViewDescriptor::default()));
+        }
+        let viewport = Vec2::conv(frame_size);
+
         // We use a separate render pass for each clipped region.
-        for (pass, (rect, _)) in window.clip_regions.iter().enumerate() {
-            if rect.size.0 == 0 || rect.size.1 == 0 {
+        for (pass, ClipRegion { rect, .. }) in window.clip_regions.iter().enumerate() {
+            let rect = match damage.and_then(|damage| rect.intersection(&damage)) {
+                Some(rect) => rect,
+                None => continue,
+            };
+            let bg_common = &self.bg_common[pass].1;
+
+            if let Some(depth_view) = window.depth.as_ref().filter(|_| uses_depth(pass)) {
+                // Shaded pipelines use depth testing, thus require a render
+                // pass with a depth attachment, which other pipelines
+                // (including custom pipes) do not support. We use sub-passes
+                // while preserving the usual order of pipelines.
+                let depth_attachment = |load| wgpu::RenderPassDepthStencilAttachment {
+                    view: depth_view,
+                    depth_ops: Some(wgpu::Operations { load, store: true }),
+                    stencil_ops: None,
+                };
+
+                {
+                    let mut rpass = begin_pass(&mut encoder, &color_attachments, None, rect);
+                    self.round_2col
+                        .render(&window.round_2col, pass, &mut rpass, bg_common);
+                }
+                color_attachments[0].ops.load = wgpu::LoadOp::Load;
+                {
+                    let depth = Some(depth_attachment(wgpu::LoadOp::Clear(0.0)));
+                    let mut rpass = begin_pass(&mut encoder, &color_attachments, depth, rect);
+                    self.shaded_square.render_with_depth(
+                        &window.shaded_square,
+                        pass,
+                        &mut rpass,
+                        bg_common,
+                        viewport,
+                    );
+                }
+                {
+                    let mut rpass = begin_pass(&mut encoder, &color_attachments, None, rect);
+                    self.images
+                        .render(&window.images, pass, &mut rpass, bg_common);
+                }
+                {
+                    let depth = Some(depth_attachment(wgpu::LoadOp::Load));
+                    let mut rpass = begin_pass(&mut encoder, &color_attachments, depth, rect);
+                    self.shaded_round.render_with_depth(
+                        &window.shaded_round,
+                        pass,
+                        &mut rpass,
+                        bg_common,
+                        viewport,
+                    );
+                }
+                {
+                    let mut rpass = begin_pass(&mut encoder, &color_attachments, None, rect);
+                    self.flat_round
+                        .render(&window.flat_round, pass, &mut rpass, bg_common);
+                    self.custom.render_pass(
+                        &mut window.custom,
+                        &self.device,
+                        pass,
+                        &mut rpass,
+                        bg_common,
+                    );
+                    self.text.render(&window.text, pass, &mut rpass, bg_common);
+                }
                 continue;
             }
-            let bg_common = &self.bg_common[pass].1;
 
             {
-                let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                    label: Some("kas-wgpu render pass"),
-                    color_attachments: &color_attachments,
-                    depth_stencil_attachment: None,
-                });
-                rpass.set_scissor_rect(
-                    rect.pos.0.cast(),
-                    rect.pos.1.cast(),
-                    rect.size.0.cast(),
-                    rect.size.1.cast(),
-                );
+                let mut rpass = begin_pass(&mut encoder, &color_attachments, None, rect);
 
                 self.round_2col
                     .render(&window.round_2col, pass, &mut rpass, bg_common);
@@ -277,16 +404,27 @@ impl<C: CustomPipe> DrawPipe<C> {
             color_attachments[0].ops.load = wgpu::LoadOp::Load;
         }
 
-        let size = window.clip_regions[0].0.size;
-
         self.custom.render_final(
             &mut window.custom,
             &self.device,
             &mut encoder,
-            frame_view,
-            size,
+            &target.view,
+            frame_size,
         );
 
+        if frame_size.0 > 0 && frame_size.1 > 0 {
+            let color_attachments = [wgpu::RenderPassColorAttachment {
+                view: frame_view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(clear_color),
+                    store: true,
+                },
+            }];
+            let mut rpass = begin_pass(&mut encoder, &color_attachments, None, frame_rect);
+            self.blit.blit(target, &mut rpass, &self.bg_common[0].1);
+        }
+
         // Keep only first clip region (which is the entire window)
         window.clip_regions.truncate(1);
 
@@ -333,6 +471,35 @@ impl<C: CustomPipe> DrawSharedImpl for DrawPipe<C> {
         };
     }
 
+    fn draw_image_nine_patch(
+        &self,
+        draw: &mut Self::Draw,
+        pass: PassId,
+        id: ImageId,
+        rect: Quad,
+        insets: Margins,
+        scale: f32,
+    ) {
+        if let Some((atlas, tex)) = self.images.get_im_atlas_coords(id) {
+            let size = self.images.image_size(id).unwrap();
+            draw.images
+                .nine_patch(pass, atlas, tex, size, rect, insets, scale);
+        };
+    }
+
+    fn draw_image_tiled(
+        &self,
+        draw: &mut Self::Draw,
+        pass: PassId,
+        id: ImageId,
+        rect: Quad,
+        tile: Vec2,
+    ) {
+        if let Some((atlas, tex)) = self.images.get_im_atlas_coords(id) {
+            draw.images.tiled(pass, atlas, tex, rect, tile);
+        };
+    }
+
     #[inline]
     fn draw_text(
         &mut self,
@@ -342,7 +509,27 @@ impl<C: CustomPipe> DrawSharedImpl for DrawPipe<C> {
         text: &TextDisplay,
         col: Rgba,
     ) {
-        draw.text.text(&mut self.text, pass, pos, text, col);
+        let scale = draw.clip_regions[pass.pass()].scale;
+        draw.text.text(&mut self.text, pass, pos, text, col, scale);
+    }
+
+    fn draw_text_spaced(
+        &mut self,
+        draw: &mut Self::Draw,
+        pass: PassId,
+        pos: Vec2,
+        text: &TextDisplay,
+        col: Rgba,
+        effects: &[Effect<()>],
+        spacer: GlyphSpacer,
+    ) {
+        let scale = draw.clip_regions[pass.pass()].scale;
+        let rects =
+            draw.text
+                .text_spaced(&mut self.text, pass, pos, text, col, effects, spacer, scale);
+        for rect in rects {
+            draw.shaded_square.rect(pass, rect, col);
+        }
     }
 
     fn draw_text_col_effects(
@@ -354,9 +541,10 @@ impl<C: CustomPipe> DrawSharedImpl for DrawPipe<C> {
         col: Rgba,
         effects: &[Effect<()>],
     ) {
-        let rects = draw
-            .text
-            .text_col_effects(&mut self.text, pass, pos, text, col, effects);
+        let scale = draw.clip_regions[pass.pass()].scale;
+        let rects =
+            draw.text
+                .text_col_effects(&mut self.text, pass, pos, text, col, effects, scale);
         for rect in rects {
             draw.shaded_square.rect(pass, rect, col);
         }
@@ -370,9 +558,10 @@ impl<C: CustomPipe> DrawSharedImpl for DrawPipe<C> {
         text: &TextDisplay,
         effects: &[Effect<Rgba>],
     ) {
+        let scale = draw.clip_regions[pass.pass()].scale;
         let rects = draw
             .text
-            .text_effects(&mut self.text, pass, pos, text, effects);
+            .text_effects(&mut self.text, pass, pos, text, effects, scale);
         for (rect, col) in rects {
             draw.shaded_square.rect(pass, rect, col);
         }
@@ -395,18 +584,22 @@ impl<CW: CustomWindow> DrawImpl for DrawWindow<CW> {
             PassType::Clip => &self.clip_regions[parent_pass.pass()],
             PassType::Overlay => &self.clip_regions[0],
         };
-        let rect = rect - parent.1;
-        let offset = offset + parent.1;
-        let rect = rect.intersection(&parent.0).unwrap_or(Rect::ZERO);
+        let region = parent.clip(rect, offset);
         let pass = self.clip_regions.len().cast();
-        self.clip_regions.push((rect, offset));
+        self.clip_regions.push(region);
+        PassId::new(pass)
+    }
+
+    fn new_transform_pass(&mut self, parent_pass: PassId, scale: f32, offset: Offset) -> PassId {
+        let region = self.clip_regions[parent_pass.pass()].transform(scale, offset);
+        let pass = self.clip_regions.len().cast();
+        self.clip_regions.push(region);
         PassId::new(pass)
     }
 
     #[inline]
     fn get_clip_rect(&self, pass: PassId) -> Rect {
-        let region = &self.clip_regions[pass.pass()];
-        region.0 + region.1
+        self.clip_regions[pass.pass()].clip_rect()
     }
 
     #[inline]
@@ -418,6 +611,11 @@ impl<CW: CustomWindow> DrawImpl for DrawWindow<CW> {
     fn frame(&mut self, pass: PassId, outer: Quad, inner: Quad, col: Rgba) {
         self.shaded_square.frame(pass, outer, inner, col);
     }
+
+    #[inline]
+    fn rect_gradient(&mut self, pass: PassId, rect: Quad, from: Rgba, to: Rgba, dir: Direction) {
+        self.shaded_square.gradient_rect(pass, rect, from, to, dir);
+    }
 }
 
 impl<CW: CustomWindow> DrawRoundedImpl for DrawWindow<CW> {
@@ -431,6 +629,20 @@ impl<CW: CustomWindow> DrawRoundedImpl for DrawWindow<CW> {
         self.flat_round.circle(pass, rect, inner_radius, col);
     }
 
+    #[inline]
+    fn arc(
+        &mut self,
+        pass: PassId,
+        rect: Quad,
+        start_angle: f32,
+        end_angle: f32,
+        inner_radius: f32,
+        col: Rgba,
+    ) {
+        self.flat_round
+            .arc(pass, rect, start_angle, end_angle, inner_radius, col);
+    }
+
     #[inline]
     fn circle_2col(&mut self, pass: PassId, rect: Quad, col1: Rgba, col2: Rgba) {
         self.round_2col.circle(pass, rect, col1, col2);
@@ -445,6 +657,16 @@ impl<CW: CustomWindow> DrawRoundedImpl for DrawWindow<CW> {
     fn rounded_frame_2col(&mut self, pass: PassId, outer: Quad, inner: Quad, c1: Rgba, c2: Rgba) {
         self.round_2col.frame(pass, outer, inner, c1, c2);
     }
+
+    #[inline]
+    fn rounded_rect(&mut self, pass: PassId, rect: Quad, radii: [f32; 4], col: Rgba) {
+        self.flat_round.rounded_rect(pass, rect, radii, col);
+    }
+
+    #[inline]
+    fn path(&mut self, pass: PassId, path: &PathBuilder, col: Rgba) {
+        self.flat_round.path(pass, path, col);
+    }
 }
 
 impl<CW: CustomWindow> DrawShadedImpl for DrawWindow<CW> {
@@ -7,7 +7,7 @@
 
 use super::common;
 use crate::draw::ShaderManager;
-use kas::draw::{color::Rgba, PassId};
+use kas::draw::{color::Rgba, PassId, PathBuilder};
 use kas::geom::{Quad, Vec2};
 use std::mem::size_of;
 
@@ -214,6 +214,157 @@ impl Window {
         ]);
     }
 
+    /// Bounds on input: `0 ≤ inner_radius ≤ 1`.
+    ///
+    /// The arc is tessellated as a fan of triangles about the centre. Outer
+    /// vertices are placed slightly outside the circle such that each triangle
+    /// covers its whole segment; the shader clips to the circle.
+    pub fn arc(
+        &mut self,
+        pass: PassId,
+        rect: Quad,
+        start_angle: f32,
+        end_angle: f32,
+        inner_radius: f32,
+        col: Rgba,
+    ) {
+        use std::f32::consts::{FRAC_PI_4, TAU};
+
+        let (mut start, mut end) = (start_angle, end_angle);
+        if !(start.is_finite() && end.is_finite()) {
+            return;
+        }
+        if end < start {
+            std::mem::swap(&mut start, &mut end);
+        }
+        let sweep = end - start;
+        if sweep >= TAU {
+            return self.circle(pass, rect, inner_radius, col);
+        }
+
+        let aa = rect.a;
+        let bb = rect.b;
+
+        if !aa.lt(bb) || col.a == 0.0 || sweep <= 0.0 {
+            // zero / negative size, transparent or empty: nothing to draw
+            return;
+        }
+
+        let inner = inner_radius.clamp(0.0, 1.0);
+        let inner = inner * inner; // shader compares to square
+
+        let mid = (aa + bb) * 0.5;
+        let half = bb - mid;
+
+        // Segments of at most 45°; for each, vertices at radius 1/cos(θ/2)
+        // give a triangle enclosing the arc.
+        let n = (sweep / FRAC_PI_4).ceil().max(1.0);
+        let step = sweep / n;
+        let k = 1.0 / (0.5 * step).cos();
+
+        // Since we take the mid-point, all offsets are uniform
+        let p = Vec2::ONE / half * AA_OFFSET;
+        let m = Vertex::new2(mid, col, inner, Vec2::splat(0.0), p);
+        let vertex = |angle: f32| {
+            let n = Vec2(angle.cos(), angle.sin()) * k;
+            Vertex::new2(mid + half * n, col, inner, n, p)
+        };
+
+        let n = n as u32;
+        let mut vertices = Vec::with_capacity(3 * n as usize);
+        let mut v0 = vertex(start);
+        for i in 1..=n {
+            let angle = if i == n { end } else { start + step * i as f32 };
+            let v1 = vertex(angle);
+            // Same winding as `circle`: higher angle, centre, lower angle
+            vertices.extend_from_slice(&[v1, m, v0]);
+            v0 = v1;
+        }
+        self.add_vertices(pass.pass(), &vertices);
+    }
+
+    /// Radii are ordered: top-left, top-right, bottom-right, bottom-left.
+    /// Each is clamped to `0 ≤ r ≤ min_comp(size) / 2`.
+    ///
+    /// Each non-zero corner is drawn as a quarter-circle; the remainder is a
+    /// fan about the mid-point with zero normals (thus drawn without clipping).
+    pub fn rounded_rect(&mut self, pass: PassId, rect: Quad, radii: [f32; 4], col: Rgba) {
+        let aa = rect.a;
+        let bb = rect.b;
+
+        if !aa.lt(bb) || col.a == 0.0 {
+            // zero / negative size or transparent: nothing to draw
+            return;
+        }
+
+        // Note: max(NaN, 0) = 0
+        let half = 0.5 * (bb - aa).min_comp();
+        let r = radii.map(|r| r.max(0.0).min(half));
+
+        let ab = Vec2(aa.0, bb.1);
+        let ba = Vec2(bb.0, aa.1);
+        let mid = (aa + bb) * 0.5;
+
+        // For each corner (clockwise from top-left): corner point, normal,
+        // centre of the quarter-circle, then edge points in clockwise order
+        let corners = [
+            (aa, Vec2(-1.0, -1.0), aa + Vec2::splat(r[0])),
+            (ba, Vec2(1.0, -1.0), ba + Vec2(-r[1], r[1])),
+            (bb, Vec2(1.0, 1.0), bb - Vec2::splat(r[2])),
+            (ab, Vec2(-1.0, 1.0), ab + Vec2(r[3], -r[3])),
+        ];
+
+        let n0 = Vec2::splat(0.0);
+        let m = Vertex::new2(mid, col, 0.0, n0, n0);
+        let mut vertices = Vec::with_capacity(3 * 20);
+        let mut outline = Vec::with_capacity(12);
+        for (i, (q, n, c)) in corners.iter().cloned().enumerate() {
+            // Edge points in clockwise order: the first lies on the edge
+            // arriving at the corner, the second on the edge leaving it
+            let h = (Vec2(q.0, c.1), Vec2(n.0, 0.0));
+            let v = (Vec2(c.0, q.1), Vec2(0.0, n.1));
+            let ((e1, n1), (e2, n2)) = if i % 2 == 0 { (h, v) } else { (v, h) };
+            outline.extend_from_slice(&[e1, c, e2]);
+
+            if r[i] > 0.0 {
+                let p = Vec2::splat(AA_OFFSET / r[i]);
+                let q = Vertex::new2(q, col, 0.0, n, p);
+                let c = Vertex::new2(c, col, 0.0, n0, p);
+                let e1 = Vertex::new2(e1, col, 0.0, n1, p);
+                let e2 = Vertex::new2(e2, col, 0.0, n2, p);
+                vertices.extend_from_slice(&[q, e2, c, q, c, e1]);
+            }
+        }
+
+        // The remaining area is star-shaped about the mid-point
+        for i in 0..outline.len() {
+            let v0 = Vertex::new2(outline[i], col, 0.0, n0, n0);
+            let v1 = Vertex::new2(outline[(i + 1) % outline.len()], col, 0.0, n0, n0);
+            vertices.extend_from_slice(&[m, v0, v1]);
+        }
+
+        self.add_vertices(pass.pass(), &vertices);
+    }
+
+    /// Fill a path
+    ///
+    /// Triangles from [`PathBuilder::tessellate`] are drawn with a zero normal
+    /// (the centre of the unit circle), thus are filled without clipping.
+    pub fn path(&mut self, pass: PassId, path: &PathBuilder, col: Rgba) {
+        if col.a == 0.0 {
+            return;
+        }
+
+        let n0 = Vec2::splat(0.0);
+        let vertices: Vec<_> = path
+            .tessellate()
+            .iter()
+            .flatten()
+            .map(|v| Vertex::new2(*v, col, 0.0, n0, n0))
+            .collect();
+        self.add_vertices(pass.pass(), &vertices);
+    }
+
     /// Bounds on input: `aa < cc < dd < bb`, `0 ≤ inner_radius ≤ 1`.
     pub fn rounded_frame(
         &mut self,
@@ -14,6 +14,7 @@ use super::{atlases, ShaderManager};
 use kas::cast::Conv;
 use kas::draw::{AllocError, ImageFormat, ImageId, PassId};
 use kas::geom::{Quad, Vec2};
+use kas::layout::Margins;
 
 #[derive(Debug)]
 struct Image {
@@ -206,6 +207,9 @@ impl Images {
     }
 }
 
+/// Maximum number of instances used to draw one tiled image
+const MAX_TILES: f32 = 4096.0;
+
 #[derive(Debug, Default)]
 pub struct Window {
     atlas: atlases::Window<Instance>,
@@ -237,4 +241,98 @@ impl Window {
         };
         self.atlas.rect(pass, atlas, instance);
     }
+
+    /// Add a nine-patch to the buffer
+    ///
+    /// `size` is the image size in pixels; `insets` are in image pixels.
+    #[allow(clippy::too_many_arguments)]
+    pub fn nine_patch(
+        &mut self,
+        pass: PassId,
+        atlas: u32,
+        tex: Quad,
+        size: (u32, u32),
+        rect: Quad,
+        insets: Margins,
+        scale: f32,
+    ) {
+        // Calculate (dest, tex) coordinates of the four edges of each patch
+        // column/row: start, end of first inset, start of last inset, end.
+        let axis = |a: f32, b: f32, ta: f32, tb: f32, len: u32, m: (u16, u16)| {
+            let len = f32::conv(len.max(1));
+            let m0 = f32::from(m.0).min(len);
+            let m1 = f32::from(m.1).min(len - m0);
+            let (t0, t1) = (ta + (tb - ta) * m0 / len, tb - (tb - ta) * m1 / len);
+            let (mut d0, mut d1) = (m0 * scale, m1 * scale);
+            let avail = (b - a).max(0.0);
+            if d0 + d1 > avail {
+                let f = avail / (d0 + d1);
+                d0 *= f;
+                d1 *= f;
+            }
+            ([a, a + d0, b - d1, b], [ta, t0, t1, tb])
+        };
+        let (x, tx) = axis(rect.a.0, rect.b.0, tex.a.0, tex.b.0, size.0, insets.horiz);
+        let (y, ty) = axis(rect.a.1, rect.b.1, tex.a.1, tex.b.1, size.1, insets.vert);
+
+        for j in 0..3 {
+            for i in 0..3 {
+                let rect = Quad::from_coords(Vec2(x[i], y[j]), Vec2(x[i + 1], y[j + 1]));
+                let tex = Quad::from_coords(Vec2(tx[i], ty[j]), Vec2(tx[i + 1], ty[j + 1]));
+                // This skips empty patches
+                self.rect(pass, atlas, tex, rect);
+            }
+        }
+    }
+
+    /// Add a tiled image to the buffer
+    ///
+    /// Since images are stored in an atlas, texture coordinates cannot wrap;
+    /// instead one instance is added per tile. Tiles at the far edges are
+    /// clipped by scaling their texture coordinates.
+    ///
+    /// At most `MAX_TILES` instances are added: where more tiles would be
+    /// required, tiles are enlarged (preserving aspect ratio).
+    pub fn tiled(&mut self, pass: PassId, atlas: u32, tex: Quad, rect: Quad, mut tile: Vec2) {
+        if !rect.a.lt(rect.b) {
+            return;
+        }
+        if !(tile.0 > 0.0 && tile.1 > 0.0) {
+            self.rect(pass, atlas, tex, rect);
+            return;
+        }
+
+        let size = rect.b - rect.a;
+        let count = |tile: Vec2| (size.0 / tile.0).ceil() * (size.1 / tile.1).ceil();
+        let n = count(tile);
+        if n > MAX_TILES {
+            tile = tile * (n / MAX_TILES).sqrt();
+            // Rounding up at edges may still exceed the limit
+            while count(tile) > MAX_TILES {
+                tile = tile * 1.125;
+            }
+        }
+
+        // Calculate (dest, tex) coordinate pairs of each tile along an axis
+        let axis = |a: f32, b: f32, ta: f32, tb: f32, len: f32| {
+            let mut v = vec![];
+            let mut p = a;
+            while p < b {
+                let q = (p + len).min(b);
+                v.push(((p, q), (ta, ta + (tb - ta) * (q - p) / len)));
+                p += len;
+            }
+            v
+        };
+        let xs = axis(rect.a.0, rect.b.0, tex.a.0, tex.b.0, tile.0);
+        let ys = axis(rect.a.1, rect.b.1, tex.a.1, tex.b.1, tile.1);
+
+        for ((y0, y1), (ty0, ty1)) in ys.iter().cloned() {
+            for ((x0, x1), (tx0, tx1)) in xs.iter().cloned() {
+                let rect = Quad::from_coords(Vec2(x0, y0), Vec2(x1, y1));
+                let tex = Quad::from_coords(Vec2(tx0, ty0), Vec2(tx1, ty1));
+                self.rect(pass, atlas, tex, rect);
+            }
+        }
+    }
 }
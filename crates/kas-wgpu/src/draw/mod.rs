@@ -8,6 +8,7 @@
 //! Extensions to the API of [`kas::draw`], plus some utility types.
 
 mod atlases;
+mod blit;
 mod common;
 mod custom;
 mod draw_pipe;
@@ -19,8 +20,9 @@ mod shaded_square;
 mod shaders;
 mod text_pipe;
 
+use kas::cast::{Cast, Conv, ConvFloat};
 use kas::draw::AnimationState;
-use kas::geom::{Offset, Rect};
+use kas::geom::{Coord, Offset, Rect, Vec2};
 use shaders::ShaderManager;
 use wgpu::TextureFormat;
 
@@ -32,8 +34,95 @@ pub use custom::{CustomPipe, CustomPipeBuilder, CustomWindow, DrawCustom};
 /// and let the graphics pipeline handle colour conversions.
 pub(crate) const RENDER_TEX_FORMAT: TextureFormat = TextureFormat::Bgra8UnormSrgb;
 
+/// Depth buffer format
+///
+/// The depth buffer is used only by shaded pipelines, and only in passes where
+/// some draw operation has non-zero depth (see [`kas::draw::PassId::with_depth`]).
+const DEPTH_FORMAT: TextureFormat = TextureFormat::Depth32Float;
+
+/// Depth-stencil state of shaded pipelines: greater depth is in front
+///
+/// Equal depth passes the test, thus draw order is otherwise preserved.
+///
+/// Limitation: these pipelines also use alpha blending, and every fragment
+/// not discarded writes depth, including partially-transparent anti-aliased
+/// edges. Where such an edge overlaps content of lesser depth drawn *later*,
+/// that content is occluded instead of showing through the edge. Content
+/// drawn earlier blends correctly.
+fn depth_stencil_state() -> wgpu::DepthStencilState {
+    wgpu::DepthStencilState {
+        format: DEPTH_FORMAT,
+        depth_write_enabled: true,
+        depth_compare: wgpu::CompareFunction::GreaterEqual,
+        stencil: Default::default(),
+        bias: Default::default(),
+    }
+}
+
 type Scale = [f32; 4];
 
+/// A clip region (one per draw pass)
+///
+/// `rect` is the clip rect in window coordinates. A point `p` in the pass's
+/// coordinate system is drawn at `p * scale - offset` in window coordinates.
+#[derive(Clone, Copy, Debug)]
+struct ClipRegion {
+    rect: Rect,
+    offset: Vec2,
+    scale: f32,
+}
+
+impl Default for ClipRegion {
+    fn default() -> Self {
+        ClipRegion {
+            rect: Rect::ZERO,
+            offset: Vec2::ZERO,
+            scale: 1.0,
+        }
+    }
+}
+
+impl ClipRegion {
+    /// Derive a region clipped to `rect` and translated by `offset`
+    ///
+    /// Both are specified in this region's coordinate system.
+    fn clip(&self, rect: Rect, offset: Offset) -> Self {
+        let a = Vec2::conv(rect.pos) * self.scale - self.offset;
+        let b = Vec2::conv(rect.pos2()) * self.scale - self.offset;
+        let (a, b) = (Coord::conv_floor(a), Coord::conv_ceil(b));
+        let rect = Rect::new(a, (b - a).cast());
+        ClipRegion {
+            rect: rect.intersection(&self.rect).unwrap_or(Rect::ZERO),
+            offset: self.offset + Vec2::conv(offset) * self.scale,
+            scale: self.scale,
+        }
+    }
+
+    /// Derive a region scaled by `scale` then translated by `offset`
+    fn transform(&self, scale: f32, offset: Offset) -> Self {
+        ClipRegion {
+            rect: self.rect,
+            offset: self.offset + Vec2::conv(offset) * self.scale,
+            scale: self.scale * scale,
+        }
+    }
+
+    /// The clip rect in this region's coordinate system
+    fn clip_rect(&self) -> Rect {
+        let a = (Vec2::conv(self.rect.pos) + self.offset) / self.scale;
+        let b = (Vec2::conv(self.rect.pos2()) + self.offset) / self.scale;
+        let (a, b) = (Coord::conv_floor(a), Coord::conv_ceil(b));
+        Rect::new(a, (b - a).cast())
+    }
+
+    /// Vertex shader uniform, given that of the window
+    fn scale_uniform(&self, window: Scale) -> Scale {
+        let offset = (Vec2(window[0], window[1]) - self.offset) / self.scale;
+        let s = self.scale;
+        [offset.0, offset.1, window[2] * s, window[3] * s]
+    }
+}
+
 /// Shared pipeline data
 pub struct DrawPipe<C> {
     pub(crate) device: wgpu::Device,
@@ -48,6 +137,7 @@ pub struct DrawPipe<C> {
     shaded_round: shaded_round::Pipeline,
     flat_round: flat_round::Pipeline,
     round_2col: round_2col::Pipeline,
+    blit: blit::Pipeline,
     custom: C,
     pub(crate) text: text_pipe::Pipeline,
 }
@@ -56,7 +146,7 @@ pub struct DrawPipe<C> {
 pub struct DrawWindow<CW: CustomWindow> {
     pub(crate) animation: AnimationState,
     scale: Scale,
-    clip_regions: Vec<(Rect, Offset)>,
+    clip_regions: Vec<ClipRegion>,
     images: images::Window,
     shaded_square: shaded_square::Window,
     shaded_round: shaded_round::Window,
@@ -64,4 +154,6 @@ pub struct DrawWindow<CW: CustomWindow> {
     round_2col: round_2col::Window,
     custom: CW,
     pub(crate) text: text_pipe::Window,
+    depth: Option<wgpu::TextureView>,
+    target: Option<blit::Target>,
 }
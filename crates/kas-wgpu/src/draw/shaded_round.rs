@@ -42,6 +42,7 @@ pub type Window = common::Window<Vertex>;
 /// Uses 4x sampling for anti-aliasing.
 pub struct Pipeline {
     render_pipeline: wgpu::RenderPipeline,
+    depth_pipeline: wgpu::RenderPipeline,
 }
 
 impl Pipeline {
@@ -57,47 +58,54 @@ impl Pipeline {
             push_constant_ranges: &[],
         });
 
-        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("SR render_pipeline"),
-            layout: Some(&pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shaders.vert_shaded_round,
-                entry_point: "main",
-                buffers: &[wgpu::VertexBufferLayout {
-                    array_stride: size_of::<Vertex>() as wgpu::BufferAddress,
-                    step_mode: wgpu::VertexStepMode::Vertex,
-                    attributes: &wgpu::vertex_attr_array![
-                        0 => Float32x2,
-                        1 => Float32x4,
-                        2 => Float32x2,
-                        3 => Float32x2,
-                        4 => Float32x2
-                    ],
-                }],
-            },
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                strip_index_format: None,
-                front_face: wgpu::FrontFace::Cw,
-                cull_mode: Some(wgpu::Face::Back), // not required
-                clamp_depth: false,
-                polygon_mode: wgpu::PolygonMode::Fill,
-                conservative: false,
-            },
-            depth_stencil: None,
-            multisample: Default::default(),
-            fragment: Some(wgpu::FragmentState {
-                module: &shaders.frag_shaded_round,
-                entry_point: "main",
-                targets: &[wgpu::ColorTargetState {
-                    format: super::RENDER_TEX_FORMAT,
-                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
-                    write_mask: wgpu::ColorWrites::ALL,
-                }],
-            }),
-        });
+        let create = |label, depth_stencil| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shaders.vert_shaded_round,
+                    entry_point: "main",
+                    buffers: &[wgpu::VertexBufferLayout {
+                        array_stride: size_of::<Vertex>() as wgpu::BufferAddress,
+                        step_mode: wgpu::VertexStepMode::Vertex,
+                        attributes: &wgpu::vertex_attr_array![
+                            0 => Float32x2,
+                            1 => Float32x4,
+                            2 => Float32x2,
+                            3 => Float32x2,
+                            4 => Float32x2
+                        ],
+                    }],
+                },
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    strip_index_format: None,
+                    front_face: wgpu::FrontFace::Cw,
+                    cull_mode: Some(wgpu::Face::Back), // not required
+                    clamp_depth: false,
+                    polygon_mode: wgpu::PolygonMode::Fill,
+                    conservative: false,
+                },
+                depth_stencil,
+                multisample: Default::default(),
+                fragment: Some(wgpu::FragmentState {
+                    module: &shaders.frag_shaded_round,
+                    entry_point: "main",
+                    targets: &[wgpu::ColorTargetState {
+                        format: super::RENDER_TEX_FORMAT,
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    }],
+                }),
+            })
+        };
+        let render_pipeline = create("SR render_pipeline", None);
+        let depth_pipeline = create("SR depth_pipeline", Some(super::depth_stencil_state()));
 
-        Pipeline { render_pipeline }
+        Pipeline {
+            render_pipeline,
+            depth_pipeline,
+        }
     }
 
     /// Enqueue render commands
@@ -110,6 +118,20 @@ impl Pipeline {
     ) {
         window.render(pass, rpass, &self.render_pipeline, bg_common);
     }
+
+    /// Enqueue render commands with depth testing
+    ///
+    /// This must be used within a render pass with a depth attachment.
+    pub fn render_with_depth<'a>(
+        &'a self,
+        window: &'a Window,
+        pass: usize,
+        rpass: &mut wgpu::RenderPass<'a>,
+        bg_common: &'a wgpu::BindGroup,
+        size: Vec2,
+    ) {
+        window.render_with_depth(pass, rpass, &self.depth_pipeline, bg_common, size);
+    }
 }
 
 impl Window {
@@ -147,7 +169,7 @@ impl Window {
         let mid = Vertex::new2(mid, col, n0, adjust, p);
 
         #[rustfmt::skip]
-        self.add_vertices(pass.pass(), &[
+        self.add_vertices_with_depth(pass, &[
             aa, ba, mid,
             mid, ba, bb,
             bb, ab, mid,
@@ -231,7 +253,7 @@ impl Window {
         let dd = Vertex::new2(dd, col, n0, adjust, pbb);
 
         #[rustfmt::skip]
-        self.add_vertices(pass.pass(), &[
+        self.add_vertices_with_depth(pass, &[
             // top bar: ba - dc - cc - aa
             ba, dc, da,
             da, dc, ca,
@@ -7,6 +7,7 @@
 
 use super::common;
 use crate::draw::ShaderManager;
+use kas::dir::Direction;
 use kas::draw::{color::Rgba, PassId};
 use kas::geom::{Quad, Vec2};
 use std::mem::size_of;
@@ -29,6 +30,7 @@ pub type Window = common::Window<Vertex>;
 /// Does not use anti-aliasing since edges are usually pixel-aligned.
 pub struct Pipeline {
     render_pipeline: wgpu::RenderPipeline,
+    depth_pipeline: wgpu::RenderPipeline,
 }
 
 impl Pipeline {
@@ -44,41 +46,48 @@ impl Pipeline {
             push_constant_ranges: &[],
         });
 
-        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("SS render_pipeline"),
-            layout: Some(&pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shaders.vert_shaded_square,
-                entry_point: "main",
-                buffers: &[wgpu::VertexBufferLayout {
-                    array_stride: size_of::<Vertex>() as wgpu::BufferAddress,
-                    step_mode: wgpu::VertexStepMode::Vertex,
-                    attributes: &wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x4, 2 => Float32x2],
-                }],
-            },
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                strip_index_format: None,
-                front_face: wgpu::FrontFace::Cw,
-                cull_mode: Some(wgpu::Face::Back), // not required
-                clamp_depth: false,
-                polygon_mode: wgpu::PolygonMode::Fill,
-                conservative: false,
-            },
-            depth_stencil: None,
-            multisample: Default::default(),
-            fragment: Some(wgpu::FragmentState {
-                module: &shaders.frag_shaded_square,
-                entry_point: "main",
-                targets: &[wgpu::ColorTargetState {
-                    format: super::RENDER_TEX_FORMAT,
-                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
-                    write_mask: wgpu::ColorWrites::ALL,
-                }],
-            }),
-        });
+        let create = |label, depth_stencil| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shaders.vert_shaded_square,
+                    entry_point: "main",
+                    buffers: &[wgpu::VertexBufferLayout {
+                        array_stride: size_of::<Vertex>() as wgpu::BufferAddress,
+                        step_mode: wgpu::VertexStepMode::Vertex,
+                        attributes: &wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x4, 2 => Float32x2],
+                    }],
+                },
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    strip_index_format: None,
+                    front_face: wgpu::FrontFace::Cw,
+                    cull_mode: Some(wgpu::Face::Back), // not required
+                    clamp_depth: false,
+                    polygon_mode: wgpu::PolygonMode::Fill,
+                    conservative: false,
+                },
+                depth_stencil,
+                multisample: Default::default(),
+                fragment: Some(wgpu::FragmentState {
+                    module: &shaders.frag_shaded_square,
+                    entry_point: "main",
+                    targets: &[wgpu::ColorTargetState {
+                        format: super::RENDER_TEX_FORMAT,
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    }],
+                }),
+            })
+        };
+        let render_pipeline = create("SS render_pipeline", None);
+        let depth_pipeline = create("SS depth_pipeline", Some(super::depth_stencil_state()));
 
-        Pipeline { render_pipeline }
+        Pipeline {
+            render_pipeline,
+            depth_pipeline,
+        }
     }
 
     /// Enqueue render commands
@@ -91,6 +100,20 @@ impl Pipeline {
     ) {
         window.render(pass, rpass, &self.render_pipeline, bg_common);
     }
+
+    /// Enqueue render commands with depth testing
+    ///
+    /// This must be used within a render pass with a depth attachment.
+    pub fn render_with_depth<'a>(
+        &'a self,
+        window: &'a Window,
+        pass: usize,
+        rpass: &mut wgpu::RenderPass<'a>,
+        bg_common: &'a wgpu::BindGroup,
+        size: Vec2,
+    ) {
+        window.render_with_depth(pass, rpass, &self.depth_pipeline, bg_common, size);
+    }
 }
 
 impl Window {
@@ -110,12 +133,50 @@ impl Window {
         let t = Vec2(0.0, 0.0);
 
         #[rustfmt::skip]
-        self.add_vertices(pass.pass(), &[
+        self.add_vertices_with_depth(pass, &[
             Vertex(aa, col, t), Vertex(ba, col, t), Vertex(ab, col, t),
             Vertex(ab, col, t), Vertex(ba, col, t), Vertex(bb, col, t),
         ]);
     }
 
+    /// Add a rectangle with a linear gradient from `from` to `to` along `dir`
+    pub fn gradient_rect(
+        &mut self,
+        pass: PassId,
+        rect: Quad,
+        from: Rgba,
+        to: Rgba,
+        dir: Direction,
+    ) {
+        let aa = rect.a;
+        let bb = rect.b;
+
+        if !aa.lt(bb) {
+            // zero / negative size: nothing to draw
+            return;
+        }
+
+        let ab = Vec2(aa.0, bb.1);
+        let ba = Vec2(bb.0, aa.1);
+
+        // Colours at corners aa, ba, ab, bb. Since colour varies along only
+        // one axis, interpolation within each triangle is exact.
+        let (c_aa, c_ba, c_ab, c_bb) = match dir {
+            Direction::Right => (from, to, from, to),
+            Direction::Left => (to, from, to, from),
+            Direction::Down => (from, from, to, to),
+            Direction::Up => (to, to, from, from),
+        };
+
+        let t = Vec2(0.0, 0.0);
+
+        #[rustfmt::skip]
+        self.add_vertices_with_depth(pass, &[
+            Vertex(aa, c_aa, t), Vertex(ba, c_ba, t), Vertex(ab, c_ab, t),
+            Vertex(ab, c_ab, t), Vertex(ba, c_ba, t), Vertex(bb, c_bb, t),
+        ]);
+    }
+
     /// Add a rect to the buffer, defined by two outer corners, `aa` and `bb`.
     ///
     /// Bounds on input: `aa < cc` and `-1 ≤ norm ≤ 1`.
@@ -141,7 +202,7 @@ impl Window {
         let tr = (Vec2(norm.1, 0.0), Vec2(norm.0, 0.0));
 
         #[rustfmt::skip]
-        self.add_vertices(pass.pass(), &[
+        self.add_vertices_with_depth(pass, &[
             Vertex(ba, col, tt.0), Vertex(mid, col, tt.1), Vertex(aa, col, tt.0),
             Vertex(aa, col, tl.0), Vertex(mid, col, tl.1), Vertex(ab, col, tl.0),
             Vertex(ab, col, tb.0), Vertex(mid, col, tb.1), Vertex(bb, col, tb.0),
@@ -201,7 +262,7 @@ impl Window {
         let tr = (Vec2(norm.1, 0.0), Vec2(norm.0, 0.0));
 
         #[rustfmt::skip]
-        self.add_vertices(pass.pass(), &[
+        self.add_vertices_with_depth(pass, &[
             // top bar: ba - dc - cc - aa
             Vertex(ba, outer_col, tt.0), Vertex(dc, inner_col, tt.1), Vertex(aa, outer_col, tt.0),
             Vertex(aa, outer_col, tt.0), Vertex(dc, inner_col, tt.1), Vertex(cc, inner_col, tt.1),
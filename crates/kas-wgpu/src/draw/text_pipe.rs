@@ -4,10 +4,19 @@
 //     https://www.apache.org/licenses/LICENSE-2.0
 
 //! Text drawing pipeline
+//!
+//! Glyphs are rastered to an alpha-only (`R8Unorm`) atlas and tinted by the
+//! text colour.
+//!
+//! With feature `color_glyphs`, glyphs with a colour bitmap (e.g. emoji from
+//! `CBDT` or `sbix` font tables) are decoded to a separate RGBA atlas and drawn
+//! untinted by a second instance batch. Where no colour bitmap is available
+//! (including glyphs from `COLR` tables, and always without the feature) the
+//! glyph is drawn monochrome if the font provides an outline.
 
 use super::{atlases, ShaderManager};
 use kas::cast::*;
-use kas::draw::{color::Rgba, PassId};
+use kas::draw::{color::Rgba, GlyphSpacer, PassId};
 use kas::geom::{Quad, Vec2};
 use kas::text::fonts::FaceId;
 use kas::text::{Effect, Glyph, TextDisplay};
@@ -28,9 +37,26 @@ struct Sprite {
     size: Vec2,
     offset: Vec2,
     tex_quad: Quad,
+    /// True if rastered to the colour (RGBA) atlas
+    color: bool,
+}
+
+impl Sprite {
+    /// Get an instance for a glyph at `pos`
+    ///
+    /// The sprite is assumed to be rastered at `scale` times the size at
+    /// which it is drawn; its position is aligned to whole scaled pixels.
+    fn instance(&self, pos: Vec2, scale: f32, col: Rgba) -> Instance {
+        let a = ((pos * scale).floor() + self.offset) / scale;
+        let b = a + self.size / scale;
+        let (ta, tb) = (self.tex_quad.a, self.tex_quad.b);
+        Instance { a, b, ta, tb, col }
+    }
 }
 
 /// Screen and texture coordinates
+///
+/// The colour pipeline uses the same layout but ignores `col`.
 #[repr(C)]
 #[derive(Clone, Copy, Debug)]
 pub struct Instance {
@@ -50,6 +76,13 @@ pub struct Pipeline {
     glyphs: HashMap<SpriteDescriptor, Option<Sprite>>,
     #[allow(clippy::type_complexity)]
     prepare: Vec<(u32, (u32, u32), (u32, u32), Vec<u8>)>,
+    #[cfg(feature = "color_glyphs")]
+    color_pipe: atlases::Pipeline<Instance>,
+    #[cfg(feature = "color_glyphs")]
+    color_glyphs: HashMap<(FaceId, u16, u32), Option<Sprite>>,
+    #[cfg(feature = "color_glyphs")]
+    #[allow(clippy::type_complexity)]
+    color_prepare: Vec<(u32, (u32, u32), (u32, u32), Vec<u8>)>,
 }
 
 impl Pipeline {
@@ -89,6 +122,36 @@ impl Pipeline {
                 }],
             },
         );
+        #[cfg(feature = "color_glyphs")]
+        let color_pipe = atlases::Pipeline::new(
+            device,
+            bgl_common,
+            512,
+            wgpu::TextureFormat::Rgba8UnormSrgb,
+            wgpu::VertexState {
+                module: &shaders.vert_image,
+                entry_point: "main",
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: size_of::<Instance>() as wgpu::BufferAddress,
+                    step_mode: wgpu::VertexStepMode::Instance,
+                    attributes: &wgpu::vertex_attr_array![
+                        0 => Float32x2,
+                        1 => Float32x2,
+                        2 => Float32x2,
+                        3 => Float32x2,
+                    ],
+                }],
+            },
+            wgpu::FragmentState {
+                module: &shaders.frag_image,
+                entry_point: "main",
+                targets: &[wgpu::ColorTargetState {
+                    format: super::RENDER_TEX_FORMAT,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                }],
+            },
+        );
         Pipeline {
             config: Config::new(
                 config.mode,
@@ -99,43 +162,38 @@ impl Pipeline {
             atlas_pipe,
             glyphs: Default::default(),
             prepare: Default::default(),
+            #[cfg(feature = "color_glyphs")]
+            color_pipe,
+            #[cfg(feature = "color_glyphs")]
+            color_glyphs: Default::default(),
+            #[cfg(feature = "color_glyphs")]
+            color_prepare: Default::default(),
         }
     }
 
     /// Write to textures
     pub fn prepare(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
         self.atlas_pipe.prepare(device);
+        #[cfg(feature = "color_glyphs")]
+        self.color_pipe.prepare(device);
 
-        if !self.prepare.is_empty() {
-            log::trace!(
-                "Pipeline::prepare: uploading {} sprites",
-                self.prepare.len()
-            );
+        #[allow(unused_mut)]
+        let mut len = self.prepare.len();
+        #[cfg(feature = "color_glyphs")]
+        {
+            len += self.color_prepare.len();
+        }
+        if len > 0 {
+            log::trace!("Pipeline::prepare: uploading {} sprites", len);
         }
         for (atlas, origin, size, data) in self.prepare.drain(..) {
-            queue.write_texture(
-                wgpu::ImageCopyTexture {
-                    texture: self.atlas_pipe.get_texture(atlas),
-                    mip_level: 0,
-                    origin: wgpu::Origin3d {
-                        x: origin.0,
-                        y: origin.1,
-                        z: 0,
-                    },
-                    aspect: wgpu::TextureAspect::All,
-                },
-                &data,
-                wgpu::ImageDataLayout {
-                    offset: 0,
-                    bytes_per_row: NonZeroU32::new(size.0),
-                    rows_per_image: NonZeroU32::new(size.1),
-                },
-                wgpu::Extent3d {
-                    width: size.0,
-                    height: size.1,
-                    depth_or_array_layers: 1,
-                },
-            );
+            let texture = self.atlas_pipe.get_texture(atlas);
+            write_sprite(queue, texture, origin, size, 1, &data);
+        }
+        #[cfg(feature = "color_glyphs")]
+        for (atlas, origin, size, data) in self.color_prepare.drain(..) {
+            let texture = self.color_pipe.get_texture(atlas);
+            write_sprite(queue, texture, origin, size, 4, &data);
         }
     }
 
@@ -149,13 +207,23 @@ impl Pipeline {
     ) {
         self.atlas_pipe
             .render(&window.atlas, pass, rpass, bg_common);
+        #[cfg(feature = "color_glyphs")]
+        self.color_pipe
+            .render(&window.color_atlas, pass, rpass, bg_common);
     }
 
     /// Get a rendered sprite
     ///
     /// This returns `None` if there's nothing to render. It may also return
     /// `None` (with a warning) on error.
+    ///
+    /// A colour sprite is preferred where available; otherwise the glyph is
+    /// rastered monochrome.
     fn get_glyph(&mut self, face: FaceId, dpem: f32, glyph: Glyph) -> Option<Sprite> {
+        if let Some(sprite) = self.get_color_glyph(face, dpem, glyph) {
+            return Some(sprite);
+        }
+
         let desc = SpriteDescriptor::new(&self.config, face, glyph, dpem);
         if let Some(opt_sprite) = self.glyphs.get(&desc).cloned() {
             opt_sprite
@@ -178,6 +246,7 @@ impl Pipeline {
                         size: Vec2(rs.size.0.cast(), rs.size.1.cast()),
                         offset: Vec2(rs.offset.0.cast(), rs.offset.1.cast()),
                         tex_quad,
+                        color: false,
                     };
 
                     self.prepare.push((s.atlas, origin, rs.size, rs.data));
@@ -202,12 +271,144 @@ impl Pipeline {
         self.glyphs.insert(desc, sprite.clone());
         sprite
     }
+
+    /// Get a colour sprite, if the glyph has a colour bitmap
+    #[cfg(feature = "color_glyphs")]
+    fn get_color_glyph(&mut self, face: FaceId, dpem: f32, glyph: Glyph) -> Option<Sprite> {
+        // Bitmaps are not rastered at sub-pixel offsets, hence this key
+        let key = (face, glyph.id.0, dpem.to_bits());
+        if let Some(opt_sprite) = self.color_glyphs.get(&key).cloned() {
+            opt_sprite
+        } else {
+            let sprite = self.raster_color_glyph(face, dpem, glyph);
+            self.color_glyphs.insert(key, sprite.clone());
+            sprite
+        }
+    }
+
+    #[cfg(not(feature = "color_glyphs"))]
+    #[inline]
+    fn get_color_glyph(&mut self, _: FaceId, _: f32, _: Glyph) -> Option<Sprite> {
+        None
+    }
+
+    /// Decode a colour bitmap (`sbix` or `CBDT` table) to the RGBA atlas
+    ///
+    /// The strike closest to `dpem` is used and scaled on draw.
+    #[cfg(feature = "color_glyphs")]
+    fn raster_color_glyph(&mut self, face: FaceId, dpem: f32, glyph: Glyph) -> Option<Sprite> {
+        let ppem = u16::try_conv_nearest(dpem).ok()?;
+        let store = kas::text::fonts::fonts().get_face_store(face);
+        let id = ttf_parser::GlyphId(glyph.id.0);
+        let image = store.face().glyph_raster_image(id, ppem)?;
+        let (size, data) = match decode_png(image.data) {
+            Some(result) => result,
+            None => {
+                log::warn!(
+                    "text_pipe: failed to decode colour glyph {:?} of face {:?}",
+                    glyph.id,
+                    face
+                );
+                return None;
+            }
+        };
+
+        // Image coordinates are in pixels of the strike, with y upwards from
+        // the baseline to the bottom of the image.
+        let strike_scale = dpem / f32::conv(image.pixels_per_em);
+        let x = f32::conv(image.x);
+        let y = -f32::conv(i32::conv(image.y) + i32::conv(size.1));
+        match self.color_pipe.allocate(size) {
+            Ok((atlas, _, origin, tex_quad)) => {
+                let s = Sprite {
+                    atlas,
+                    size: Vec2(size.0.cast(), size.1.cast()) * strike_scale,
+                    offset: Vec2(x, y) * strike_scale,
+                    tex_quad,
+                    color: true,
+                };
+                self.color_prepare.push((s.atlas, origin, size, data));
+                Some(s)
+            }
+            Err(_) => {
+                log::warn!(
+                    "text_pipe: failed to allocate colour glyph with size {:?}",
+                    size
+                );
+                None
+            }
+        }
+    }
+}
+
+/// Upload sprite `data` with `bpp` bytes per pixel to `texture`
+fn write_sprite(
+    queue: &wgpu::Queue,
+    texture: &wgpu::Texture,
+    origin: (u32, u32),
+    size: (u32, u32),
+    bpp: u32,
+    data: &[u8],
+) {
+    queue.write_texture(
+        wgpu::ImageCopyTexture {
+            texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d {
+                x: origin.0,
+                y: origin.1,
+                z: 0,
+            },
+            aspect: wgpu::TextureAspect::All,
+        },
+        data,
+        wgpu::ImageDataLayout {
+            offset: 0,
+            bytes_per_row: NonZeroU32::new(size.0 * bpp),
+            rows_per_image: NonZeroU32::new(size.1),
+        },
+        wgpu::Extent3d {
+            width: size.0,
+            height: size.1,
+            depth_or_array_layers: 1,
+        },
+    );
+}
+
+/// Decode a PNG image to RGBA with 8 bits per channel
+#[cfg(feature = "color_glyphs")]
+fn decode_png(data: &[u8]) -> Option<((u32, u32), Vec<u8>)> {
+    use png::ColorType;
+
+    let mut decoder = png::Decoder::new(data);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info().ok()?;
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buf).ok()?;
+    buf.truncate(info.buffer_size());
+
+    let data = match info.color_type {
+        ColorType::Rgba => buf,
+        ColorType::Rgb => buf
+            .chunks_exact(3)
+            .flat_map(|p| [p[0], p[1], p[2], 255])
+            .collect(),
+        ColorType::GrayscaleAlpha => buf
+            .chunks_exact(2)
+            .flat_map(|p| [p[0], p[0], p[0], p[1]])
+            .collect(),
+        ColorType::Grayscale => buf.iter().flat_map(|&v| [v, v, v, 255]).collect(),
+        // Palette images are expanded by the decoder
+        ColorType::Indexed => return None,
+    };
+    Some(((info.width, info.height), data))
 }
 
 /// Per-window state
 #[derive(Debug, Default)]
 pub struct Window {
     atlas: atlases::Window<Instance>,
+    color_atlas: atlases::Window<Instance>,
     duration: std::time::Duration,
 }
 
@@ -220,6 +421,17 @@ impl Window {
         encoder: &mut wgpu::CommandEncoder,
     ) {
         self.atlas.write_buffers(device, staging_belt, encoder);
+        self.color_atlas
+            .write_buffers(device, staging_belt, encoder);
+    }
+
+    /// Add a glyph instance to the batch matching its sprite
+    fn rect(&mut self, pass: PassId, sprite: &Sprite, instance: Instance) {
+        if sprite.color {
+            self.color_atlas.rect(pass, sprite.atlas, instance);
+        } else {
+            self.atlas.rect(pass, sprite.atlas, instance);
+        }
     }
 
     /// Get microseconds used for text during since last call
@@ -236,19 +448,17 @@ impl Window {
         pos: Vec2,
         text: &TextDisplay,
         col: Rgba,
+        scale: f32,
     ) {
         let pos = pos.round();
         let time = std::time::Instant::now();
 
         let for_glyph = |face: FaceId, dpem: f32, glyph: Glyph| {
-            if let Some(sprite) = pipe.get_glyph(face, dpem, glyph) {
-                let pos = pos + Vec2::from(glyph.position).floor();
-                let a = pos + sprite.offset;
-                let b = a + sprite.size;
-                let (ta, tb) = (sprite.tex_quad.a, sprite.tex_quad.b);
-                let instance = Instance { a, b, ta, tb, col };
+            if let Some(sprite) = pipe.get_glyph(face, dpem * scale, glyph) {
+                let pos = pos + Vec2::from(glyph.position);
+                let instance = sprite.instance(pos, scale, col);
                 // TODO(opt): avoid calling repeatedly?
-                self.atlas.rect(pass, sprite.atlas, instance);
+                self.rect(pass, &sprite, instance);
             }
         };
         let _ = text.glyphs(for_glyph);
@@ -256,6 +466,59 @@ impl Window {
         self.duration += time.elapsed();
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn text_spaced(
+        &mut self,
+        pipe: &mut Pipeline,
+        pass: PassId,
+        pos: Vec2,
+        text: &TextDisplay,
+        col: Rgba,
+        effects: &[Effect<()>],
+        mut spacer: GlyphSpacer,
+        scale: f32,
+    ) -> Vec<Quad> {
+        let pos = pos.round();
+        let time = std::time::Instant::now();
+        let mut lines = vec![];
+
+        let mut for_glyph = |face: FaceId, dpem: f32, mut glyph: Glyph, _: usize, _: ()| {
+            // Adjust before rastering: sprites depend on sub-pixel position
+            glyph.position = spacer.position(glyph.position.into()).into();
+            if let Some(sprite) = pipe.get_glyph(face, dpem * scale, glyph) {
+                let pos = pos + Vec2::from(glyph.position);
+                let instance = sprite.instance(pos, scale, col);
+                self.rect(pass, &sprite, instance);
+            }
+        };
+
+        if effects.len() > 1
+            || effects
+                .get(0)
+                .map(|e| *e != Effect::default(()))
+                .unwrap_or(false)
+        {
+            let for_rect = |x1, x2, y, h: f32, _, _| lines.push((x1, x2, y, h));
+            let _ = text.glyphs_with_effects(effects, (), for_glyph, for_rect);
+        } else {
+            let _ = text.glyphs(|face, dpem, glyph| for_glyph(face, dpem, glyph, 0, ()));
+        }
+
+        let rects = lines
+            .into_iter()
+            .map(|(x1, x2, y, h)| {
+                let (x1, x2, y) = spacer.effect(x1, x2, y);
+                let y = y.ceil();
+                let y2 = y + h.ceil();
+                Quad::from_coords(pos + Vec2(x1, y), pos + Vec2(x2, y2))
+            })
+            .collect();
+
+        self.duration += time.elapsed();
+        rects
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn text_col_effects(
         &mut self,
         pipe: &mut Pipeline,
@@ -264,6 +527,7 @@ impl Window {
         text: &TextDisplay,
         col: Rgba,
         effects: &[Effect<()>],
+        scale: f32,
     ) -> Vec<Quad> {
         let pos = pos.round();
         // Optimisation: use cheaper TextDisplay::glyphs method
@@ -273,7 +537,7 @@ impl Window {
                 .map(|e| e.flags == Default::default())
                 .unwrap_or(true)
         {
-            self.text(pipe, pass, pos, text, col);
+            self.text(pipe, pass, pos, text, col, scale);
             return vec![];
         }
 
@@ -281,14 +545,11 @@ impl Window {
         let mut rects = vec![];
 
         let mut for_glyph = |face: FaceId, dpem: f32, glyph: Glyph, _: usize, _: ()| {
-            if let Some(sprite) = pipe.get_glyph(face, dpem, glyph) {
-                let pos = pos + Vec2::from(glyph.position).floor();
-                let a = pos + sprite.offset;
-                let b = a + sprite.size;
-                let (ta, tb) = (sprite.tex_quad.a, sprite.tex_quad.b);
-                let instance = Instance { a, b, ta, tb, col };
+            if let Some(sprite) = pipe.get_glyph(face, dpem * scale, glyph) {
+                let pos = pos + Vec2::from(glyph.position);
+                let instance = sprite.instance(pos, scale, col);
                 // TODO(opt): avoid calling repeatedly?
-                self.atlas.rect(pass, sprite.atlas, instance);
+                self.rect(pass, &sprite, instance);
             }
         };
 
@@ -320,6 +581,7 @@ impl Window {
         pos: Vec2,
         text: &TextDisplay,
         effects: &[Effect<Rgba>],
+        scale: f32,
     ) -> Vec<(Quad, Rgba)> {
         let pos = pos.round();
         // Optimisation: use cheaper TextDisplay::glyphs method
@@ -330,7 +592,7 @@ impl Window {
                 .unwrap_or(true)
         {
             let col = effects.get(0).map(|e| e.aux).unwrap_or(Rgba::BLACK);
-            self.text(pipe, pass, pos, text, col);
+            self.text(pipe, pass, pos, text, col, scale);
             return vec![];
         }
 
@@ -338,14 +600,11 @@ impl Window {
         let mut rects = vec![];
 
         let for_glyph = |face: FaceId, dpem: f32, glyph: Glyph, _, col: Rgba| {
-            if let Some(sprite) = pipe.get_glyph(face, dpem, glyph) {
-                let pos = pos + Vec2::from(glyph.position).floor();
-                let a = pos + sprite.offset;
-                let b = a + sprite.size;
-                let (ta, tb) = (sprite.tex_quad.a, sprite.tex_quad.b);
-                let instance = Instance { a, b, ta, tb, col };
+            if let Some(sprite) = pipe.get_glyph(face, dpem * scale, glyph) {
+                let pos = pos + Vec2::from(glyph.position);
+                let instance = sprite.instance(pos, scale, col);
                 // TODO(opt): avoid calling repeatedly?
-                self.atlas.rect(pass, sprite.atlas, instance);
+                self.rect(pass, &sprite, instance);
             }
         };
 
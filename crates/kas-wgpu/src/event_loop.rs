@@ -87,6 +87,9 @@ where
                         .pending
                         .push(PendingAction::Update(handle, payload));
                 }
+                ProxyAction::WakeAsync => {
+                    // Futures are polled by Window::update (MainEventsCleared)
+                }
             },
 
             NewEvents(cause) => {
@@ -32,6 +32,8 @@ mod window;
 
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::task::{Wake, Waker};
 use thiserror::Error;
 
 use kas::draw::DrawShared;
@@ -154,10 +156,11 @@ where
         };
         let config = Rc::new(RefCell::new(config));
         let scale_factor = find_scale_factor(&el);
+        let waker = ProxyWaker::new(el.create_proxy());
         Ok(Toolkit {
             el,
             windows: vec![],
-            shared: SharedState::new(custom, theme, options, config, scale_factor)?,
+            shared: SharedState::new(custom, theme, options, config, scale_factor, waker)?,
         })
     }
 
@@ -177,10 +180,11 @@ where
     ) -> Result<Self> {
         let el = EventLoop::with_user_event();
         let scale_factor = find_scale_factor(&el);
+        let waker = ProxyWaker::new(el.create_proxy());
         Ok(Toolkit {
             el,
             windows: vec![],
-            shared: SharedState::new(custom, theme, options, config, scale_factor)?,
+            shared: SharedState::new(custom, theme, options, config, scale_factor, waker)?,
         })
     }
 
@@ -308,4 +312,25 @@ enum ProxyAction {
     CloseAll,
     Close(WindowId),
     Update(UpdateId, u64),
+    WakeAsync,
+}
+
+/// Wakes the event loop, causing futures to be polled
+struct ProxyWaker(Mutex<EventLoopProxy<ProxyAction>>);
+
+impl ProxyWaker {
+    fn new(proxy: EventLoopProxy<ProxyAction>) -> Waker {
+        Waker::from(Arc::new(ProxyWaker(Mutex::new(proxy))))
+    }
+}
+
+impl Wake for ProxyWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        // An error implies that the event loop has terminated; ignore
+        let _ = self.0.lock().unwrap().send_event(ProxyAction::WakeAsync);
+    }
 }
@@ -9,6 +9,7 @@ use log::info;
 use std::cell::RefCell;
 use std::num::NonZeroU32;
 use std::rc::Rc;
+use std::task::Waker;
 use std::time::Duration;
 
 use crate::draw::{CustomPipe, CustomPipeBuilder, DrawPipe, DrawWindow};
@@ -16,6 +17,7 @@ use crate::{warn_about_error, Error, Options, WindowId};
 use kas::cast::Conv;
 use kas::draw;
 use kas::event::UpdateId;
+use kas::geom::Rect;
 use kas::TkAction;
 use kas_theme::{Theme, ThemeConfig};
 
@@ -34,6 +36,7 @@ pub struct SharedState<C: CustomPipe, T> {
     /// Estimated scale factor (from last window constructed or available screens)
     pub scale_factor: f64,
     pub frame_dur: Duration,
+    pub waker: Waker,
     window_id: u32,
     options: Options,
 }
@@ -49,6 +52,7 @@ where
         options: Options,
         config: Rc<RefCell<kas::event::Config>>,
         scale_factor: f64,
+        waker: Waker,
     ) -> Result<Self, Error> {
         let instance = wgpu::Instance::new(options.backend());
         let adapter_options = options.adapter_options();
@@ -84,6 +88,7 @@ where
             pending: vec![],
             scale_factor,
             frame_dur,
+            waker,
             window_id: 0,
             options,
         })
@@ -113,8 +118,11 @@ where
         window: &mut DrawWindow<C::Window>,
         frame_view: &wgpu::TextureView,
         clear_color: wgpu::Color,
+        damage: Option<Rect>,
     ) {
-        self.draw.draw.render(window, frame_view, clear_color);
+        self.draw
+            .draw
+            .render(window, frame_view, clear_color, damage);
     }
 
     #[inline]
@@ -144,6 +152,45 @@ where
         }
     }
 
+    /// Open `url` via the platform's URL handler
+    ///
+    /// Only `http`, `https` and `mailto` URLs are accepted; others are
+    /// rejected with a warning. The handler runs in the background; failure
+    /// is logged.
+    pub fn open_url(&mut self, url: &str) {
+        use std::process::Command;
+
+        if let Err(msg) = check_url(url) {
+            log::warn!("Refusing to open URL {url:?}: {msg}");
+            return;
+        }
+
+        // The URL is passed as a single argument, never via a shell
+        #[cfg(target_os = "windows")]
+        let mut cmd = {
+            let mut cmd = Command::new("rundll32");
+            cmd.arg("url.dll,FileProtocolHandler");
+            cmd
+        };
+        #[cfg(target_os = "macos")]
+        let mut cmd = Command::new("open");
+        #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+        let mut cmd = Command::new("xdg-open");
+
+        match cmd.arg(url).spawn() {
+            Ok(mut child) => {
+                let url = url.to_string();
+                // Reap the child without blocking the event loop
+                std::thread::spawn(move || match child.wait() {
+                    Ok(status) if status.success() => (),
+                    Ok(status) => log::warn!("Failed to open URL {url}: handler {status}"),
+                    Err(e) => warn_about_error("Failed to open URL", &e),
+                });
+            }
+            Err(e) => warn_about_error("Failed to open URL", &e),
+        }
+    }
+
     pub fn trigger_update(&mut self, id: UpdateId, payload: u64) {
         self.pending.push(PendingAction::Update(id, payload));
     }
@@ -159,6 +206,24 @@ where
     }
 }
 
+/// Check that `url` is safe to pass to the platform's URL handler
+fn check_url(url: &str) -> Result<(), &'static str> {
+    if url.starts_with('-') {
+        return Err("URL may not start with '-'");
+    }
+    if url.chars().any(|c| c.is_control() || c.is_whitespace()) {
+        return Err("URL may not contain whitespace or control characters");
+    }
+    let scheme = match url.split_once(':') {
+        Some((scheme, _)) => scheme.to_ascii_lowercase(),
+        None => return Err("URL has no scheme"),
+    };
+    match scheme.as_str() {
+        "http" | "https" | "mailto" => Ok(()),
+        _ => Err("unsupported URL scheme"),
+    }
+}
+
 pub enum PendingAction {
     AddPopup(winit::window::WindowId, WindowId, kas::Popup),
     AddWindow(WindowId, Box<dyn kas::Window>),
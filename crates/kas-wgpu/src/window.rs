@@ -8,7 +8,7 @@
 use log::{debug, error, info, trace};
 use std::time::Instant;
 
-use kas::cast::Cast;
+use kas::cast::{Cast, CastFloat, Conv};
 use kas::draw::{AnimationState, DrawIface, DrawShared, PassId};
 use kas::event::{CursorIcon, EventState, UpdateId};
 use kas::geom::{Coord, Rect, Size};
@@ -40,6 +40,8 @@ pub(crate) struct Window<C: CustomPipe, T: Theme<DrawPipe<C>>> {
     theme_window: T::Window,
     next_avail_frame_time: Instant,
     queued_frame_time: Option<Instant>,
+    /// Redraw the whole window, regardless of damage reported by `ev_state`
+    full_redraw: bool,
 }
 
 // Public functions, for use by the toolkit
@@ -85,25 +87,27 @@ impl<C: CustomPipe, T: Theme<DrawPipe<C>>> Window<C, T> {
         let size_mgr = SizeMgr::new(theme_window.size_handle());
         let mut solve_cache = SolveCache::find_constraints(widget.as_widget_mut(), size_mgr);
 
-        // Opening a zero-size window causes a crash, so force at least 1x1:
-        let ideal = solve_cache.ideal(true).max(Size(1, 1));
-        let ideal = match use_logical_size {
-            false => ideal.as_physical(),
-            true => ideal.as_logical(),
+        let (min, max) = size_limits(&widget, &solve_cache, scale_factor);
+        let as_dpi_size = |size: Size| match use_logical_size {
+            false => size.as_physical(),
+            true => size.as_logical(),
         };
 
-        let mut builder = WindowBuilder::new().with_inner_size(ideal);
-        let restrict_dimensions = widget.restrict_dimensions();
-        if restrict_dimensions.0 {
-            let min = solve_cache.min(true);
-            let min = match use_logical_size {
-                false => min.as_physical(),
-                true => min.as_logical(),
-            };
-            builder = builder.with_min_inner_size(min);
+        // Opening a zero-size window causes a crash, so force at least 1x1:
+        let mut ideal = solve_cache.ideal(true).max(Size(1, 1));
+        if let Some(min) = min {
+            ideal = ideal.max(min);
+        }
+        if let Some(max) = max {
+            ideal = ideal.min(max);
+        }
+
+        let mut builder = WindowBuilder::new().with_inner_size(as_dpi_size(ideal));
+        if let Some(min) = min {
+            builder = builder.with_min_inner_size(as_dpi_size(min));
         }
-        if restrict_dimensions.1 {
-            builder = builder.with_max_inner_size(ideal);
+        if let Some(max) = max {
+            builder = builder.with_max_inner_size(as_dpi_size(max));
         }
         let window = builder
             .with_title(widget.title())
@@ -153,6 +157,7 @@ impl<C: CustomPipe, T: Theme<DrawPipe<C>>> Window<C, T> {
             theme_window,
             next_avail_frame_time: time,
             queued_frame_time: Some(time),
+            full_redraw: true,
         };
         r.apply_size(shared, true);
 
@@ -205,7 +210,7 @@ impl<C: CustomPipe, T: Theme<DrawPipe<C>>> Window<C, T> {
         if action.contains(TkAction::CLOSE | TkAction::EXIT) {
             return (action, None);
         }
-        self.handle_action(shared, action);
+        self.apply_action(shared, action);
 
         let mut resume = self.ev_state.next_resume();
 
@@ -223,6 +228,15 @@ impl<C: CustomPipe, T: Theme<DrawPipe<C>>> Window<C, T> {
 
     /// Handle an action (excludes handling of CLOSE and EXIT)
     pub fn handle_action(&mut self, shared: &mut SharedState<C, T>, action: TkAction) {
+        // This action did not come through ev_state, thus is not reflected in
+        // its damage tracking
+        if !action.is_empty() {
+            self.full_redraw = true;
+        }
+        self.apply_action(shared, action);
+    }
+
+    fn apply_action(&mut self, shared: &mut SharedState<C, T>, action: TkAction) {
         if action.contains(TkAction::RECONFIGURE) {
             self.reconfigure(shared);
         }
@@ -334,14 +348,13 @@ impl<C: CustomPipe, T: Theme<DrawPipe<C>>> Window<C, T> {
         solve_cache.apply_rect(widget.as_widget_mut(), &mut mgr, rect, true, first);
         widget.resize_popups(&mut mgr);
 
-        let restrict_dimensions = self.widget.restrict_dimensions();
-        if restrict_dimensions.0 {
-            let min = self.solve_cache.min(true).as_physical();
-            self.window.set_min_inner_size(Some(min));
+        let scale_factor = self.window.scale_factor() as f32;
+        let (min, max) = size_limits(&self.widget, &self.solve_cache, scale_factor);
+        if let Some(min) = min {
+            self.window.set_min_inner_size(Some(min.as_physical()));
         };
-        if restrict_dimensions.1 {
-            let ideal = self.solve_cache.ideal(true).as_physical();
-            self.window.set_max_inner_size(Some(ideal));
+        if let Some(max) = max {
+            self.window.set_max_inner_size(Some(max.as_physical()));
         };
 
         self.window.request_redraw();
@@ -405,6 +418,7 @@ impl<C: CustomPipe, T: Theme<DrawPipe<C>>> Window<C, T> {
             }
         }
 
+        let animating = !matches!(self.draw.animation, AnimationState::None);
         self.queued_frame_time = match self.draw.animation {
             AnimationState::None => None,
             AnimationState::Animate => Some(self.next_avail_frame_time),
@@ -430,8 +444,12 @@ impl<C: CustomPipe, T: Theme<DrawPipe<C>>> Window<C, T> {
         // TODO: check frame.suboptimal ?
         let view = frame.texture.create_view(&Default::default());
 
+        let damage = self.ev_state.take_damage();
+        let damage = damage.filter(|_| !self.full_redraw);
         let clear_color = to_wgpu_color(shared.theme.clear_color());
-        shared.render(&mut self.draw, &view, clear_color);
+        shared.render(&mut self.draw, &view, clear_color, damage);
+        // Animated content changes outside of any reported damage
+        self.full_redraw = animating;
 
         frame.present();
 
@@ -467,6 +485,55 @@ fn to_wgpu_color(c: kas::draw::color::Rgba) -> wgpu::Color {
     }
 }
 
+/// Find `(min, max)` limits on the window's inner size
+///
+/// Sizes are in the units used by `solve_cache`, with explicit limits from
+/// [`kas::Window::size_limits`] (in logical pixels) scaled by `scale_factor`.
+fn size_limits(
+    widget: &kas::RootWidget,
+    solve_cache: &SolveCache,
+    scale_factor: f32,
+) -> (Option<Size>, Option<Size>) {
+    let sizes = (solve_cache.min(true), solve_cache.ideal(true));
+    let restrict = widget.restrict_dimensions();
+    resolve_size_limits(sizes, restrict, widget.size_limits(), scale_factor)
+}
+
+/// Resolve `(min, max)` size limits
+///
+/// Inputs are the `(min, ideal)` sizes of the content, whether these restrict
+/// the `(min, max)` size, and optional explicit `(min, max)` limits. The
+/// greater of the two minimums is used; an explicit maximum takes precedence
+/// but is raised to the minimum if less.
+fn resolve_size_limits(
+    sizes: (Size, Size),
+    restrict: (bool, bool),
+    limits: (Option<Size>, Option<Size>),
+    scale_factor: f32,
+) -> (Option<Size>, Option<Size>) {
+    let scale = |size: Size| {
+        Size(
+            (f32::conv(size.0) * scale_factor).cast_nearest(),
+            (f32::conv(size.1) * scale_factor).cast_nearest(),
+        )
+    };
+
+    let mut min = restrict.0.then(|| sizes.0);
+    if let Some(size) = limits.0.map(scale) {
+        min = Some(min.map(|min| min.max(size)).unwrap_or(size));
+    }
+
+    let mut max = limits.1.map(scale);
+    if max.is_none() && restrict.1 {
+        max = Some(sizes.1);
+    }
+    if let (Some(min), Some(max)) = (min, max.as_mut()) {
+        *max = (*max).max(min);
+    }
+
+    (min, max)
+}
+
 struct TkWindow<'a, C: CustomPipe, T: Theme<DrawPipe<C>>>
 where
     T::Window: kas_theme::Window,
@@ -531,16 +598,26 @@ where
         self.shared.trigger_update(id, payload);
     }
 
+    #[inline]
+    fn waker(&self) -> std::task::Waker {
+        self.shared.waker.clone()
+    }
+
     #[inline]
     fn get_clipboard(&mut self) -> Option<String> {
         self.shared.get_clipboard()
     }
 
     #[inline]
-    fn set_clipboard<'c>(&mut self, content: String) {
+    fn set_clipboard(&mut self, content: String) {
         self.shared.set_clipboard(content);
     }
 
+    #[inline]
+    fn open_url(&mut self, url: &str) {
+        self.shared.open_url(url);
+    }
+
     fn adjust_theme(&mut self, f: &mut dyn FnMut(&mut dyn ThemeControl) -> TkAction) {
         let action = f(&mut self.shared.theme);
         self.shared.pending.push(PendingAction::TkAction(action));
@@ -562,3 +639,45 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn size_limits_unset() {
+        let sizes = (Size(100, 50), Size(200, 100));
+        let limits = resolve_size_limits(sizes, (false, false), (None, None), 2.0);
+        assert_eq!(limits, (None, None));
+
+        let limits = resolve_size_limits(sizes, (true, true), (None, None), 2.0);
+        assert_eq!(limits, (Some(sizes.0), Some(sizes.1)));
+    }
+
+    #[test]
+    fn size_limits_explicit() {
+        let sizes = (Size(100, 50), Size(200, 100));
+        let limits = (Some(Size(60, 40)), Some(Size(150, 150)));
+
+        // Explicit limits are scaled; the greater minimum applies per axis
+        let result = resolve_size_limits(sizes, (false, false), limits, 1.5);
+        assert_eq!(result, (Some(Size(90, 60)), Some(Size(225, 225))));
+        let result = resolve_size_limits(sizes, (true, true), limits, 1.5);
+        assert_eq!(result, (Some(Size(100, 60)), Some(Size(225, 225))));
+    }
+
+    #[test]
+    fn size_limits_min_exceeds_max() {
+        let sizes = (Size(100, 50), Size(200, 100));
+        let limits = (Some(Size(80, 80)), Some(Size(60, 100)));
+
+        // Max is raised to min on each axis
+        let result = resolve_size_limits(sizes, (false, false), limits, 1.0);
+        assert_eq!(result, (Some(Size(80, 80)), Some(Size(80, 100))));
+
+        // Also where the content minimum exceeds an explicit maximum
+        let limits = (None, Some(Size(60, 60)));
+        let result = resolve_size_limits(sizes, (true, false), limits, 1.0);
+        assert_eq!(result, (Some(Size(100, 50)), Some(Size(100, 60))));
+    }
+}
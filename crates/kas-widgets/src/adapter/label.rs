@@ -78,16 +78,19 @@ impl_scope! {
 
         /// Enable/disable line wrapping
         ///
+        /// See [`crate::Label::set_wrap`].
+        ///
         /// By default this is enabled.
         #[inline]
-        pub fn set_wrap(&mut self, wrap: bool) {
-            self.label.set_wrap(wrap);
+        pub fn set_wrap(&mut self, wrap: bool) -> TkAction {
+            self.label.set_wrap(wrap)
         }
 
         /// Enable/disable line wrapping (inline)
         #[inline]
+        #[must_use]
         pub fn with_wrap(mut self, wrap: bool) -> Self {
-            self.label.set_wrap(wrap);
+            self.label = self.label.with_wrap(wrap);
             self
         }
 
@@ -122,5 +125,10 @@ impl_scope! {
         fn set_accel_string(&mut self, string: AccelString) -> TkAction {
             self.label.set_accel_string(string)
         }
+
+        #[inline]
+        fn set_mnemonic(&mut self, c: Option<char>) -> TkAction {
+            self.label.set_mnemonic(c)
+        }
     }
 }
@@ -42,6 +42,25 @@ impl_scope! {
                 _n: PhantomData,
             }
         }
+
+        /// Compose an additional message map
+        ///
+        /// Returns a single `MapMessage` which applies `self`'s map and then
+        /// `g`, avoiding nested wrappers when chaining transformations, e.g.
+        /// `widget.map_msg(|m: M| ..).map(|n| ..)`. The argument type of `g`
+        /// is inferred from the current output type `N`.
+        ///
+        /// This should be called during construction (before the widget is
+        /// configured).
+        #[must_use]
+        pub fn map<O, G>(self, mut g: G) -> MapMessage<W, M, O, impl FnMut(M) -> O + 'static>
+        where
+            O: Debug + 'static,
+            G: FnMut(N) -> O + 'static,
+        {
+            let mut f = self.map;
+            MapMessage::new(self.inner, move |msg| g(f(msg)))
+        }
     }
 
     impl Widget for Self {
@@ -110,6 +110,10 @@ impl_scope! {
     }
 
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::Button
+        }
+
         fn configure(&mut self, mgr: &mut SetRectMgr) {
             mgr.add_accel_keys(self.id_ref(), &self.keys1);
         }
@@ -234,9 +238,22 @@ impl_scope! {
         fn set_accel_string(&mut self, string: AccelString) -> TkAction {
             self.label.set_accel_string(string)
         }
+
+        #[inline]
+        fn set_mnemonic(&mut self, c: Option<char>) -> TkAction {
+            self.label.set_mnemonic(c)
+        }
     }
 
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::Button
+        }
+
+        fn accessible_label(&self) -> Option<String> {
+            Some(self.label.get_str().to_string())
+        }
+
         fn configure(&mut self, mgr: &mut SetRectMgr) {
             mgr.add_accel_keys(self.id_ref(), &self.keys1);
             mgr.add_accel_keys(self.id_ref(), self.label.keys());
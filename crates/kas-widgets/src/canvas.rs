@@ -0,0 +1,74 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE-APACHE file or at:
+//     https://www.apache.org/licenses/LICENSE-2.0
+
+//! Canvas widget
+
+use kas::prelude::*;
+
+impl_scope! {
+    /// A canvas with a user-defined draw callback
+    ///
+    /// This widget draws via a closure using the [`DrawMgr`] API (rects,
+    /// frames, text, etc.), avoiding the need for a custom draw pipe for
+    /// simple custom graphics. Size requirements are provided by a second
+    /// closure.
+    ///
+    /// The draw callback is passed the widget's rect; drawing is clipped to
+    /// this rect. The callback is not called when the widget has zero size.
+    ///
+    /// (Unlike `kas_resvg::Canvas`, this does not render to a pixmap: the
+    /// callback is called each time the widget is drawn.)
+    #[autoimpl(Debug ignore self.size_fn, self.draw_fn)]
+    #[widget]
+    pub struct Canvas {
+        core: widget_core!(),
+        size_fn: Box<dyn FnMut(SizeMgr, AxisInfo) -> SizeRules>,
+        draw_fn: Box<dyn FnMut(&mut DrawMgr, Rect)>,
+    }
+
+    impl Layout for Self {
+        fn size_rules(&mut self, size_mgr: SizeMgr, axis: AxisInfo) -> SizeRules {
+            (self.size_fn)(size_mgr, axis)
+        }
+
+        fn draw(&mut self, mut draw: DrawMgr) {
+            let rect = self.core.rect;
+            if rect.size.0 <= 0 || rect.size.1 <= 0 {
+                return;
+            }
+            let draw_fn = &mut self.draw_fn;
+            draw.with_clip_region(rect, Offset::ZERO, |mut draw| draw_fn(&mut draw, rect));
+        }
+    }
+
+    impl Self {
+        /// Construct with size and draw callbacks
+        ///
+        /// `size_fn` is called from [`Layout::size_rules`] and `draw_fn` from
+        /// [`Layout::draw`].
+        #[inline]
+        pub fn new(
+            size_fn: impl FnMut(SizeMgr, AxisInfo) -> SizeRules + 'static,
+            draw_fn: impl FnMut(&mut DrawMgr, Rect) + 'static,
+        ) -> Self {
+            Canvas {
+                core: Default::default(),
+                size_fn: Box::new(size_fn),
+                draw_fn: Box::new(draw_fn),
+            }
+        }
+
+        /// Replace the draw callback
+        ///
+        /// The widget must be redrawn for this to take effect.
+        pub fn set_draw_fn(
+            &mut self,
+            draw_fn: impl FnMut(&mut DrawMgr, Rect) + 'static,
+        ) -> TkAction {
+            self.draw_fn = Box::new(draw_fn);
+            TkAction::REDRAW
+        }
+    }
+}
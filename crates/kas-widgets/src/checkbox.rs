@@ -134,6 +134,10 @@ impl_scope! {
     }
 
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::CheckBox
+        }
+
         fn handle_event(&mut self, mgr: &mut EventMgr, event: Event) -> Response {
             event.on_activate(mgr, self.id(), |mgr| {
                 self.state = !self.state;
@@ -170,6 +174,14 @@ impl_scope! {
     }
 
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::CheckBox
+        }
+
+        fn accessible_label(&self) -> Option<String> {
+            Some(self.label.get_str().to_string())
+        }
+
         fn configure(&mut self, mgr: &mut SetRectMgr) {
             mgr.add_accel_keys(self.inner.id_ref(), self.label.keys());
         }
@@ -50,6 +50,14 @@ impl_scope! {
     }
 
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::ComboBox
+        }
+
+        fn accessible_label(&self) -> Option<String> {
+            Some(self.label.get_str().to_string())
+        }
+
         fn pre_configure(&mut self, mgr: &mut SetRectMgr, id: WidgetId) {
             self.core.id = id;
             mgr.new_accel_layer(self.id(), true);
@@ -62,11 +70,8 @@ impl_scope! {
 
         fn handle_event(&mut self, mgr: &mut EventMgr, event: Event) -> Response {
             let open_popup = |s: &mut Self, mgr: &mut EventMgr, key_focus: bool| {
-                s.popup_id = mgr.add_popup(kas::Popup {
-                    id: s.popup.id(),
-                    parent: s.id(),
-                    direction: Direction::Down,
-                });
+                let popup = kas::Popup::new(s.popup.id(), s.id(), Direction::Down);
+                s.popup_id = mgr.add_popup(popup);
                 if let Some(id) = s.popup.inner.inner.get_child(s.active).map(|w| w.id()) {
                     mgr.set_nav_focus(id, key_focus);
                 }
@@ -24,12 +24,23 @@ impl_scope! {
     pub struct Window<W: Widget> {
         core: widget_core!(),
         restrict_dimensions: (bool, bool),
+        size_limits: (Option<Size>, Option<Size>),
         title: String,
         #[widget]
         inner: W,
         icon: Option<Icon>,
     }
 
+    impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::Window
+        }
+
+        fn accessible_label(&self) -> Option<String> {
+            Some(self.title.clone())
+        }
+    }
+
     impl<W: Widget> kas::Window for Window<W> {
         fn title(&self) -> &str {
             &self.title
@@ -42,6 +53,10 @@ impl_scope! {
         fn restrict_dimensions(&self) -> (bool, bool) {
             self.restrict_dimensions
         }
+
+        fn size_limits(&self) -> (Option<Size>, Option<Size>) {
+            self.size_limits
+        }
     }
 }
 
@@ -51,6 +66,7 @@ impl<W: Widget> Window<W> {
         Window {
             core: Default::default(),
             restrict_dimensions: (true, false),
+            size_limits: (None, None),
             title: title.to_string(),
             inner,
             icon: None,
@@ -64,6 +80,28 @@ impl<W: Widget> Window<W> {
         self.restrict_dimensions = (min, max);
     }
 
+    /// Set the minimum window size (inline)
+    ///
+    /// The size is in logical pixels. The window may not be made smaller than
+    /// this, nor than the minimum size of its content.
+    /// See [`kas::Window::size_limits`].
+    #[must_use]
+    pub fn with_min_size(mut self, size: Size) -> Self {
+        self.size_limits.0 = Some(size);
+        self
+    }
+
+    /// Set the maximum window size (inline)
+    ///
+    /// The size is in logical pixels. This replaces any maximum implied by
+    /// [`Self::set_restrict_dimensions`].
+    /// See [`kas::Window::size_limits`].
+    #[must_use]
+    pub fn with_max_size(mut self, size: Size) -> Self {
+        self.size_limits.1 = Some(size);
+        self
+    }
+
     /// Set the window icon
     pub fn set_icon(&mut self, icon: Option<Icon>) {
         self.icon = icon;
@@ -160,7 +198,7 @@ impl_scope! {
                 core: Default::default(),
                 title: title.into(),
                 data,
-                edit: EditBox::new(text).multi_line(multi_line),
+                edit: EditBox::new(text).with_multi_line(multi_line),
             }
         }
 
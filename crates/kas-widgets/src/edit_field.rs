@@ -7,7 +7,7 @@
 
 use super::Scrollable;
 use kas::event::components::{TextInput, TextInputAction};
-use kas::event::{Command, CursorIcon, Scroll, ScrollDelta};
+use kas::event::{Command, CursorIcon, Scroll};
 use kas::geom::Vec2;
 use kas::prelude::*;
 use kas::text::{NotReady, SelectionHelper};
@@ -166,6 +166,12 @@ impl_scope! {
         inner: EditField<G>,
     }
 
+    impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::TextInput
+        }
+    }
+
     impl Layout for Self {
         fn draw(&mut self, mut draw: DrawMgr) {
             let mut draw = draw.re_id(self.inner.id());
@@ -285,10 +291,30 @@ impl<G: EditGuard> EditBox<G> {
     }
 
     /// Set whether this `EditBox` shows multiple text lines
+    ///
+    /// See [`EditField::with_multi_line`].
     #[inline]
     #[must_use]
-    pub fn multi_line(mut self, multi_line: bool) -> Self {
-        self.inner = self.inner.multi_line(multi_line);
+    pub fn with_multi_line(mut self, multi_line: bool) -> Self {
+        self.inner = self.inner.with_multi_line(multi_line);
+        self
+    }
+
+    /// Set whether this `EditBox` shows multiple text lines
+    #[inline]
+    #[must_use]
+    #[deprecated(since = "0.11.0", note = "use with_multi_line")]
+    pub fn multi_line(self, multi_line: bool) -> Self {
+        self.with_multi_line(multi_line)
+    }
+
+    /// Set whether <kbd>Tab</kbd> inserts a tab character
+    ///
+    /// See [`EditField::with_tab_inserts`].
+    #[inline]
+    #[must_use]
+    pub fn with_tab_inserts(mut self, tab_inserts: bool) -> Self {
+        self.inner = self.inner.with_tab_inserts(tab_inserts);
         self
     }
 
@@ -325,9 +351,15 @@ impl_scope! {
     /// This widget is intended for use with short input strings. Internally it
     /// uses a [`String`], for which edits have `O(n)` cost.
     ///
-    /// Optionally, [`EditField::multi_line`] mode can be activated (enabling
+    /// Optionally, [`EditField::with_multi_line`] mode can be activated (enabling
     /// line-wrapping and a larger vertical height). This mode is only recommended
     /// for short texts for performance reasons.
+    ///
+    /// In single-line mode, <kbd>Enter</kbd> activates the field. In multi-line
+    /// mode, <kbd>Enter</kbd> inserts a line break while <kbd>Shift+Enter</kbd>
+    /// or <kbd>Ctrl+Enter</kbd> activates the field. <kbd>Tab</kbd> moves
+    /// keyboard focus to the next widget unless [`EditField::with_tab_inserts`]
+    /// is enabled in multi-line mode.
     #[derive(Clone, Default, Debug)]
     #[widget{
         key_nav = true;
@@ -339,6 +371,7 @@ impl_scope! {
         view_offset: Offset,
         editable: bool,
         multi_line: bool,
+        tab_inserts: bool,
         text: Text<String>,
         required: Vec2,
         selection: SelectionHelper,
@@ -401,6 +434,10 @@ impl_scope! {
     }
 
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::TextInput
+        }
+
         #[inline]
         fn translation(&self) -> Offset {
             self.scroll_offset()
@@ -461,14 +498,8 @@ impl_scope! {
                     }
                 },
                 Event::Scroll(delta) => {
-                    let delta2 = match delta {
-                        ScrollDelta::LineDelta(x, y) => {
-                            // We arbitrarily scroll 3 lines:
-                            let dist = 3.0 * self.text.env().height(Default::default());
-                            Offset((x * dist).cast_nearest(), (y * dist).cast_nearest())
-                        }
-                        ScrollDelta::PixelDelta(coord) => coord,
-                    };
+                    let line_height = self.text.env().height(Default::default());
+                    let delta2 = delta.to_offset(mgr.config(), Some(line_height));
                     self.pan_delta(mgr, delta2)
                 }
                 event => match self.input_handler.handle(mgr, self.id(), event) {
@@ -565,6 +596,7 @@ impl EditField<()> {
             view_offset: Default::default(),
             editable: true,
             multi_line: false,
+            tab_inserts: false,
             text: Text::new(Default::default(), text),
             required: Vec2::ZERO,
             selection: SelectionHelper::new(len, len),
@@ -593,6 +625,7 @@ impl EditField<()> {
             view_offset: self.view_offset,
             editable: self.editable,
             multi_line: self.multi_line,
+            tab_inserts: self.tab_inserts,
             text: self.text,
             required: self.required,
             selection: self.selection,
@@ -679,13 +712,37 @@ impl<G: EditGuard> EditField<G> {
     }
 
     /// Set whether this `EditField` shows multiple text lines
+    ///
+    /// In multi-line mode, <kbd>Enter</kbd> inserts a line break; use
+    /// <kbd>Shift+Enter</kbd> or <kbd>Ctrl+Enter</kbd> to activate.
     #[inline]
     #[must_use]
-    pub fn multi_line(mut self, multi_line: bool) -> Self {
+    pub fn with_multi_line(mut self, multi_line: bool) -> Self {
         self.multi_line = multi_line;
         self
     }
 
+    /// Set whether this `EditField` shows multiple text lines
+    #[inline]
+    #[must_use]
+    #[deprecated(since = "0.11.0", note = "use with_multi_line")]
+    pub fn multi_line(self, multi_line: bool) -> Self {
+        self.with_multi_line(multi_line)
+    }
+
+    /// Set whether <kbd>Tab</kbd> inserts a tab character
+    ///
+    /// This only has effect in multi-line mode (see
+    /// [`Self::with_multi_line`]). By default (and always in single-line mode),
+    /// <kbd>Tab</kbd> moves keyboard focus to the next widget. Note that
+    /// enabling this prevents leaving the field using the <kbd>Tab</kbd> key.
+    #[inline]
+    #[must_use]
+    pub fn with_tab_inserts(mut self, tab_inserts: bool) -> Self {
+        self.tab_inserts = tab_inserts;
+        self
+    }
+
     /// Get whether the widget currently has keyboard input focus
     #[inline]
     pub fn has_key_focus(&self) -> bool {
@@ -766,13 +823,16 @@ impl<G: EditGuard> EditField<G> {
                 Action::None
             }
             Command::Activate => Action::Activate,
-            Command::Return if shift || !self.multi_line => Action::Activate,
+            Command::Return if shift || mgr.modifiers().ctrl() || !self.multi_line => {
+                Action::Activate
+            }
             Command::Return if self.multi_line => {
                 Action::Insert('\n'.encode_utf8(&mut buf), LastEdit::Insert)
             }
-            // NOTE: we might choose to optionally handle Tab in the future,
-            // but without some workaround it prevents keyboard navigation.
-            // Command::Tab => Action::Insert('\t'.encode_utf8(&mut buf), LastEdit::Insert),
+            // NOTE: when unused, Tab is used for keyboard navigation
+            Command::Tab if self.multi_line && self.tab_inserts => {
+                Action::Insert('\t'.encode_utf8(&mut buf), LastEdit::Insert)
+            }
             Command::Left => {
                 let mut cursor = GraphemeCursor::new(pos, self.text.str_len(), true);
                 cursor
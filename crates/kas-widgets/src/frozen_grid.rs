@@ -0,0 +1,269 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE-APACHE file or at:
+//     https://www.apache.org/licenses/LICENSE-2.0
+
+//! A scrollable grid with frozen headers
+
+use super::{Grid, Scrollable};
+use kas::event::{components::ScrollComponent, Scroll};
+use kas::layout::GridChildInfo;
+use kas::prelude::*;
+use kas::theme::TextClass;
+
+/// Which axes a cell is pinned on: `(horizontally, vertically)`
+fn pinning(info: &GridChildInfo, cols: u32, rows: u32) -> (bool, bool) {
+    (info.col_end <= cols, info.row_end <= rows)
+}
+
+impl_scope! {
+    /// A scrollable grid with frozen header rows and columns
+    ///
+    /// This is a [`Grid`] within a scroll region (similar to
+    /// [`crate::ScrollRegion`]), except that the first
+    /// [`Self::with_frozen_rows`] rows and [`Self::with_frozen_cols`] columns
+    /// are kept in view: frozen rows scroll only horizontally, frozen columns
+    /// only vertically and cells in both (the corner) do not scroll.
+    ///
+    /// Since headers and body are laid out by the same grid, header cells stay
+    /// exactly aligned with body columns (and rows). A cell spanning both
+    /// frozen and non-frozen rows (or columns) scrolls with the body.
+    ///
+    /// Scrollbars are not included; use [`crate::ScrollBars`] if you want those.
+    #[derive(Clone, Debug, Default)]
+    #[widget]
+    pub struct FrozenGrid<W: Widget> {
+        core: widget_core!(),
+        frozen_rows: u32,
+        frozen_cols: u32,
+        min_child_size: Size,
+        offset: Offset,
+        frame_size: Size,
+        header_size: Size,
+        // (index, unpinned rect, pinning) of cells in frozen rows or columns
+        pinned: Vec<(usize, Rect, (bool, bool))>,
+        scroll: ScrollComponent,
+        #[widget]
+        grid: Grid<W>,
+    }
+
+    impl Self {
+        /// Construct around a grid
+        #[inline]
+        pub fn new(grid: Grid<W>) -> Self {
+            FrozenGrid {
+                core: Default::default(),
+                frozen_rows: 0,
+                frozen_cols: 0,
+                min_child_size: Size::ZERO,
+                offset: Default::default(),
+                frame_size: Default::default(),
+                header_size: Size::ZERO,
+                pinned: vec![],
+                scroll: Default::default(),
+                grid,
+            }
+        }
+
+        /// Set the number of frozen header rows (inline)
+        #[inline]
+        #[must_use]
+        pub fn with_frozen_rows(mut self, rows: u32) -> Self {
+            self.frozen_rows = rows;
+            self
+        }
+
+        /// Set the number of frozen header columns (inline)
+        #[inline]
+        #[must_use]
+        pub fn with_frozen_cols(mut self, cols: u32) -> Self {
+            self.frozen_cols = cols;
+            self
+        }
+
+        /// Access the grid directly
+        #[inline]
+        pub fn inner(&self) -> &Grid<W> {
+            &self.grid
+        }
+
+        /// Access the grid directly
+        #[inline]
+        pub fn inner_mut(&mut self) -> &mut Grid<W> {
+            &mut self.grid
+        }
+
+        // Move pinned cells such that they appear stationary despite the
+        // scroll offset (on pinned axes).
+        fn pin_headers(&mut self, mgr: &mut SetRectMgr) {
+            let offset = self.scroll.offset();
+            for (index, rect, pin) in self.pinned.iter().cloned() {
+                let shift = Offset(
+                    if pin.0 { offset.0 } else { 0 },
+                    if pin.1 { offset.1 } else { 0 },
+                );
+                if let Some(w) = self.grid.get_mut(index) {
+                    w.set_rect(mgr, rect + shift, AlignHints::NONE);
+                }
+            }
+        }
+    }
+
+    impl Scrollable for Self {
+        fn scroll_axes(&self, size: Size) -> (bool, bool) {
+            (
+                self.min_child_size.0 > size.0,
+                self.min_child_size.1 > size.1,
+            )
+        }
+
+        #[inline]
+        fn max_scroll_offset(&self) -> Offset {
+            self.scroll.max_offset()
+        }
+
+        #[inline]
+        fn scroll_offset(&self) -> Offset {
+            self.scroll.offset()
+        }
+
+        fn set_scroll_offset(&mut self, mgr: &mut EventMgr, offset: Offset) -> Offset {
+            let action = self.scroll.set_offset(offset);
+            if !action.is_empty() {
+                *mgr |= action;
+                mgr.set_rect_mgr(|mgr| self.pin_headers(mgr));
+            }
+            self.scroll.offset()
+        }
+
+        #[inline]
+        fn draws_clipped(&self) -> bool {
+            true
+        }
+    }
+
+    impl Layout for Self {
+        fn size_rules(&mut self, size_mgr: SizeMgr, axis: AxisInfo) -> SizeRules {
+            let mut rules = self.grid.size_rules(size_mgr.re(), axis);
+            self.min_child_size.set_component(axis, rules.min_size());
+            let line_height = size_mgr.line_height(TextClass::Label(false));
+            rules.reduce_min_to(line_height);
+
+            // We use a frame to contain the content margin within the scrollable area.
+            let frame = kas::layout::FrameRules::new(0, 0, 0, (0, 0));
+            let (rules, offset, size) = frame.surround_with_margin(rules);
+            self.offset.set_component(axis, offset);
+            self.frame_size.set_component(axis, size);
+            rules
+        }
+
+        fn set_rect(&mut self, mgr: &mut SetRectMgr, rect: Rect, _: AlignHints) {
+            self.core.rect = rect;
+            let child_size = (rect.size - self.frame_size).max(self.min_child_size);
+            let child_rect = Rect::new(rect.pos + self.offset, child_size);
+            self.grid.set_rect(mgr, child_rect, AlignHints::NONE);
+            let _ = self
+                .scroll
+                .set_sizes(rect.size, child_size + self.frame_size);
+
+            // Headers extend to the far edge of the last frozen cell
+            let (cols, rows) = (self.frozen_cols, self.frozen_rows);
+            let mut end = rect.pos;
+            self.pinned.clear();
+            for (index, (info, w)) in self.grid.iter().enumerate() {
+                let pin = pinning(info, cols, rows);
+                if pin.0 || pin.1 {
+                    let r = w.rect();
+                    if pin.0 {
+                        end.0 = end.0.max(r.pos.0 + r.size.0);
+                    }
+                    if pin.1 {
+                        end.1 = end.1.max(r.pos.1 + r.size.1);
+                    }
+                    self.pinned.push((index, r, pin));
+                }
+            }
+            let header = end - rect.pos;
+            self.header_size = Size(header.0, header.1).min(rect.size);
+
+            self.pin_headers(mgr);
+        }
+
+        fn find_id(&mut self, coord: Coord) -> Option<WidgetId> {
+            if !self.rect().contains(coord) {
+                return None;
+            }
+
+            // The region under coord determines which cells may be found: body
+            // cells scrolled under a header are hidden.
+            let rel = coord - self.core.rect.pos;
+            let region = (rel.0 < self.header_size.0, rel.1 < self.header_size.1);
+            let (cols, rows) = (self.frozen_cols, self.frozen_rows);
+            let coord = coord + self.translation();
+            self.grid
+                .iter_mut()
+                .filter(|(info, _)| pinning(info, cols, rows) == region)
+                .find_map(|(_, w)| w.find_id(coord))
+                .or_else(|| Some(self.id()))
+        }
+
+        fn draw(&mut self, mut draw: DrawMgr) {
+            let rect = self.core.rect;
+            let header = self.header_size;
+            let offset = self.scroll.offset();
+            let (cols, rows) = (self.frozen_cols, self.frozen_rows);
+
+            // Draw the body first, then headers, each clipped to its region
+            for region in [(false, false), (false, true), (true, false), (true, true)] {
+                let (x0, x1) = match region.0 {
+                    false => (rect.pos.0 + header.0, rect.pos.0 + rect.size.0),
+                    true => (rect.pos.0, rect.pos.0 + header.0),
+                };
+                let (y0, y1) = match region.1 {
+                    false => (rect.pos.1 + header.1, rect.pos.1 + rect.size.1),
+                    true => (rect.pos.1, rect.pos.1 + header.1),
+                };
+                if x1 <= x0 || y1 <= y0 {
+                    continue;
+                }
+
+                let clip = Rect::new(Coord(x0, y0), Size(x1 - x0, y1 - y0));
+                let grid = &mut self.grid;
+                draw.with_clip_region(clip, offset, |mut draw| {
+                    for (info, w) in grid.iter_mut() {
+                        if pinning(info, cols, rows) == region {
+                            draw.recurse(w);
+                        }
+                    }
+                });
+            }
+        }
+    }
+
+    impl Widget for Self {
+        fn configure(&mut self, mgr: &mut SetRectMgr) {
+            mgr.register_nav_fallback(self.id());
+        }
+
+        #[inline]
+        fn translation(&self) -> Offset {
+            self.scroll.offset()
+        }
+
+        fn handle_event(&mut self, mgr: &mut EventMgr, event: Event) -> Response {
+            let (moved, r) = self.scroll.scroll_by_event(mgr, event, self.id(), self.core.rect);
+            if moved {
+                mgr.set_rect_mgr(|mgr| self.pin_headers(mgr));
+            }
+            r
+        }
+
+        fn handle_scroll(&mut self, mgr: &mut EventMgr, scroll: Scroll) {
+            let old_offset = self.scroll.offset();
+            self.scroll.scroll(mgr, self.rect(), scroll);
+            if self.scroll.offset() != old_offset {
+                mgr.set_rect_mgr(|mgr| self.pin_headers(mgr));
+            }
+        }
+    }
+}
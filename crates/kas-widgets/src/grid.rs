@@ -30,9 +30,10 @@ impl_scope! {
     /// Note that all child widgets are stored in a list internally. The order of
     /// widgets in that list does not affect display position, but does have a few
     /// effects: (a) widgets may be accessed in this order via indexing, (b) widgets
-    /// are configured and drawn in this order, (c) navigating
-    /// through widgets with the Tab key currently uses the list order (though it
-    /// may be changed in the future to use display order).
+    /// are configured and drawn in this order. Navigating through widgets with
+    /// the Tab key uses display order: along each row, then wrapping to the
+    /// next row. A widget spanning multiple cells is visited once, at its
+    /// first (top-left) cell.
     ///
     /// There is no protection against multiple widgets occupying the same cell.
     /// If this does happen, the last widget in that cell will appear on top, but
@@ -109,6 +110,27 @@ impl_scope! {
     }
 
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::Grid
+        }
+
+        fn spatial_nav(
+            &mut self,
+            _: &mut SetRectMgr,
+            reverse: bool,
+            from: Option<usize>,
+        ) -> Option<usize> {
+            // Sort into row-major display order; stable sort preserves list
+            // order of widgets starting in the same cell.
+            let mut order: Vec<usize> = (0..self.widgets.len()).collect();
+            order.sort_by_key(|i| {
+                let info = self.widgets[*i].0;
+                (info.row, info.col)
+            });
+            let from = from.and_then(|index| order.iter().position(|i| *i == index));
+            kas::util::spatial_nav(reverse, from, order.len()).map(|pos| order[pos])
+        }
+
         fn handle_message(&mut self, mgr: &mut EventMgr, index: usize) {
             if let Some(f) = self.on_message {
                 f(mgr, index);
@@ -237,6 +259,51 @@ impl<W: Widget> Grid<W> {
             list: &mut self.widgets,
         }
     }
+
+    /// Append child widgets from an iterator
+    ///
+    /// New children are configured immediately. Triggers [`TkAction::RESIZE`].
+    ///
+    /// (Before the grid is configured, [`Extend`] may be used instead.)
+    pub fn extend_configured<T: IntoIterator<Item = (GridChildInfo, W)>>(
+        &mut self,
+        mgr: &mut SetRectMgr,
+        iter: T,
+    ) {
+        let old_len = self.widgets.len();
+        self.widgets.extend(iter);
+        self.calc_dim();
+        for index in old_len..self.widgets.len() {
+            let id = self.make_child_id(index);
+            mgr.configure(id, &mut self.widgets[index].1);
+        }
+
+        *mgr |= TkAction::RESIZE;
+    }
+}
+
+impl<W: Widget> FromIterator<(GridChildInfo, W)> for Grid<W> {
+    #[inline]
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = (GridChildInfo, W)>,
+    {
+        Self::new_vec(iter.into_iter().collect())
+    }
+}
+
+/// Append children without configuring them
+///
+/// This is intended for use before the grid is configured. In a running UI,
+/// use [`Grid::extend_configured`] instead.
+impl<W: Widget> Extend<(GridChildInfo, W)> for Grid<W> {
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = (GridChildInfo, W)>,
+    {
+        self.widgets.extend(iter);
+        self.calc_dim();
+    }
 }
 
 pub struct GridBuilder<'a, W: Widget>(&'a mut Vec<(GridChildInfo, W)>);
@@ -148,6 +148,12 @@ impl_scope! {
         }
     }
 
+    impl Widget for Image {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::Image
+        }
+    }
+
     impl Layout for Image {
         fn size_rules(&mut self, size_mgr: SizeMgr, axis: AxisInfo) -> SizeRules {
             self.scaling.size_rules(size_mgr, axis)
@@ -160,7 +166,12 @@ impl_scope! {
 
         fn draw(&mut self, mut draw: DrawMgr) {
             if let Some(id) = self.handle.as_ref().map(|h| h.id()) {
-                draw.image(self.rect(), id);
+                if self.scaling.tile {
+                    let tile = self.scaling.tile_size(draw.size_mgr().scale_factor());
+                    draw.image_tiled(self.rect(), id, tile);
+                } else {
+                    draw.image(self.rect(), id);
+                }
             }
         }
     }
@@ -5,9 +5,15 @@
 
 //! Text widgets
 
+use kas::geom::Vec2;
+use kas::layout::SizeTarget;
 use kas::text::format::{EditableText, FormattableText};
 use kas::theme::TextClass;
 use kas::{event, prelude::*};
+use unicode_segmentation::UnicodeSegmentation;
+
+// Inputs to Label::size_rules besides the axis: (generation, other axis, target)
+type RulesKey = (u32, Option<i32>, SizeTarget);
 
 impl_scope! {
     /// A text label
@@ -16,6 +22,18 @@ impl_scope! {
     /// alignment depends on the script direction if not specified.
     /// Line-wrapping is enabled by default.
     ///
+    /// # Wrapping and ellipsis
+    ///
+    /// With wrapping enabled, the label's height is calculated from the width
+    /// assigned during the horizontal size pass, hence it may grow to as many
+    /// lines as required. With wrapping disabled, text is laid out on a single
+    /// line (excepting explicit line breaks) and may be clipped.
+    ///
+    /// If [ellipsis](Self::with_ellipsis) is enabled and wrapping is disabled,
+    /// text too wide for the assigned width is truncated and terminated with
+    /// `…`. Truncation uses the plain text: formatting effects are not drawn
+    /// on truncated text.
+    ///
     /// This type is generic over the text type.
     /// See also: [`StrLabel`], [`StringLabel`], [`AccelLabel`].
     #[impl_default(where T: Default)]
@@ -25,6 +43,10 @@ impl_scope! {
         core: widget_core!(),
         class: TextClass = TextClass::Label(true),
         label: Text<T>,
+        ellipsis: bool,
+        elided: Option<Text<String>>,
+        // Cached size_rules result per axis; see AxisInfo::generation
+        rules_cache: [Option<(RulesKey, SizeRules)>; 2],
     }
 
     impl Self {
@@ -35,6 +57,9 @@ impl_scope! {
                 core: Default::default(),
                 class: TextClass::Label(true),
                 label: Text::new_multi(label),
+                ellipsis: false,
+                elided: None,
+                rules_cache: Default::default(),
             }
         }
 
@@ -50,6 +75,8 @@ impl_scope! {
         #[inline]
         pub fn set_class(&mut self, class: TextClass) {
             self.class = class;
+            self.rules_cache = Default::default();
+            self.elided = None;
         }
 
         /// Set text class (inline)
@@ -70,48 +97,170 @@ impl_scope! {
         /// Enable/disable line wrapping
         ///
         /// This is equivalent to `label.set_class(TextClass::Label(wrap))`.
+        /// Since this changes the label's size requirements, the returned
+        /// action is [`TkAction::RESIZE`].
         ///
         /// By default this is enabled.
         #[inline]
-        pub fn set_wrap(&mut self, wrap: bool) {
-            self.class = TextClass::Label(wrap);
+        pub fn set_wrap(&mut self, wrap: bool) -> TkAction {
+            self.set_class(TextClass::Label(wrap));
+            TkAction::RESIZE
         }
 
         /// Enable/disable line wrapping (inline)
         #[inline]
+        #[must_use]
         pub fn with_wrap(mut self, wrap: bool) -> Self {
             self.class = TextClass::Label(wrap);
             self
         }
 
+        /// Get whether ellipsis is enabled
+        #[inline]
+        pub fn ellipsis(&self) -> bool {
+            self.ellipsis
+        }
+
+        /// Enable/disable ellipsis
+        ///
+        /// When enabled and line-wrapping is disabled, text exceeding the
+        /// available width is truncated and terminated with `…`.
+        ///
+        /// By default this is disabled.
+        #[inline]
+        pub fn set_ellipsis(&mut self, ellipsis: bool) -> TkAction {
+            self.ellipsis = ellipsis;
+            self.elided = None;
+            TkAction::SET_SIZE
+        }
+
+        /// Enable/disable ellipsis (inline)
+        #[inline]
+        #[must_use]
+        pub fn with_ellipsis(mut self, ellipsis: bool) -> Self {
+            self.ellipsis = ellipsis;
+            self
+        }
+
         /// Set text in an existing `Label`
         ///
         /// Note: this must not be called before fonts have been initialised
         /// (usually done by the theme when the main loop starts).
         pub fn set_text(&mut self, text: T) -> TkAction {
-            kas::text::util::set_text_and_prepare(&mut self.label, text, self.core.rect.size)
+            self.rules_cache = Default::default();
+            let action =
+                kas::text::util::set_text_and_prepare(&mut self.label, text, self.core.rect.size);
+            action | self.elide_action()
+        }
+
+        fn elides(&self) -> bool {
+            self.ellipsis && !self.class.multi_line()
+        }
+
+        // Truncated text must be re-computed with the text environment
+        fn elide_action(&mut self) -> TkAction {
+            if self.elides() {
+                self.elided = None;
+                TkAction::SET_SIZE
+            } else {
+                TkAction::empty()
+            }
+        }
+
+        /// Update `self.elided` given the `required` size of the full text
+        fn elide(&mut self, mgr: &SetRectMgr, required: Vec2, align: (Align, Align)) {
+            self.elided = None;
+            let size = self.core.rect.size;
+            let width = size.0 as f32;
+            if !self.elides() || required.0 <= width {
+                return;
+            }
+
+            // Truncate at a grapheme boundary no further than the text fits
+            // without an ellipsis. Width is monotonic in the truncation
+            // point, hence we may use a binary search.
+            let s = self.label.as_str();
+            let limit = self.label.text_index_nearest(Vec2(width, 0.0)).unwrap_or(s.len());
+            let ends: Vec<usize> = s
+                .grapheme_indices(true)
+                .map(|(i, _)| i)
+                .take_while(|i| *i <= limit)
+                .collect();
+            let class = self.class;
+            let mut elided = Text::new_single(String::new());
+            let mut fits = |end: usize| {
+                elided.set_text(format!("{}\u{2026}", s[..end].trim_end()));
+                mgr.text_set_size(&mut elided, class, size, align).0 <= width
+            };
+
+            // Invariant: ends[hi..] do not fit; ends[lo] fits or lo == 0
+            let (mut lo, mut hi) = (0, ends.len());
+            while hi - lo > 1 {
+                let mid = (lo + hi) / 2;
+                match fits(ends[mid]) {
+                    true => lo = mid,
+                    false => hi = mid,
+                }
+            }
+            fits(ends.get(lo).cloned().unwrap_or(0));
+            self.elided = Some(elided);
         }
     }
 
     impl Layout for Self {
         #[inline]
         fn size_rules(&mut self, size_mgr: SizeMgr, axis: AxisInfo) -> SizeRules {
-            size_mgr.text_bound(&mut self.label, self.class, axis)
+            // Text layout is expensive, so we re-use rules from the same
+            // generation. Side-effects on the text are redone by set_rect.
+            let cache = &mut self.rules_cache[usize::from(axis.is_vertical())];
+            let key = (axis.generation(), axis.other(), axis.target());
+            match cache {
+                Some((k, rules)) if key.0 != 0 && *k == key => *rules,
+                _ => {
+                    let rules = size_mgr.text_bound(&mut self.label, self.class, axis);
+                    *cache = Some((key, rules));
+                    rules
+                }
+            }
         }
 
         fn set_rect(&mut self, mgr: &mut SetRectMgr, rect: Rect, align: AlignHints) {
             self.core.rect = rect;
             let align = align.unwrap_or(Align::Default, Align::Center);
-            mgr.text_set_size(&mut self.label, self.class, rect.size, align);
+            let required = mgr.text_set_size(&mut self.label, self.class, rect.size, align);
+            self.elide(mgr, required, align);
+        }
+
+        fn baseline(&mut self) -> Option<i32> {
+            let marker = self.label.text_glyph_pos(0).ok()?.next()?;
+            Some(i32::conv_nearest(marker.pos.1))
         }
 
         #[cfg(feature = "min_spec")]
         default fn draw(&mut self, mut draw: DrawMgr) {
-            draw.text_effects(self.rect().pos, &self.label, self.class);
+            if let Some(ref elided) = self.elided {
+                draw.text(self.rect().pos, elided, self.class);
+            } else {
+                draw.text_effects(self.rect().pos, &self.label, self.class);
+            }
         }
         #[cfg(not(feature = "min_spec"))]
         fn draw(&mut self, mut draw: DrawMgr) {
-            draw.text_effects(self.rect().pos, &self.label, self.class);
+            if let Some(ref elided) = self.elided {
+                draw.text(self.rect().pos, elided, self.class);
+            } else {
+                draw.text_effects(self.rect().pos, &self.label, self.class);
+            }
+        }
+    }
+
+    impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::Label
+        }
+
+        fn accessible_label(&self) -> Option<String> {
+            Some(self.label.as_str().to_string())
         }
     }
 
@@ -126,7 +275,10 @@ impl_scope! {
         T: EditableText,
     {
         fn set_string(&mut self, string: String) -> TkAction {
-            kas::text::util::set_string_and_prepare(&mut self.label, string, self.core.rect.size)
+            self.rules_cache = Default::default();
+            let size = self.core.rect.size;
+            let action = kas::text::util::set_string_and_prepare(&mut self.label, string, size);
+            action | self.elide_action()
         }
     }
 }
@@ -135,13 +287,21 @@ impl_scope! {
 #[cfg(feature = "min_spec")]
 impl<'a> Layout for Label<&'a str> {
     fn draw(&mut self, mut draw: DrawMgr) {
-        draw.text(self.rect().pos, &self.label, self.class);
+        if let Some(ref elided) = self.elided {
+            draw.text(self.rect().pos, elided, self.class);
+        } else {
+            draw.text(self.rect().pos, &self.label, self.class);
+        }
     }
 }
 #[cfg(feature = "min_spec")]
 impl Layout for StringLabel {
     fn draw(&mut self, mut draw: DrawMgr) {
-        draw.text(self.rect().pos, &self.label, self.class);
+        if let Some(ref elided) = self.elided {
+            draw.text(self.rect().pos, elided, self.class);
+        } else {
+            draw.text(self.rect().pos, &self.label, self.class);
+        }
     }
 }
 
@@ -245,17 +405,19 @@ impl_scope! {
         /// Enable/disable line wrapping
         ///
         /// This is equivalent to `label.set_class(AccelLabel::Label(wrap))`.
+        /// See [`Label::set_wrap`].
         ///
         /// By default this is enabled.
         #[inline]
-        pub fn set_wrap(&mut self, wrap: bool) {
-            self.0.set_wrap(wrap);
+        pub fn set_wrap(&mut self, wrap: bool) -> TkAction {
+            self.0.set_wrap(wrap)
         }
 
         /// Enable/disable line wrapping (inline)
         #[inline]
+        #[must_use]
         pub fn with_wrap(mut self, wrap: bool) -> Self {
-            self.0.set_wrap(wrap);
+            self.0 = self.0.with_wrap(wrap);
             self
         }
 
@@ -264,6 +426,7 @@ impl_scope! {
         /// Note: this must not be called before fonts have been initialised
         /// (usually done by the theme when the main loop starts).
         pub fn set_text(&mut self, text: AccelString) -> TkAction {
+            self.0.rules_cache = Default::default();
             kas::text::util::set_text_and_prepare(&mut self.0.label, text, self.0.core.rect.size)
         }
     }
@@ -308,7 +471,17 @@ impl_scope! {
             if self.0.label.text().keys() != string.keys() {
                 action |= TkAction::RECONFIGURE;
             }
+            self.0.rules_cache = Default::default();
             action | kas::text::util::set_text_and_prepare(&mut self.0.label, string, self.0.core.rect.size)
         }
+
+        fn set_mnemonic(&mut self, c: Option<char>) -> TkAction {
+            let mut string = self.0.label.text().clone();
+            string.set_mnemonic(c);
+            if string == *self.0.label.text() {
+                return TkAction::empty();
+            }
+            self.set_accel_string(string)
+        }
     }
 }
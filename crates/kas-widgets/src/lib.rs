@@ -16,6 +16,7 @@
 //!
 //! -   [`Frame`], [`NavFrame`], [`PopupFrame`]: frames around content
 //! -   [`ScrollRegion`], [`ScrollBarRegion`]: larger on the inside
+//! -   [`FrozenGrid`]: a scrollable grid with frozen header rows/columns
 //! -   [`Stack`], [`TabStack`]: a stack of widgets in the same rect
 //! -   [`List`]: a row / column of children
 //! -   [`Splitter`]: like [`List`] but with resizing handles
@@ -37,6 +38,7 @@
 //! -   [`Label`]: a simple text label
 //! -   [`ScrollLabel`]: text label supporting scrolling and selection
 //! -   [`Image`]: a pixmap image
+//! -   [`Canvas`]: custom drawing via a callback
 //! -   [`ProgressBar`]: show completion level
 //!
 //! ## Components
@@ -58,6 +60,7 @@
 #![cfg_attr(feature = "min_spec", feature(min_specialization))]
 
 mod button;
+mod canvas;
 mod checkbox;
 mod combobox;
 pub mod dialog;
@@ -65,6 +68,7 @@ mod drag;
 mod edit_field;
 mod filler;
 mod frame;
+mod frozen_grid;
 mod grid;
 mod image;
 mod label;
@@ -89,12 +93,14 @@ pub mod view;
 
 pub use crate::image::Image;
 pub use button::{Button, TextButton};
+pub use canvas::Canvas;
 pub use checkbox::{CheckBox, CheckBoxBare};
 pub use combobox::ComboBox;
 pub use drag::DragHandle;
 pub use edit_field::{EditBox, EditField, EditGuard};
 pub use filler::Filler;
 pub use frame::{Frame, PopupFrame};
+pub use frozen_grid::FrozenGrid;
 pub use grid::{BoxGrid, Grid};
 pub use label::{AccelLabel, Label, StrLabel, StringLabel};
 pub use list::*;
@@ -110,4 +116,4 @@ pub use slider::{Slider, SliderType};
 pub use spinner::{Spinner, SpinnerType};
 pub use splitter::*;
 pub use stack::{BoxStack, RefStack, Stack};
-pub use tab_stack::{BoxTabStack, Tab, TabStack};
+pub use tab_stack::{BoxTabStack, MsgTabClose, MsgTabNew, Tab, TabStack};
@@ -5,10 +5,41 @@
 
 //! A row or column with run-time adjustable contents
 
+use crate::view::{SelectionError, SelectionMode};
+use crate::SelectMsg;
 use kas::dir::{Down, Right};
-use kas::{layout, prelude::*};
+use kas::event::{components::TypeAhead, Scroll};
+use kas::layout::{self, RowSolver, RulesSolver};
+use kas::prelude::*;
+use kas::state::{SaveState, StateValue};
+use linear_map::set::LinearSet;
 use std::collections::hash_map::{Entry, HashMap};
 use std::ops::{Index, IndexMut};
+use std::time::{Duration, Instant};
+
+const PAYLOAD_EXIT: u64 = 1;
+
+/// Message emitted by [`List`] when its selection changes
+///
+/// Use [`List::selected_iter`] to read the new selection.
+#[derive(Clone, Debug)]
+pub struct SelectionChanged;
+
+/// Message emitted by [`List`] when the user moves a child
+///
+/// The child previously at index `from` is now at index `to`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ItemMoved {
+    pub from: usize,
+    pub to: usize,
+}
+
+// Enter/exit animation state of a child
+#[derive(Clone, Copy, Debug)]
+struct ItemAnim {
+    start: Instant,
+    exit: bool,
+}
 
 /// A generic row widget
 ///
@@ -59,13 +90,66 @@ impl_scope! {
     /// Drawing and event handling is O(log n) in the number of children (assuming
     /// only a small number are visible at any one time).
     ///
+    /// For large data sets, consider [`crate::view::ListView`] instead: this
+    /// only constructs widgets for visible items, re-using these as the view
+    /// is scrolled.
+    ///
     /// # Messages
     ///
     /// If a handler is specified via [`Self::on_message`] then this handler is
     /// called when a child pushes a message. This allows associating the
     /// child's index with a message.
+    ///
+    /// # Type-ahead search
+    ///
+    /// Where children implement [`HasStr`], type-ahead search may be enabled
+    /// via [`Self::with_type_ahead`].
+    ///
+    /// # Selection
+    ///
+    /// Selection is disabled by default; see [`Self::with_selection_mode`].
+    /// When enabled, clicking a child (or activating a child wrapped in a
+    /// [`crate::NavFrame`]) selects it. In [`SelectionMode::Multiple`], holding
+    /// <kbd>Ctrl</kbd> toggles the clicked child while <kbd>Shift</kbd> selects
+    /// the range from the last clicked child. Selected children are
+    /// highlighted and [`SelectionChanged`] is pushed on each change. While
+    /// selection is enabled, an inner margin is reserved around each child
+    /// for this highlight.
+    ///
+    /// Selection tracks children by identifier, thus is preserved when other
+    /// children are inserted or removed.
+    ///
+    /// # Keyboard reordering
+    ///
+    /// When enabled via [`Self::with_reorder`], <kbd>Shift</kbd>+<kbd>Alt</kbd>
+    /// (<kbd>Option</kbd>+<kbd>Command</kbd> on MacOS) plus an arrow key in
+    /// the list's direction ([`Command::MoveUp`] and similar) moves the
+    /// child containing the navigation focus by one place. Focus and selection
+    /// move with the child and [`ItemMoved`] is pushed. At either end of the
+    /// list the command is unused, thus may be handled by a parent.
+    ///
+    /// # Scrolling
+    ///
+    /// Within a scroll region (e.g. [`crate::ScrollRegion`]), a child gaining
+    /// navigation focus via the keyboard (including type-ahead search) is
+    /// scrolled into view, as is a child moved by keyboard reordering. Focus
+    /// gained via mouse or touch input does not cause scrolling, thus does not
+    /// fight the user's own scrolling.
+    ///
+    /// # Animation
+    ///
+    /// Enter/exit animations are disabled by default; see
+    /// [`Self::with_animation`]. When enabled, children added via
+    /// [`Self::push`], [`Self::insert`], [`Self::extend`] or
+    /// [`Self::resize_with`] fade in. Children removed via
+    /// [`Self::remove_animated`] fade out and are then removed and dropped;
+    /// until then they remain children of the list. Other removal methods
+    /// take effect immediately, also when the child is mid-animation.
+    /// Reconfiguring the list completes enter animations immediately.
+    ///
+    /// [`Command::MoveUp`]: kas::event::Command::MoveUp
     #[autoimpl(Clone where W: Clone)]
-    #[autoimpl(Debug ignore self.on_message)]
+    #[autoimpl(Debug ignore self.on_message, self.label_fn)]
     #[autoimpl(Default where D: Default)]
     #[widget {
         layout = slice(self.direction) 'layout: self.widgets;
@@ -77,6 +161,66 @@ impl_scope! {
         next: usize,
         id_map: HashMap<usize, usize>, // map key of WidgetId to index
         on_message: Option<fn(&mut EventMgr, usize)>,
+        type_ahead: TypeAhead,
+        label_fn: Option<fn(&W) -> &str>,
+        sel_mode: SelectionMode,
+        selection: LinearSet<usize>, // keys of selected children
+        sel_anchor: Option<usize>,   // key of last clicked child
+        reorder: bool,
+        anim_dur: Option<Duration>,
+        anims: HashMap<usize, ItemAnim>, // keys of animating children
+    }
+
+    impl Layout for Self {
+        fn size_rules(&mut self, size_mgr: SizeMgr, axis: AxisInfo) -> SizeRules {
+            if matches!(self.sel_mode, SelectionMode::None) {
+                return <Self as layout::AutoLayout>::size_rules(self, size_mgr, axis);
+            }
+
+            // As in ListView, selection boxes are drawn into an inner margin
+            // reserved around each child
+            let m = u16::conv(size_mgr.inner_margin().extract(axis));
+            let dim = (self.direction, self.widgets.len());
+            let data = &mut self.core.layout;
+            let mut solver = RowSolver::new(axis, dim, data);
+            for (n, child) in self.widgets.iter_mut().enumerate() {
+                solver.for_child(data, n, |axis| {
+                    let mut rules = child.size_rules(size_mgr.re(), axis);
+                    rules.include_margins((m, m));
+                    rules
+                });
+            }
+            solver.finish(data)
+        }
+
+        fn draw(&mut self, mut draw: DrawMgr) {
+            <Self as layout::AutoLayout>::draw(self, draw.re_clone());
+            for w in &self.widgets {
+                let key = w.id_ref().next_key_after(self.id_ref());
+                if key.map(|k| self.selection.contains(&k)).unwrap_or(false) {
+                    draw.selection_box(w.rect());
+                }
+            }
+
+            if let Some(dur) = self.anim_dur {
+                let now = Instant::now();
+                let (widgets, id_map) = (&self.widgets, &self.id_map);
+                self.anims.retain(|key, anim| {
+                    let elapsed = now.saturating_duration_since(anim.start);
+                    let f = (elapsed.as_secs_f32() / dur.as_secs_f32()).min(1.0);
+                    if f >= 1.0 && !anim.exit {
+                        return false;
+                    }
+                    if let Some(w) = id_map.get(key).and_then(|i| widgets.get(*i)) {
+                        draw.fade_overlay(w.rect(), if anim.exit { f } else { 1.0 - f });
+                    }
+                    true
+                });
+                if self.anims.values().any(|anim| now < anim.start + dur) {
+                    draw.draw_device().animate();
+                }
+            }
+        }
     }
 
     impl WidgetChildren for Self {
@@ -123,6 +267,24 @@ impl_scope! {
         fn pre_configure(&mut self, _: &mut SetRectMgr, id: WidgetId) {
             self.core.id = id;
             self.id_map.clear();
+            self.anims.retain(|_, anim| anim.exit);
+        }
+
+        fn configure(&mut self, mgr: &mut SetRectMgr) {
+            if self.label_fn.is_some() {
+                mgr.request_type_ahead(self.id());
+            }
+
+            // Reconfiguring clears scheduled updates
+            if let Some(dur) = self.anim_dur {
+                if self.anims.values().any(|anim| anim.exit) {
+                    mgr.update_on_timer(dur, self.id(), PAYLOAD_EXIT);
+                }
+            }
+        }
+
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::List
         }
 
         fn spatial_nav(
@@ -135,11 +297,107 @@ impl_scope! {
             kas::util::spatial_nav(reverse, from, self.num_children())
         }
 
+        fn handle_event(&mut self, mgr: &mut EventMgr, event: Event) -> Response {
+            match event {
+                Event::ReceivedCharacter(c) => {
+                    let label_fn = match self.label_fn {
+                        Some(label_fn) => label_fn,
+                        None => return Response::Unused,
+                    };
+                    let from = mgr.nav_focus().and_then(|id| self.find_child_index(id));
+                    let widgets = &self.widgets;
+                    let label = |index: usize| widgets.get(index).map(label_fn);
+                    match self.type_ahead.search(c, from, widgets.len(), label) {
+                        Some(index) => {
+                            mgr.set_nav_focus(self.widgets[index].id(), true);
+                            Response::Used
+                        }
+                        None => Response::Unused,
+                    }
+                }
+                Event::TimerUpdate(PAYLOAD_EXIT) => {
+                    self.remove_exited(mgr);
+                    Response::Used
+                }
+                _ => Response::Unused,
+            }
+        }
+
+        fn handle_unused(&mut self, mgr: &mut EventMgr, index: usize, event: Event) -> Response {
+            match event {
+                Event::PressStart { source, .. }
+                    if source.is_primary() && !matches!(self.sel_mode, SelectionMode::None) =>
+                {
+                    self.select_by_user(mgr, index);
+                    Response::Used
+                }
+                Event::Command(cmd) if self.reorder => {
+                    if let Some(dir) = cmd.as_move_direction() {
+                        self.move_by_user(mgr, index, dir)
+                    } else {
+                        self.handle_event(mgr, event)
+                    }
+                }
+                event => self.handle_event(mgr, event),
+            }
+        }
+
         fn handle_message(&mut self, mgr: &mut EventMgr, index: usize) {
+            if !matches!(self.sel_mode, SelectionMode::None) {
+                if let Some(SelectMsg) = mgr.try_pop_msg() {
+                    self.select_by_user(mgr, index);
+                }
+            }
+
             if let Some(f) = self.on_message {
                 f(mgr, index);
             }
         }
+
+        #[inline]
+        fn as_save_state(&mut self) -> Option<&mut dyn SaveState> {
+            Some(self)
+        }
+    }
+
+    /// Saves the selection, if selection is enabled
+    ///
+    /// Children are identified by their key, thus the selection is restored
+    /// to the same children where the list is constructed identically.
+    impl SaveState for Self {
+        fn save_state(&self) -> Option<StateValue> {
+            if matches!(self.sel_mode, SelectionMode::None) {
+                return None;
+            }
+            let mut keys: Vec<_> = self.selection.iter().map(|key| vec![*key]).collect();
+            keys.sort_unstable();
+            Some(StateValue::Keys(keys))
+        }
+
+        fn load_state(&mut self, mgr: &mut SetRectMgr, state: &StateValue) {
+            let paths = match state {
+                StateValue::Keys(paths) => paths,
+                _ => return,
+            };
+            if matches!(self.sel_mode, SelectionMode::None) {
+                return;
+            }
+
+            let id_map = &self.id_map;
+            let keys = paths.iter().filter_map(|path| match path[..] {
+                [key] if id_map.contains_key(&key) => Some(key),
+                _ => None,
+            });
+            self.selection.clear();
+            for key in keys {
+                self.selection.insert(key);
+                if matches!(self.sel_mode, SelectionMode::Single) {
+                    break;
+                }
+            }
+            self.sel_anchor = None;
+            *mgr |= TkAction::REDRAW;
+        }
     }
 
     impl Self where D: Default {
@@ -190,6 +448,14 @@ impl_scope! {
                 next: 0,
                 id_map: Default::default(),
                 on_message: None,
+                type_ahead: Default::default(),
+                label_fn: None,
+                sel_mode: SelectionMode::None,
+                selection: Default::default(),
+                sel_anchor: None,
+                reorder: false,
+                anim_dur: None,
+                anims: Default::default(),
             }
         }
 
@@ -212,6 +478,333 @@ impl_scope! {
             self
         }
 
+        /// Enable type-ahead search (inline style)
+        ///
+        /// When a child has navigation focus, typing moves focus to the next
+        /// child whose label ([`HasStr::get_str`]) starts with the typed text.
+        /// See [`TypeAhead`] for details.
+        #[inline]
+        #[must_use]
+        pub fn with_type_ahead(mut self) -> Self
+        where
+            W: HasStr,
+        {
+            self.label_fn = Some(<W as HasStr>::get_str);
+            self
+        }
+
+        /// Get the current selection mode
+        pub fn selection_mode(&self) -> SelectionMode {
+            self.sel_mode
+        }
+
+        /// Set the current selection mode
+        ///
+        /// While selection is enabled, an inner margin is reserved around each
+        /// child for drawing the selection box. Enabling or disabling
+        /// selection thus requires a resize.
+        pub fn set_selection_mode(&mut self, mode: SelectionMode) -> TkAction {
+            let was_enabled = !matches!(self.sel_mode, SelectionMode::None);
+            self.sel_mode = mode;
+            let action = match mode {
+                SelectionMode::None if !self.selection.is_empty() => {
+                    self.selection.clear();
+                    TkAction::REDRAW
+                }
+                SelectionMode::Single if self.selection.len() > 1 => {
+                    if let Some(first) = self.selection.iter().next().cloned() {
+                        self.selection.retain(|key| *key == first);
+                    }
+                    TkAction::REDRAW
+                }
+                _ => TkAction::empty(),
+            };
+            if was_enabled != !matches!(mode, SelectionMode::None) {
+                action | TkAction::RESIZE
+            } else {
+                action
+            }
+        }
+
+        /// Set the selection mode (inline)
+        #[must_use]
+        pub fn with_selection_mode(mut self, mode: SelectionMode) -> Self {
+            let _ = self.set_selection_mode(mode);
+            self
+        }
+
+        /// Iterate over indices of selected children, in order
+        pub fn selected_iter(&self) -> impl Iterator<Item = usize> + '_ {
+            (0..self.widgets.len()).filter(move |index| self.is_selected(*index))
+        }
+
+        /// Check whether the child at `index` is selected
+        pub fn is_selected(&self, index: usize) -> bool {
+            self.child_key(index)
+                .map(|key| self.selection.contains(&key))
+                .unwrap_or(false)
+        }
+
+        /// Clear all selected children
+        pub fn clear_selected(&mut self) -> TkAction {
+            if self.selection.is_empty() {
+                TkAction::empty()
+            } else {
+                self.selection.clear();
+                TkAction::REDRAW
+            }
+        }
+
+        /// Directly select the child at `index`
+        ///
+        /// Returns `TkAction::REDRAW` if newly selected, `TkAction::empty()` if
+        /// already selected. Fails if selection mode does not permit selection
+        /// or if the index is invalid (or the child is not yet configured).
+        pub fn select(&mut self, index: usize) -> Result<TkAction, SelectionError> {
+            if matches!(self.sel_mode, SelectionMode::None) {
+                return Err(SelectionError::Disabled);
+            }
+            let key = self.child_key(index).ok_or(SelectionError::Key)?;
+            if matches!(self.sel_mode, SelectionMode::Single) {
+                self.selection.retain(|k| *k == key);
+            }
+            match self.selection.insert(key) {
+                true => Ok(TkAction::REDRAW),
+                false => Ok(TkAction::empty()),
+            }
+        }
+
+        /// Directly deselect the child at `index`
+        ///
+        /// Returns `TkAction::REDRAW` if deselected, `TkAction::empty()` if not
+        /// previously selected or if the index is invalid.
+        pub fn deselect(&mut self, index: usize) -> TkAction {
+            match self.child_key(index).map(|key| self.selection.remove(&key)) {
+                Some(true) => TkAction::REDRAW,
+                _ => TkAction::empty(),
+            }
+        }
+
+        // Key of child's WidgetId relative to self
+        fn child_key(&self, index: usize) -> Option<usize> {
+            let child = self.widgets.get(index)?;
+            child.id_ref().next_key_after(self.id_ref())
+        }
+
+        // Update selection on click or activation of child `index`
+        fn select_by_user(&mut self, mgr: &mut EventMgr, index: usize) {
+            let key = match self.child_key(index) {
+                Some(key) => key,
+                None => return,
+            };
+            let modifiers = mgr.modifiers();
+            let anchor = self.sel_anchor.and_then(|k| self.id_map.get(&k).cloned());
+
+            match (self.sel_mode, anchor) {
+                (SelectionMode::None, _) => return,
+                (SelectionMode::Multiple, Some(anchor)) if modifiers.shift() => {
+                    if !modifiers.ctrl() {
+                        self.selection.clear();
+                    }
+                    for i in anchor.min(index)..=anchor.max(index) {
+                        if let Some(k) = self.child_key(i) {
+                            self.selection.insert(k);
+                        }
+                    }
+                }
+                (SelectionMode::Multiple, _) if modifiers.ctrl() => {
+                    if !self.selection.remove(&key) {
+                        self.selection.insert(key);
+                    }
+                    self.sel_anchor = Some(key);
+                }
+                _ => {
+                    if self.selection.len() == 1 && self.selection.contains(&key) {
+                        return;
+                    }
+                    self.selection.clear();
+                    self.selection.insert(key);
+                    self.sel_anchor = Some(key);
+                }
+            }
+
+            mgr.redraw(self.id());
+            mgr.push_msg(SelectionChanged);
+        }
+
+        /// Get whether keyboard reordering is enabled
+        #[inline]
+        pub fn reorder(&self) -> bool {
+            self.reorder
+        }
+
+        /// Enable or disable keyboard reordering
+        ///
+        /// See the [type-level documentation](Self#keyboard-reordering).
+        #[inline]
+        pub fn set_reorder(&mut self, reorder: bool) {
+            self.reorder = reorder;
+        }
+
+        /// Enable keyboard reordering (inline)
+        ///
+        /// See the [type-level documentation](Self#keyboard-reordering).
+        #[inline]
+        #[must_use]
+        pub fn with_reorder(mut self) -> Self {
+            self.reorder = true;
+            self
+        }
+
+        // Move child `index` one place in direction `dir`, if possible
+        fn move_by_user(
+            &mut self,
+            mgr: &mut EventMgr,
+            index: usize,
+            dir: Direction,
+        ) -> Response {
+            if dir.is_vertical() != self.direction.is_vertical() {
+                return Response::Unused;
+            }
+            let to = if dir.is_reversed() == self.direction.is_reversed() {
+                index + 1
+            } else {
+                match index.checked_sub(1) {
+                    Some(to) => to,
+                    None => return Response::Unused,
+                }
+            };
+            if to >= self.widgets.len() {
+                return Response::Unused;
+            }
+
+            // The two adjacent children together occupy the same region after
+            // the move, thus revealing this region reveals the moved child.
+            let rect = self.widgets[index].rect().union(&self.widgets[to].rect());
+            mgr.set_rect_mgr(|mgr| self.move_item(mgr, index, to));
+            mgr.push_msg(ItemMoved { from: index, to });
+            mgr.set_scroll(Scroll::Rect(rect));
+            Response::Used
+        }
+
+        /// Get the enter/exit animation duration, if enabled
+        #[inline]
+        pub fn animation(&self) -> Option<Duration> {
+            self.anim_dur
+        }
+
+        /// Enable or disable enter/exit animations
+        ///
+        /// When disabling, animations in progress complete immediately.
+        /// See the [type-level documentation](Self#animation).
+        pub fn set_animation(&mut self, mgr: &mut SetRectMgr, dur: Option<Duration>) {
+            self.anim_dur = dur;
+            if dur.is_none() {
+                self.anims.retain(|_, anim| anim.exit);
+                mgr.update_on_timer(Duration::ZERO, self.id(), PAYLOAD_EXIT);
+            }
+            mgr.redraw(self.id());
+        }
+
+        /// Enable enter/exit animations with the given duration (inline)
+        ///
+        /// See the [type-level documentation](Self#animation).
+        #[inline]
+        #[must_use]
+        pub fn with_animation(mut self, dur: Duration) -> Self {
+            self.anim_dur = Some(dur);
+            self
+        }
+
+        // Start the enter animation of child `index`, if enabled
+        fn anim_enter(&mut self, index: usize) {
+            if self.anim_dur.is_some() {
+                if let Some(key) = self.child_key(index) {
+                    let start = Instant::now();
+                    self.anims.insert(key, ItemAnim { start, exit: false });
+                }
+            }
+        }
+
+        // Remove state associated with the child's key
+        fn forget_child(&mut self, w: &W) {
+            if w.id_ref().is_valid() {
+                if let Some(key) = w.id_ref().next_key_after(self.id_ref()) {
+                    self.id_map.remove(&key);
+                    self.selection.remove(&key);
+                    self.anims.remove(&key);
+                }
+            }
+        }
+
+        // Remove children whose exit animation is complete
+        fn remove_exited(&mut self, mgr: &mut EventMgr) {
+            let now = Instant::now();
+            let dur = self.anim_dur.unwrap_or_default();
+            let mut next = None;
+            let mut index = self.widgets.len();
+            while index > 0 {
+                index -= 1;
+                let anim = match self.child_key(index).and_then(|k| self.anims.get(&k)) {
+                    Some(anim) if anim.exit => *anim,
+                    _ => continue,
+                };
+                let end = anim.start + dur;
+                if end <= now {
+                    mgr.set_rect_mgr(|mgr| self.remove(mgr, index));
+                } else {
+                    next = Some(next.map(|t: Instant| t.min(end)).unwrap_or(end));
+                }
+            }
+            // Drop state of exiting children no longer present
+            let id_map = &self.id_map;
+            self.anims.retain(|key, _| id_map.contains_key(key));
+
+            if let Some(end) = next {
+                mgr.update_on_timer(end - now, self.id(), PAYLOAD_EXIT);
+            }
+        }
+
+        /// Remove the child at position `index` after its exit animation
+        ///
+        /// Panics if `index` is out of bounds.
+        ///
+        /// If animations are disabled (see [`Self::with_animation`]) this
+        /// removes the child immediately. Otherwise the child fades out, then
+        /// is removed and dropped. A child which is mid-way through its enter
+        /// animation fades out from its current state. Triggers
+        /// [`TkAction::RESIZE`] once the child is removed.
+        pub fn remove_animated(&mut self, mgr: &mut SetRectMgr, index: usize) {
+            let dur = match self.anim_dur {
+                Some(dur) => dur,
+                None => {
+                    self.remove(mgr, index);
+                    return;
+                }
+            };
+            let key = match self.child_key(index) {
+                Some(key) => key,
+                None => {
+                    self.remove(mgr, index);
+                    return;
+                }
+            };
+
+            let now = Instant::now();
+            let start = match self.anims.get(&key) {
+                Some(anim) if anim.exit => return,
+                Some(anim) => {
+                    // Continue from the current opacity
+                    let elapsed = now.saturating_duration_since(anim.start).min(dur);
+                    now.checked_sub(dur - elapsed).unwrap_or(now)
+                }
+                None => now,
+            };
+            self.anims.insert(key, ItemAnim { start, exit: true });
+            mgr.update_on_timer(start + dur - now, self.id(), PAYLOAD_EXIT);
+            mgr.redraw(self.id());
+        }
+
         /// Edit the list of children directly
         ///
         /// This may be used to edit children before window construction. It may
@@ -250,6 +843,8 @@ impl_scope! {
         /// Remove all child widgets
         pub fn clear(&mut self) {
             self.widgets.clear();
+            self.selection.clear();
+            self.anims.clear();
         }
 
         /// Returns a reference to the child, if any
@@ -273,6 +868,7 @@ impl_scope! {
             self.widgets.push(widget);
             let id = self.make_child_id(index);
             mgr.configure(id, &mut self.widgets[index]);
+            self.anim_enter(index);
             *mgr |= TkAction::RESIZE;
             index
         }
@@ -284,12 +880,7 @@ impl_scope! {
             let result = self.widgets.pop();
             if let Some(w) = result.as_ref() {
                 *mgr |= TkAction::RESIZE;
-
-                if w.id_ref().is_valid() {
-                    if let Some(key) = w.id_ref().next_key_after(self.id_ref()) {
-                        self.id_map.remove(&key);
-                    }
-                }
+                self.forget_child(w);
             }
             result
         }
@@ -308,6 +899,7 @@ impl_scope! {
             self.widgets.insert(index, widget);
             let id = self.make_child_id(index);
             mgr.configure(id, &mut self.widgets[index]);
+            self.anim_enter(index);
             *mgr |= TkAction::RESIZE;
         }
 
@@ -318,11 +910,7 @@ impl_scope! {
         /// Triggers [`TkAction::RESIZE`].
         pub fn remove(&mut self, mgr: &mut SetRectMgr, index: usize) -> W {
             let w = self.widgets.remove(index);
-            if w.id_ref().is_valid() {
-                if let Some(key) = w.id_ref().next_key_after(self.id_ref()) {
-                    self.id_map.remove(&key);
-                }
-            }
+            self.forget_child(&w);
 
             *mgr |= TkAction::RESIZE;
 
@@ -341,12 +929,7 @@ impl_scope! {
         /// The new child is configured immediately. Triggers [`TkAction::RESIZE`].
         pub fn replace(&mut self, mgr: &mut SetRectMgr, index: usize, mut w: W) -> W {
             std::mem::swap(&mut w, &mut self.widgets[index]);
-
-            if w.id_ref().is_valid() {
-                if let Some(key) = w.id_ref().next_key_after(self.id_ref()) {
-                    self.id_map.remove(&key);
-                }
-            }
+            self.forget_child(&w);
 
             let id = self.make_child_id(index);
             mgr.configure(id, &mut self.widgets[index]);
@@ -356,6 +939,46 @@ impl_scope! {
             w
         }
 
+        /// Swap the children at positions `a` and `b`
+        ///
+        /// Panics if either index is out of bounds.
+        ///
+        /// Children keep their identifiers, thus focus and selection remain
+        /// with the moved children. Triggers [`TkAction::RESIZE`].
+        pub fn swap(&mut self, mgr: &mut SetRectMgr, a: usize, b: usize) {
+            self.widgets.swap(a, b);
+            for v in self.id_map.values_mut() {
+                if *v == a {
+                    *v = b;
+                } else if *v == b {
+                    *v = a;
+                }
+            }
+            *mgr |= TkAction::RESIZE;
+        }
+
+        /// Move the child at position `from` to position `to`
+        ///
+        /// Children between the two positions are shifted by one place.
+        /// Panics if either index is out of bounds.
+        ///
+        /// Children keep their identifiers, thus focus and selection remain
+        /// with the moved children. Triggers [`TkAction::RESIZE`].
+        pub fn move_item(&mut self, mgr: &mut SetRectMgr, from: usize, to: usize) {
+            let w = self.widgets.remove(from);
+            self.widgets.insert(to, w);
+            for v in self.id_map.values_mut() {
+                if *v == from {
+                    *v = to;
+                } else if from < *v && *v <= to {
+                    *v -= 1;
+                } else if to <= *v && *v < from {
+                    *v += 1;
+                }
+            }
+            *mgr |= TkAction::RESIZE;
+        }
+
         /// Append child widgets from an iterator
         ///
         /// New children are configured immediately. Triggers [`TkAction::RESIZE`].
@@ -365,6 +988,7 @@ impl_scope! {
             for index in old_len..self.widgets.len() {
                 let id = self.make_child_id(index);
                 mgr.configure(id, &mut self.widgets[index]);
+                self.anim_enter(index);
             }
 
             *mgr |= TkAction::RESIZE;
@@ -380,11 +1004,7 @@ impl_scope! {
                 *mgr |= TkAction::RESIZE;
                 loop {
                     let w = self.widgets.pop().unwrap();
-                    if w.id_ref().is_valid() {
-                        if let Some(key) = w.id_ref().next_key_after(self.id_ref()) {
-                            self.id_map.remove(&key);
-                        }
-                    }
+                    self.forget_child(&w);
                     if len == self.widgets.len() {
                         return;
                     }
@@ -398,11 +1018,29 @@ impl_scope! {
                     let mut widget = f(index);
                     mgr.configure(id, &mut widget);
                     self.widgets.push(widget);
+                    self.anim_enter(index);
                 }
                 *mgr |= TkAction::RESIZE;
             }
         }
 
+        /// Binary search over children with a comparator function
+        ///
+        /// This delegates to [`slice::binary_search_by`]: `f` should return
+        /// the ordering of a child relative to the target. Returns `Ok(index)`
+        /// of a matching child or `Err(index)` where a matching child could be
+        /// inserted (e.g. via [`Self::insert`]) while maintaining sort order.
+        ///
+        /// Children must already be sorted by the same ordering used by `f`,
+        /// otherwise the result is unspecified (but not unsafe).
+        #[inline]
+        pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+        where
+            F: FnMut(&W) -> std::cmp::Ordering,
+        {
+            self.widgets.binary_search_by(f)
+        }
+
         /// Iterate over childern
         pub fn iter(&self) -> impl Iterator<Item = &W> {
             ListIter {
@@ -416,6 +1054,38 @@ impl_scope! {
                 list: &mut self.widgets,
             }
         }
+
+        /// Iterate over `(index, rect)` of children
+        ///
+        /// This yields the [`Rect`] of each child (via
+        /// [`WidgetChildren::get_child`]), in the same coordinate space as
+        /// the list's own rect. This may be used, for example, to compute
+        /// drop targets when reordering items.
+        ///
+        /// Rects are only meaningful after the list has been sized and
+        /// positioned: before [`Layout::set_rect`] is called (or for children
+        /// added since) rects are default (zero-sized, at the origin).
+        pub fn children_rects(&self) -> impl Iterator<Item = (usize, Rect)> + '_ {
+            (0..self.num_children())
+                .filter_map(move |i| self.get_child(i).map(|w| (i, w.rect())))
+        }
+
+        /// Give navigation focus to the first navigable child
+        ///
+        /// Children which are not themselves navigable are searched for a
+        /// navigable descendant (see [`EventMgr::nav_focus_first`]).
+        /// Returns true on success; if the list is empty or contains nothing
+        /// navigable, this does nothing and returns false.
+        pub fn focus_first(&mut self, mgr: &mut EventMgr) -> bool {
+            mgr.nav_focus_first(self, false, true)
+        }
+
+        /// Give navigation focus to the last navigable child
+        ///
+        /// See [`Self::focus_first`].
+        pub fn focus_last(&mut self, mgr: &mut EventMgr) -> bool {
+            mgr.nav_focus_first(self, true, true)
+        }
     }
 
     impl Index<usize> for Self {
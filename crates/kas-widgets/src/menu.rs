@@ -15,6 +15,7 @@
 //! -   [`SubMenu`]
 //! -   [`MenuEntry`]
 //! -   [`MenuToggle`]
+//! -   [`MenuRadio`]
 //! -   [`Separator`]
 
 use crate::Separator;
@@ -26,7 +27,7 @@ mod menu_entry;
 mod menubar;
 mod submenu;
 
-pub use menu_entry::{MenuEntry, MenuToggle};
+pub use menu_entry::{MenuEntry, MenuRadio, MenuToggle};
 pub use menubar::{MenuBar, MenuBuilder};
 pub use submenu::SubMenu;
 
@@ -80,6 +81,14 @@ pub trait Menu: Widget {
         None
     }
 
+    /// Get the label text, if any
+    ///
+    /// This is used for type-ahead search within a menu. By default, this is
+    /// `None`.
+    fn menu_label(&self) -> Option<&str> {
+        None
+    }
+
     /// Report whether a submenu (if any) is open
     ///
     /// By default, this is `false`.
@@ -6,7 +6,7 @@
 //! Menu Entries
 
 use super::{Menu, SubItems};
-use crate::{AccelLabel, CheckBoxBare};
+use crate::{AccelLabel, CheckBoxBare, RadioBoxBare, RadioBoxGroup};
 use kas::theme::{FrameStyle, TextClass};
 use kas::{layout, prelude::*};
 use std::fmt::Debug;
@@ -72,9 +72,22 @@ impl_scope! {
         fn set_accel_string(&mut self, string: AccelString) -> TkAction {
             self.label.set_accel_string(string)
         }
+
+        #[inline]
+        fn set_mnemonic(&mut self, c: Option<char>) -> TkAction {
+            self.label.set_mnemonic(c)
+        }
     }
 
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::MenuItem
+        }
+
+        fn accessible_label(&self) -> Option<String> {
+            Some(self.label.get_str().to_string())
+        }
+
         fn configure(&mut self, mgr: &mut SetRectMgr) {
             mgr.add_accel_keys(self.id_ref(), self.label.keys());
         }
@@ -97,11 +110,24 @@ impl_scope! {
                 ..Default::default()
             })
         }
+
+        fn menu_label(&self) -> Option<&str> {
+            Some(self.label.get_str())
+        }
     }
 }
 
 impl_scope! {
     /// A menu entry which can be toggled
+    ///
+    /// This draws a check mark beside the label; activating the entry (by
+    /// click, <kbd>Enter</kbd>/<kbd>Space</kbd> or its accelerator key)
+    /// toggles the state.
+    ///
+    /// # Messages
+    ///
+    /// When constructed with [`MenuToggle::new_msg`], a message is pushed on
+    /// each toggle, derived from the new state.
     #[autoimpl(Debug)]
     #[autoimpl(HasBool using self.checkbox)]
     #[derive(Clone, Default)]
@@ -129,6 +155,14 @@ impl_scope! {
     }
 
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::MenuItem
+        }
+
+        fn accessible_label(&self) -> Option<String> {
+            Some(self.label.get_str().to_string())
+        }
+
         fn configure(&mut self, mgr: &mut SetRectMgr) {
             mgr.add_accel_keys(self.checkbox.id_ref(), self.label.keys());
         }
@@ -142,6 +176,10 @@ impl_scope! {
                 ..Default::default()
             })
         }
+
+        fn menu_label(&self) -> Option<&str> {
+            Some(self.label.get_str())
+        }
     }
 
     impl MenuToggle {
@@ -184,6 +222,18 @@ impl_scope! {
             MenuToggle::new(label).on_toggle(f)
         }
 
+        /// Construct a toggleable menu entry with a given `label` and message generator `f`
+        ///
+        /// When the checkbox is set or unset, the message `f(state)` is pushed.
+        /// Use `|state| state` to push the state itself as a `bool`.
+        #[inline]
+        pub fn new_msg<T: Into<AccelString>, M>(label: T, f: fn(bool) -> M) -> Self
+        where
+            M: Debug + 'static,
+        {
+            MenuToggle::new_on(label, move |mgr, state| mgr.push_msg(f(state)))
+        }
+
         /// Set the initial state of the checkbox.
         #[inline]
         #[must_use]
@@ -193,3 +243,132 @@ impl_scope! {
         }
     }
 }
+
+impl_scope! {
+    /// A menu entry which is one of an exclusive group
+    ///
+    /// This draws a radio mark beside the label. All instances of
+    /// [`MenuRadio`], [`crate::RadioBox`] and [`RadioBoxBare`] constructed over
+    /// the same [`RadioBoxGroup`] form a single group: activating the entry
+    /// selects it and deselects all other members.
+    ///
+    /// # Messages
+    ///
+    /// When constructed with [`MenuRadio::new_msg`], a clone of the message is
+    /// pushed when the entry is selected (but not on deselection).
+    #[autoimpl(Debug)]
+    #[autoimpl(HasBool using self.radiobox)]
+    #[derive(Clone)]
+    #[widget {
+        layout = row: [self.radiobox, self.label];
+    }]
+    pub struct MenuRadio {
+        core: widget_core!(),
+        #[widget]
+        radiobox: RadioBoxBare,
+        #[widget]
+        label: AccelLabel,
+    }
+
+    impl Layout for Self {
+        fn find_id(&mut self, coord: Coord) -> Option<WidgetId> {
+            self.rect().contains(coord).then(|| self.radiobox.id())
+        }
+
+        fn draw(&mut self, mut draw: DrawMgr) {
+            let mut draw = draw.re_id(self.radiobox.id());
+            draw.frame(self.rect(), FrameStyle::MenuEntry, Default::default());
+            <Self as layout::AutoLayout>::draw(self, draw);
+        }
+    }
+
+    impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::MenuItem
+        }
+
+        fn accessible_label(&self) -> Option<String> {
+            Some(self.label.get_str().to_string())
+        }
+
+        fn configure(&mut self, mgr: &mut SetRectMgr) {
+            mgr.add_accel_keys(self.radiobox.id_ref(), self.label.keys());
+        }
+    }
+
+    impl Menu for Self {
+        fn sub_items(&mut self) -> Option<SubItems> {
+            Some(SubItems {
+                label: Some(&mut self.label),
+                toggle: Some(&mut self.radiobox),
+                ..Default::default()
+            })
+        }
+
+        fn menu_label(&self) -> Option<&str> {
+            Some(self.label.get_str())
+        }
+    }
+
+    impl Self {
+        /// Construct a radio menu entry with a given `label` and `group`
+        #[inline]
+        pub fn new<T: Into<AccelString>>(label: T, group: RadioBoxGroup) -> Self {
+            MenuRadio {
+                core: Default::default(),
+                radiobox: RadioBoxBare::new(group),
+                label: AccelLabel::new(label).with_class(TextClass::MenuLabel),
+            }
+        }
+
+        /// Set event handler `f`
+        ///
+        /// When the entry is selected, the closure `f` is called.
+        ///
+        /// No handler is called on deselection.
+        #[inline]
+        #[must_use]
+        pub fn on_select<F>(self, f: F) -> Self
+        where
+            F: Fn(&mut EventMgr) + 'static,
+        {
+            MenuRadio {
+                core: self.core,
+                radiobox: self.radiobox.on_select(f),
+                label: self.label,
+            }
+        }
+
+        /// Construct a radio menu entry with a given `label`, `group` and event handler `f`
+        ///
+        /// When the entry is selected, the closure `f` is called.
+        ///
+        /// No handler is called on deselection.
+        #[inline]
+        pub fn new_on<T: Into<AccelString>, F>(label: T, group: RadioBoxGroup, f: F) -> Self
+        where
+            F: Fn(&mut EventMgr) + 'static,
+        {
+            MenuRadio::new(label, group).on_select(f)
+        }
+
+        /// Construct a radio menu entry with a given `label`, `group` and message `msg`
+        ///
+        /// When the entry is selected, a clone of `msg` is pushed.
+        #[inline]
+        pub fn new_msg<T: Into<AccelString>, M>(label: T, group: RadioBoxGroup, msg: M) -> Self
+        where
+            M: Clone + Debug + 'static,
+        {
+            MenuRadio::new_on(label, group, move |mgr| mgr.push_msg(msg.clone()))
+        }
+
+        /// Set the initial state of the entry.
+        #[inline]
+        #[must_use]
+        pub fn with_state(mut self, state: bool) -> Self {
+            self.radiobox = self.radiobox.with_state(state);
+            self
+        }
+    }
+}
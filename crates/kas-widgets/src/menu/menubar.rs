@@ -111,6 +111,10 @@ impl_scope! {
     }
 
     impl<D: Directional> Widget for MenuBar<D> {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::Menu
+        }
+
         fn handle_event(&mut self, mgr: &mut EventMgr, event: Event) -> Response {
             match event {
                 Event::TimerUpdate(id_code) => {
@@ -7,7 +7,7 @@
 
 use super::{BoxedMenu, Menu, SubItems};
 use crate::{AccelLabel, Mark, PopupFrame};
-use kas::event::{Command, Scroll};
+use kas::event::{components::TypeAhead, Command, Scroll};
 use kas::layout::{self, RulesSetter, RulesSolver};
 use kas::prelude::*;
 use kas::theme::{FrameStyle, MarkStyle, TextClass};
@@ -80,11 +80,9 @@ impl_scope! {
 
         fn open_menu(&mut self, mgr: &mut EventMgr, set_focus: bool) {
             if self.popup_id.is_none() {
-                self.popup_id = mgr.add_popup(kas::Popup {
-                    id: self.list.id(),
-                    parent: self.id(),
-                    direction: self.direction.as_direction(),
-                });
+                let direction = self.direction.as_direction();
+                let popup = kas::Popup::new(self.list.id(), self.id(), direction);
+                self.popup_id = mgr.add_popup(popup);
                 if set_focus {
                     mgr.next_nav_focus(self, false, true);
                 }
@@ -97,32 +95,29 @@ impl_scope! {
         }
 
         fn handle_dir_key(&mut self, mgr: &mut EventMgr, cmd: Command) -> Response {
-            if self.menu_is_open() {
-                if let Some(dir) = cmd.as_direction() {
-                    if dir.is_vertical() {
-                        let rev = dir.is_reversed();
-                        mgr.next_nav_focus(self, rev, true);
-                        Response::Used
-                    } else if dir == self.direction.as_direction().reversed() {
-                        self.close_menu(mgr, true);
-                        Response::Used
-                    } else {
-                        Response::Unused
-                    }
-                } else if matches!(cmd, Command::Home | Command::End) {
+            let dir = self.direction.as_direction();
+            if !self.menu_is_open() {
+                if cmd == Command::from_direction(dir) {
+                    self.open_menu(mgr, true);
+                    return Response::Used;
+                }
+                return Response::Unused;
+            }
+
+            match cmd {
+                Command::Up | Command::Down => {
+                    mgr.next_nav_focus(self, cmd == Command::Up, true);
+                }
+                Command::Home | Command::End => {
                     mgr.clear_nav_focus();
-                    let rev = cmd == Command::End;
-                    mgr.next_nav_focus(self, rev, true);
-                    Response::Used
-                } else {
-                    Response::Unused
+                    mgr.next_nav_focus(self, cmd == Command::End, true);
                 }
-            } else if Some(self.direction.as_direction()) == cmd.as_direction() {
-                self.open_menu(mgr, true);
-                Response::Used
-            } else {
-                Response::Unused
+                cmd if cmd == Command::from_direction(dir.reversed()) => {
+                    self.close_menu(mgr, true);
+                }
+                _ => return Response::Unused,
             }
+            Response::Used
         }
     }
 
@@ -141,6 +136,14 @@ impl_scope! {
     }
 
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::MenuItem
+        }
+
+        fn accessible_label(&self) -> Option<String> {
+            Some(self.label.get_str().to_string())
+        }
+
         fn pre_configure(&mut self, mgr: &mut SetRectMgr, id: WidgetId) {
             self.core.id = id;
             mgr.add_accel_keys(self.id_ref(), self.label.keys());
@@ -164,7 +167,7 @@ impl_scope! {
                     }
                     Response::Used
                 }
-                Event::Command(cmd) => self.handle_dir_key(mgr, cmd),
+                Event::Command(cmd) if cmd.is_navigation() => self.handle_dir_key(mgr, cmd),
                 Event::PopupRemoved(id) => {
                     debug_assert_eq!(Some(id), self.popup_id);
                     self.popup_id = None;
@@ -192,6 +195,10 @@ impl_scope! {
             })
         }
 
+        fn menu_label(&self) -> Option<&str> {
+            Some(self.label.get_str())
+        }
+
         fn menu_is_open(&self) -> bool {
             self.popup_id.is_some()
         }
@@ -242,6 +249,7 @@ impl_scope! {
         dim: layout::GridDimensions,
         store: layout::DynGridStorage, //NOTE(opt): number of columns is fixed
         list: Vec<W>,
+        type_ahead: TypeAhead,
     }
 
     impl kas::WidgetChildren for Self {
@@ -261,12 +269,17 @@ impl_scope! {
 
     impl kas::Layout for Self {
         fn size_rules(&mut self, mgr: SizeMgr, axis: AxisInfo) -> SizeRules {
-            self.dim = layout::GridDimensions {
-                cols: MENU_VIEW_COLS,
-                col_spans: self.list.iter_mut().filter_map(|w| w.sub_items().is_none().then(|| ())).count().cast(),
-                rows: self.list.len().cast(),
-                row_spans: 0,
-            };
+            let col_spans = self
+                .list
+                .iter_mut()
+                .filter_map(|w| w.sub_items().is_none().then(|| ()))
+                .count();
+            self.dim = layout::GridDimensions::new(
+                MENU_VIEW_COLS,
+                col_spans.cast(),
+                self.list.len().cast(),
+                0,
+            );
 
             let store = &mut self.store;
             let mut solver = layout::GridSolver::<Vec<_>, Vec<_>, _>::new(axis, self.dim, store);
@@ -391,6 +404,30 @@ impl_scope! {
         }
     }
 
+    impl Widget for Self {
+        fn configure(&mut self, mgr: &mut SetRectMgr) {
+            mgr.request_type_ahead(self.id());
+        }
+
+        fn handle_event(&mut self, mgr: &mut EventMgr, event: Event) -> Response {
+            match event {
+                Event::ReceivedCharacter(c) => {
+                    let from = mgr.nav_focus().and_then(|id| self.find_child_index(id));
+                    let list = &self.list;
+                    let label = |index: usize| list.get(index).and_then(|w| w.menu_label());
+                    match self.type_ahead.search(c, from, list.len(), label) {
+                        Some(index) => {
+                            mgr.set_nav_focus(self.list[index].id(), true);
+                            Response::Used
+                        }
+                        None => Response::Unused,
+                    }
+                }
+                _ => Response::Unused,
+            }
+        }
+    }
+
     impl Self {
         /// Construct from a list of menu items
         pub fn new(list: Vec<W>) -> Self {
@@ -399,6 +436,7 @@ impl_scope! {
                 dim: Default::default(),
                 store: Default::default(),
                 list,
+                type_ahead: Default::default(),
             }
         }
 
@@ -75,6 +75,12 @@ impl_scope! {
         }
     }
 
+    impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::ProgressBar
+        }
+    }
+
     impl Layout for Self {
         fn size_rules(&mut self, size_mgr: SizeMgr, axis: AxisInfo) -> SizeRules {
             let mut size = size_mgr.progress_bar();
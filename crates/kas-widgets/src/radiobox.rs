@@ -14,6 +14,18 @@ use std::rc::Rc;
 use std::time::Instant;
 
 /// Type of radiobox group
+///
+/// A group is a shared handle over the identifier of the selected radiobox,
+/// if any. Construct with `RadioBoxGroup::default()` and pass a clone to
+/// each [`RadioBoxBare`] or [`RadioBox`] which should be a member; these may
+/// be placed anywhere in the widget tree. Selecting any member (by user input
+/// or via [`RadioBoxBare::select`]) deselects all other members, using the
+/// group's [`UpdateId`] to notify them.
+///
+/// Since update notifications are broadcast to all windows, a group may
+/// include radioboxes from multiple windows of the same application.
+///
+/// [`UpdateId`]: kas::event::UpdateId
 pub type RadioBoxGroup = SharedRc<Option<WidgetId>>;
 
 impl_scope! {
@@ -33,6 +45,10 @@ impl_scope! {
     }
 
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::RadioButton
+        }
+
         fn handle_event(&mut self, mgr: &mut EventMgr, event: Event) -> Response {
             match event {
                 Event::Update { id, .. } if id == self.group.id() => {
@@ -45,16 +61,7 @@ impl_scope! {
                     Response::Used
                 }
                 event => event.on_activate(mgr, self.id(), |mgr| {
-                    if !self.state {
-                        trace!("RadioBoxBare: set {}", self.id());
-                        self.state = true;
-                        self.last_change = Some(Instant::now());
-                        mgr.redraw(self.id());
-                        self.group.update(mgr, Some(self.id()));
-                        if let Some(f) = self.on_select.as_ref() {
-                            f(mgr);
-                        }
-                    }
+                    self.select(mgr);
                     Response::Used
                 })
             }
@@ -141,6 +148,24 @@ impl_scope! {
             self
         }
 
+        /// Select this radiobox
+        ///
+        /// This sets the state of this radiobox and deselects all other
+        /// members of the group, then calls the `on_select` handler (if any).
+        /// Does nothing if already selected.
+        pub fn select(&mut self, mgr: &mut EventMgr) {
+            if !self.state {
+                trace!("RadioBoxBare: set {}", self.id());
+                self.state = true;
+                self.last_change = Some(Instant::now());
+                mgr.redraw(self.id());
+                self.group.update(mgr, Some(self.id()));
+                if let Some(f) = self.on_select.as_ref() {
+                    f(mgr);
+                }
+            }
+        }
+
         /// Unset all radioboxes in the group
         ///
         /// Note: state will not update until the next draw.
@@ -190,6 +215,14 @@ impl_scope! {
     }
 
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::RadioButton
+        }
+
+        fn accessible_label(&self) -> Option<String> {
+            Some(self.label.get_str().to_string())
+        }
+
         fn configure(&mut self, mgr: &mut SetRectMgr) {
             mgr.add_accel_keys(self.inner.id_ref(), self.label.keys());
         }
@@ -278,6 +311,14 @@ impl_scope! {
             self
         }
 
+        /// Select this radiobox
+        ///
+        /// See [`RadioBoxBare::select`].
+        #[inline]
+        pub fn select(&mut self, mgr: &mut EventMgr) {
+            self.inner.select(mgr)
+        }
+
         /// Unset all radioboxes in the group
         ///
         /// Note: state will not update until the next draw.
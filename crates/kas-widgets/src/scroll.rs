@@ -6,15 +6,22 @@
 //! Scroll region
 
 use super::Scrollable;
-use kas::event::{components::ScrollComponent, Scroll};
+use kas::event::{components::ScrollComponent, Scroll, ScrollDelta, UpdateId};
 use kas::prelude::*;
+use kas::state::{SaveState, StateValue};
 use kas::theme::TextClass;
 use std::fmt::Debug;
+use std::time::Duration;
+
+const PAYLOAD_SPRING: u64 = 1;
+const PAYLOAD_NOTIFY: u64 = 2;
+const SPRING_POLL_MS: u64 = 16;
 
 impl_scope! {
     /// A scrollable region
     ///
     /// This region supports scrolling via mouse wheel and click/touch drag.
+    /// Holding <kbd>Shift</kbd> while using the mouse wheel scrolls horizontally.
     ///
     /// The ideal size of a `ScrollRegion` is the ideal size of its content:
     /// that is, all content may be shown at ideal size without scrolling.
@@ -24,6 +31,13 @@ impl_scope! {
     ///
     /// Scrollbars are not included; use [`ScrollBarRegion`] if you want those.
     ///
+    /// Other widgets may be notified of scrolling: see
+    /// [`ScrollRegion::with_update_id`].
+    ///
+    /// Optionally, dragging beyond the scroll limits may displace content
+    /// temporarily, springing back on release: see
+    /// [`ScrollRegion::with_overscroll`].
+    ///
     /// [`ScrollBarRegion`]: crate::ScrollBarRegion
     #[autoimpl(Deref, DerefMut using self.inner)]
     #[autoimpl(class_traits using self.inner where W: trait)]
@@ -32,9 +46,14 @@ impl_scope! {
     pub struct ScrollRegion<W: Widget> {
         core: widget_core!(),
         min_child_size: Size,
+        ideal_child_size: Size,
+        content_size: Size,
         offset: Offset,
         frame_size: Size,
         scroll: ScrollComponent,
+        update_id: Option<UpdateId>,
+        overscroll: bool,
+        overscroll_offset: Offset,
         #[widget]
         inner: W,
     }
@@ -46,13 +65,104 @@ impl_scope! {
             ScrollRegion {
                 core: Default::default(),
                 min_child_size: Size::ZERO,
+                ideal_child_size: Size::ZERO,
+                content_size: Size::ZERO,
                 offset: Default::default(),
                 frame_size: Default::default(),
                 scroll: Default::default(),
+                update_id: None,
+                overscroll: false,
+                overscroll_offset: Offset::ZERO,
                 inner,
             }
         }
 
+        /// Trigger an update on scroll
+        ///
+        /// If set, the region calls [`EventMgr::trigger_update`] with this `id`
+        /// whenever its scroll offset changes. The `payload` encodes the new
+        /// offset; use [`ScrollRegion::offset_from_payload`] to decode.
+        ///
+        /// A sibling widget (e.g. a minimap or linked view) may subscribe by
+        /// sharing the same [`UpdateId`] and matching
+        /// [`Event::Update`] with this `id` in its [`Widget::handle_event`].
+        #[inline]
+        #[must_use]
+        pub fn with_update_id(mut self, id: UpdateId) -> Self {
+            self.update_id = Some(id);
+            self
+        }
+
+        /// Enable or disable overscroll (default: disabled)
+        ///
+        /// When enabled, dragging content beyond the scroll limits (via touch
+        /// or mouse panning) displaces the content by half the excess distance;
+        /// on release the content springs back to the limit.
+        ///
+        /// Overscroll does not affect [`Scrollable::scroll_offset`], which
+        /// remains within the range `0..=max_scroll_offset()`.
+        #[inline]
+        #[must_use]
+        pub fn with_overscroll(mut self, overscroll: bool) -> Self {
+            self.overscroll = overscroll;
+            self
+        }
+
+        /// Offset of content including any overscroll displacement
+        #[inline]
+        fn visible_offset(&self) -> Offset {
+            self.scroll.offset() + self.overscroll_offset / 2
+        }
+
+        /// Decode the scroll offset from an update payload
+        ///
+        /// See [`ScrollRegion::with_update_id`].
+        #[inline]
+        pub fn offset_from_payload(payload: u64) -> Offset {
+            Offset(i32::conv(payload >> 32), i32::conv(payload & 0xFFFF_FFFF))
+        }
+
+        fn notify(&self, mgr: &mut EventMgr) {
+            if let Some(id) = self.update_id {
+                // The offset is never negative
+                let offset = self.scroll.offset();
+                let payload = (u64::conv(offset.0) << 32) | u64::conv(offset.1);
+                mgr.trigger_update(id, payload);
+            }
+        }
+
+        fn notify_if_scrolled(&self, mgr: &mut EventMgr, old_offset: Offset) {
+            if self.scroll.offset() != old_offset {
+                self.notify(mgr);
+            }
+        }
+
+        // Where the offset changes without an EventMgr (e.g. when clamped
+        // due to resizing), notification is deferred via a timer update
+        fn notify_deferred(&self, mgr: &mut EventState, old_offset: Offset) {
+            if self.update_id.is_some() && self.scroll.offset() != old_offset {
+                mgr.update_on_timer(Duration::ZERO, self.id(), PAYLOAD_NOTIFY);
+            }
+        }
+
+        /// Get the size of content
+        ///
+        /// This is the size allocated to the inner widget, capped to its ideal
+        /// size. On each axis:
+        ///
+        /// -   where the viewport is smaller than the inner widget's minimum
+        ///     size, this is the minimum size (and content is scrollable)
+        /// -   where the viewport is between the minimum and ideal size, the
+        ///     inner widget fills the viewport and this is the viewport size
+        /// -   where the viewport is larger than the ideal size, this is the
+        ///     ideal size
+        ///
+        /// Valid only after [`Layout::set_rect`] has been called.
+        #[inline]
+        pub fn content_size(&self) -> Size {
+            self.content_size
+        }
+
         /// Access inner widget directly
         #[inline]
         pub fn inner(&self) -> &W {
@@ -86,15 +196,23 @@ impl_scope! {
 
         #[inline]
         fn set_scroll_offset(&mut self, mgr: &mut EventMgr, offset: Offset) -> Offset {
+            let old_offset = self.scroll.offset();
             *mgr |= self.scroll.set_offset(offset);
+            self.notify_if_scrolled(mgr, old_offset);
             self.scroll.offset()
         }
+
+        #[inline]
+        fn draws_clipped(&self) -> bool {
+            true
+        }
     }
 
     impl Layout for Self {
         fn size_rules(&mut self, size_mgr: SizeMgr, axis: AxisInfo) -> SizeRules {
             let mut rules = self.inner.size_rules(size_mgr.re(), axis);
             self.min_child_size.set_component(axis, rules.min_size());
+            self.ideal_child_size.set_component(axis, rules.ideal_size());
             let line_height = size_mgr.line_height(TextClass::Label(false));
             rules.reduce_min_to(line_height);
 
@@ -111,9 +229,10 @@ impl_scope! {
             let child_size = (rect.size - self.frame_size).max(self.min_child_size);
             let child_rect = Rect::new(rect.pos + self.offset, child_size);
             self.inner.set_rect(mgr, child_rect, AlignHints::NONE);
-            let _ = self
-                .scroll
-                .set_sizes(rect.size, child_size + self.frame_size);
+            self.content_size = child_size.min(self.ideal_child_size);
+            let old_offset = self.scroll.offset();
+            *mgr |= self.scroll.set_sizes(rect.size, child_size + self.frame_size);
+            self.notify_deferred(mgr, old_offset);
         }
 
         fn find_id(&mut self, coord: Coord) -> Option<WidgetId> {
@@ -124,28 +243,96 @@ impl_scope! {
         }
 
         fn draw(&mut self, mut draw: DrawMgr) {
-            draw.with_clip_region(self.core.rect, self.scroll_offset(), |mut draw| {
+            draw.with_clip_region(self.core.rect, self.visible_offset(), |mut draw| {
                 draw.recurse(&mut self.inner);
             });
         }
     }
 
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::ScrollRegion
+        }
+
         fn configure(&mut self, mgr: &mut SetRectMgr) {
             mgr.register_nav_fallback(self.id());
         }
 
         #[inline]
         fn translation(&self) -> Offset {
-            self.scroll_offset()
+            self.visible_offset()
         }
 
-        fn handle_event(&mut self, mgr: &mut EventMgr, event: Event) -> Response {
-            self.scroll.scroll_by_event(mgr, event, self.id(), self.core.rect).1
+        fn handle_event(&mut self, mgr: &mut EventMgr, mut event: Event) -> Response {
+            match event {
+                Event::Scroll(delta) if mgr.modifiers().shift() => {
+                    // Shift+wheel scrolls horizontally (down maps to right)
+                    event = Event::Scroll(match delta {
+                        ScrollDelta::LineDelta(x, y) => ScrollDelta::LineDelta(y, x),
+                        ScrollDelta::PixelDelta(d) => ScrollDelta::PixelDelta(Offset(d.1, d.0)),
+                    });
+                }
+                Event::PressMove { ref mut delta, .. } if self.overscroll => {
+                    // Move the unclamped offset by delta. The scroll component
+                    // takes the part within range; the excess is overscroll.
+                    let target = self.scroll.offset() + self.overscroll_offset - *delta;
+                    let clamped = target.min(self.scroll.max_offset()).max(Offset::ZERO);
+                    if target - clamped != self.overscroll_offset {
+                        self.overscroll_offset = target - clamped;
+                        *mgr |= TkAction::REGION_MOVED;
+                    }
+                    *delta = self.scroll.offset() - clamped;
+                }
+                Event::PressEnd { .. } if self.overscroll_offset != Offset::ZERO => {
+                    mgr.update_on_timer(Duration::ZERO, self.id(), PAYLOAD_SPRING);
+                }
+                Event::TimerUpdate(PAYLOAD_SPRING) => {
+                    // Spring back: decay overscroll until it reaches zero
+                    self.overscroll_offset = self.overscroll_offset * 3 / 4;
+                    *mgr |= TkAction::REGION_MOVED;
+                    if self.overscroll_offset != Offset::ZERO {
+                        let dur = Duration::from_millis(SPRING_POLL_MS);
+                        mgr.update_on_timer(dur, self.id(), PAYLOAD_SPRING);
+                    }
+                    return Response::Used;
+                }
+                Event::TimerUpdate(PAYLOAD_NOTIFY) => {
+                    self.notify(mgr);
+                    return Response::Used;
+                }
+                _ => (),
+            }
+            let old_offset = self.scroll.offset();
+            let response = self.scroll.scroll_by_event(mgr, event, self.id(), self.core.rect).1;
+            self.notify_if_scrolled(mgr, old_offset);
+            response
         }
 
         fn handle_scroll(&mut self, mgr: &mut EventMgr, scroll: Scroll) {
+            let old_offset = self.scroll.offset();
             self.scroll.scroll(mgr, self.rect(), scroll);
+            self.notify_if_scrolled(mgr, old_offset);
+        }
+
+        #[inline]
+        fn as_save_state(&mut self) -> Option<&mut dyn SaveState> {
+            Some(self)
+        }
+    }
+
+    /// Saves the scroll offset
+    impl SaveState for Self {
+        fn save_state(&self) -> Option<StateValue> {
+            let offset = self.scroll.offset();
+            Some(StateValue::Offset(offset.0, offset.1))
+        }
+
+        fn load_state(&mut self, mgr: &mut SetRectMgr, state: &StateValue) {
+            if let StateValue::Offset(x, y) = *state {
+                let old_offset = self.scroll.offset();
+                *mgr |= self.scroll.set_offset(Offset(x, y));
+                self.notify_deferred(mgr, old_offset);
+            }
         }
     }
 }
@@ -7,7 +7,7 @@
 
 use super::Scrollable;
 use kas::event::components::{TextInput, TextInputAction};
-use kas::event::{Command, CursorIcon, Scroll, ScrollDelta};
+use kas::event::{Command, CursorIcon, Scroll};
 use kas::geom::Vec2;
 use kas::prelude::*;
 use kas::text::format::{EditableText, FormattableText};
@@ -156,6 +156,14 @@ impl_scope! {
     }
 
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::Label
+        }
+
+        fn accessible_label(&self) -> Option<String> {
+            Some(self.text.as_str().to_string())
+        }
+
         #[inline]
         fn translation(&self) -> Offset {
             self.scroll_offset()
@@ -189,14 +197,8 @@ impl_scope! {
                     Response::Used
                 }
                 Event::Scroll(delta) => {
-                    let delta2 = match delta {
-                        ScrollDelta::LineDelta(x, y) => {
-                            // We arbitrarily scroll 3 lines:
-                            let dist = 3.0 * self.text.env().height(Default::default());
-                            Offset((x * dist).cast_nearest(), (y * dist).cast_nearest())
-                        }
-                        ScrollDelta::PixelDelta(coord) => coord,
-                    };
+                    let line_height = self.text.env().height(Default::default());
+                    let delta2 = delta.to_offset(mgr.config(), Some(line_height));
                     self.pan_delta(mgr, delta2)
                 }
                 event => match self.input_handler.handle(mgr, self.id(), event) {
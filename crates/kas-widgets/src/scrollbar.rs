@@ -6,11 +6,15 @@
 //! `ScrollBar` control
 
 use std::fmt::Debug;
+use std::time::{Duration, Instant};
 
 use super::{DragHandle, ScrollRegion};
 use kas::event::{MsgPressFocus, Scroll};
 use kas::prelude::*;
 
+const PAYLOAD_HIDE: u64 = 1;
+const OVERLAY_HIDE_DELAY: Duration = Duration::from_millis(1000);
+
 impl_scope! {
     /// A scroll bar
     ///
@@ -34,6 +38,8 @@ impl_scope! {
         handle_value: i32, // contract: > 0
         max_value: i32,
         value: i32,
+        // Overlay mode (controlled by ScrollBars): (visible, last_change)
+        fade: Option<(bool, Option<Instant>)>,
         #[widget]
         handle: DragHandle,
     }
@@ -62,6 +68,7 @@ impl_scope! {
                 handle_value: 1,
                 max_value: 0,
                 value: 0,
+                fade: None,
                 handle: DragHandle::new(),
             }
         }
@@ -243,11 +250,19 @@ impl_scope! {
 
         fn draw(&mut self, mut draw: DrawMgr) {
             let dir = self.direction.as_direction();
-            draw.scrollbar(self.rect(), &self.handle, dir);
+            if let Some((visible, last_change)) = self.fade {
+                draw.scrollbar_overlay(self.rect(), &self.handle, dir, visible, last_change);
+            } else {
+                draw.scrollbar(self.rect(), &self.handle, dir);
+            }
         }
     }
 
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::ScrollBar
+        }
+
         fn handle_event(&mut self, mgr: &mut EventMgr, event: Event) -> Response {
             match event {
                 Event::PressStart { source, coord, .. } => {
@@ -310,6 +325,30 @@ pub trait Scrollable: Widget {
     /// The offset is clamped to the available scroll range and applied. The
     /// resulting offset is returned.
     fn set_scroll_offset(&mut self, mgr: &mut EventMgr, offset: Offset) -> Offset;
+
+    /// Scroll by a relative amount
+    ///
+    /// Adds `delta` to the current [`Scrollable::scroll_offset`], then calls
+    /// [`Scrollable::set_scroll_offset`] with the result; thus the offset is
+    /// clamped and any wrapping controls are updated as for that method.
+    /// The resulting offset is returned.
+    #[inline]
+    fn scroll_by(&mut self, mgr: &mut EventMgr, delta: Offset) -> Offset {
+        let offset = self.scroll_offset() + delta;
+        self.set_scroll_offset(mgr, offset)
+    }
+
+    /// Whether contents are drawn within a translated clip region
+    ///
+    /// If true, the widget draws its content within its own clip region
+    /// (translated by [`Scrollable::scroll_offset`]); [`ScrollBars`] then
+    /// draws scroll bars within a second clip region to force draw order.
+    ///
+    /// The default implementation returns false.
+    #[inline]
+    fn draws_clipped(&self) -> bool {
+        false
+    }
 }
 
 impl_scope! {
@@ -339,13 +378,36 @@ impl_scope! {
         /// as required when resized.
         ///
         /// This has the side-effect of reserving enough space for scroll bars even
-        /// when not required.
+        /// when not required (except in overlay mode).
         #[inline]
         #[must_use]
         pub fn with_auto_bars(self, enable: bool) -> Self {
             ScrollBarRegion(self.0.with_auto_bars(enable))
         }
 
+        /// Enable overlay mode
+        ///
+        /// See [`ScrollBars::with_overlay`].
+        #[inline]
+        #[must_use]
+        pub fn with_overlay(self, overlay: bool) -> Self {
+            ScrollBarRegion(self.0.with_overlay(overlay))
+        }
+
+        /// Enable or disable overlay mode
+        ///
+        /// A resize is required to update the child and scrollbar widgets.
+        #[inline]
+        pub fn set_overlay(&mut self, overlay: bool) -> TkAction {
+            self.0.set_overlay(overlay)
+        }
+
+        /// Query whether overlay mode is enabled
+        #[inline]
+        pub fn overlay(&self) -> bool {
+            self.0.overlay()
+        }
+
         /// Set which scroll bars are visible
         ///
         /// Calling this method also disables automatic scroll bars.
@@ -415,6 +477,15 @@ impl_scope! {
     /// the result looks poor when content is scrolled. Instead the content should
     /// force internal margins by wrapping contents with a (zero-sized) frame.
     /// [`ScrollRegion`] already does this.
+    ///
+    /// # Overlay mode
+    ///
+    /// When enabled via [`ScrollBars::with_overlay`], scroll bars do not
+    /// reserve any space; instead they are drawn over the content. Bars are
+    /// hidden until the content is scrolled or hovered by the mouse, then fade
+    /// out again after a short delay without such activity (though not while
+    /// the mouse is over a bar or a bar is being dragged). While hidden, bars
+    /// do not receive input.
     #[autoimpl(Deref, DerefMut using self.inner)]
     #[autoimpl(class_traits using self.inner where W: trait)]
     #[derive(Clone, Debug, Default)]
@@ -423,6 +494,10 @@ impl_scope! {
         core: widget_core!(),
         auto_bars: bool,
         show_bars: (bool, bool),
+        overlay: bool,
+        overlay_visible: bool,
+        overlay_change: Option<Instant>,
+        overlay_activity: Option<Instant>,
         #[widget]
         horiz_bar: ScrollBar<kas::dir::Right>,
         #[widget]
@@ -442,6 +517,10 @@ impl_scope! {
                 core: Default::default(),
                 auto_bars: true,
                 show_bars: (false, false),
+                overlay: false,
+                overlay_visible: false,
+                overlay_change: None,
+                overlay_activity: None,
                 horiz_bar: ScrollBar::new(),
                 vert_bar: ScrollBar::new(),
                 inner,
@@ -454,7 +533,7 @@ impl_scope! {
         /// as required when resized.
         ///
         /// This has the side-effect of reserving enough space for scroll bars even
-        /// when not required.
+        /// when not required (except in overlay mode).
         #[inline]
         #[must_use]
         pub fn with_auto_bars(mut self, enable: bool) -> Self {
@@ -462,6 +541,36 @@ impl_scope! {
             self
         }
 
+        /// Enable overlay mode
+        ///
+        /// If enabled, scroll bars are drawn over content without reserving
+        /// space, fading in on activity. See [overlay mode](#overlay-mode).
+        #[inline]
+        #[must_use]
+        pub fn with_overlay(mut self, overlay: bool) -> Self {
+            let _ = self.set_overlay(overlay);
+            self
+        }
+
+        /// Enable or disable overlay mode
+        ///
+        /// A resize is required to update the child and scrollbar widgets.
+        pub fn set_overlay(&mut self, overlay: bool) -> TkAction {
+            self.overlay = overlay;
+            self.overlay_visible = false;
+            self.overlay_change = None;
+            let fade = if overlay { Some((false, None)) } else { None };
+            self.horiz_bar.fade = fade;
+            self.vert_bar.fade = fade;
+            TkAction::RESIZE
+        }
+
+        /// Query whether overlay mode is enabled
+        #[inline]
+        pub fn overlay(&self) -> bool {
+            self.overlay
+        }
+
         /// Set which scroll bars are visible
         ///
         /// Calling this method also disables automatic scroll bars.
@@ -504,14 +613,37 @@ impl_scope! {
             &mut self.inner
         }
 
-        fn draw_(&mut self, mut draw: DrawMgr) {
-            if self.show_bars.0 {
-                draw.recurse(&mut self.horiz_bar);
+        // Set visibility of overlay bars (no effect if not in overlay mode)
+        fn set_overlay_visible(&mut self, mgr: &mut EventMgr, visible: bool) {
+            if self.overlay && visible != self.overlay_visible {
+                self.overlay_visible = visible;
+                self.overlay_change = Some(Instant::now());
+                let fade = Some((visible, self.overlay_change));
+                self.horiz_bar.fade = fade;
+                self.vert_bar.fade = fade;
+                mgr.redraw(self.id());
             }
-            if self.show_bars.1 {
-                draw.recurse(&mut self.vert_bar);
+        }
+
+        // Show overlay bars and (re)start the hide timer
+        fn overlay_activity(&mut self, mgr: &mut EventMgr) {
+            if self.overlay {
+                self.set_overlay_visible(mgr, true);
+                self.overlay_activity = Some(Instant::now());
+                mgr.update_on_timer(OVERLAY_HIDE_DELAY, self.id(), PAYLOAD_HIDE);
             }
-            draw.recurse(&mut self.inner);
+        }
+
+        // True if the mouse is over a bar or a bar is being dragged
+        fn bars_in_use(&self, mgr: &EventMgr) -> bool {
+            let ids = [
+                self.horiz_bar.id_ref(),
+                self.horiz_bar.handle.id_ref(),
+                self.vert_bar.id_ref(),
+                self.vert_bar.handle.id_ref(),
+            ];
+            ids.iter()
+                .any(|id| mgr.is_hovered(id) || mgr.is_depressed(id))
         }
     }
 
@@ -530,7 +662,11 @@ impl_scope! {
         }
         fn set_scroll_offset(&mut self, mgr: &mut EventMgr, offset: Offset) -> Offset {
             let offset = self.inner.set_scroll_offset(mgr, offset);
-            *mgr |= self.horiz_bar.set_value(offset.0) | self.vert_bar.set_value(offset.1);
+            let action = self.horiz_bar.set_value(offset.0) | self.vert_bar.set_value(offset.1);
+            if !action.is_empty() {
+                *mgr |= action;
+                self.overlay_activity(mgr);
+            }
             offset
         }
     }
@@ -538,10 +674,16 @@ impl_scope! {
     impl Layout for Self {
         fn size_rules(&mut self, size_mgr: SizeMgr, axis: AxisInfo) -> SizeRules {
             let mut rules = self.inner.size_rules(size_mgr.re(), axis);
-            if axis.is_horizontal() && (self.auto_bars || self.show_bars.1) {
-                rules.append(self.vert_bar.size_rules(size_mgr.re(), axis));
+            let bar_rules = if axis.is_horizontal() && (self.auto_bars || self.show_bars.1) {
+                Some(self.vert_bar.size_rules(size_mgr.re(), axis))
             } else if axis.is_vertical() && (self.auto_bars || self.show_bars.0) {
-                rules.append(self.horiz_bar.size_rules(size_mgr.re(), axis));
+                Some(self.horiz_bar.size_rules(size_mgr.re(), axis))
+            } else {
+                None
+            };
+            // In overlay mode bars are drawn over content: reserve no space
+            if let Some(bar_rules) = bar_rules.filter(|_| !self.overlay) {
+                rules.append(bar_rules);
             }
             rules
         }
@@ -549,18 +691,20 @@ impl_scope! {
         fn set_rect(&mut self, mgr: &mut SetRectMgr, rect: Rect, align: AlignHints) {
             self.core.rect = rect;
             let pos = rect.pos;
-            let mut child_size = rect.size;
+            // Size of the area not covered by bars
+            let mut bars_size = rect.size;
 
             let bar_width = (mgr.size_mgr().scrollbar().0).1;
             if self.auto_bars {
-                self.show_bars = self.inner.scroll_axes(child_size);
+                self.show_bars = self.inner.scroll_axes(bars_size);
             }
             if self.show_bars.0 {
-                child_size.1 -= bar_width;
+                bars_size.1 -= bar_width;
             }
             if self.show_bars.1 {
-                child_size.0 -= bar_width;
+                bars_size.0 -= bar_width;
             }
+            let child_size = if self.overlay { rect.size } else { bars_size };
 
             let child_rect = Rect::new(pos, child_size);
             self.inner.set_rect(mgr, child_rect, align);
@@ -568,7 +712,7 @@ impl_scope! {
 
             if self.show_bars.0 {
                 let pos = Coord(pos.0, rect.pos2().1 - bar_width);
-                let size = Size::new(child_size.0, bar_width);
+                let size = Size::new(bars_size.0, bar_width);
                 self.horiz_bar
                     .set_rect(mgr, Rect { pos, size }, AlignHints::NONE);
                 let _ = self.horiz_bar.set_limits(max_scroll_offset.0, rect.size.0);
@@ -586,30 +730,33 @@ impl_scope! {
             if !self.rect().contains(coord) {
                 return None;
             }
-            self.vert_bar.find_id(coord)
-                .or_else(|| self.horiz_bar.find_id(coord))
+            // Hidden overlay bars do not receive input
+            let bar_id = if !self.overlay || self.overlay_visible {
+                self.vert_bar.find_id(coord)
+                    .or_else(|| self.horiz_bar.find_id(coord))
+            } else {
+                None
+            };
+            bar_id
                 .or_else(|| self.inner.find_id(coord))
                 .or(Some(self.id()))
         }
 
-        #[cfg(feature = "min_spec")]
-        default fn draw(&mut self, draw: DrawMgr) {
-            self.draw_(draw);
-        }
-        #[cfg(not(feature = "min_spec"))]
-        fn draw(&mut self, draw: DrawMgr) {
-            self.draw_(draw);
-        }
-    }
-
-    #[cfg(feature = "min_spec")]
-    impl<W: Widget> Layout for ScrollBars<ScrollRegion<W>> {
         fn draw(&mut self, mut draw: DrawMgr) {
-            // Enlarge clip region to *our* rect:
-            draw.with_clip_region(self.core.rect, self.inner.scroll_offset(), |mut draw| {
+            if !self.inner.draws_clipped() && !self.overlay {
+                if self.show_bars.0 {
+                    draw.recurse(&mut self.horiz_bar);
+                }
+                if self.show_bars.1 {
+                    draw.recurse(&mut self.vert_bar);
+                }
                 draw.recurse(&mut self.inner);
-            });
-            // Use a second clip region to force draw order:
+                return;
+            }
+
+            // The inner widget applies its own clip region and offset (if any)
+            draw.recurse(&mut self.inner);
+            // Use a clip region to force draw order (bars over content):
             draw.with_clip_region(self.core.rect, Offset::ZERO, |mut draw| {
                 if self.show_bars.0 {
                     draw.recurse(&mut self.horiz_bar);
@@ -622,11 +769,42 @@ impl_scope! {
     }
 
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::ScrollRegion
+        }
+
         fn configure(&mut self, mgr: &mut SetRectMgr) {
             mgr.register_nav_fallback(self.id());
         }
 
+        fn handle_event(&mut self, mgr: &mut EventMgr, event: Event) -> Response {
+            match event {
+                // Hover events are unused by most widgets, thus bubble up to here
+                Event::MouseEnter => {
+                    self.overlay_activity(mgr);
+                    Response::Unused
+                }
+                Event::TimerUpdate(PAYLOAD_HIDE) => {
+                    let elapsed = self.overlay_activity.map(|inst| inst.elapsed());
+                    let elapsed = elapsed.unwrap_or(OVERLAY_HIDE_DELAY);
+                    if elapsed < OVERLAY_HIDE_DELAY {
+                        let delay = OVERLAY_HIDE_DELAY - elapsed;
+                        mgr.update_on_timer(delay, self.id(), PAYLOAD_HIDE);
+                    } else if self.bars_in_use(mgr) {
+                        mgr.update_on_timer(OVERLAY_HIDE_DELAY, self.id(), PAYLOAD_HIDE);
+                    } else {
+                        self.set_overlay_visible(mgr, false);
+                    }
+                    Response::Used
+                }
+                _ => Response::Unused,
+            }
+        }
+
         fn handle_message(&mut self, mgr: &mut EventMgr, index: usize) {
+            if index == widget_index![self.horiz_bar] || index == widget_index![self.vert_bar] {
+                self.overlay_activity(mgr);
+            }
             if index == widget_index![self.horiz_bar] {
                 if let Some(msg) = mgr.try_pop_msg() {
                     let offset = Offset(msg, self.inner.scroll_offset().1);
@@ -644,6 +822,7 @@ impl_scope! {
             // We assume the inner already updated its positions; this is just to set bars
             let offset = self.inner.scroll_offset();
             *mgr |= self.horiz_bar.set_value(offset.0) | self.vert_bar.set_value(offset.1);
+            self.overlay_activity(mgr);
         }
     }
 }
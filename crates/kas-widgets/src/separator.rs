@@ -30,6 +30,12 @@ impl_scope! {
         }
     }
 
+    impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::Separator
+        }
+    }
+
     impl Layout for Self {
         fn size_rules(&mut self, size_mgr: SizeMgr, axis: AxisInfo) -> SizeRules {
             SizeRules::extract_fixed(axis, size_mgr.separator(), Margins::ZERO)
@@ -87,6 +87,9 @@ impl_scope! {
     ///
     /// Sliders allow user input of a value from a fixed range.
     ///
+    /// Optionally, tick marks may be drawn along the track with values snapped
+    /// to the nearest tick; see [`Slider::with_ticks`].
+    ///
     /// # Messages
     ///
     /// On value change, pushes a value of type `T`.
@@ -102,6 +105,8 @@ impl_scope! {
         range: RangeInclusive<T>,
         step: T,
         value: T,
+        ticks: Option<T>,
+        snap_live: bool,
         #[widget]
         handle: DragHandle,
     }
@@ -140,6 +145,8 @@ impl_scope! {
                 range,
                 step,
                 value,
+                ticks: None,
+                snap_live: false,
                 handle: DragHandle::new(),
             }
         }
@@ -148,7 +155,35 @@ impl_scope! {
         #[inline]
         #[must_use]
         pub fn with_value(mut self, value: T) -> Self {
-            self.value = self.clamp_value(value);
+            self.value = self.snap_value(self.clamp_value(value));
+            self
+        }
+
+        /// Enable tick marks and snapping
+        ///
+        /// Tick marks are drawn at the range's lower bound and at each
+        /// multiple of `step` above it. Values are snapped to the nearest
+        /// tick; while dragging, this happens on release (unless
+        /// [`Slider::with_live_snap`] is used). Arrow keys move the value by
+        /// one tick and page up/down keys by 16 ticks.
+        ///
+        /// The `step` must be positive.
+        #[inline]
+        #[must_use]
+        pub fn with_ticks(mut self, step: T) -> Self {
+            self.ticks = Some(step);
+            self.value = self.snap_value(self.value);
+            self
+        }
+
+        /// Set whether to snap the value to ticks while dragging
+        ///
+        /// By default (`false`), the value snaps to the nearest tick only on
+        /// release. This has no effect unless [`Slider::with_ticks`] is used.
+        #[inline]
+        #[must_use]
+        pub fn with_live_snap(mut self, live: bool) -> Self {
+            self.snap_live = live;
             self
         }
 
@@ -170,11 +205,30 @@ impl_scope! {
             }
         }
 
+        // snap value (within range) to the nearest tick, if enabled
+        fn snap_value(&self, value: T) -> T {
+            let step = match self.ticks {
+                Some(step) => step,
+                None => return value,
+            };
+            let start = *self.range.start();
+            let max_ticks = (*self.range.end() - start).div_as_f64(step).floor();
+            let ticks = (value - start).div_as_f64(step).round().min(max_ticks);
+            if ticks.is_finite() && ticks > 0.0 {
+                start + step.mul_f64(ticks)
+            } else {
+                start
+            }
+        }
+
         /// Set the value
         ///
+        /// The value is clamped to the range and snapped to the nearest tick
+        /// (if enabled).
+        ///
         /// Returns [`TkAction::REDRAW`] if a redraw is required.
         pub fn set_value(&mut self, value: T) -> TkAction {
-            let value = self.clamp_value(value);
+            let value = self.snap_value(self.clamp_value(value));
             if value == self.value {
                 TkAction::empty()
             } else {
@@ -187,9 +241,14 @@ impl_scope! {
         fn offset(&self) -> Offset {
             let a = self.value - *self.range.start();
             let b = *self.range.end() - *self.range.start();
-            let max_offset = self.handle.max_offset();
-            let mut frac = a.div_as_f64(b);
+            let frac = a.div_as_f64(b);
             assert!((0.0..=1.0).contains(&frac));
+            self.frac_offset(frac)
+        }
+
+        // translate a fraction of the range to offset in local coordinates
+        fn frac_offset(&self, mut frac: f64) -> Offset {
+            let max_offset = self.handle.max_offset();
             if self.direction.is_reversed() {
                 frac = 1.0 - frac;
             }
@@ -209,7 +268,10 @@ impl_scope! {
             if self.direction.is_reversed() {
                 a = b - a;
             }
-            let value = self.clamp_value(a + *self.range.start());
+            let mut value = self.clamp_value(a + *self.range.start());
+            if self.snap_live {
+                value = self.snap_value(value);
+            }
             if value != self.value {
                 self.value = value;
                 *mgr |= self.handle.set_offset(self.offset()).1;
@@ -250,12 +312,47 @@ impl_scope! {
         }
 
         fn draw(&mut self, mut draw: DrawMgr) {
+            if let Some(step) = self.ticks {
+                self.draw_ticks(&mut draw, step);
+            }
             let dir = self.direction.as_direction();
             draw.slider(self.rect(), &self.handle, dir);
         }
     }
 
+    impl Self {
+        fn draw_ticks(&self, draw: &mut DrawMgr, step: T) {
+            let frac_step = step.div_as_f64(*self.range.end() - *self.range.start());
+            let (dir, rect) = (self.direction, self.rect());
+            let max_offset = self.handle.max_offset().extract(dir);
+            // Skip drawing when ticks would not be distinguishable
+            if !(frac_step.is_finite() && max_offset as f64 * frac_step >= 2.0) {
+                return;
+            }
+
+            let width = draw.size_mgr().separator().extract(dir).max(1);
+            let half_handle = self.handle.rect().size.extract(dir) / 2;
+            let n: i32 = (1.0 / frac_step).cast_floor();
+            for i in 0..=n {
+                let offset = self.frac_offset(f64::conv(i) * frac_step);
+                let mid = offset.extract(dir) + half_handle;
+                let tick = if dir.is_horizontal() {
+                    let pos = rect.pos + Offset(mid - width / 2, 0);
+                    Rect::new(pos, Size(width, rect.size.1))
+                } else {
+                    let pos = rect.pos + Offset(0, mid - width / 2);
+                    Rect::new(pos, Size(rect.size.0, width))
+                };
+                draw.separator(tick);
+            }
+        }
+    }
+
     impl Widget for Self {
+        fn accessible_role(&self) -> AccessibleRole {
+            AccessibleRole::Slider
+        }
+
         fn handle_event(&mut self, mgr: &mut EventMgr, event: Event) -> Response {
             match event {
                 Event::NavFocus(true) => {
@@ -263,18 +360,19 @@ impl_scope! {
                 }
                 Event::Command(cmd) => {
                     let rev = self.direction.is_reversed();
+                    let step = self.ticks.unwrap_or(self.step);
                     let v = match cmd {
                         Command::Left | Command::Up => match rev {
-                            false => self.value - self.step,
-                            true => self.value + self.step,
+                            false => self.value - step,
+                            true => self.value + step,
                         },
                         Command::Right | Command::Down => match rev {
-                            false => self.value + self.step,
-                            true => self.value - self.step,
+                            false => self.value + step,
+                            true => self.value - step,
                         },
                         Command::PageUp | Command::PageDown => {
                             // Generics makes this easier than constructing a literal and multiplying!
-                            let mut x = self.step + self.step;
+                            let mut x = step + step;
                             x = x + x;
                             x = x + x;
                             x = x + x;
@@ -302,6 +400,19 @@ impl_scope! {
             Response::Used
         }
 
+        fn steal_event(&mut self, mgr: &mut EventMgr, id: &WidgetId, event: &Event) -> Response {
+            // Snap on release of the handle (the handle still receives the event)
+            if matches!(event, Event::PressEnd { .. }) && self.handle.id_ref() == id {
+                let value = self.snap_value(self.value);
+                if value != self.value {
+                    self.value = value;
+                    *mgr |= self.handle.set_offset(self.offset()).1;
+                    mgr.push_msg(self.value);
+                }
+            }
+            Response::Unused
+        }
+
         fn handle_message(&mut self, mgr: &mut EventMgr, _: usize) {
             if let Some(MsgPressFocus) = mgr.try_pop_msg() {
                 mgr.set_nav_focus(self.id(), false);
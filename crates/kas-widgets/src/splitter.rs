@@ -13,6 +13,7 @@ use kas::dir::{Down, Right};
 use kas::event::MsgPressFocus;
 use kas::layout::{self, RulesSetter, RulesSolver};
 use kas::prelude::*;
+use kas::state::{SaveState, StateValue};
 
 /// A generic row widget
 ///
@@ -260,6 +261,35 @@ impl_scope! {
                 }
             }
         }
+
+        #[inline]
+        fn as_save_state(&mut self) -> Option<&mut dyn SaveState> {
+            Some(self)
+        }
+    }
+
+    /// Saves handle positions
+    impl SaveState for Self {
+        fn save_state(&self) -> Option<StateValue> {
+            if self.handles.is_empty() {
+                return None;
+            }
+            let offsets = self.handles.iter().map(|h| h.offset()).map(|o| (o.0, o.1));
+            Some(StateValue::Offsets(offsets.collect()))
+        }
+
+        fn load_state(&mut self, mgr: &mut SetRectMgr, state: &StateValue) {
+            if let StateValue::Offsets(offsets) = state {
+                // Ignore state saved with a different number of handles
+                if offsets.len() != self.handles.len() {
+                    return;
+                }
+                for (n, (x, y)) in offsets.iter().cloned().enumerate() {
+                    *mgr |= self.handles[n].set_offset(Offset(x, y)).1;
+                    self.adjust_size(mgr, n);
+                }
+            }
+        }
     }
 
     impl Index<usize> for Self {
@@ -6,6 +6,7 @@
 //! A stack
 
 use kas::prelude::*;
+use kas::state::{SaveState, StateValue};
 use std::collections::hash_map::{Entry, HashMap};
 use std::fmt::Debug;
 use std::ops::{Index, IndexMut, Range};
@@ -140,6 +141,26 @@ impl_scope! {
                 _ => None,
             }
         }
+
+        #[inline]
+        fn as_save_state(&mut self) -> Option<&mut dyn SaveState> {
+            Some(self)
+        }
+    }
+
+    /// Saves the active page index
+    impl SaveState for Self {
+        fn save_state(&self) -> Option<StateValue> {
+            Some(StateValue::Index(self.active))
+        }
+
+        fn load_state(&mut self, mgr: &mut SetRectMgr, state: &StateValue) {
+            if let StateValue::Index(index) = *state {
+                if index < self.widgets.len() {
+                    self.set_active(mgr, index);
+                }
+            }
+        }
     }
 
     impl Index<usize> for Self {
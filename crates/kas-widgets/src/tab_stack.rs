@@ -6,6 +6,7 @@
 //! A tabbed stack
 
 use crate::{Row, Stack, TextButton};
+use kas::event::Command;
 use kas::prelude::*;
 use std::fmt::Debug;
 
@@ -15,6 +16,21 @@ struct MsgSelect;
 #[derive(Clone, Debug)]
 struct MsgSelectIndex(usize);
 
+/// Message: a new tab was requested
+///
+/// This is emitted by [`TabStack`] on [`Command::TabNew`]. The handler may
+/// add a page via [`TabStack::push`].
+#[derive(Clone, Debug)]
+pub struct MsgTabNew;
+
+/// Message: closing of the tab at this index was requested
+///
+/// This is emitted by [`TabStack`] on [`Command::Close`] when tabs are
+/// closable (see [`TabStack::with_closable`]). The handler may remove the page
+/// via [`TabStack::remove`].
+#[derive(Clone, Debug)]
+pub struct MsgTabClose(pub usize);
+
 /// A tab
 ///
 /// TODO: a tab is not a button! Support directional graphics, icon and close button.
@@ -40,6 +56,13 @@ impl_scope! {
     /// This may only be parametrised with a single widget type, thus usually
     /// it will be necessary to box children (this is what [`BoxTabStack`] is).
     ///
+    /// # Keyboard control
+    ///
+    /// [`Command::TabNext`] and [`Command::TabPrev`] (by default `Ctrl+Tab` and
+    /// `Ctrl+Shift+Tab`) select the next and previous page. [`Command::TabNew`]
+    /// emits [`MsgTabNew`]; if tabs are closable, [`Command::Close`] emits
+    /// [`MsgTabClose`]. Pages are added and removed by the message handler.
+    ///
     /// See also the main implementing widget: [`Stack`].
     #[impl_default]
     #[derive(Clone, Debug)]
@@ -52,6 +75,7 @@ impl_scope! {
     pub struct TabStack<W: Widget> {
         core: widget_core!(),
         direction: Direction = Direction::Up,
+        closable: bool,
         #[widget]
         tabs: Row<Tab>, // TODO: want a TabBar widget for scrolling support?
         #[widget]
@@ -64,6 +88,7 @@ impl_scope! {
             Self {
                 core: Default::default(),
                 direction: Direction::Up,
+                closable: false,
                 stack: Stack::new(),
                 tabs: Row::new().on_message(|mgr, index| {
                     if let Some(MsgSelect) = mgr.try_pop_msg() {
@@ -81,9 +106,46 @@ impl_scope! {
             // Note: most of the time SET_SIZE would be enough, but margins can be different
             TkAction::RESIZE
         }
+
+        /// Set whether tabs are closable (inline)
+        ///
+        /// If true, [`Command::Close`] emits [`MsgTabClose`] for the active
+        /// page. Default value: false.
+        #[inline]
+        #[must_use]
+        pub fn with_closable(mut self, closable: bool) -> Self {
+            self.closable = closable;
+            self
+        }
+
+        fn select_offset(&mut self, mgr: &mut EventMgr, reverse: bool) {
+            let len = self.len();
+            if len == 0 {
+                return;
+            }
+            let active = self.active().min(len - 1);
+            let index = match reverse {
+                false => (active + 1) % len,
+                true => (active + len - 1) % len,
+            };
+            mgr.set_rect_mgr(|mgr| self.set_active(mgr, index));
+        }
     }
 
     impl Widget for Self {
+        fn handle_event(&mut self, mgr: &mut EventMgr, event: Event) -> Response {
+            match event {
+                Event::Command(Command::TabNext) => self.select_offset(mgr, false),
+                Event::Command(Command::TabPrev) => self.select_offset(mgr, true),
+                Event::Command(Command::TabNew) => mgr.push_msg(MsgTabNew),
+                Event::Command(Command::Close) if self.closable && self.active() < self.len() => {
+                    mgr.push_msg(MsgTabClose(self.active()))
+                }
+                _ => return Response::Unused,
+            }
+            Response::Used
+        }
+
         fn handle_message(&mut self, mgr: &mut EventMgr, _: usize) {
             if let Some(MsgSelectIndex(index)) = mgr.try_pop_msg() {
                 mgr.set_rect_mgr(|mgr| self.set_active(mgr, index));
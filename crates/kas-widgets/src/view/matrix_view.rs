@@ -13,6 +13,7 @@ use kas::event::components::ScrollComponent;
 use kas::event::{Command, CursorIcon, Scroll};
 use kas::layout::solve_size_rules;
 use kas::prelude::*;
+use kas::state::{SaveState, StateValue};
 use kas::updatable::MatrixData;
 use linear_map::set::LinearSet;
 use log::{debug, trace};
@@ -413,7 +414,9 @@ impl_scope! {
                         .min(self.child_size_ideal.extract(other_axis))
                         .max(self.child_size_min.extract(other_axis))
                 });
-                let axis = AxisInfo::new(axis.is_vertical(), other);
+                let axis = AxisInfo::new(axis.is_vertical(), other)
+                    .with_target(axis.target())
+                    .with_generation(axis.generation());
                 for w in self.widgets.iter_mut() {
                     rules = rules.max(w.widget.size_rules(size_mgr.re(), axis));
                 }
@@ -791,6 +794,60 @@ impl_scope! {
             self.scroll.scroll(mgr, self.rect(), scroll);
             mgr.set_rect_mgr(|mgr| self.update_widgets(mgr));
         }
+
+        #[inline]
+        fn as_save_state(&mut self) -> Option<&mut dyn SaveState> {
+            Some(self)
+        }
+    }
+
+    /// Saves the selection, if selection is enabled
+    ///
+    /// Items are identified by the [`WidgetId`] path made from their key (see
+    /// [`MatrixData::make_id`]); keys no longer present in the data are ignored
+    /// when loading.
+    impl SaveState for Self {
+        fn save_state(&self) -> Option<StateValue> {
+            if matches!(self.sel_mode, SelectionMode::None) {
+                return None;
+            }
+            let id = self.id_ref();
+            let mut keys: Vec<Vec<usize>> = self
+                .selection
+                .iter()
+                .map(|key| self.data.make_id(id, key).iter_keys_after(id).collect())
+                .collect();
+            keys.sort_unstable();
+            Some(StateValue::Keys(keys))
+        }
+
+        fn load_state(&mut self, mgr: &mut SetRectMgr, state: &StateValue) {
+            let paths = match state {
+                StateValue::Keys(paths) => paths,
+                _ => return,
+            };
+            if matches!(self.sel_mode, SelectionMode::None) {
+                return;
+            }
+
+            let id = self.id_ref();
+            let data = &self.data;
+            let keys: Vec<_> = paths
+                .iter()
+                .filter_map(|path| {
+                    let child = path.iter().fold(id.clone(), |id, key| id.make_child(*key));
+                    data.reconstruct_key(id, &child).filter(|key| data.contains(key))
+                })
+                .collect();
+            self.selection.clear();
+            for key in keys {
+                self.selection.insert(key);
+                if matches!(self.sel_mode, SelectionMode::Single) {
+                    break;
+                }
+            }
+            *mgr |= TkAction::REDRAW;
+        }
     }
 }
 
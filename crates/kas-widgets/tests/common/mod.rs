@@ -0,0 +1,671 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE-APACHE file or at:
+//     https://www.apache.org/licenses/LICENSE-2.0
+
+//! Test harness: a headless shell, size handle and recording draw handle
+//!
+//! Sizes are fixed, simple values (see [`TestSize`]). Text is sized by
+//! character count and is never prepared, hence widgets requiring font
+//! access must not be used here.
+
+#![allow(dead_code, clippy::new_without_default)]
+
+use kas::draw::color::Rgba;
+use kas::draw::{AllocError, Draw, DrawShared, ImageFormat, ImageHandle, ImageId};
+use kas::draw::{AnimationState, DrawIface, DrawImpl, DrawSharedImpl, SharedState};
+use kas::draw::{PassId, PassType, PathBuilder};
+use kas::event::{Config, CursorIcon};
+use kas::geom::{Quad, Vec2};
+use kas::layout::{FrameRules, SolveCache};
+use kas::prelude::*;
+use kas::text::{Effect, TextDisplay};
+use kas::theme::{Background, DrawHandle, FrameStyle, MarkStyle, SizeHandle, TextClass};
+use kas::{ShellWindow, WindowId};
+use std::cell::{Cell, RefCell};
+use std::ops::Range;
+use std::rc::Rc;
+use std::time::Instant;
+
+/// Width of a frame (each side)
+pub const FRAME: i32 = 2;
+/// Width of a character of text
+pub const CHAR_WIDTH: i32 = 8;
+/// Height of a line of text
+pub const LINE_HEIGHT: i32 = 16;
+/// Size of inner margins (each axis)
+pub const INNER_MARGIN: i32 = 1;
+
+/// Size required by text `s`: [`CHAR_WIDTH`] per character of the longest
+/// line by [`LINE_HEIGHT`] per line
+pub fn text_size(s: &str) -> Size {
+    let len = s.lines().map(|line| line.chars().count()).max();
+    let lines = s.lines().count().max(1);
+    Size(
+        CHAR_WIDTH * len.unwrap_or(0) as i32,
+        LINE_HEIGHT * lines as i32,
+    )
+}
+
+/// A size handle returning fixed dimensions
+#[derive(Default)]
+pub struct TestSize {
+    /// Number of calls to [`SizeHandle::text_bound`]
+    pub text_bound_calls: Cell<usize>,
+}
+
+impl SizeHandle for TestSize {
+    fn scale_factor(&self) -> f32 {
+        1.0
+    }
+
+    fn pixels_from_points(&self, pt: f32) -> f32 {
+        pt
+    }
+
+    fn pixels_from_em(&self, em: f32) -> f32 {
+        em * LINE_HEIGHT as f32
+    }
+
+    fn frame(&self, _: FrameStyle, _: bool) -> FrameRules {
+        FrameRules::new_sym(FRAME, 0, 0)
+    }
+
+    fn separator(&self) -> Size {
+        Size::splat(1)
+    }
+
+    fn inner_margin(&self) -> Size {
+        Size::splat(INNER_MARGIN)
+    }
+
+    fn outer_margins(&self) -> Margins {
+        Margins::ZERO
+    }
+
+    fn text_margins(&self) -> Margins {
+        Margins::ZERO
+    }
+
+    fn line_height(&self, _: TextClass) -> i32 {
+        LINE_HEIGHT
+    }
+
+    fn text_bound(&self, text: &mut dyn TextApi, _: TextClass, axis: AxisInfo) -> SizeRules {
+        self.text_bound_calls.set(self.text_bound_calls.get() + 1);
+        let size = text_size(text.as_str());
+        SizeRules::fixed(size.extract(axis), (0, 0))
+    }
+
+    fn text_set_size(
+        &self,
+        text: &mut dyn TextApi,
+        _: TextClass,
+        _: Size,
+        _: (Align, Align),
+    ) -> Vec2 {
+        Vec2::conv(text_size(text.as_str()))
+    }
+
+    fn checkbox(&self) -> Size {
+        Size::splat(LINE_HEIGHT)
+    }
+
+    fn radiobox(&self) -> Size {
+        Size::splat(LINE_HEIGHT)
+    }
+
+    fn mark(&self, _: MarkStyle, _: bool) -> SizeRules {
+        SizeRules::fixed(LINE_HEIGHT, (0, 0))
+    }
+
+    fn scrollbar(&self) -> (Size, i32) {
+        (Size(8, 8), 16)
+    }
+
+    fn slider(&self) -> (Size, i32) {
+        (Size(12, 12), 24)
+    }
+
+    fn progress_bar(&self) -> Size {
+        Size(24, 8)
+    }
+}
+
+/// Image storage which does not support allocation
+pub struct TestDrawShared;
+
+impl DrawShared for TestDrawShared {
+    fn image_alloc(&mut self, _: (u32, u32)) -> Result<ImageHandle, AllocError> {
+        Err(AllocError)
+    }
+
+    fn image_upload(&mut self, _: &ImageHandle, _: &[u8], _: ImageFormat) {}
+
+    fn image_free(&mut self, _: ImageHandle) {}
+
+    fn image_size(&self, _: &ImageHandle) -> Option<Size> {
+        None
+    }
+}
+
+impl DrawSharedImpl for TestDrawShared {
+    type Draw = TestDevice;
+
+    fn image_alloc(&mut self, _: (u32, u32)) -> Result<ImageId, AllocError> {
+        Err(AllocError)
+    }
+
+    fn image_upload(&mut self, _: ImageId, _: &[u8], _: ImageFormat) {}
+
+    fn image_free(&mut self, _: ImageId) {}
+
+    fn image_size(&self, _: ImageId) -> Option<(u32, u32)> {
+        None
+    }
+
+    fn draw_image(&self, _: &mut TestDevice, _: PassId, _: ImageId, _: Quad) {}
+
+    fn draw_image_nine_patch(
+        &self,
+        _: &mut TestDevice,
+        _: PassId,
+        _: ImageId,
+        _: Quad,
+        _: Margins,
+        _: f32,
+    ) {
+    }
+
+    fn draw_image_tiled(&self, _: &mut TestDevice, _: PassId, _: ImageId, _: Quad, _: Vec2) {}
+
+    fn draw_text(&mut self, _: &mut TestDevice, _: PassId, _: Vec2, _: &TextDisplay, _: Rgba) {}
+
+    fn draw_text_col_effects(
+        &mut self,
+        _: &mut TestDevice,
+        _: PassId,
+        _: Vec2,
+        _: &TextDisplay,
+        _: Rgba,
+        _: &[Effect<()>],
+    ) {
+    }
+
+    fn draw_text_effects(
+        &mut self,
+        _: &mut TestDevice,
+        _: PassId,
+        _: Vec2,
+        _: &TextDisplay,
+        _: &[Effect<Rgba>],
+    ) {
+    }
+}
+
+/// A low-level draw device
+///
+/// Draw operations are ignored; animation requests are recorded.
+pub struct TestDevice {
+    pub animation: AnimationState,
+    clip_rect: Rect,
+}
+
+impl DrawImpl for TestDevice {
+    fn animation_mut(&mut self) -> &mut AnimationState {
+        &mut self.animation
+    }
+
+    fn new_pass(&mut self, parent: PassId, _: Rect, _: Offset, _: PassType) -> PassId {
+        parent
+    }
+
+    fn new_transform_pass(&mut self, parent: PassId, _: f32, _: Offset) -> PassId {
+        parent
+    }
+
+    fn get_clip_rect(&self, _: PassId) -> Rect {
+        self.clip_rect
+    }
+
+    fn rect(&mut self, _: PassId, _: Quad, _: Rgba) {}
+
+    fn frame(&mut self, _: PassId, _: Quad, _: Quad, _: Rgba) {}
+
+    fn rect_gradient(&mut self, _: PassId, _: Quad, _: Rgba, _: Rgba, _: Direction) {}
+}
+
+/// A headless shell window
+///
+/// Pop-ups, windows and triggered updates are recorded but not shown.
+pub struct TestShell {
+    pub size: TestSize,
+    pub shared: TestDrawShared,
+    pub popups: Vec<(WindowId, kas::Popup)>,
+    pub clipboard: Option<String>,
+    pub updates: Vec<(UpdateId, u64)>,
+    next_id: u32,
+}
+
+impl TestShell {
+    fn next_window_id(&mut self) -> WindowId {
+        self.next_id += 1;
+        WindowId::new(std::num::NonZeroU32::new(self.next_id).unwrap())
+    }
+}
+
+impl ShellWindow for TestShell {
+    fn add_popup(&mut self, popup: kas::Popup) -> Option<WindowId> {
+        let id = self.next_window_id();
+        self.popups.push((id, popup));
+        Some(id)
+    }
+
+    fn add_window(&mut self, _: Box<dyn kas::Window>) -> WindowId {
+        self.next_window_id()
+    }
+
+    fn close_window(&mut self, id: WindowId) {
+        self.popups.retain(|popup| popup.0 != id);
+    }
+
+    fn trigger_update(&mut self, id: UpdateId, payload: u64) {
+        self.updates.push((id, payload));
+    }
+
+    fn get_clipboard(&mut self) -> Option<String> {
+        self.clipboard.clone()
+    }
+
+    fn set_clipboard(&mut self, content: String) {
+        self.clipboard = Some(content);
+    }
+
+    fn adjust_theme(&mut self, _: &mut dyn FnMut(&mut dyn ThemeControl) -> TkAction) {}
+
+    fn size_and_draw_shared(
+        &mut self,
+        f: &mut dyn FnMut(&mut dyn SizeHandle, &mut dyn DrawShared),
+    ) {
+        f(&mut self.size, &mut self.shared);
+    }
+
+    fn set_cursor_icon(&mut self, _: CursorIcon) {}
+}
+
+/// A recorded draw operation
+#[derive(Clone, Debug, PartialEq)]
+pub enum Op {
+    /// Start of a new pass
+    Pass(PassType, Rect, Offset),
+    /// End of the last-started pass
+    EndPass,
+    Frame(WidgetId, Rect),
+    Separator(Rect),
+    SelectionBox(Rect),
+    FocusRing(Rect),
+    DisabledOverlay(Rect),
+    FadeOverlay(Rect, f32),
+    Shadow(Rect),
+    /// Text drawn by a widget; the string is empty if drawn from a `TextDisplay`
+    Text(WidgetId, Coord, String),
+    Caret(WidgetId, Coord),
+    Checkbox(WidgetId, Rect, bool),
+    Radiobox(WidgetId, Rect, bool),
+    Mark(WidgetId, Rect),
+    Scrollbar(WidgetId, Rect),
+    Slider(WidgetId, Rect),
+    ProgressBar(WidgetId, Rect),
+    Image(ImageId, Rect),
+    /// Some other (low-level) draw operation
+    Other,
+}
+
+/// A recorded draw operation with context
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    /// Nesting depth of the pass (0 for the window's base pass)
+    pub depth: usize,
+    /// Total offset: a point `p` is drawn at `p - offset` in the window
+    pub offset: Offset,
+    /// The operation
+    pub op: Op,
+}
+
+/// A draw handle recording all operations
+pub struct TestDraw<'a> {
+    size: &'a TestSize,
+    shared: &'a mut TestDrawShared,
+    ev: &'a mut EventState,
+    clip_rect: Rect,
+    offset: Offset,
+    depth: usize,
+    device: DrawIface<'a, TestDrawShared>,
+    pub ops: Vec<Record>,
+}
+
+impl<'a> TestDraw<'a> {
+    fn push(&mut self, op: Op) {
+        self.ops.push(Record {
+            depth: self.depth,
+            offset: self.offset,
+            op,
+        });
+    }
+
+    fn pass<'b>(
+        &mut self,
+        rect: Rect,
+        offset: Offset,
+        f: Box<dyn FnOnce(&mut dyn DrawHandle) + 'b>,
+    ) {
+        let (clip_rect, total) = (self.clip_rect, self.offset);
+        self.clip_rect = rect + offset;
+        self.offset = total + offset;
+        self.depth += 1;
+        f(self);
+        self.depth -= 1;
+        self.clip_rect = clip_rect;
+        self.offset = total;
+        self.push(Op::EndPass);
+    }
+}
+
+impl<'a> DrawHandle for TestDraw<'a> {
+    fn components(&mut self) -> (&dyn SizeHandle, &mut dyn DrawShared, &mut EventState) {
+        (self.size, &mut *self.shared, &mut *self.ev)
+    }
+
+    fn draw_device(&mut self) -> &mut dyn Draw {
+        &mut self.device
+    }
+
+    fn new_pass<'b>(
+        &mut self,
+        rect: Rect,
+        offset: Offset,
+        class: PassType,
+        f: Box<dyn FnOnce(&mut dyn DrawHandle) + 'b>,
+    ) {
+        self.push(Op::Pass(class, rect, offset));
+        if class == PassType::Overlay {
+            // Overlays are positioned relative to the window
+            let total = self.offset;
+            self.offset = Offset::ZERO;
+            self.pass(rect, offset, f);
+            self.offset = total;
+        } else {
+            self.pass(rect, offset, f);
+        }
+    }
+
+    fn new_transform_pass<'b>(
+        &mut self,
+        _: f32,
+        offset: Offset,
+        f: Box<dyn FnOnce(&mut dyn DrawHandle) + 'b>,
+    ) {
+        let rect = self.clip_rect;
+        self.push(Op::Pass(PassType::Clip, rect, offset));
+        self.pass(rect, offset, f);
+    }
+
+    fn get_clip_rect(&self) -> Rect {
+        self.clip_rect
+    }
+
+    fn frame(&mut self, id: &WidgetId, rect: Rect, _: FrameStyle, _: Background) {
+        self.push(Op::Frame(id.clone(), rect));
+    }
+
+    fn separator(&mut self, rect: Rect) {
+        self.push(Op::Separator(rect));
+    }
+
+    fn selection_box(&mut self, rect: Rect) {
+        self.push(Op::SelectionBox(rect));
+    }
+
+    fn focus_ring(&mut self, rect: Rect) {
+        self.push(Op::FocusRing(rect));
+    }
+
+    fn disabled_overlay(&mut self, rect: Rect) {
+        self.push(Op::DisabledOverlay(rect));
+    }
+
+    fn fade_overlay(&mut self, rect: Rect, amount: f32) {
+        self.push(Op::FadeOverlay(rect, amount));
+    }
+
+    fn shadow(&mut self, rect: Rect) {
+        self.push(Op::Shadow(rect));
+    }
+
+    fn text(&mut self, id: &WidgetId, pos: Coord, text: &dyn TextApi, _: TextClass) {
+        self.push(Op::Text(id.clone(), pos, text.as_str().to_string()));
+    }
+
+    fn text_effects(&mut self, id: &WidgetId, pos: Coord, text: &dyn TextApi, _: TextClass) {
+        self.push(Op::Text(id.clone(), pos, text.as_str().to_string()));
+    }
+
+    fn text_selected_range(
+        &mut self,
+        id: &WidgetId,
+        pos: Coord,
+        _: &TextDisplay,
+        _: Range<usize>,
+        _: TextClass,
+    ) {
+        self.push(Op::Text(id.clone(), pos, String::new()));
+    }
+
+    fn text_cursor(&mut self, id: &WidgetId, pos: Coord, _: &TextDisplay, _: TextClass, _: usize) {
+        self.push(Op::Caret(id.clone(), pos));
+    }
+
+    fn caret(&mut self, id: &WidgetId, pos: Coord, _: i32) {
+        self.push(Op::Caret(id.clone(), pos));
+    }
+
+    fn checkbox(&mut self, id: &WidgetId, rect: Rect, checked: bool, _: Option<Instant>) {
+        self.push(Op::Checkbox(id.clone(), rect, checked));
+    }
+
+    fn radiobox(&mut self, id: &WidgetId, rect: Rect, checked: bool, _: Option<Instant>) {
+        self.push(Op::Radiobox(id.clone(), rect, checked));
+    }
+
+    fn mark(&mut self, id: &WidgetId, rect: Rect, _: MarkStyle) {
+        self.push(Op::Mark(id.clone(), rect));
+    }
+
+    fn scrollbar(&mut self, id: &WidgetId, _: &WidgetId, rect: Rect, _: Rect, _: Direction) {
+        self.push(Op::Scrollbar(id.clone(), rect));
+    }
+
+    fn scrollbar_overlay(
+        &mut self,
+        id: &WidgetId,
+        _: &WidgetId,
+        rect: Rect,
+        _: Rect,
+        _: Direction,
+        _: bool,
+        _: Option<Instant>,
+    ) {
+        self.push(Op::Scrollbar(id.clone(), rect));
+    }
+
+    fn slider(&mut self, id: &WidgetId, _: &WidgetId, rect: Rect, _: Rect, _: Direction) {
+        self.push(Op::Slider(id.clone(), rect));
+    }
+
+    fn progress_bar(&mut self, id: &WidgetId, rect: Rect, _: Direction, _: f32) {
+        self.push(Op::ProgressBar(id.clone(), rect));
+    }
+
+    fn image(&mut self, id: ImageId, rect: Rect) {
+        self.push(Op::Image(id, rect));
+    }
+
+    fn arc(&mut self, _: Quad, _: f32, _: f32, _: f32, _: Rgba) {
+        self.push(Op::Other);
+    }
+
+    fn rounded_rect(&mut self, _: Quad, _: [f32; 4], _: Rgba) {
+        self.push(Op::Other);
+    }
+
+    fn path(&mut self, _: &PathBuilder, _: Rgba) {
+        self.push(Op::Other);
+    }
+}
+
+/// A headless window: shell and event state
+pub struct Harness {
+    pub shell: TestShell,
+    pub ev: EventState,
+    /// Animation requested by the last call to [`Harness::draw`]
+    pub animation: AnimationState,
+}
+
+impl Harness {
+    /// Construct
+    pub fn new() -> Self {
+        let shell = TestShell {
+            size: TestSize::default(),
+            shared: TestDrawShared,
+            popups: vec![],
+            clipboard: None,
+            updates: vec![],
+            next_id: 0,
+        };
+        let config = Rc::new(RefCell::new(Config::default()));
+        let ev = EventState::new(config, 1.0);
+        Harness {
+            shell,
+            ev,
+            animation: AnimationState::None,
+        }
+    }
+
+    /// Access a [`SizeMgr`]
+    pub fn size_mgr(&self) -> SizeMgr {
+        SizeMgr::new(&self.shell.size)
+    }
+
+    /// Configure `widget`, then solve its layout within `rect`
+    pub fn init(&mut self, widget: &mut dyn Widget, rect: Rect) -> SolveCache {
+        self.ev.full_configure(&mut self.shell, widget);
+        let mut solve_cache = SolveCache::find_constraints(widget, self.size_mgr());
+        self.set_rect(widget, &mut solve_cache, rect);
+        solve_cache
+    }
+
+    /// Apply a new `rect` to `widget`
+    pub fn set_rect(&mut self, widget: &mut dyn Widget, solve_cache: &mut SolveCache, rect: Rect) {
+        let mut mgr = SetRectMgr::new(&self.shell.size, &mut self.shell.shared, &mut self.ev);
+        solve_cache.apply_rect(widget, &mut mgr, rect, false, false);
+    }
+
+    /// Run `f` with access to a [`SetRectMgr`]
+    pub fn with_set_rect_mgr<T>(&mut self, f: impl FnOnce(&mut SetRectMgr) -> T) -> T {
+        let mut mgr = SetRectMgr::new(&self.shell.size, &mut self.shell.shared, &mut self.ev);
+        f(&mut mgr)
+    }
+
+    /// Run `f` with access to an [`EventMgr`]
+    pub fn with_mgr<T>(&mut self, f: impl FnOnce(&mut EventMgr) -> T) -> T {
+        let mut result = None;
+        self.ev.with(&mut self.shell, |mgr| result = Some(f(mgr)));
+        result.unwrap()
+    }
+
+    /// Send `event` to the widget `id` (a descendant of `widget`)
+    pub fn send(&mut self, widget: &mut dyn Widget, id: WidgetId, event: Event) -> Response {
+        self.with_mgr(|mgr| mgr.send(widget, id, event))
+    }
+
+    /// Draw `widget` within a window of size `widget.rect()`, returning
+    /// recorded operations
+    pub fn draw(&mut self, widget: &mut dyn Widget) -> Vec<Record> {
+        let mut device = TestDevice {
+            animation: AnimationState::None,
+            clip_rect: widget.rect(),
+        };
+        let mut device_shared = SharedState::new(TestDrawShared);
+        let mut draw = TestDraw {
+            size: &self.shell.size,
+            shared: &mut self.shell.shared,
+            ev: &mut self.ev,
+            clip_rect: widget.rect(),
+            offset: Offset::ZERO,
+            depth: 0,
+            device: DrawIface {
+                draw: &mut device,
+                shared: &mut device_shared,
+                pass: PassId::new(0),
+            },
+            ops: vec![],
+        };
+        let id = widget.id();
+        widget.draw(DrawMgr::new(&mut draw, id));
+        let ops = draw.ops;
+        self.animation = device.animation;
+        ops
+    }
+
+    /// Deliver timer updates which are due to `widget`
+    ///
+    /// See [`EventMgr::update_on_timer`].
+    pub fn update_timers(&mut self, widget: &mut dyn Widget) {
+        self.with_mgr(|mgr| mgr.update_timer(widget));
+    }
+}
+
+impl_scope! {
+    /// A widget of fixed (or minimum and ideal) size which draws a frame
+    #[derive(Clone, Debug, Default)]
+    #[widget]
+    pub struct Block {
+        core: widget_core!(),
+        min_size: Size,
+        size: Size,
+    }
+
+    impl Self {
+        /// Construct, with the given fixed size
+        pub fn new(size: Size) -> Self {
+            Self::new_flexible(size, size)
+        }
+
+        /// Construct, with the given minimum and ideal sizes
+        pub fn new_flexible(min_size: Size, size: Size) -> Self {
+            Block {
+                core: Default::default(),
+                min_size,
+                size,
+            }
+        }
+    }
+
+    impl Layout for Self {
+        fn size_rules(&mut self, _: SizeMgr, axis: AxisInfo) -> SizeRules {
+            let min = self.min_size.extract(axis);
+            let ideal = self.size.extract(axis);
+            SizeRules::new(min, ideal, (0, 0), Stretch::None)
+        }
+
+        fn draw(&mut self, mut draw: DrawMgr) {
+            draw.frame(self.core.rect, FrameStyle::Frame, Background::Default);
+        }
+    }
+}
+
+/// Find the first record of a frame drawn by widget `id`
+pub fn find_frame<'a>(ops: &'a [Record], id: &WidgetId) -> Option<&'a Record> {
+    ops.iter()
+        .find(|record| matches!(&record.op, Op::Frame(frame_id, _) if frame_id == id))
+}
@@ -0,0 +1,167 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE-APACHE file or at:
+//     https://www.apache.org/licenses/LICENSE-2.0
+
+mod common;
+
+use common::*;
+use kas::class::SetAccel;
+use kas::event::VirtualKeyCode as VK;
+use kas::prelude::*;
+use kas::text::AccelString;
+use kas::theme::TextClass;
+use kas_widgets::{AccelLabel, Label, StrLabel};
+
+#[test]
+fn accel_string_set_mnemonic() {
+    let mut s = AccelString::from("&File");
+    assert_eq!(s.keys(), &[VK::F]);
+
+    assert!(s.set_mnemonic(Some('i')));
+    assert_eq!(s.keys(), &[VK::I]);
+    assert_eq!(s.text(), "File");
+
+    // Unchanged bindings
+    assert!(!s.set_mnemonic(Some('I')));
+
+    // A mnemonic not found in the text is still bound
+    assert!(s.set_mnemonic(Some('x')));
+    assert_eq!(s.keys(), &[VK::X]);
+
+    assert!(s.set_mnemonic(None));
+    assert!(s.keys().is_empty());
+}
+
+#[test]
+fn accel_label_set_mnemonic() {
+    let mut label = AccelLabel::new("&Open");
+    assert_eq!(label.keys(), &[VK::O]);
+
+    let action = label.set_mnemonic(Some('p'));
+    assert!(action.contains(TkAction::RECONFIGURE));
+    assert_eq!(label.keys(), &[VK::P]);
+
+    assert_eq!(label.set_mnemonic(Some('p')), TkAction::empty());
+}
+
+#[test]
+fn label_caches_size_rules() {
+    let harness = Harness::new();
+    let calls = || harness.shell.size.text_bound_calls.get();
+    let mut label = StrLabel::new("Hello");
+    let horiz = |generation| AxisInfo::new(false, None).with_generation(generation);
+    let vert = |generation| AxisInfo::new(true, Some(40)).with_generation(generation);
+
+    let rules = label.size_rules(harness.size_mgr(), horiz(1));
+    assert_eq!(rules.ideal_size(), 5 * CHAR_WIDTH);
+    label.size_rules(harness.size_mgr(), vert(1));
+    assert_eq!(calls(), 2);
+
+    // Same generation and inputs: rules are re-used on each axis
+    assert_eq!(label.size_rules(harness.size_mgr(), horiz(1)), rules);
+    label.size_rules(harness.size_mgr(), vert(1));
+    assert_eq!(calls(), 2);
+
+    // Other-axis size differs
+    let axis = AxisInfo::new(true, Some(80)).with_generation(1);
+    label.size_rules(harness.size_mgr(), axis);
+    assert_eq!(calls(), 3);
+
+    // New or unknown generation
+    label.size_rules(harness.size_mgr(), horiz(2));
+    label.size_rules(harness.size_mgr(), horiz(0));
+    label.size_rules(harness.size_mgr(), horiz(0));
+    assert_eq!(calls(), 6);
+
+    // Changing the text class invalidates the cache
+    label.size_rules(harness.size_mgr(), horiz(3));
+    label.set_class(TextClass::Label(false));
+    label.size_rules(harness.size_mgr(), horiz(3));
+    assert_eq!(calls(), 8);
+}
+
+// Lay out `label` with a width of `cols` characters, then return the text drawn
+fn elide(harness: &mut Harness, label: &mut dyn Widget, cols: i32) -> String {
+    let size = Size(cols * CHAR_WIDTH, LINE_HEIGHT);
+    harness.init(label, Rect::new(Coord::ZERO, size));
+    drawn_text(harness, label)
+}
+
+fn drawn_text(harness: &mut Harness, label: &mut dyn Widget) -> String {
+    let ops = harness.draw(label);
+    let mut iter = ops.into_iter().filter_map(|record| match record.op {
+        Op::Text(_, _, text) => Some(text),
+        _ => None,
+    });
+    let text = iter.next().expect("no text drawn");
+    assert!(iter.next().is_none());
+    text
+}
+
+#[test]
+fn ellipsis_not_used_when_text_fits() {
+    let mut harness = Harness::new();
+    let mut label = StrLabel::new("Hello world")
+        .with_wrap(false)
+        .with_ellipsis(true);
+    assert_eq!(elide(&mut harness, &mut label, 11), "Hello world");
+    assert_eq!(elide(&mut harness, &mut label, 20), "Hello world");
+}
+
+#[test]
+fn ellipsis_truncates_text() {
+    let mut harness = Harness::new();
+    let mut label = StrLabel::new("Hello world")
+        .with_wrap(false)
+        .with_ellipsis(true);
+
+    // Trailing space before the ellipsis is trimmed
+    assert_eq!(elide(&mut harness, &mut label, 6), "Hello\u{2026}");
+    assert_eq!(elide(&mut harness, &mut label, 8), "Hello w\u{2026}");
+    assert_eq!(elide(&mut harness, &mut label, 10), "Hello wor\u{2026}");
+
+    // Too narrow for any text: only the ellipsis remains
+    assert_eq!(elide(&mut harness, &mut label, 0), "\u{2026}");
+}
+
+#[test]
+fn ellipsis_at_grapheme_boundary() {
+    let mut harness = Harness::new();
+
+    // Multi-byte characters
+    let mut label = Label::new("Gr\u{fc}\u{df}e aus K\u{f6}ln".to_string())
+        .with_wrap(false)
+        .with_ellipsis(true);
+    let text = elide(&mut harness, &mut label, 6);
+    assert_eq!(text, "Gr\u{fc}\u{df}e\u{2026}");
+
+    // "e" plus a combining acute accent is a single grapheme of two chars
+    // (each of width CHAR_WIDTH here): this is kept or removed as a whole
+    let mut label = StrLabel::new("Cafe\u{301} au lait")
+        .with_wrap(false)
+        .with_ellipsis(true);
+    assert_eq!(elide(&mut harness, &mut label, 5), "Caf\u{2026}");
+    assert_eq!(elide(&mut harness, &mut label, 6), "Cafe\u{301}\u{2026}");
+}
+
+#[test]
+fn ellipsis_requires_single_line() {
+    let mut harness = Harness::new();
+    let full = "Hello world";
+    let mut label = StrLabel::new(full).with_ellipsis(true);
+    assert!(label.wrap());
+    assert_eq!(elide(&mut harness, &mut label, 6), full);
+
+    assert_eq!(label.set_wrap(false), TkAction::RESIZE);
+    assert_eq!(elide(&mut harness, &mut label, 6), "Hello\u{2026}");
+
+    // Disabling either option takes effect before the next layout
+    assert_eq!(label.set_wrap(true), TkAction::RESIZE);
+    assert_eq!(drawn_text(&mut harness, &mut label), full);
+    label.set_wrap(false);
+    assert_eq!(elide(&mut harness, &mut label, 6), "Hello\u{2026}");
+    assert_eq!(label.set_ellipsis(false), TkAction::SET_SIZE);
+    assert_eq!(drawn_text(&mut harness, &mut label), full);
+    assert_eq!(elide(&mut harness, &mut label, 6), full);
+}
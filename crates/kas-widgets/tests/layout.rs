@@ -0,0 +1,187 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE-APACHE file or at:
+//     https://www.apache.org/licenses/LICENSE-2.0
+
+mod common;
+
+use common::*;
+use kas::layout::{SizeTarget, SolveCache};
+use kas::prelude::*;
+use kas_widgets::{Column, Row};
+
+impl_scope! {
+    /// A fixed-size widget reporting a text baseline
+    #[derive(Clone, Debug)]
+    #[widget]
+    struct Text {
+        core: widget_core!(),
+        size: Size,
+        ascent: i32,
+    }
+
+    impl Self {
+        fn new(size: Size, ascent: i32) -> Self {
+            let core = Default::default();
+            Text { core, size, ascent }
+        }
+    }
+
+    impl Layout for Self {
+        fn size_rules(&mut self, _: SizeMgr, axis: AxisInfo) -> SizeRules {
+            SizeRules::extract_fixed(axis, self.size, Margins::ZERO)
+        }
+
+        fn set_rect(&mut self, _: &mut SetRectMgr, rect: Rect, _: AlignHints) {
+            self.core.rect = rect;
+        }
+
+        fn baseline(&mut self) -> Option<i32> {
+            Some(self.ascent)
+        }
+
+        fn draw(&mut self, _: DrawMgr) {}
+    }
+}
+
+impl_scope! {
+    /// A row of two texts aligned on their baselines
+    #[derive(Debug)]
+    #[widget{
+        layout = align(baseline): row: [self.a, self.b];
+    }]
+    struct Pair {
+        core: widget_core!(),
+        #[widget]
+        a: Text,
+        #[widget]
+        b: Text,
+    }
+}
+
+impl_scope! {
+    /// A widget with an optional child
+    #[derive(Debug)]
+    #[widget{
+        layout = row: [self.icon, self.body];
+    }]
+    struct WithIcon {
+        core: widget_core!(),
+        #[widget]
+        body: Block,
+        #[widget]
+        icon: Option<Block>,
+    }
+
+    impl Self {
+        fn icon_index(&self) -> usize {
+            widget_index![self.icon]
+        }
+    }
+}
+
+impl_scope! {
+    /// A widget with fixed area, wrapping onto more rows when narrow
+    #[derive(Clone, Debug, Default)]
+    #[widget]
+    struct Wrap {
+        core: widget_core!(),
+        targets: Vec<(Option<i32>, SizeTarget)>,
+    }
+
+    impl Layout for Self {
+        fn size_rules(&mut self, _: SizeMgr, axis: AxisInfo) -> SizeRules {
+            if !axis.is_vertical() {
+                return SizeRules::new(10, 40, (0, 0), Stretch::None);
+            }
+            self.targets.push((axis.other(), axis.target()));
+            let height = 400 / axis.other().unwrap_or(40);
+            SizeRules::fixed(height, (0, 0))
+        }
+
+        fn draw(&mut self, _: DrawMgr) {}
+    }
+}
+
+#[test]
+fn baseline_shift_is_reserved() {
+    // Ascent 24 + descent 6 and ascent 12 + descent 8: aligned, these need a
+    // height of 24 + 8, though neither child is that tall.
+    let mut pair = Pair {
+        core: Default::default(),
+        a: Text::new(Size(20, 30), 24),
+        b: Text::new(Size(20, 20), 12),
+    };
+    let mut harness = Harness::new();
+    harness.init(&mut pair, Rect::new(Coord::ZERO, Size(40, 32)));
+
+    let rules = pair.size_rules(harness.size_mgr(), AxisInfo::new(true, Some(40)));
+    assert_eq!(rules.min_size(), 32);
+    assert_eq!(rules.ideal_size(), 32);
+
+    harness.with_set_rect_mgr(|mgr| pair.set_rect(mgr, pair.rect(), AlignHints::NONE));
+    assert_eq!(pair.a.rect(), Rect::new(Coord(0, 0), Size(20, 32)));
+    assert_eq!(pair.b.rect(), Rect::new(Coord(20, 12), Size(20, 20)));
+}
+
+#[test]
+fn optional_child() {
+    let mut w = WithIcon {
+        core: Default::default(),
+        body: Block::new(Size(20, 10)),
+        icon: None,
+    };
+    let mut harness = Harness::new();
+    harness.init(&mut w, Rect::new(Coord::ZERO, Size(20, 10)));
+    assert_eq!(w.num_children(), 1);
+    assert_eq!(w.get_child(0).map(|c| c.id()), Some(w.body.id()));
+    assert!(w.get_child(1).is_none());
+    assert_eq!(w.icon_index(), usize::MAX);
+    assert_eq!(w.body.rect(), Rect::new(Coord(0, 0), Size(20, 10)));
+
+    // After the child is added the widget must be reconfigured and resized
+    w.icon = Some(Block::new(Size(10, 10)));
+    harness.init(&mut w, Rect::new(Coord::ZERO, Size(30, 10)));
+    assert_eq!(w.num_children(), 2);
+    let icon = w.icon.as_ref().unwrap();
+    assert!(icon.id_ref().is_valid());
+    assert_eq!(w.icon_index(), 1);
+    assert_eq!(w.get_child(1).map(|c| c.id()), Some(icon.id()));
+    assert_eq!(icon.rect(), Rect::new(Coord(0, 0), Size(10, 10)));
+    assert_eq!(w.body.rect(), Rect::new(Coord(10, 0), Size(20, 10)));
+}
+
+#[test]
+fn nested_disabled_overlay_drawn_once() {
+    let column = || Column::new_vec(vec![Block::new(Size(10, 10)), Block::new(Size(10, 10))]);
+    let mut row = Row::new_vec(vec![column(), column()]);
+    let mut harness = Harness::new();
+    harness.init(&mut row, Rect::new(Coord::ZERO, Size(20, 20)));
+
+    // Both a column and one of its children are disabled
+    let (outer, inner) = (row[0].id(), row[0][1].id());
+    harness.with_mgr(|mgr| {
+        mgr.set_disabled(inner, true);
+        mgr.set_disabled(outer, true);
+    });
+    let overlays: Vec<Rect> = (harness.draw(&mut row).iter())
+        .filter_map(|record| match record.op {
+            Op::DisabledOverlay(rect) => Some(rect),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(overlays, [row[0].rect()]);
+}
+
+#[test]
+fn min_height_measured_at_min_width() {
+    let mut w = Wrap::default();
+    let harness = Harness::new();
+    let cache = SolveCache::find_constraints(&mut w, harness.size_mgr());
+    assert_eq!(cache.ideal(false), Size(40, 10));
+    assert_eq!(cache.min(false), Size(10, 40));
+    assert_eq!(
+        w.targets,
+        [(Some(40), SizeTarget::Ideal), (Some(10), SizeTarget::Min)]
+    );
+}
@@ -0,0 +1,399 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE-APACHE file or at:
+//     https://www.apache.org/licenses/LICENSE-2.0
+
+mod common;
+
+use common::*;
+use kas::draw::AnimationState;
+use kas::event::{Command, MouseButton, PressSource};
+use kas::layout::solve_size_rules;
+use kas::prelude::*;
+use kas_widgets::view::{SelectionError, SelectionMode};
+use kas_widgets::{Column, ItemMoved};
+use std::time::Duration;
+
+fn column(n: usize) -> Column<Block> {
+    Column::new_vec((0..n).map(|_| Block::new(Size(20, 10))).collect())
+}
+
+fn rect() -> Rect {
+    Rect::new(Coord::ZERO, Size(20, 30))
+}
+
+// Size of a column of three children with selection enabled
+fn sel_rect() -> Rect {
+    Rect::new(Coord::ZERO, Size(20, 32))
+}
+
+fn click(harness: &mut Harness, list: &mut Column<Block>, index: usize) {
+    let id = list[index].id();
+    let event = Event::PressStart {
+        source: PressSource::Mouse(MouseButton::Left, 1),
+        start_id: Some(id.clone()),
+        coord: list[index].rect().pos,
+    };
+    harness.send(list, id, event);
+}
+
+// Send `cmd` to child `index`, returning the response and any ItemMoved message
+fn command(
+    harness: &mut Harness,
+    list: &mut Column<Block>,
+    index: usize,
+    cmd: Command,
+) -> (Response, Option<ItemMoved>) {
+    let id = list[index].id();
+    harness.with_mgr(|mgr| {
+        let response = mgr.send(list, id, Event::Command(cmd));
+        (response, mgr.try_pop_msg())
+    })
+}
+
+// Check that each child's identifier maps back to its index
+fn assert_ids_consistent(list: &Column<Block>) {
+    for index in 0..list.len() {
+        assert_eq!(list.find_child_index(list[index].id_ref()), Some(index));
+    }
+}
+
+fn selected(list: &Column<Block>) -> Vec<usize> {
+    list.selected_iter().collect()
+}
+
+fn fade_overlays(ops: &[Record]) -> Vec<(Rect, f32)> {
+    ops.iter()
+        .filter_map(|record| match record.op {
+            Op::FadeOverlay(rect, amount) => Some((rect, amount)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn remove_animated_without_animation() {
+    let mut harness = Harness::new();
+    let mut list = column(3);
+    harness.init(&mut list, rect());
+
+    harness.with_set_rect_mgr(|mgr| list.remove_animated(mgr, 1));
+    assert_eq!(list.len(), 2);
+    assert!(harness.ev.next_resume().is_none());
+}
+
+#[test]
+fn remove_animated() {
+    let dur = Duration::from_millis(20);
+    let mut harness = Harness::new();
+    let mut list = column(3).with_animation(dur);
+    harness.init(&mut list, rect());
+    let id = list[1].id();
+    let child_rect = list[1].rect();
+
+    harness.with_set_rect_mgr(|mgr| list.remove_animated(mgr, 1));
+    // The child remains until its exit animation completes
+    assert_eq!(list.len(), 3);
+    assert_eq!(list[1].id(), id);
+    assert!(harness.ev.next_resume().is_some());
+
+    // Repeated removal does not restart the animation
+    harness.with_set_rect_mgr(|mgr| list.remove_animated(mgr, 1));
+    assert_eq!(list.len(), 3);
+
+    std::thread::sleep(dur);
+    // The exiting child is fully faded (and no longer animating)
+    let ops = harness.draw(&mut list);
+    assert_eq!(fade_overlays(&ops), vec![(child_rect, 1.0)]);
+    assert_eq!(harness.animation, AnimationState::None);
+
+    harness.update_timers(&mut list);
+    assert_eq!(list.len(), 2);
+    assert!(list.iter().all(|w| w.id() != id));
+    assert!(fade_overlays(&harness.draw(&mut list)).is_empty());
+}
+
+#[test]
+fn remove_animated_fades_out() {
+    let dur = Duration::from_secs(2);
+    let mut harness = Harness::new();
+    let mut list = column(3).with_animation(dur);
+    harness.init(&mut list, rect());
+    let child_rect = list[1].rect();
+
+    harness.with_set_rect_mgr(|mgr| list.remove_animated(mgr, 1));
+    let overlays = fade_overlays(&harness.draw(&mut list));
+    assert_eq!(overlays.len(), 1);
+    assert_eq!(overlays[0].0, child_rect);
+    // The overlay starts (almost) transparent, allowing for slow test runs
+    assert!(overlays[0].1 < 0.1, "amount: {}", overlays[0].1);
+    assert_eq!(harness.animation, AnimationState::Animate);
+
+    // The timer is not yet due
+    harness.update_timers(&mut list);
+    assert_eq!(list.len(), 3);
+}
+
+#[test]
+fn remove_animated_continues_enter() {
+    let dur = Duration::from_secs(2);
+    let mut harness = Harness::new();
+    let mut list = column(2).with_animation(dur);
+    harness.init(&mut list, rect());
+
+    harness.with_set_rect_mgr(|mgr| list.push(mgr, Block::new(Size(20, 10))));
+    std::thread::sleep(dur / 4);
+    let overlays = fade_overlays(&harness.draw(&mut list));
+    assert_eq!(overlays.len(), 1);
+    let entering = overlays[0].1;
+    // The enter overlay fades from opaque
+    assert!(entering <= 0.75, "amount: {}", entering);
+    assert!(entering > 0.5, "amount: {}", entering);
+
+    // Exit continues from the current opacity instead of transparent
+    harness.with_set_rect_mgr(|mgr| list.remove_animated(mgr, 2));
+    let overlays = fade_overlays(&harness.draw(&mut list));
+    assert_eq!(overlays.len(), 1);
+    let exiting = overlays[0].1;
+    assert!((exiting - entering).abs() < 0.1, "{exiting} vs {entering}");
+    assert_eq!(harness.animation, AnimationState::Animate);
+}
+
+#[test]
+fn enter_animation_completes() {
+    let dur = Duration::from_millis(20);
+    let mut harness = Harness::new();
+    let mut list = column(2).with_animation(dur);
+    harness.init(&mut list, rect());
+
+    let index = harness.with_set_rect_mgr(|mgr| list.push(mgr, Block::new(Size(20, 10))));
+    assert_eq!(index, 2);
+    assert_eq!(list.len(), 3);
+
+    std::thread::sleep(dur);
+    // Completed enter animations are dropped without drawing an overlay
+    assert!(fade_overlays(&harness.draw(&mut list)).is_empty());
+}
+
+#[test]
+fn remove_during_enter_animation() {
+    let dur = Duration::from_millis(20);
+    let mut harness = Harness::new();
+    let mut list = column(2).with_animation(dur);
+    harness.init(&mut list, rect());
+
+    harness.with_set_rect_mgr(|mgr| list.push(mgr, Block::new(Size(20, 10))));
+    // Immediate removal drops the animation state along with the child
+    harness.with_set_rect_mgr(|mgr| list.remove(mgr, 2));
+    assert_eq!(list.len(), 2);
+
+    std::thread::sleep(dur);
+    assert!(fade_overlays(&harness.draw(&mut list)).is_empty());
+}
+
+#[test]
+fn disable_animation_completes_exit() {
+    let dur = Duration::from_secs(60);
+    let mut harness = Harness::new();
+    let mut list = column(3).with_animation(dur);
+    harness.init(&mut list, rect());
+
+    harness.with_set_rect_mgr(|mgr| list.remove_animated(mgr, 0));
+    assert_eq!(list.len(), 3);
+
+    harness.with_set_rect_mgr(|mgr| list.set_animation(mgr, None));
+    assert_eq!(list.animation(), None);
+    // Exit is completed on the next timer update
+    harness.update_timers(&mut list);
+    assert_eq!(list.len(), 2);
+}
+
+#[test]
+fn selection_disabled_by_default() {
+    let mut harness = Harness::new();
+    let mut list = column(3);
+    harness.init(&mut list, rect());
+
+    click(&mut harness, &mut list, 1);
+    assert!(selected(&list).is_empty());
+    assert!(matches!(list.select(1), Err(SelectionError::Disabled)));
+}
+
+#[test]
+fn select_by_click() {
+    let mut harness = Harness::new();
+    let mut list = column(3).with_selection_mode(SelectionMode::Single);
+    harness.init(&mut list, sel_rect());
+
+    click(&mut harness, &mut list, 1);
+    assert_eq!(selected(&list), vec![1]);
+    click(&mut harness, &mut list, 2);
+    assert_eq!(selected(&list), vec![2]);
+
+    // Disabling selection clears it and requires a resize
+    let action = list.set_selection_mode(SelectionMode::None);
+    assert!(action.contains(TkAction::RESIZE));
+    assert!(selected(&list).is_empty());
+}
+
+#[test]
+fn selection_tracks_children() {
+    let mut harness = Harness::new();
+    let mut list = column(3).with_selection_mode(SelectionMode::Multiple);
+    harness.init(&mut list, sel_rect());
+
+    assert_eq!(list.select(0).unwrap(), TkAction::REDRAW);
+    assert_eq!(list.select(2).unwrap(), TkAction::REDRAW);
+    assert_eq!(list.select(2).unwrap(), TkAction::empty());
+    assert!(matches!(list.select(3), Err(SelectionError::Key)));
+
+    harness.with_set_rect_mgr(|mgr| list.insert(mgr, 0, Block::new(Size(20, 10))));
+    assert_eq!(selected(&list), vec![1, 3]);
+    harness.with_set_rect_mgr(|mgr| list.remove(mgr, 1));
+    assert_eq!(selected(&list), vec![2]);
+    assert_eq!(list.deselect(2), TkAction::REDRAW);
+    assert!(selected(&list).is_empty());
+}
+
+#[test]
+fn selection_box_in_margin() {
+    let mut harness = Harness::new();
+    let mut list = column(3).with_selection_mode(SelectionMode::Single);
+    let rules = list.size_rules(harness.size_mgr(), AxisInfo::new(true, None));
+    assert_eq!(rules.margins_i32(), (INNER_MARGIN, INNER_MARGIN));
+    assert_eq!(rules.ideal_size(), 3 * 10 + 2 * INNER_MARGIN);
+
+    harness.init(&mut list, sel_rect());
+    // Children are separated by a margin used to draw the selection box
+    let gap = list[1].rect().pos.1 - (list[0].rect().pos.1 + list[0].rect().size.1);
+    assert_eq!(gap, INNER_MARGIN);
+
+    click(&mut harness, &mut list, 1);
+    let ops = harness.draw(&mut list);
+    let frame = ops
+        .iter()
+        .position(|r| matches!(&r.op, Op::Frame(id, _) if *id == list[1].id()));
+    let sel = ops
+        .iter()
+        .position(|r| r.op == Op::SelectionBox(list[1].rect()));
+    // The selection box is drawn over (after) the child
+    assert!(frame.unwrap() < sel.unwrap());
+}
+
+#[test]
+fn clone_then_solve_layout() {
+    let mut harness = Harness::new();
+    let mut list = column(3);
+    harness.init(&mut list, rect());
+
+    // The clone's layout storage is reset, thus layout is solved from scratch
+    let mut clone = list.clone();
+    assert_eq!(clone.rect(), Rect::ZERO);
+    solve_size_rules(&mut clone, harness.size_mgr(), None, None);
+    harness.with_set_rect_mgr(|mgr| clone.set_rect(mgr, rect(), AlignHints::NONE));
+    assert_eq!(clone.rect(), rect());
+    for i in 0..3 {
+        assert_eq!(clone[i].rect(), list[i].rect());
+        assert_eq!(clone[i].rect().pos, Coord(0, 10 * i as i32));
+    }
+
+    // Once configured, the clone may be drawn
+    harness.init(&mut clone, rect());
+    let ops = harness.draw(&mut clone);
+    assert!(find_frame(&ops, clone[2].id_ref()).is_some());
+}
+
+#[test]
+fn focus_ring_on_nav_focus() {
+    let focus_rings = |ops: &[Record]| -> Vec<Rect> {
+        let iter = ops.iter().filter_map(|record| match record.op {
+            Op::FocusRing(rect) => Some(rect),
+            _ => None,
+        });
+        iter.collect()
+    };
+
+    let mut harness = Harness::new();
+    let mut list = column(3);
+    harness.init(&mut list, rect());
+    assert!(focus_rings(&harness.draw(&mut list)).is_empty());
+
+    // The ring is drawn over (only) the child with navigation focus
+    let id = list[1].id();
+    harness.with_mgr(|mgr| mgr.set_nav_focus(id, true));
+    let rings = focus_rings(&harness.draw(&mut list));
+    assert_eq!(rings, vec![list[1].rect()]);
+
+    harness.with_mgr(|mgr| mgr.clear_nav_focus());
+    assert!(focus_rings(&harness.draw(&mut list)).is_empty());
+}
+
+#[test]
+fn reorder_by_command() {
+    let mut harness = Harness::new();
+    let mut list = column(3).with_reorder();
+    harness.init(&mut list, rect());
+    let ids: Vec<_> = (0..3).map(|i| list[i].id()).collect();
+
+    let moved = |from, to| Some(ItemMoved { from, to });
+    let result = command(&mut harness, &mut list, 0, Command::MoveDown);
+    assert_eq!(result, (Response::Used, moved(0, 1)));
+    assert_eq!(list[1].id(), ids[0]);
+    let result = command(&mut harness, &mut list, 1, Command::MoveUp);
+    assert_eq!(result, (Response::Used, moved(1, 0)));
+    assert_eq!(list[0].id(), ids[0]);
+
+    // Directions across the list are not used
+    for cmd in [Command::MoveLeft, Command::MoveRight] {
+        let result = command(&mut harness, &mut list, 1, cmd);
+        assert_eq!(result, (Response::Unused, None));
+    }
+    assert_eq!((0..3).map(|i| list[i].id()).collect::<Vec<_>>(), ids);
+    assert_ids_consistent(&list);
+}
+
+#[test]
+fn reorder_unused_at_ends() {
+    let mut harness = Harness::new();
+    let mut list = column(3).with_reorder();
+    harness.init(&mut list, rect());
+    let ids: Vec<_> = (0..3).map(|i| list[i].id()).collect();
+
+    let result = command(&mut harness, &mut list, 0, Command::MoveUp);
+    assert_eq!(result, (Response::Unused, None));
+    let result = command(&mut harness, &mut list, 2, Command::MoveDown);
+    assert_eq!(result, (Response::Unused, None));
+    assert_eq!((0..3).map(|i| list[i].id()).collect::<Vec<_>>(), ids);
+
+    // Without reordering enabled, commands are not used
+    list.set_reorder(false);
+    let result = command(&mut harness, &mut list, 0, Command::MoveDown);
+    assert_eq!(result, (Response::Unused, None));
+}
+
+#[test]
+fn move_preserves_ids_and_selection() {
+    let mut harness = Harness::new();
+    let mut list = column(4).with_selection_mode(SelectionMode::Multiple);
+    harness.init(&mut list, Rect::new(Coord::ZERO, Size(20, 42)));
+    let ids: Vec<_> = (0..4).map(|i| list[i].id()).collect();
+    list.select(0).unwrap();
+    list.select(2).unwrap();
+
+    harness.with_set_rect_mgr(|mgr| list.move_item(mgr, 0, 3));
+    assert_eq!(list[3].id(), ids[0]);
+    assert_eq!(list[0].id(), ids[1]);
+    assert_eq!(selected(&list), vec![1, 3]);
+    assert_ids_consistent(&list);
+
+    harness.with_set_rect_mgr(|mgr| list.move_item(mgr, 3, 1));
+    assert_eq!(list[1].id(), ids[0]);
+    assert_eq!(selected(&list), vec![1, 2]);
+    assert_ids_consistent(&list);
+
+    harness.with_set_rect_mgr(|mgr| list.swap(mgr, 0, 2));
+    assert_eq!(list[0].id(), ids[2]);
+    assert_eq!(list[2].id(), ids[1]);
+    assert_eq!(selected(&list), vec![0, 1]);
+    assert_ids_consistent(&list);
+}
@@ -0,0 +1,229 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE-APACHE file or at:
+//     https://www.apache.org/licenses/LICENSE-2.0
+
+mod common;
+
+use common::*;
+use kas::event::{ScrollDelta, UpdateId};
+use kas::prelude::*;
+use kas_widgets::{FrozenGrid, Grid, ScrollBarRegion, ScrollBars, ScrollRegion, Scrollable};
+use std::time::Duration;
+
+#[test]
+fn scroll_bar_region_draw_offset() {
+    for overlay in [false, true] {
+        let mut harness = Harness::new();
+        let block = Block::new(Size(50, 400));
+        let mut region = ScrollBarRegion::new(block).with_overlay(overlay);
+        harness.init(&mut region, Rect::new(Coord::ZERO, Size(100, 100)));
+
+        let offset = harness.with_mgr(|mgr| region.set_scroll_offset(mgr, Offset(0, 50)));
+        assert_eq!(offset, Offset(0, 50));
+
+        let ops = harness.draw(&mut region);
+        let record = find_frame(&ops, region.inner().id_ref()).unwrap();
+        // Content is offset exactly once, within a single clip region
+        assert_eq!(record.offset, offset, "overlay = {overlay}");
+        assert_eq!(record.depth, 1, "overlay = {overlay}");
+    }
+}
+
+#[test]
+fn line_and_pixel_deltas_agree() {
+    let mut harness = Harness::new();
+    let dist = harness.with_mgr(|mgr| mgr.config().scroll_distance((1.0, 1.0), None));
+    assert!(dist.0 > 0 && dist.1 > 0);
+
+    for delta in [
+        ScrollDelta::LineDelta(1.0, 1.0),
+        ScrollDelta::PixelDelta(dist),
+    ] {
+        let mut region = ScrollRegion::new(Block::new(Size(400, 400)));
+        harness.init(&mut region, Rect::new(Coord::ZERO, Size(100, 100)));
+        let start = Offset(150, 150);
+        harness.with_mgr(|mgr| region.set_scroll_offset(mgr, start));
+
+        // Positive deltas move content right and down on both axes
+        let id = region.id();
+        harness.send(&mut region, id, Event::Scroll(delta));
+        assert_eq!(region.scroll_offset(), start - dist, "{delta:?}");
+    }
+}
+
+#[test]
+fn scroll_region_content_size() {
+    let mut harness = Harness::new();
+    let block = Block::new_flexible(Size(50, 50), Size(100, 100));
+    let mut region = ScrollRegion::new(block);
+
+    for (viewport, content) in [(40, 50), (80, 80), (150, 100)] {
+        let rect = Rect::new(Coord::ZERO, Size::splat(viewport));
+        harness.init(&mut region, rect);
+        let size = region.content_size();
+        assert_eq!(size, Size::splat(content), "viewport = {viewport}");
+    }
+}
+
+#[test]
+fn scroll_region_update_id() {
+    let mut harness = Harness::new();
+    let update_id = UpdateId::new();
+    let block = Block::new(Size(400, 400));
+    let mut region = ScrollRegion::new(block).with_update_id(update_id);
+    let mut cache = harness.init(&mut region, Rect::new(Coord::ZERO, Size(100, 100)));
+    let offsets = |harness: &mut Harness| {
+        let updates = std::mem::take(&mut harness.shell.updates);
+        let iter = updates.into_iter().map(|(id, payload)| {
+            assert_eq!(id, update_id);
+            ScrollRegion::<Block>::offset_from_payload(payload)
+        });
+        iter.collect::<Vec<_>>()
+    };
+
+    harness.with_mgr(|mgr| region.set_scroll_offset(mgr, Offset(300, 250)));
+    assert_eq!(offsets(&mut harness), vec![Offset(300, 250)]);
+    // Unchanged offsets are not reported
+    harness.with_mgr(|mgr| region.set_scroll_offset(mgr, Offset(300, 250)));
+    assert!(offsets(&mut harness).is_empty());
+
+    // Resizing clamps the offset; notification is deferred via a timer
+    let rect = Rect::new(Coord::ZERO, Size(200, 200));
+    harness.set_rect(&mut region, &mut cache, rect);
+    assert_eq!(region.scroll_offset(), Offset(200, 200));
+    assert!(harness.ev.next_resume().is_some());
+    assert!(offsets(&mut harness).is_empty());
+    harness.update_timers(&mut region);
+    assert_eq!(offsets(&mut harness), vec![Offset(200, 200)]);
+}
+
+// A 3×3 grid of 20×20 cells, with cell index 3 * row + col, in a 40×40 view
+// with one frozen row and column, scrolled by (10, 10)
+fn frozen_grid() -> (Harness, FrozenGrid<Block>) {
+    let mut grid = Grid::new();
+    for row in 0..3 {
+        for col in 0..3 {
+            grid.push_cell(col, row, Block::new(Size(20, 20)));
+        }
+    }
+    let mut frozen = FrozenGrid::new(grid)
+        .with_frozen_rows(1)
+        .with_frozen_cols(1);
+    let mut harness = Harness::new();
+    harness.init(&mut frozen, Rect::new(Coord::ZERO, Size(40, 40)));
+    let offset = harness.with_mgr(|mgr| frozen.set_scroll_offset(mgr, Offset(10, 10)));
+    assert_eq!(offset, Offset(10, 10));
+    (harness, frozen)
+}
+
+#[test]
+fn frozen_grid_find_id() {
+    let (_, mut frozen) = frozen_grid();
+    let cell = |frozen: &FrozenGrid<Block>, index| frozen.inner().get(index).unwrap().id();
+    let cases = [
+        (Coord(5, 5), 0),   // corner
+        (Coord(25, 5), 1),  // frozen row: (35, 5)
+        (Coord(5, 25), 3),  // frozen column: (5, 35)
+        (Coord(25, 25), 4), // body: (35, 35)
+        (Coord(35, 35), 8), // body: (45, 45)
+    ];
+    for (coord, index) in cases {
+        let expected = cell(&frozen, index);
+        assert_eq!(frozen.find_id(coord), Some(expected), "coord = {coord:?}");
+    }
+}
+
+#[test]
+fn frozen_grid_draw() {
+    let (mut harness, mut frozen) = frozen_grid();
+    let ops = harness.draw(&mut frozen);
+    let cases = [
+        (0, Coord(0, 0)),
+        (1, Coord(10, 0)),
+        (3, Coord(0, 10)),
+        (4, Coord(10, 10)),
+        (8, Coord(30, 30)),
+    ];
+    for (index, pos) in cases {
+        let id = frozen.inner().get(index).unwrap().id();
+        let record = find_frame(&ops, &id).unwrap();
+        let rect = match record.op {
+            Op::Frame(_, rect) => rect,
+            _ => unreachable!(),
+        };
+        // Each cell is drawn once, at its visible position
+        let count = ops
+            .iter()
+            .filter(|r| matches!(&r.op, Op::Frame(i, _) if *i == id));
+        assert_eq!(count.count(), 1);
+        assert_eq!(rect.pos - record.offset, pos, "index = {index}");
+    }
+}
+
+// Block of 50×400 within a 100×100 region: only a vertical bar is required
+fn scroll_bars(overlay: bool) -> (Harness, ScrollBars<ScrollRegion<Block>>) {
+    let mut harness = Harness::new();
+    let inner = ScrollRegion::new(Block::new(Size(50, 400)));
+    let mut bars = ScrollBars::new(inner).with_overlay(overlay);
+    harness.init(&mut bars, Rect::new(Coord::ZERO, Size(100, 100)));
+    (harness, bars)
+}
+
+#[test]
+fn overlay_bars_reserve_no_space() {
+    let (harness, mut bars) = scroll_bars(false);
+    let rules = bars.size_rules(harness.size_mgr(), AxisInfo::new(false, None));
+    assert_eq!(rules.ideal_size(), 50 + 8);
+    assert_eq!(bars.inner().rect().size, Size(92, 100));
+
+    let (harness, mut bars) = scroll_bars(true);
+    let rules = bars.size_rules(harness.size_mgr(), AxisInfo::new(false, None));
+    assert_eq!(rules.ideal_size(), 50);
+    assert_eq!(bars.inner().rect().size, Size(100, 100));
+}
+
+#[test]
+fn overlay_bars_hidden_until_activity() {
+    // Over the vertical bar
+    let coord = Coord(96, 50);
+    let in_bar = |bars: &mut ScrollBars<ScrollRegion<Block>>| {
+        let id = bars.find_id(coord).unwrap();
+        !bars.inner().id_ref().is_ancestor_of(&id)
+    };
+
+    let (mut harness, mut bars) = scroll_bars(false);
+    assert!(in_bar(&mut bars));
+
+    // Hidden overlay bars are skipped
+    let (mut overlay_harness, mut overlay) = scroll_bars(true);
+    assert!(!in_bar(&mut overlay));
+    overlay_harness.with_mgr(|mgr| overlay.set_scroll_offset(mgr, Offset(0, 50)));
+    assert!(in_bar(&mut overlay));
+
+    // Non-overlay bars are unaffected by activity and the timer
+    harness.with_mgr(|mgr| bars.set_scroll_offset(mgr, Offset(0, 50)));
+    harness.update_timers(&mut bars);
+    assert!(in_bar(&mut bars));
+}
+
+#[test]
+fn overlay_bars_hide_after_delay() {
+    // Equal to OVERLAY_HIDE_DELAY
+    let delay = Duration::from_millis(1000);
+
+    let (mut harness, mut bars) = scroll_bars(true);
+    let coord = Coord(96, 50);
+    harness.with_mgr(|mgr| bars.set_scroll_offset(mgr, Offset(0, 50)));
+    let bar_id = bars.find_id(coord).unwrap();
+    assert!(!bars.inner().id_ref().is_ancestor_of(&bar_id));
+
+    // The timer is not yet due
+    harness.update_timers(&mut bars);
+    assert_eq!(bars.find_id(coord), Some(bar_id));
+
+    std::thread::sleep(delay);
+    harness.update_timers(&mut bars);
+    let id = bars.find_id(coord).unwrap();
+    assert!(bars.inner().id_ref().is_ancestor_of(&id));
+}
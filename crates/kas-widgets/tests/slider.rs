@@ -0,0 +1,95 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE-APACHE file or at:
+//     https://www.apache.org/licenses/LICENSE-2.0
+
+mod common;
+
+use common::*;
+use kas::dir::Right;
+use kas::event::{Command, MouseButton, PressSource};
+use kas::prelude::*;
+use kas_widgets::Slider;
+
+// The handle is 12 wide, thus its offset matches the value
+fn slider() -> (Harness, Slider<i32, Direction>) {
+    let mut slider = Slider::new_with_direction(0..=100, 1, Direction::Right).with_ticks(10);
+    let mut harness = Harness::new();
+    harness.init(&mut slider, Rect::new(Coord::ZERO, Size(112, 12)));
+    (harness, slider)
+}
+
+#[test]
+fn values_snap_to_ticks() {
+    let slider: Slider<i32, Right> = Slider::new(0..=100, 1).with_value(34);
+    assert_eq!(slider.value(), 34);
+    let mut slider = slider.with_ticks(10);
+    assert_eq!(slider.value(), 30);
+    let _ = slider.set_value(36);
+    assert_eq!(slider.value(), 40);
+
+    // The last tick may be below the end of the range
+    let mut slider: Slider<i32, Right> = Slider::new(0..=95, 1).with_ticks(10);
+    let _ = slider.set_value(96);
+    assert_eq!(slider.value(), 90);
+}
+
+#[test]
+fn keys_move_by_tick() {
+    let (mut harness, mut slider) = slider();
+    let id = slider.id();
+    harness.send(&mut slider, id.clone(), Event::Command(Command::Right));
+    assert_eq!(slider.value(), 10);
+    harness.send(&mut slider, id, Event::Command(Command::PageUp));
+    assert_eq!(slider.value(), 100);
+}
+
+#[test]
+fn drag_snaps_on_release() {
+    let (mut harness, mut slider) = slider();
+    let id = slider.id();
+    let handle = slider.get_child(0).unwrap().id();
+    let source = PressSource::Mouse(MouseButton::Left, 1);
+    let coord = Coord(44, 6);
+    let start_id = Some(id.clone());
+    harness.send(
+        &mut slider,
+        id,
+        Event::PressStart {
+            source,
+            start_id,
+            coord,
+        },
+    );
+    assert_eq!(slider.value(), 38);
+
+    let end_id = Some(handle.clone());
+    let event = Event::PressEnd {
+        source,
+        end_id,
+        coord,
+        success: true,
+    };
+    harness.send(&mut slider, handle, event);
+    assert_eq!(slider.value(), 40);
+}
+
+#[test]
+fn ticks_are_drawn() {
+    let (mut harness, mut slider) = slider();
+    let ticks: Vec<_> = harness
+        .draw(&mut slider)
+        .into_iter()
+        .filter_map(|record| match record.op {
+            Op::Separator(rect) => Some(rect),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(ticks.len(), 11);
+    // Ticks are centred on the handle's possible positions
+    let x: Vec<_> = ticks
+        .iter()
+        .map(|rect| rect.pos.0 + rect.size.0 / 2)
+        .collect();
+    assert_eq!(x, (0..=10).map(|i| 6 + 10 * i).collect::<Vec<_>>());
+}
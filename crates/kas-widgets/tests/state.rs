@@ -0,0 +1,254 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE-APACHE file or at:
+//     https://www.apache.org/licenses/LICENSE-2.0
+
+mod common;
+
+use common::*;
+use kas::dir::Down;
+use kas::prelude::*;
+use kas::state::{SaveState, SavedState, StateValue};
+use kas::updatable::MatrixData;
+use kas_widgets::view::{Driver, ListView, MatrixView, SelectionMode};
+use kas_widgets::{Column, RowSplitter, ScrollRegion, Scrollable, Stack};
+
+fn region() -> ScrollRegion<Block> {
+    ScrollRegion::new(Block::new(Size(400, 400)))
+}
+
+fn rect(w: i32, h: i32) -> Rect {
+    Rect::new(Coord::ZERO, Size(w, h))
+}
+
+#[test]
+fn scroll_region_state() {
+    let mut harness = Harness::new();
+    let mut a = region();
+    harness.init(&mut a, rect(100, 100));
+    harness.with_mgr(|mgr| a.set_scroll_offset(mgr, Offset(50, 60)));
+    let state = a.save_state().unwrap();
+    assert_eq!(state, StateValue::Offset(50, 60));
+
+    let mut b = region();
+    harness.init(&mut b, rect(100, 100));
+    harness.with_set_rect_mgr(|mgr| b.load_state(mgr, &state));
+    assert_eq!(b.scroll_offset(), Offset(50, 60));
+
+    // Out-of-range offsets are clamped; values of another kind are ignored
+    let state = StateValue::Offset(1000, 1000);
+    harness.with_set_rect_mgr(|mgr| b.load_state(mgr, &state));
+    assert_eq!(b.scroll_offset(), Offset(300, 300));
+    harness.with_set_rect_mgr(|mgr| b.load_state(mgr, &StateValue::Index(0)));
+    assert_eq!(b.scroll_offset(), Offset(300, 300));
+}
+
+#[test]
+fn stack_state() {
+    let pages = || (0..3).map(|_| Block::new(Size(10, 10))).collect();
+    let mut harness = Harness::new();
+    let mut a = Stack::new_vec(pages());
+    harness.init(&mut a, rect(10, 10));
+    harness.with_set_rect_mgr(|mgr| a.set_active(mgr, 2));
+    assert_eq!(a.save_state(), Some(StateValue::Index(2)));
+
+    let mut b = Stack::new_vec(pages());
+    harness.init(&mut b, rect(10, 10));
+    harness.with_set_rect_mgr(|mgr| b.load_state(mgr, &StateValue::Index(1)));
+    assert_eq!(b.active(), 1);
+
+    // Indices out of range are ignored
+    harness.with_set_rect_mgr(|mgr| b.load_state(mgr, &StateValue::Index(3)));
+    assert_eq!(b.active(), 1);
+}
+
+#[test]
+fn splitter_state() {
+    let block = || Block::new_flexible(Size(10, 10), Size(50, 10));
+    let mut harness = Harness::new();
+    let mut splitter = RowSplitter::new(vec![block(), block()]);
+    harness.init(&mut splitter, rect(120, 10));
+
+    let state = StateValue::Offsets(vec![(20, 0)]);
+    harness.with_set_rect_mgr(|mgr| splitter.load_state(mgr, &state));
+    assert_eq!(splitter.save_state(), Some(state.clone()));
+
+    // State saved with a different number of handles is ignored
+    let other = StateValue::Offsets(vec![(5, 0), (10, 0)]);
+    harness.with_set_rect_mgr(|mgr| splitter.load_state(mgr, &other));
+    assert_eq!(splitter.save_state(), Some(state));
+}
+
+#[test]
+fn saved_state_tree() {
+    let column = || Column::new_vec(vec![region(), region()]);
+    let mut harness = Harness::new();
+    let mut a = column();
+    harness.init(&mut a, rect(100, 200));
+    harness.with_mgr(|mgr| a[0].set_scroll_offset(mgr, Offset(10, 20)));
+    harness.with_mgr(|mgr| a[1].set_scroll_offset(mgr, Offset(30, 40)));
+
+    let mut state = SavedState::save(&mut a);
+    assert_eq!(state.0.len(), 2);
+    assert_eq!(
+        state.0.get(&SavedState::key(a[1].id_ref())),
+        Some(&StateValue::Offset(30, 40))
+    );
+
+    // Keys are stable across identically constructed trees. Unknown keys and
+    // values of an unexpected kind are ignored.
+    state.0.insert("#unknown".to_string(), StateValue::Index(1));
+    let key = SavedState::key(a[0].id_ref());
+    state.0.insert(key, StateValue::Index(1));
+    let mut b = column();
+    harness.init(&mut b, rect(100, 200));
+    harness.with_set_rect_mgr(|mgr| state.load(mgr, &mut b));
+    assert_eq!(b[0].scroll_offset(), Offset::ZERO);
+    assert_eq!(b[1].scroll_offset(), Offset(30, 40));
+}
+
+#[test]
+fn list_selection_state() {
+    let blocks = || (0..4).map(|_| Block::new(Size(10, 10))).collect();
+    let list = || Column::new_vec(blocks()).with_selection_mode(SelectionMode::Multiple);
+    let mut harness = Harness::new();
+    let mut a = list();
+    harness.init(&mut a, rect(10, 40));
+    let _ = a.select(3).unwrap();
+    let _ = a.select(1).unwrap();
+    let state = a.save_state().unwrap();
+    assert_eq!(state, StateValue::Keys(vec![vec![1], vec![3]]));
+
+    let mut b = list();
+    harness.init(&mut b, rect(10, 40));
+    harness.with_set_rect_mgr(|mgr| b.load_state(mgr, &state));
+    assert_eq!(b.selected_iter().collect::<Vec<_>>(), vec![1, 3]);
+
+    // Unknown keys are ignored; single selection mode keeps only one item
+    let state = StateValue::Keys(vec![vec![2], vec![9], vec![0, 1]]);
+    harness.with_set_rect_mgr(|mgr| b.load_state(mgr, &state));
+    assert_eq!(b.selected_iter().collect::<Vec<_>>(), vec![2]);
+    let _ = b.set_selection_mode(SelectionMode::Single);
+    let state = StateValue::Keys(vec![vec![0], vec![3]]);
+    harness.with_set_rect_mgr(|mgr| b.load_state(mgr, &state));
+    assert_eq!(b.selected_iter().collect::<Vec<_>>(), vec![0]);
+
+    // Nothing is saved (or loaded) while selection is disabled
+    let mut c = Column::new_vec(blocks());
+    harness.init(&mut c, rect(10, 40));
+    assert_eq!(c.save_state(), None);
+    harness.with_set_rect_mgr(|mgr| c.load_state(mgr, &state));
+    assert_eq!(c.selected_iter().count(), 0);
+}
+
+#[derive(Clone, Debug, Default)]
+struct BlockDriver;
+
+impl<T> Driver<T> for BlockDriver {
+    type Widget = Block;
+
+    fn make(&self) -> Block {
+        Block::new(Size(10, 10))
+    }
+
+    fn set(&self, _: &mut Block, _: T) -> TkAction {
+        TkAction::empty()
+    }
+}
+
+#[test]
+fn list_view_selection_state() {
+    let view = || {
+        ListView::<Down, _, _>::new_with_driver(BlockDriver, vec![0u32; 6])
+            .with_selection_mode(SelectionMode::Multiple)
+    };
+    let mut harness = Harness::new();
+    let mut a = view();
+    harness.init(&mut a, rect(10, 30));
+    let _ = a.select(4).unwrap();
+    let _ = a.select(2).unwrap();
+    let state = a.save_state().unwrap();
+    assert_eq!(state, StateValue::Keys(vec![vec![2], vec![4]]));
+
+    // Keys out of range of the data are ignored
+    let mut b = view();
+    harness.init(&mut b, rect(10, 30));
+    let state = StateValue::Keys(vec![vec![4], vec![6], vec![2]]);
+    harness.with_set_rect_mgr(|mgr| b.load_state(mgr, &state));
+    let mut selected: Vec<usize> = b.selected_iter().cloned().collect();
+    selected.sort_unstable();
+    assert_eq!(selected, vec![2, 4]);
+}
+
+#[derive(Debug)]
+struct Table(usize);
+
+impl MatrixData for Table {
+    type ColKey = usize;
+    type RowKey = usize;
+    type Key = (usize, usize);
+    type Item = usize;
+
+    fn version(&self) -> u64 {
+        1
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+    fn len(&self) -> (usize, usize) {
+        (self.0, self.0)
+    }
+
+    fn make_id(&self, parent: &WidgetId, key: &Self::Key) -> WidgetId {
+        parent.make_child(key.0).make_child(key.1)
+    }
+    fn reconstruct_key(&self, parent: &WidgetId, child: &WidgetId) -> Option<Self::Key> {
+        let mut iter = child.iter_keys_after(parent);
+        iter.next().zip(iter.next())
+    }
+
+    fn contains(&self, key: &Self::Key) -> bool {
+        key.0 < self.0 && key.1 < self.0
+    }
+    fn get_cloned(&self, key: &Self::Key) -> Option<Self::Item> {
+        self.contains(key).then(|| key.0 * key.1)
+    }
+
+    fn update(&self, _: &mut EventMgr, _: &Self::Key, _: Self::Item) {}
+
+    fn col_iter_vec_from(&self, start: usize, limit: usize) -> Vec<Self::ColKey> {
+        (start..self.0.min(start + limit)).collect()
+    }
+    fn row_iter_vec_from(&self, start: usize, limit: usize) -> Vec<Self::RowKey> {
+        (start..self.0.min(start + limit)).collect()
+    }
+
+    fn make_key(col: &Self::ColKey, row: &Self::RowKey) -> Self::Key {
+        (*col, *row)
+    }
+}
+
+#[test]
+fn matrix_view_selection_state() {
+    let view = || {
+        MatrixView::new_with_driver(BlockDriver, Table(3))
+            .with_selection_mode(SelectionMode::Single)
+    };
+    let mut harness = Harness::new();
+    let mut a = view();
+    harness.init(&mut a, rect(30, 30));
+    let _ = a.select((2, 1)).unwrap();
+    let state = a.save_state().unwrap();
+    assert_eq!(state, StateValue::Keys(vec![vec![2, 1]]));
+
+    let mut b = view();
+    harness.init(&mut b, rect(30, 30));
+    harness.with_set_rect_mgr(|mgr| b.load_state(mgr, &state));
+    assert_eq!(b.selected_iter().collect::<Vec<_>>(), vec![&(2, 1)]);
+
+    // Keys out of range of the data are ignored
+    let state = StateValue::Keys(vec![vec![3, 0], vec![0, 2]]);
+    harness.with_set_rect_mgr(|mgr| b.load_state(mgr, &state));
+    assert_eq!(b.selected_iter().collect::<Vec<_>>(), vec![&(0, 2)]);
+}
@@ -0,0 +1,77 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE-APACHE file or at:
+//     https://www.apache.org/licenses/LICENSE-2.0
+
+mod common;
+
+use common::*;
+use kas::layout::{SizeTarget, SolveCache};
+use kas::prelude::*;
+use kas_widgets::view::{Driver, ListView};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+// Log of (is_vertical, other-axis size, target) for each call to size_rules
+type Log = Rc<RefCell<Vec<(bool, Option<i32>, SizeTarget)>>>;
+
+impl_scope! {
+    /// A fixed-size widget logging its size_rules calls
+    #[derive(Clone, Debug)]
+    #[widget]
+    struct Probe {
+        core: widget_core!(),
+        log: Log,
+    }
+
+    impl Layout for Self {
+        fn size_rules(&mut self, _: SizeMgr, axis: AxisInfo) -> SizeRules {
+            let entry = (axis.is_vertical(), axis.other(), axis.target());
+            self.log.borrow_mut().push(entry);
+            SizeRules::extract_fixed(axis, Size(20, 10), Margins::ZERO)
+        }
+
+        fn draw(&mut self, _: DrawMgr) {}
+    }
+}
+
+#[derive(Debug)]
+struct ProbeDriver(Log);
+
+impl Driver<u32> for ProbeDriver {
+    type Widget = Probe;
+
+    fn make(&self) -> Probe {
+        Probe {
+            core: Default::default(),
+            log: self.0.clone(),
+        }
+    }
+
+    fn set(&self, _: &mut Probe, _: u32) -> TkAction {
+        TkAction::empty()
+    }
+}
+
+#[test]
+fn list_view_passes_size_target() {
+    let log = Log::default();
+    let driver = ProbeDriver(log.clone());
+    let mut view = ListView::new_with_dir_driver(Direction::Down, driver, vec![1u32, 2, 3]);
+    let mut h = Harness::new();
+    let _ = h.init(&mut view, Rect::new(Coord::ZERO, Size(100, 100)));
+
+    // Child widgets now exist, thus these are also measured
+    log.borrow_mut().clear();
+    let _ = SolveCache::find_constraints(&mut view, h.size_mgr());
+
+    let log = log.borrow();
+    let children = log.iter().filter(|entry| entry.1.is_some()).count();
+    assert!(children >= 3, "children not measured: {:?}", log);
+    for (vertical, _, target) in log.iter() {
+        match vertical {
+            false => assert_eq!(*target, SizeTarget::Both),
+            true => assert_ne!(*target, SizeTarget::Both),
+        }
+    }
+}